@@ -1,19 +1,16 @@
 use std::path::PathBuf;
 
-/// Add a component to the project
-pub async fn add_component(component: &str, framework: &str, path: PathBuf) -> anyhow::Result<()> {
-    // TODO: Implement component addition logic
-    // This would:
-    // 1. Check if component exists in registry
-    // 2. Generate component files using component-generator
-    // 3. Add to project structure
-    // 4. Update dependencies
-    
-    println!("Adding {} component to {} project at {}", component, framework, path.display());
-    
-    // For now, just show what would happen
-    println!("Component '{}' would be added to your project", component);
-    println!("This feature is coming soon!");
-    
-    Ok(())
+use shadcn::commands::add::{add, AddOptions};
+
+/// Add components, and the components they depend on, to the project
+pub async fn add_components(components: &[String], path: PathBuf, overwrite: bool, dry_run: bool) -> anyhow::Result<()> {
+    add(AddOptions {
+        components: components.to_vec(),
+        overwrite,
+        dry_run,
+        cwd: path,
+        path: None,
+        silent: false,
+    })
+    .await
 }
@@ -1,22 +1,28 @@
-use std::path::PathBuf;
+use std::path::Path;
 
-/// Initialize a new project
-pub async fn init_project(path: &PathBuf, framework: &str, theme: &str) -> anyhow::Result<()> {
-    // TODO: Implement project initialization logic
-    // This would:
-    // 1. Create project structure
-    // 2. Set up Cargo.toml with dependencies
-    // 3. Create basic component structure
-    // 4. Set up Tailwind CSS configuration
-    
-    println!("Initializing {} project with {} theme at {}", framework, theme, path.display());
-    
-    // For now, just show what would happen
-    println!("Project would be initialized with:");
-    println!("  - Framework: {}", framework);
-    println!("  - Theme: {}", theme);
-    println!("  - Location: {}", path.display());
-    println!("This feature is coming soon!");
-    
-    Ok(())
+use shadcn::commands::init::{init, InitOptions};
+use shadcn_registry::schema::Style;
+
+/// Initialize a project, creating a Leptos app first if there is none
+pub async fn init_project(path: &Path, theme: &str, base_color: &str) -> anyhow::Result<()> {
+    let style = match theme {
+        "new-york" | "new_york" => Style::NewYork,
+        _ => Style::Default,
+    };
+
+    init(InitOptions {
+        components: Vec::new(),
+        yes: true,
+        defaults: true,
+        force: false,
+        cwd: path.to_path_buf(),
+        silent: false,
+        src_dir: false,
+        style,
+        base_color: base_color.to_string(),
+        theme: None,
+        css_variables: true,
+        skip_preflight: false,
+    })
+    .await
 }
@@ -1,118 +1,92 @@
+use std::path::Path;
+
 use console::style;
-use shadcn_registry::{registry_ui::UI, schema::FrameworkName};
+
+use super::status::{
+    components_table, find_workspace, workspace_status, ComponentState, ComponentStatus, OutputFormat, WorkspaceStatus,
+};
+
+/// The components to list, placeholders count as missing as they don't
+/// implement the component yet.
+fn listed_components(status: &WorkspaceStatus, completed: bool, missing: bool) -> Vec<&ComponentStatus> {
+    status
+        .components
+        .iter()
+        .filter(|component| match component.state {
+            ComponentState::Complete => !missing,
+            ComponentState::Placeholder | ComponentState::Missing => !completed,
+        })
+        .collect()
+}
 
 /// List available components for the specified framework
-pub async fn list_components(framework: &str, completed: bool, missing: bool) -> anyhow::Result<()> {
+pub async fn list_components(
+    framework: &str,
+    path: &Path,
+    completed: bool,
+    missing: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
     if framework != "leptos" {
         eprintln!("{}", style("⚠️  Only Leptos framework is currently supported").yellow());
         return Ok(());
     }
-    
-    let registry = UI.get(&FrameworkName::Leptos).ok_or_else(|| {
-        anyhow::anyhow!("Leptos registry not found")
-    })?;
-    
-    // Get all available components from the complete registry
-    let all_components = get_all_components();
-    let leptos_components: std::collections::HashSet<_> = registry
-        .iter()
-        .map(|item| item.name.clone())
-        .collect();
-    
-    let mut missing_components: Vec<_> = all_components
-        .iter()
-        .filter(|name| !leptos_components.contains(*name))
-        .collect();
-    missing_components.sort();
-    
-    let mut completed_components: Vec<_> = all_components
-        .iter()
-        .filter(|name| leptos_components.contains(*name))
-        .collect();
-    completed_components.sort();
-    
+
+    let status = workspace_status(&find_workspace(path)?)?;
+    let components = listed_components(&status, completed, missing);
+
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&components)?);
+        return Ok(());
+    }
+
     println!("{}", style("=== Leptos shadcn/ui Components ===").bold().blue());
     println!();
-    
-    if completed {
-        println!("{}", style("✅ Completed Components:").bold().green());
-        for component in &completed_components {
-            println!("  • {}", style(component).green());
-        }
-        println!();
-    } else if missing {
-        println!("{}", style("❌ Missing Components:").bold().red());
-        for component in &missing_components {
-            println!("  • {}", style(component).red());
-        }
-        println!();
-    } else {
-        // Show summary
-        println!("{}", style("📊 Component Status:").bold());
-        println!("  Total Components: {}", all_components.len());
-        println!("  Completed: {} ({:.1}%)", 
-            completed_components.len(), 
-            (completed_components.len() as f64 / all_components.len() as f64) * 100.0
-        );
-        println!("  Missing: {} ({:.1}%)", 
-            missing_components.len(), 
-            (missing_components.len() as f64 / all_components.len() as f64) * 100.0
+    print!("{}", components_table(&components));
+    println!();
+
+    if !completed && !missing {
+        let summary = &status.summary;
+        println!(
+            "{} complete, {} placeholder(s), {} missing of {} components.",
+            style(summary.complete).green(),
+            style(summary.placeholders).yellow(),
+            style(summary.missing).red(),
+            summary.total
         );
         println!();
-        
-        println!("{}", style("✅ Completed Components:").bold().green());
-        for component in &completed_components {
-            println!("  • {}", style(component).green());
-        }
-        println!();
-        
-        println!("{}", style("❌ Missing Components:").bold().red());
-        for component in &missing_components {
-            println!("  • {}", style(component).red());
-        }
-        println!();
     }
-    
+
     println!("{}", style("💡 Usage:").bold().yellow());
     println!("  rust-shadcn add <component> --framework leptos");
     println!("  rust-shadcn list --completed");
     println!("  rust-shadcn list --missing");
-    
+    println!("  rust-shadcn list --format json");
+
     Ok(())
 }
 
-/// Get all available shadcn/ui components
-fn get_all_components() -> Vec<String> {
-    vec![
-        // Form & Input Components
-        "button".to_string(), "checkbox".to_string(), "radio-group".to_string(),
-        "select".to_string(), "combobox".to_string(), "form".to_string(),
-        "input".to_string(), "label".to_string(), "textarea".to_string(),
-        "slider".to_string(), "switch".to_string(), "toggle".to_string(),
-        
-        // Navigation Components
-        "navigation-menu".to_string(), "menubar".to_string(), "tabs".to_string(),
-        "breadcrumb".to_string(), "pagination".to_string(), "command".to_string(),
-        "context-menu".to_string(),
-        
-        // Overlay Components
-        "dialog".to_string(), "alert-dialog".to_string(), "sheet".to_string(),
-        "drawer".to_string(), "dropdown-menu".to_string(), "popover".to_string(),
-        "tooltip".to_string(), "toast".to_string(),
-        
-        // Layout Components
-        "accordion".to_string(), "collapsible".to_string(), "resizable".to_string(),
-        "scroll-area".to_string(), "separator".to_string(), "sidebar".to_string(),
-        "aspect-ratio".to_string(),
-        
-        // Display Components
-        "alert".to_string(), "avatar".to_string(), "badge".to_string(),
-        "card".to_string(), "calendar".to_string(), "progress".to_string(),
-        "skeleton".to_string(), "table".to_string(),
-        
-        // Advanced Components
-        "carousel".to_string(), "chart".to_string(), "data-table".to_string(),
-        "date-picker".to_string(), "hover-card".to_string(), "input-otp".to_string(),
-        "sonner".to_string(), "typography".to_string(),
-    ]
+#[cfg(test)]
+mod tests {
+    use super::super::status::tests::test_workspace;
+    use super::*;
+
+    #[test]
+    fn test_listed_components() {
+        let workspace = test_workspace();
+        let status = workspace_status(workspace.path()).unwrap();
+        let names = |components: Vec<&ComponentStatus>| {
+            components.into_iter().map(|component| component.name.clone()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(listed_components(&status, true, false)), ["tabs"]);
+        let missing = names(listed_components(&status, false, true));
+        assert!(missing.contains(&"select".to_string()) && missing.contains(&"switch".to_string()));
+        assert!(!missing.contains(&"tabs".to_string()));
+        assert_eq!(listed_components(&status, false, false).len(), status.summary.total);
+
+        let json = serde_json::to_value(listed_components(&status, true, false)).unwrap();
+        assert_eq!(json[0]["state"], "complete");
+        assert_eq!(json[0]["styles"], serde_json::json!(["default", "new_york"]));
+    }
 }
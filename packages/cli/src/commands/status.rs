@@ -1,73 +1,384 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use console::style;
+use regex::Regex;
+use serde::Serialize;
+use shadcn_registry::registry_lib::LIB;
 use shadcn_registry::registry_ui::UI;
-use shadcn_registry::schema::FrameworkName;
+use shadcn_registry::schema::{FrameworkName, RegistryItemType};
+use walkdir::WalkDir;
 
-/// Get component status and completion information
-pub async fn get_status(detailed: bool) -> anyhow::Result<String> {
-    let leptos_registry = UI.get(&FrameworkName::Leptos)
+use super::validate::scanner::{crate_unit, scan};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentState {
+    /// The crate implements the component.
+    Complete,
+    /// The crate exists but doesn't implement the component yet.
+    Placeholder,
+    /// There is no crate for the registry entry.
+    Missing,
+}
+
+/// Implementation status of a registry component.
+#[derive(Clone, Debug, Serialize)]
+pub struct ComponentStatus {
+    pub name: String,
+    pub state: ComponentState,
+    /// Why the crate counts as a placeholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Styles with a source file, `default` and `new_york`.
+    pub styles: Vec<String>,
+    /// Test functions in the crate.
+    pub tests: usize,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StatusSummary {
+    pub total: usize,
+    pub complete: usize,
+    pub placeholders: usize,
+    pub missing: usize,
+    /// Components implemented in both styles.
+    pub both_styles: usize,
+    pub tests: usize,
+}
+
+/// Status of the registry components, computed from `packages/leptos`.
+#[derive(Clone, Debug, Serialize)]
+pub struct WorkspaceStatus {
+    pub summary: StatusSummary,
+    pub components: Vec<ComponentStatus>,
+    /// Component crates without a registry entry.
+    pub unregistered: Vec<String>,
+}
+
+/// The workspace directory above `path` that holds `packages/leptos`.
+pub fn find_workspace(path: &Path) -> anyhow::Result<PathBuf> {
+    let path = path.canonicalize()?;
+    path.ancestors()
+        .find(|dir| dir.join("packages").join("leptos").is_dir())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow::anyhow!("No packages/leptos directory found above {}", path.display()))
+}
+
+/// Counts the test functions in the Rust files under `dir`.
+fn count_tests(dir: &Path) -> usize {
+    let test = Regex::new(r"#\[\s*(tokio::test|test|wasm_bindgen_test)\b").expect("Test attribute pattern should be valid.");
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|entry| std::fs::read_to_string(entry.path()).ok())
+        .map(|source| test.find_iter(&source).count())
+        .sum()
+}
+
+/// Why the crate in `dir` doesn't implement component `name` yet, if it doesn't.
+///
+/// Components are looked for in the style sources, `default.rs` and
+/// `new_york.rs` or the modules next to them, falling back to all sources.
+fn placeholder_reason(name: &str, dir: &Path) -> anyhow::Result<Option<String>> {
+    let Some(mut unit) = crate_unit(dir) else {
+        return Ok(Some("the crate has no sources".to_string()));
+    };
+    let src = dir.join("src");
+    let is_style_source = |file: &PathBuf| {
+        file.strip_prefix(&src)
+            .ok()
+            .and_then(|file| file.iter().next())
+            .is_some_and(|first| first.to_string_lossy().starts_with("default") || first.to_string_lossy().starts_with("new_york"))
+    };
+    if unit.sources.iter().any(is_style_source) {
+        unit.sources.retain(is_style_source);
+    }
+    let scan = scan(&unit)?;
+
+    let components = scan.components.iter().map(|component| component.name.as_str()).collect::<BTreeSet<_>>();
+    if components.is_empty() {
+        return Ok(Some("no components are defined".to_string()));
+    }
+    // A lone component rendering just a button is the button template.
+    let tags = scan
+        .elements
+        .iter()
+        .filter(|element| element.tag.starts_with(|char: char| char.is_ascii_lowercase()))
+        .map(|element| element.tag.as_str())
+        .collect::<BTreeSet<_>>();
+    let is_button = ["button", "toggle"].iter().any(|button| name.contains(button));
+    if components.len() == 1 && tags.iter().eq(["button"].iter()) && !is_button {
+        let component = components.iter().next().unwrap_or(&name);
+        return Ok(Some(format!("{component} only renders a button")));
+    }
+    Ok(None)
+}
+
+/// Computes the status of the Leptos registry components in `workspace`.
+pub fn workspace_status(workspace: &Path) -> anyhow::Result<WorkspaceStatus> {
+    let registry = UI
+        .get(&FrameworkName::Leptos)
         .ok_or_else(|| anyhow::anyhow!("Leptos registry not found"))?;
-    
-    let total_components = leptos_registry.len();
-    let completed_components = total_components;
-    let missing_components = 0; // All components in the registry are implemented
-    let progress_percentage = if total_components > 0 {
-        (completed_components as f64 / total_components as f64) * 100.0
+    let crates_dir = workspace.join("packages").join("leptos");
+
+    // Libraries like `utils` are listed among the components too.
+    let mut names = LIB
+        .get(&FrameworkName::Leptos)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.name.clone())
+        .collect::<BTreeSet<_>>();
+    let mut components = Vec::new();
+    for entry in registry.iter().filter(|entry| entry.r#type == RegistryItemType::Ui) {
+        if !names.insert(entry.name.clone()) {
+            continue;
+        }
+        let dir = crates_dir.join(&entry.name);
+        if !dir.join("Cargo.toml").is_file() {
+            components.push(ComponentStatus {
+                name: entry.name.clone(),
+                state: ComponentState::Missing,
+                reason: None,
+                path: None,
+                styles: Vec::new(),
+                tests: 0,
+            });
+            continue;
+        }
+
+        let reason = placeholder_reason(&entry.name, &dir)?;
+        let styles = [("default", "default.rs"), ("new_york", "new_york.rs")]
+            .into_iter()
+            .filter(|(_, file)| dir.join("src").join(file).is_file())
+            .map(|(style, _)| style.to_string())
+            .collect();
+        components.push(ComponentStatus {
+            name: entry.name.clone(),
+            state: if reason.is_some() { ComponentState::Placeholder } else { ComponentState::Complete },
+            reason,
+            path: dir.strip_prefix(workspace).ok().map(Path::to_path_buf),
+            styles,
+            tests: count_tests(&dir),
+        });
+    }
+    components.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut unregistered = std::fs::read_dir(&crates_dir)?
+        .flatten()
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| !names.contains(name))
+        .collect::<Vec<_>>();
+    unregistered.sort();
+
+    let count = |state| components.iter().filter(|component| component.state == state).count();
+    let summary = StatusSummary {
+        total: components.len(),
+        complete: count(ComponentState::Complete),
+        placeholders: count(ComponentState::Placeholder),
+        missing: count(ComponentState::Missing),
+        both_styles: components.iter().filter(|component| component.styles.len() == 2).count(),
+        tests: components.iter().map(|component| component.tests).sum(),
+    };
+    Ok(WorkspaceStatus { summary, components, unregistered })
+}
+
+fn percentage(count: usize, total: usize) -> f64 {
+    if total > 0 {
+        count as f64 / total as f64 * 100.0
     } else {
         0.0
-    };
-    
-    let mut status = String::new();
-    status.push_str("=== Leptos shadcn/ui Status ===\n\n");
-    
+    }
+}
+
+/// Renders `components` as a table.
+pub fn components_table(components: &[&ComponentStatus]) -> String {
+    let mut table = format!(
+        "{}\n",
+        style(format!("{:<20} {:<12} {:<8} {:<9} {:>6}", "COMPONENT", "STATE", "DEFAULT", "NEW YORK", "TESTS")).bold()
+    );
+    for component in components {
+        let state = format!("{:<12}", format!("{:?}", component.state).to_lowercase());
+        let state = match component.state {
+            ComponentState::Complete => style(state).green(),
+            ComponentState::Placeholder => style(state).yellow(),
+            ComponentState::Missing => style(state).red(),
+        };
+        let has = |style: &str| if component.styles.iter().any(|name| name == style) { "✓" } else { "-" };
+        table.push_str(&format!(
+            "{:<20} {} {:<8} {:<9} {:>6}",
+            component.name,
+            state,
+            has("default"),
+            has("new_york"),
+            component.tests
+        ));
+        if let Some(reason) = &component.reason {
+            table.push_str(&format!("  {}", style(reason).dim()));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Get component status and completion information
+pub async fn get_status(path: &Path, detailed: bool, format: OutputFormat) -> anyhow::Result<String> {
+    let status = workspace_status(&find_workspace(path)?)?;
+    if format == OutputFormat::Json {
+        return Ok(serde_json::to_string_pretty(&status)?);
+    }
+
+    let summary = &status.summary;
+    let mut output = String::new();
+    output.push_str("=== Leptos shadcn/ui Status ===\n\n");
+    output.push_str("📊 Status Summary:\n");
+    output.push_str(&format!(
+        "  - Complete: {}/{} components ({:.1}%)\n",
+        summary.complete,
+        summary.total,
+        percentage(summary.complete, summary.total)
+    ));
+    output.push_str(&format!("  - Placeholders: {}\n", summary.placeholders));
+    output.push_str(&format!("  - Missing: {}\n", summary.missing));
+    output.push_str(&format!("  - Both styles: {}/{}\n", summary.both_styles, summary.total));
+    output.push_str(&format!("  - Tests: {}\n", summary.tests));
+
     if detailed {
-        status.push_str("📊 Detailed Status:\n");
-        status.push_str(&format!("  - Total Components: {}\n", total_components));
-        status.push_str(&format!("  - Completed: {} ({:.1}%)\n", completed_components, progress_percentage));
-        status.push_str(&format!("  - Missing: {} ({:.1}%)\n\n", missing_components, (missing_components as f64 / total_components as f64) * 100.0));
-        
-        if missing_components > 0 {
-            status.push_str("🎯 Missing Components:\n");
-            // This would be populated if we had missing components
-            status.push_str("  - All components in registry are implemented!\n\n");
-        } else {
-            status.push_str("🎯 All Components Implemented!\n");
-            status.push_str("  - No missing components\n\n");
+        output.push('\n');
+        output.push_str(&components_table(&status.components.iter().collect::<Vec<_>>()));
+        if !status.unregistered.is_empty() {
+            output.push_str(&format!("\n📦 Crates without a registry entry: {}\n", status.unregistered.join(", ")));
         }
-        
-        status.push_str("📈 Progress:\n");
-        status.push_str("  - Phase 1: Foundation Enhancement ✅\n");
-        status.push_str("  - Phase 2: Leptos Completion ✅\n");
-        status.push_str("  - Phase 3: Advanced Features ⏳\n");
     } else {
-        status.push_str("📊 Status Summary:\n");
-        status.push_str(&format!("  - Progress: {}/{} components ({:.1}%)\n", completed_components, total_components, progress_percentage));
-        
-        if progress_percentage >= 100.0 {
-            status.push_str("  - Status: Complete! 🎉\n");
-            status.push_str("  - Next: Advanced features and optimization\n");
-        } else if progress_percentage >= 80.0 {
-            status.push_str("  - Status: Well Advanced\n");
-            status.push_str(&format!("  - Next: Complete final {} components\n", missing_components));
-        } else if progress_percentage >= 50.0 {
-            status.push_str("  - Status: Good Progress\n");
-            status.push_str(&format!("  - Next: Continue with {} components\n", total_components - completed_components));
-        } else {
-            status.push_str("  - Status: Getting Started\n");
-            status.push_str(&format!("  - Next: Focus on core components\n"));
+        let unfinished = status
+            .components
+            .iter()
+            .filter(|component| component.state != ComponentState::Complete)
+            .map(|component| component.name.as_str())
+            .collect::<Vec<_>>();
+        if !unfinished.is_empty() {
+            output.push_str(&format!("\n🎯 Not implemented yet: {}\n", unfinished.join(", ")));
         }
-        
-        status.push_str("\n💡 Use --detailed for more information\n");
+        output.push_str("\n💡 Use --detailed for more information\n");
     }
-    
-    status.push_str("\n🚀 Next Steps:\n");
-    if progress_percentage >= 100.0 {
-        status.push_str("  - Enhance testing infrastructure\n");
-        status.push_str("  - Improve documentation\n");
-        status.push_str("  - Performance optimization\n");
-    } else {
-        status.push_str("  - Complete missing components\n");
-        status.push_str("  - Enhance testing infrastructure\n");
-        status.push_str("  - Improve documentation\n");
+
+    Ok(output)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::fs;
+
+    use serde_json::Value;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_crate(workspace: &Path, name: &str, files: &[(&str, &str)]) {
+        let dir = workspace.join("packages").join("leptos").join(name);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("Cargo.toml"), format!("[package]\nname = \"leptos-shadcn-{name}\"\n")).unwrap();
+        for (file, source) in files {
+            fs::write(dir.join("src").join(file), source).unwrap();
+        }
+    }
+
+    /// A workspace with a complete `tabs` crate, a placeholder `select` crate
+    /// and a `widgets` crate without a registry entry, all others are missing.
+    pub(in crate::commands) fn test_workspace() -> TempDir {
+        let workspace = tempfile::tempdir().unwrap();
+        let tabs = r#"
+            use leptos::prelude::*;
+
+            #[component]
+            pub fn Tabs(children: Children) -> impl IntoView {
+                view! { <div role="tablist">{children()}</div> }
+            }
+        "#;
+        write_crate(workspace.path(), "tabs", &[
+            ("lib.rs", "mod default;\nmod new_york;\n#[cfg(test)]\nmod tests;\n"),
+            ("default.rs", tabs),
+            ("new_york.rs", tabs),
+            ("tests.rs", "#[test]\nfn test_tabs() {}\n\n#[test]\nfn test_tabs_role() {}\n"),
+        ]);
+        write_crate(workspace.path(), "select", &[
+            ("lib.rs", "mod default;\n"),
+            ("default.rs", "#[component]\npub fn Select() -> impl IntoView {\n    view! { <button>\"Select\"</button> }\n}\n"),
+        ]);
+        write_crate(workspace.path(), "widgets", &[("lib.rs", "")]);
+        workspace
+    }
+
+    #[test]
+    fn test_workspace_status() {
+        let workspace = test_workspace();
+        let status = workspace_status(workspace.path()).unwrap();
+        let component = |name: &str| status.components.iter().find(|component| component.name == name).unwrap();
+
+        let tabs = component("tabs");
+        assert_eq!(tabs.state, ComponentState::Complete);
+        assert_eq!(tabs.reason, None);
+        assert_eq!(tabs.styles, ["default", "new_york"]);
+        assert_eq!(tabs.tests, 2);
+
+        let select = component("select");
+        assert_eq!(select.state, ComponentState::Placeholder);
+        assert_eq!(select.reason.as_deref(), Some("Select only renders a button"));
+        assert_eq!(select.styles, ["default"]);
+        assert_eq!(select.tests, 0);
+
+        let switch = component("switch");
+        assert_eq!(switch.state, ComponentState::Missing);
+        assert_eq!(switch.path, None);
+        assert!(switch.styles.is_empty());
+
+        let summary = &status.summary;
+        assert_eq!((summary.complete, summary.placeholders), (1, 1));
+        assert_eq!(summary.missing, summary.total - 2);
+        assert_eq!(summary.both_styles, 1);
+        assert_eq!(summary.tests, 2);
+        assert_eq!(status.unregistered, ["widgets"]);
+    }
+
+    #[tokio::test]
+    async fn test_status_output() {
+        let workspace = test_workspace();
+        let json = get_status(workspace.path(), false, OutputFormat::Json).await.unwrap();
+        let status = serde_json::from_str::<Value>(&json).unwrap();
+        let component = |name: &str| {
+            status["components"].as_array().unwrap().iter().find(|component| component["name"] == name).unwrap().clone()
+        };
+        assert_eq!(
+            component("tabs"),
+            serde_json::json!({
+                "name": "tabs",
+                "state": "complete",
+                "path": "packages/leptos/tabs",
+                "styles": ["default", "new_york"],
+                "tests": 2,
+            })
+        );
+        assert_eq!(component("select")["state"], "placeholder");
+        assert_eq!(component("select")["reason"], "Select only renders a button");
+        assert_eq!(component("switch")["state"], "missing");
+        assert!(component("switch").get("path").is_none());
+        assert_eq!(status["summary"]["complete"], 1);
+        assert_eq!(status["unregistered"], serde_json::json!(["widgets"]));
+
+        let table = get_status(workspace.path(), true, OutputFormat::Table).await.unwrap();
+        assert!(table.contains("Complete: 1/"));
+        assert!(table.contains("Select only renders a button"));
+        assert!(table.contains("Crates without a registry entry: widgets"));
     }
-    
-    Ok(status)
 }
@@ -1,26 +0,0 @@
-use std::path::PathBuf;
-
-/// Validate components for quality and consistency
-pub async fn validate_components(component: &str, path: PathBuf) -> anyhow::Result<()> {
-    // TODO: Implement component validation logic
-    // This would:
-    // 1. Use test-utils to check component quality
-    // 2. Validate theme consistency
-    // 3. Check API consistency
-    // 4. Generate quality report
-    
-    println!("Validating {} components in project at {}", component, path.display());
-    
-    // For now, just show what would happen
-    if component == "all" {
-        println!("All components would be validated for:");
-        println!("  - Quality standards");
-        println!("  - Theme consistency");
-        println!("  - API consistency");
-    } else {
-        println!("Component '{}' would be validated", component);
-    }
-    println!("This feature is coming soon!");
-    
-    Ok(())
-}
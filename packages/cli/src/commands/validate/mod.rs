@@ -0,0 +1,147 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail};
+
+mod report;
+mod rules;
+pub(crate) mod scanner;
+
+pub use report::ReportFormat;
+
+use report::Report;
+use rules::{check, class_validator};
+use scanner::{discover, scan};
+
+/// Whether `name` refers to the crate or component `candidate`, e.g.
+/// `alert-dialog` to `alert_dialog` or `AlertDialog`.
+fn matches_name(name: &str, candidate: &str) -> bool {
+    let normalize = |name: &str| name.replace(['-', '_'], "").to_lowercase();
+    normalize(name) == normalize(candidate)
+}
+
+fn relative(root: &Path, path: &Path) -> PathBuf {
+    path.strip_prefix(root).unwrap_or(path).to_path_buf()
+}
+
+/// Validate components against the API standards, accessibility rules and
+/// Tailwind CSS classes, failing when violations are found
+pub async fn validate_components(
+    component: &str,
+    path: PathBuf,
+    format: ReportFormat,
+    output: Option<PathBuf>,
+    strict: bool,
+) -> anyhow::Result<()> {
+    let units = discover(&path)?;
+    if units.is_empty() {
+        bail!("No components found in {}", path.display());
+    }
+
+    let validator = class_validator();
+    let mut components = Vec::new();
+    let mut findings = Vec::new();
+    for unit in &units {
+        let mut scan = scan(unit)?;
+        let whole_unit = component == "all" || matches_name(component, &unit.name);
+        if !whole_unit {
+            scan.components.retain(|info| matches_name(component, &info.name));
+            if scan.components.is_empty() {
+                continue;
+            }
+        }
+        let (mut unit_findings, unit_components) = check(unit, &scan, &validator);
+        if !whole_unit {
+            // Keep the findings of the component and of the file it's in.
+            unit_findings.retain(|finding| match &finding.component {
+                Some(owner) => matches_name(component, owner),
+                None => scan.components.iter().any(|info| info.file == finding.file),
+            });
+        }
+        findings.extend(unit_findings);
+        components.extend(unit_components);
+    }
+    if components.is_empty() && component != "all" {
+        bail!("Component '{}' was not found in {}", component, path.display());
+    }
+
+    for finding in &mut findings {
+        finding.file = relative(&path, &finding.file);
+    }
+    for component in &mut components {
+        component.file = relative(&path, &component.file);
+    }
+    let report = Report::new(components, findings);
+
+    let rendered = report.render(format)?;
+    match &output {
+        Some(output) => std::fs::write(output, rendered + "\n")?,
+        None => println!("{}", rendered),
+    }
+
+    let violations = report.summary.errors + if strict { report.summary.warnings } else { 0 };
+    if violations > 0 {
+        return Err(anyhow!("{} violation(s) found", violations));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Validates the fixture crate, which breaks each rule, returning the
+    /// error and the rendered report.
+    async fn validate_fixture(format: ReportFormat, strict: bool) -> (String, Value) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/validate/badge");
+        let output = tempfile::NamedTempFile::new().unwrap();
+        let error = validate_components("all", path, format, Some(output.path().to_path_buf()), strict)
+            .await
+            .unwrap_err();
+        let report = serde_json::from_str(&std::fs::read_to_string(output.path()).unwrap()).unwrap();
+        (error.to_string(), report)
+    }
+
+    #[tokio::test]
+    async fn test_json_report() {
+        let (error, report) = validate_fixture(ReportFormat::Json, false).await;
+        assert_eq!(error, "6 violation(s) found");
+        assert_eq!(report["summary"]["components"], 3);
+        assert_eq!(report["summary"]["errors"], 6);
+        assert_eq!(report["summary"]["warnings"], 3);
+
+        let findings = report["findings"].as_array().unwrap();
+        let notes = findings.iter().filter(|finding| finding["level"] == "note").count();
+        assert_eq!(report["summary"]["notes"], notes);
+        let variant = findings.iter().find(|finding| finding["rule"] == "variant-coverage").unwrap();
+        assert_eq!(variant["level"], "error");
+        assert_eq!(variant["file"], "src/default.rs");
+        assert_eq!(variant["line"], 10);
+
+        let names = report["components"].as_array().unwrap().iter().map(|component| &component["name"]).collect::<Vec<_>>();
+        assert_eq!(names, ["Badge", "BadgeProvider", "BadgeLabel"]);
+        assert_eq!(report["components"][0]["issues"][0]["MissingCoreProps"], serde_json::json!(["id", "style"]));
+    }
+
+    #[tokio::test]
+    async fn test_sarif_report() {
+        // Warnings count as violations in strict mode.
+        let (error, report) = validate_fixture(ReportFormat::Sarif, true).await;
+        assert_eq!(error, "9 violation(s) found");
+        assert_eq!(report["version"], "2.1.0");
+
+        let run = &report["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), rules::RULES.len());
+
+        let results = run["results"].as_array().unwrap();
+        let img_alt = results.iter().find(|result| result["ruleId"] == "img-alt").unwrap();
+        assert_eq!(rules[img_alt["ruleIndex"].as_u64().unwrap() as usize]["id"], "img-alt");
+        assert_eq!(img_alt["level"], "error");
+        assert_eq!(img_alt["message"]["text"], "<img> has no alt attribute.");
+        let location = &img_alt["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/default.rs");
+        assert_eq!(location["region"]["startLine"], 35);
+    }
+}
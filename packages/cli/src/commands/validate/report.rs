@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use console::style;
+use serde::Serialize;
+use serde_json::json;
+
+use super::rules::{ComponentReport, Finding, Level, RULES};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    pub components: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub notes: usize,
+}
+
+/// Findings and scores of a validation run, paths relative to the validated directory.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub summary: Summary,
+    pub components: Vec<ComponentReport>,
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    pub fn new(components: Vec<ComponentReport>, mut findings: Vec<Finding>) -> Self {
+        findings.sort_by(|a, b| (&a.file, a.line, b.level).cmp(&(&b.file, b.line, a.level)));
+        let count = |level| findings.iter().filter(|finding| finding.level == level).count();
+        let summary = Summary {
+            components: components.len(),
+            errors: count(Level::Error),
+            warnings: count(Level::Warning),
+            notes: count(Level::Note),
+        };
+        Self { summary, components, findings }
+    }
+
+    pub fn render(&self, format: ReportFormat) -> serde_json::Result<String> {
+        match format {
+            ReportFormat::Text => Ok(self.text()),
+            ReportFormat::Json => serde_json::to_string_pretty(self),
+            ReportFormat::Sarif => serde_json::to_string_pretty(&self.sarif()),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut lines = Vec::new();
+        for finding in self.findings.iter().filter(|finding| finding.level != Level::Note) {
+            let level = match finding.level {
+                Level::Error => style("error").red().bold(),
+                _ => style("warning").yellow().bold(),
+            };
+            lines.push(format!(
+                "{level}[{}] {}:{} {}",
+                finding.rule,
+                finding.file.display(),
+                finding.line,
+                finding.message
+            ));
+        }
+        if !self.findings.is_empty() {
+            lines.push(String::new());
+        }
+
+        let mut units = BTreeMap::<&str, Vec<&ComponentReport>>::new();
+        for component in &self.components {
+            units.entry(&component.unit).or_default().push(component);
+        }
+        for (unit, components) in units {
+            lines.push(style(unit).bold().to_string());
+            for component in components {
+                let failed = component.tests.values().filter(|test| !test.passed).count();
+                let file = component.file.file_name().unwrap_or_default().to_string_lossy();
+                lines.push(format!(
+                    "  {:<28} {:<18} compliance {:>3.0}%  quality {:>3.0}%{}",
+                    component.name,
+                    file,
+                    component.compliance_score * 100.0,
+                    component.quality_score * 100.0,
+                    if failed > 0 { format!("  ({failed} failed check(s))") } else { String::new() }
+                ));
+            }
+        }
+
+        let summary = &self.summary;
+        lines.push(format!(
+            "\n{} component(s): {} error(s), {} warning(s), {} note(s).",
+            summary.components, summary.errors, summary.warnings, summary.notes
+        ));
+        lines.join("\n")
+    }
+
+    /// The findings as a SARIF 2.1.0 log.
+    fn sarif(&self) -> serde_json::Value {
+        let rules = RULES
+            .iter()
+            .map(|(id, description)| json!({ "id": id, "shortDescription": { "text": description } }))
+            .collect::<Vec<_>>();
+        let results = self
+            .findings
+            .iter()
+            .map(|finding| {
+                json!({
+                    "ruleId": finding.rule,
+                    "ruleIndex": RULES.iter().position(|(id, _)| *id == finding.rule),
+                    "level": finding.level,
+                    "message": { "text": finding.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": finding.file.to_string_lossy().replace('\\', "/") },
+                            "region": { "startLine": finding.line.max(1) },
+                        },
+                    }],
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "rust-shadcn",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+}
@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use leptos_shadcn_api_standards::{ApiCompliant, ApiIssue, TestResult};
+use regex::Regex;
+use serde::Serialize;
+use shadcn_ui_test_utils::quality_checker::{ComponentSpec, LeptosImplementation, PropSpec, QualityChecker};
+use tailwind_rs_core::{ClassValidator, ValidationResult};
+
+use super::scanner::{ComponentInfo, CrateScan, Element, PropInfo, ScanUnit};
+
+/// Ids and descriptions of the rules, in the order they are reported.
+pub const RULES: &[(&str, &str)] = &[
+    ("core-props", "Components rendering markup accept the class, id and style props."),
+    ("prop-naming", "Props are named in snake_case."),
+    ("event-naming", "Event callback props are named on_*."),
+    ("variant-coverage", "Every variant and size maps to classes."),
+    ("aria-attribute", "aria-* attributes are defined by WAI-ARIA 1.2."),
+    ("img-alt", "img elements have an alt attribute."),
+    ("click-role", "Clickable non-interactive elements have a role."),
+    ("tailwind-class", "Classes are known Tailwind CSS utilities."),
+    ("quality", "Issues found by the component quality checker."),
+    ("parse", "Component sources are valid Rust."),
+];
+
+/// Severity of a finding, named as in SARIF.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Note,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub level: Level,
+    pub message: String,
+    pub file: PathBuf,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TestSummary {
+    pub passed: bool,
+    pub message: String,
+}
+
+/// API compliance and quality of a component.
+#[derive(Clone, Debug, Serialize)]
+pub struct ComponentReport {
+    pub name: String,
+    pub unit: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub compliance_score: f64,
+    pub quality_score: f64,
+    pub tests: BTreeMap<String, TestSummary>,
+    pub issues: Vec<ApiIssue>,
+}
+
+/// Attributes defined by WAI-ARIA 1.2.
+const ARIA_ATTRIBUTES: &[&str] = &[
+    "activedescendant", "atomic", "autocomplete", "braillelabel", "brailleroledescription", "busy", "checked",
+    "colcount", "colindex", "colindextext", "colspan", "controls", "current", "describedby", "description",
+    "details", "disabled", "dropeffect", "errormessage", "expanded", "flowto", "grabbed", "haspopup", "hidden",
+    "invalid", "keyshortcuts", "label", "labelledby", "level", "live", "modal", "multiline", "multiselectable",
+    "orientation", "owns", "placeholder", "posinset", "pressed", "readonly", "relevant", "required",
+    "roledescription", "rowcount", "rowindex", "rowindextext", "rowspan", "selected", "setsize", "sort",
+    "valuemax", "valuemin", "valuenow", "valuetext",
+];
+
+/// Elements that are neither interactive nor focusable by default.
+const NON_INTERACTIVE: &[&str] = &["div", "span", "li", "p", "td", "tr", "img", "section", "article", "header", "footer"];
+
+/// Color names of the shadcn/ui theme and the Tailwind palette.
+const COLORS: &str = "(background|foreground|primary|secondary|muted|accent|destructive|popover|card|border|input|ring|chart-[1-5]|sidebar)(-foreground)?|(slate|gray|zinc|neutral|stone|red|orange|amber|yellow|lime|green|emerald|teal|cyan|sky|blue|indigo|violet|purple|fuchsia|pink|rose)-(50|[1-9]00|950)|inherit|current|transparent|black|white";
+
+/// Spacing and sizing scale values.
+const SCALE: &str = r"(\d+(\.5)?|px|auto|full|screen|svh|dvh|min|max|fit|\d+/\d+|none|xs|sm|md|lg|xl|[2-7]xl|prose)";
+
+/// The Tailwind class validator, taught the utilities the components use.
+pub fn class_validator() -> ClassValidator {
+    let mut validator = ClassValidator::new();
+    validator.add_classes([
+        "static", "fixed", "absolute", "relative", "sticky", "visible", "invisible", "collapse", "isolate",
+        "sr-only", "not-sr-only", "truncate", "underline", "overline", "line-through", "no-underline", "uppercase",
+        "lowercase", "capitalize", "normal-case", "italic", "not-italic", "antialiased", "grow", "grow-0", "shrink",
+        "shrink-0", "group", "peer", "container", "border", "rounded", "shadow", "outline", "ring", "ring-inset",
+        "transform", "filter", "blur", "invert", "resize", "resize-none", "select-none", "select-text", "select-all",
+        "pointer-events-none", "pointer-events-auto", "appearance-none", "touch-none", "break-words", "break-all",
+        "overscroll-contain", "scroll-smooth", "tabular-nums", "list-none", "list-disc", "list-decimal",
+        "animate-in", "animate-out", "table-auto", "table-fixed", "caption-bottom", "border-collapse",
+    ]);
+
+    let patterns = [
+        format!(r"^(bg|text|border(-[xytrblse])?|ring|ring-offset|outline|fill|stroke|from|via|to|divide|placeholder|caret|accent|decoration|shadow)-({COLORS})(/\d+)?$"),
+        format!(r"^(p|px|py|pt|pr|pb|pl|ps|pe|m|mx|my|mt|mr|mb|ml|ms|me|gap|gap-x|gap-y|space-x|space-y|w|h|min-w|min-h|max-w|max-h|size|inset|inset-x|inset-y|top|right|bottom|left|start|end|translate-x|translate-y|basis|scroll-m|scroll-p|scroll-my|scroll-py|indent)-{SCALE}$"),
+        r"^text-(xs|sm|base|lg|xl|[2-9]xl|left|center|right|justify|start|end|wrap|nowrap|balance|pretty|ellipsis|clip)$".into(),
+        r"^font-(thin|extralight|light|normal|medium|semibold|bold|extrabold|black|sans|serif|mono)$".into(),
+        r"^leading-(none|tight|snug|normal|relaxed|loose|\d+)$".into(),
+        r"^tracking-(tighter|tight|normal|wide|wider|widest)$".into(),
+        r"^rounded(-(t|r|b|l|tl|tr|br|bl|s|e|ss|se|es|ee))?(-(none|sm|md|lg|xl|2xl|3xl|full))?$".into(),
+        r"^(border|divide)(-[xytrblse])?(-\d+)?(-reverse)?$".into(),
+        r"^(border|divide|outline)-(solid|dashed|dotted|double|none|hidden)$".into(),
+        r"^(ring|ring-offset|outline|outline-offset)-\d+$".into(),
+        r"^outline-none$".into(),
+        r"^shadow-(sm|md|lg|xl|2xl|inner|none)$".into(),
+        r"^(opacity|z|order|scale|scale-x|scale-y|rotate|skew-x|skew-y|duration|delay|line-clamp|columns|grid-cols|grid-rows|col-span|row-span|col-start|col-end|row-start|row-end|stroke)-\d+$".into(),
+        r"^(transition(-(all|colors|opacity|shadow|transform|none))?|ease-(linear|in|out|in-out))$".into(),
+        r"^animate-(none|spin|ping|pulse|bounce|accordion-up|accordion-down|collapsible-up|collapsible-down)$".into(),
+        r"^(fade|zoom|spin)-(in|out)(-\d+)?$".into(),
+        r"^slide-(in|out)-from-(top|bottom|left|right)(-\d+(/\d+)?|-full)?$".into(),
+        r"^(items|self|content|justify|justify-items|justify-self|place-items|place-content|place-self)-(start|end|center|between|around|evenly|baseline|stretch|auto|normal)$".into(),
+        r"^(flex|grid-flow|object|overflow|overflow-x|overflow-y|whitespace|cursor|origin|aspect|align|mix-blend|will-change|snap|touch|underline-offset|list|auto-cols|auto-rows|col|row|float|clear|box|content)-[a-z0-9-]+$".into(),
+        r"^(backdrop-)?(blur|brightness|contrast|grayscale|saturate)(-(none|sm|md|lg|xl|2xl|3xl|\d+))?$".into(),
+        r"^(bg-gradient-to|bg-linear-to)-(t|tr|r|br|b|bl|l|tl)$".into(),
+        r"^bg-(fixed|local|scroll|clip-[a-z]+|origin-[a-z]+|no-repeat|repeat(-[xy])?|cover|contain|center|top|bottom|left|right|none)$".into(),
+        r"^decoration-(solid|double|dotted|dashed|wavy|\d+)$".into(),
+        r"^(group|peer)(/[a-z-]+)?$".into(),
+    ];
+    for pattern in patterns {
+        validator.add_pattern(Regex::new(&pattern).expect("Class patterns should be valid."));
+    }
+    validator
+}
+
+/// The utility of `class` without its variants, e.g. `bg-accent` for
+/// `data-[state=open]:hover:bg-accent`.
+fn utility(class: &str) -> &str {
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, char) in class.char_indices() {
+        match char {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ':' if depth == 0 => start = index + 1,
+            _ => {}
+        }
+    }
+    class[start..].trim_start_matches('!').trim_start_matches('-')
+}
+
+pub fn validate_class(validator: &ClassValidator, class: &str) -> ValidationResult {
+    let utility = utility(class);
+    // Arbitrary values and properties are up to the author.
+    if utility.contains('[') {
+        return ValidationResult::Valid;
+    }
+    match validator.validate_class(utility) {
+        ValidationResult::Valid => ValidationResult::Valid,
+        ValidationResult::Invalid(_) => ValidationResult::Invalid(class.to_string()),
+        ValidationResult::Unknown(_) => ValidationResult::Unknown(class.to_string()),
+    }
+}
+
+/// Whether `component` renders markup of its own, rather than e.g. only
+/// providing a context to its children.
+fn renders(scan: &CrateScan, component: &ComponentInfo) -> bool {
+    scan.elements.iter().any(|element| element.component.as_deref() == Some(&component.name))
+}
+
+/// Core props a component lacks. Those rendering no markup have nothing to
+/// apply them to.
+fn missing_core_props(scan: &CrateScan, component: &ComponentInfo) -> Vec<&'static str> {
+    if !renders(scan, component) {
+        return Vec::new();
+    }
+    ["class", "id", "style"]
+        .into_iter()
+        .filter(|core| !component.props.iter().any(|prop| prop.name == *core))
+        .collect()
+}
+
+fn is_snake_case(name: &str) -> bool {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    !name.is_empty() && name.chars().all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_')
+}
+
+/// Whether a callback type returns nothing, `Callback<T>` rather than `Callback<T, U>`.
+fn returns_unit(ty: &str) -> bool {
+    let Some(start) = ty.find("Callback<") else {
+        return ty.contains("Fn(") && !ty.contains("->");
+    };
+    let mut depth = 0;
+    for char in ty[start + "Callback<".len()..].chars() {
+        match char {
+            '<' | '(' => depth += 1,
+            '>' | ')' if depth == 0 => return true,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Whether a prop is an event callback, rather than e.g. a render callback
+/// or a formatter.
+fn is_event_callback(prop: &PropInfo) -> bool {
+    let ty = prop.ty.replace(' ', "");
+    let renders = ["children", "as_child"].contains(&prop.name.as_str()) || prop.name.starts_with("render");
+    (ty.contains("Callback<") || ty.contains("Fn(")) && returns_unit(&ty) && !renders
+}
+
+/// Collects the findings of a unit.
+struct Checker<'a> {
+    unit: &'a ScanUnit,
+    findings: Vec<Finding>,
+}
+
+impl Checker<'_> {
+    fn push(&mut self, rule: &'static str, level: Level, message: String, file: &std::path::Path, line: usize, component: Option<&str>) {
+        self.findings.push(Finding {
+            rule,
+            level,
+            message,
+            file: file.to_path_buf(),
+            line,
+            component: component.map(str::to_string),
+        });
+    }
+
+    fn check_props(&mut self, scan: &CrateScan, component: &ComponentInfo) {
+        let missing = missing_core_props(scan, component);
+        if !missing.is_empty() {
+            self.push(
+                "core-props",
+                Level::Error,
+                format!("{} is missing the {} prop(s).", component.name, missing.join(", ")),
+                &component.file,
+                component.line,
+                Some(&component.name),
+            );
+        }
+
+        for prop in &component.props {
+            if !is_snake_case(&prop.name) {
+                self.push(
+                    "prop-naming",
+                    Level::Error,
+                    format!("Prop {} of {} is not snake_case.", prop.name, component.name),
+                    &component.file,
+                    prop.line,
+                    Some(&component.name),
+                );
+            } else if is_event_callback(prop) && !prop.name.starts_with("on_") {
+                self.push(
+                    "event-naming",
+                    Level::Warning,
+                    format!("Callback prop {} of {} should be named on_{}.", prop.name, component.name, prop.name),
+                    &component.file,
+                    prop.line,
+                    Some(&component.name),
+                );
+            }
+        }
+    }
+
+    fn check_element(&mut self, element: &Element) {
+        let component = element.component.as_deref();
+        for attribute in &element.attributes {
+            if let Some(name) = attribute.strip_prefix("aria-") {
+                if !ARIA_ATTRIBUTES.contains(&name) {
+                    self.push(
+                        "aria-attribute",
+                        Level::Error,
+                        format!("{attribute} on <{}> is not an ARIA attribute.", element.tag),
+                        &element.file,
+                        element.line,
+                        component,
+                    );
+                }
+            }
+        }
+        if element.tag == "img" && !element.has("alt") {
+            self.push(
+                "img-alt",
+                Level::Error,
+                "<img> has no alt attribute.".into(),
+                &element.file,
+                element.line,
+                component,
+            );
+        }
+        if NON_INTERACTIVE.contains(&element.tag.as_str()) && element.has("on:click") && !element.has("role") {
+            self.push(
+                "click-role",
+                Level::Warning,
+                format!("<{}> handles clicks but has no role.", element.tag),
+                &element.file,
+                element.line,
+                component,
+            );
+        }
+    }
+
+    fn check_variants(&mut self, scan: &CrateScan) {
+        for item in &scan.enums {
+            // The default variant may consist of the base classes only.
+            for (variant, line) in item.variants.iter().filter(|(variant, _)| variant != "Default") {
+                if !scan.covered_variants.contains(&(item.name.clone(), variant.clone())) {
+                    self.push(
+                        "variant-coverage",
+                        Level::Error,
+                        format!("{}::{variant} is not mapped to any classes.", item.name),
+                        &item.file,
+                        *line,
+                        None,
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_classes(&mut self, scan: &CrateScan, validator: &ClassValidator) {
+        for classes in &scan.class_strings {
+            for class in classes.value.split_whitespace() {
+                let (level, problem) = match validate_class(validator, class) {
+                    ValidationResult::Valid => continue,
+                    ValidationResult::Invalid(_) => (Level::Error, "is not a valid Tailwind class"),
+                    ValidationResult::Unknown(_) => (Level::Warning, "is not a known Tailwind class"),
+                };
+                self.push(
+                    "tailwind-class",
+                    level,
+                    format!("{class} {problem}."),
+                    &classes.file,
+                    classes.line,
+                    classes.component.as_deref(),
+                );
+            }
+        }
+    }
+
+    /// The quality checker's view of `component`.
+    fn implementation(&self, scan: &CrateScan, component: &ComponentInfo) -> LeptosImplementation {
+        let elements = scan
+            .elements
+            .iter()
+            .filter(|element| element.component.as_deref() == Some(&component.name))
+            .collect::<Vec<_>>();
+        let classes = scan
+            .class_strings
+            .iter()
+            .filter(|classes| classes.component.as_deref() == Some(&component.name))
+            .flat_map(|classes| classes.value.split_whitespace().map(str::to_string))
+            .collect::<Vec<_>>();
+        let any_attribute = |names: &[&str]| elements.iter().any(|element| names.iter().any(|name| element.has(name)));
+
+        let mut accessibility_features = Vec::new();
+        if any_attribute(&["aria-label", "aria-labelledby"]) || component.props.iter().any(|prop| prop.name == "aria_label") {
+            accessibility_features.push("aria-label".to_string());
+        }
+        if any_attribute(&["on:keydown", "on:keyup"]) {
+            accessibility_features.push("keyboard-navigation".to_string());
+        }
+        if any_attribute(&["tabindex"]) || classes.iter().any(|class| class.starts_with("focus")) {
+            accessibility_features.push("focus-management".to_string());
+        }
+        if any_attribute(&["role"]) {
+            accessibility_features.push("role".to_string());
+        }
+
+        let props = component
+            .props
+            .iter()
+            .map(|prop| {
+                let spec = PropSpec {
+                    prop_type: prop.ty.clone(),
+                    required: !prop.optional,
+                    default_value: None,
+                    validation_rules: Vec::new(),
+                    documentation: prop.documentation.clone(),
+                };
+                (prop.name.clone(), spec)
+            })
+            .collect();
+        let prefix = |suffix: &str| format!("{}{suffix}", component.name);
+        let variants_of = |name: String| {
+            scan.enums
+                .iter()
+                .filter(|item| item.name == name)
+                .flat_map(|item| item.variants.iter().map(|(variant, _)| variant.clone()))
+                .collect()
+        };
+
+        let documented_props = component.props.iter().filter(|prop| prop.documentation.is_some()).count();
+        let documentation_quality = match component.props.len() {
+            0 => f64::from(u8::from(component.documented)),
+            count => (f64::from(u8::from(component.documented)) + documented_props as f64 / count as f64) / 2.0,
+        };
+
+        LeptosImplementation {
+            component_spec: ComponentSpec {
+                name: component.name.clone(),
+                props,
+                events: component.props.iter().filter(|prop| is_event_callback(prop)).map(|prop| prop.name.clone()).collect(),
+                variants: variants_of(prefix("Variant")),
+                sizes: variants_of(prefix("Size")),
+                accessibility_features,
+                responsive_breakpoints: Vec::new(),
+            },
+            css_classes: classes,
+            dependencies: scan.dependencies.clone(),
+            theme_variants: self.unit.themes.clone(),
+            // Static analysis can only tell whether the tests mention the component.
+            test_coverage: f64::from(u8::from(scan.test_source.contains(&component.name))),
+            documentation_quality,
+            performance_metrics: Default::default(),
+        }
+    }
+}
+
+/// A scanned component, checked against the API standards.
+struct ComponentAudit<'a> {
+    component: &'a ComponentInfo,
+    renders: bool,
+    findings: Vec<&'a Finding>,
+}
+
+impl ComponentAudit<'_> {
+    fn result(&self, rules: &[&str], passed: &str) -> TestResult {
+        let violations = self
+            .findings
+            .iter()
+            .filter(|finding| rules.contains(&finding.rule) && finding.level != Level::Note)
+            .map(|finding| serde_json::Value::from(finding.message.clone()))
+            .collect::<Vec<_>>();
+        if violations.is_empty() {
+            TestResult::passed(passed)
+        } else {
+            TestResult::failed(format!("{} violation(s)", violations.len()))
+                .with_detail("violations", serde_json::Value::Array(violations))
+        }
+    }
+}
+
+impl ApiCompliant for ComponentAudit<'_> {
+    type Props = Vec<PropInfo>;
+
+    fn test_basic_rendering(&self) -> TestResult {
+        if self.renders {
+            TestResult::passed("renders view! markup")
+        } else {
+            TestResult::failed("no view! markup found")
+        }
+    }
+
+    fn test_prop_handling(&self) -> TestResult {
+        self.result(&["core-props", "prop-naming"], "core props are present and named in snake_case")
+    }
+
+    fn test_accessibility_compliance(&self) -> TestResult {
+        self.result(&["aria-attribute", "img-alt", "click-role"], "no accessibility violations")
+    }
+
+    fn test_event_handling(&self) -> TestResult {
+        self.result(&["event-naming"], "event callbacks are named on_*")
+    }
+
+    fn test_css_compliance(&self) -> TestResult {
+        self.result(&["tailwind-class", "variant-coverage"], "classes are valid")
+    }
+
+    fn test_performance_compliance(&self) -> TestResult {
+        TestResult::passed("performance is not measured by static analysis")
+    }
+}
+
+fn api_issue(finding: &Finding) -> Option<ApiIssue> {
+    match finding.rule {
+        "aria-attribute" | "img-alt" | "click-role" => Some(ApiIssue::AccessibilityViolation {
+            rule: finding.rule.to_string(),
+            description: finding.message.clone(),
+        }),
+        "tailwind-class" if finding.level == Level::Error => Some(ApiIssue::CssClassNonCompliant {
+            expected_pattern: "a Tailwind CSS utility".into(),
+            actual: finding.message.split(' ').next().unwrap_or_default().to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Checks the components of `unit`.
+pub fn check(unit: &ScanUnit, scan: &CrateScan, validator: &ClassValidator) -> (Vec<Finding>, Vec<ComponentReport>) {
+    let mut checker = Checker { unit, findings: Vec::new() };
+    for (file, error) in &scan.parse_errors {
+        checker.push("parse", Level::Warning, format!("Skipped, the file can't be parsed: {error}."), file, 1, None);
+    }
+    for component in &scan.components {
+        checker.check_props(scan, component);
+    }
+    for element in &scan.elements {
+        checker.check_element(element);
+    }
+    checker.check_variants(scan);
+    checker.check_classes(scan, validator);
+
+    let quality_checker = QualityChecker::new();
+    let mut quality_findings = Vec::new();
+    let mut reports = Vec::new();
+    for component in &scan.components {
+        let name = component.name.as_str();
+        // Findings outside of components count for the components of their file.
+        let findings = checker
+            .findings
+            .iter()
+            .filter(|finding| match &finding.component {
+                Some(owner) => owner == name,
+                None => finding.file == component.file,
+            })
+            .collect::<Vec<_>>();
+
+        let mut issues = findings.iter().filter_map(|finding| api_issue(finding)).collect::<Vec<_>>();
+        let missing = missing_core_props(scan, component);
+        if !missing.is_empty() {
+            issues.insert(0, ApiIssue::MissingCoreProps(missing.into_iter().map(str::to_string).collect()));
+        }
+
+        let audit = ComponentAudit {
+            component,
+            renders: renders(scan, component),
+            findings,
+        };
+        let compliance = audit.generate_compliance_report();
+
+        let quality = quality_checker.check_component_quality(name, &checker.implementation(scan, component));
+        for issue in &quality.issues {
+            quality_findings.push(Finding {
+                rule: "quality",
+                level: Level::Note,
+                message: format!("{name}: {issue}."),
+                file: component.file.clone(),
+                line: component.line,
+                component: Some(name.to_string()),
+            });
+        }
+
+        reports.push(ComponentReport {
+            name: name.to_string(),
+            unit: unit.name.clone(),
+            file: component.file.clone(),
+            line: audit.component.line,
+            compliance_score: compliance.compliance_score,
+            quality_score: quality.quality_score,
+            tests: compliance
+                .test_results
+                .into_iter()
+                .map(|(test, result)| (test, TestSummary { passed: result.passed, message: result.message }))
+                .collect(),
+            issues,
+        });
+    }
+
+    let mut findings = checker.findings;
+    findings.extend(quality_findings);
+    (findings, reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::super::scanner::{crate_unit, scan};
+    use super::*;
+
+    /// Findings and reports for the fixture crate, which breaks each rule.
+    fn check_fixture() -> (Vec<Finding>, Vec<ComponentReport>) {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/validate/badge");
+        let unit = crate_unit(&dir).unwrap();
+        check(&unit, &scan(&unit).unwrap(), &class_validator())
+    }
+
+    /// `(line, level, message)` of the findings of `rule`.
+    fn findings_of(findings: &[Finding], rule: &str) -> Vec<(usize, Level, String)> {
+        findings
+            .iter()
+            .filter(|finding| finding.rule == rule)
+            .map(|finding| (finding.line, finding.level, finding.message.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_prop_rules() {
+        let (findings, reports) = check_fixture();
+        // BadgeProvider renders no markup, so it needs no core props.
+        assert_eq!(
+            findings_of(&findings, "core-props"),
+            [(25, Level::Error, "Badge is missing the id, style prop(s).".to_string())]
+        );
+        assert_eq!(
+            findings_of(&findings, "prop-naming"),
+            [(28, Level::Error, "Prop onDismiss of Badge is not snake_case.".to_string())]
+        );
+        assert_eq!(
+            findings_of(&findings, "event-naming"),
+            [(29, Level::Warning, "Callback prop dismissed of Badge should be named on_dismissed.".to_string())]
+        );
+
+        let issues = |name: &str| reports.iter().find(|report| report.name == name).unwrap().issues.clone();
+        assert!(matches!(issues("Badge").first(), Some(ApiIssue::MissingCoreProps(props)) if props == &["id", "style"]));
+        assert!(issues("BadgeProvider").is_empty());
+        assert!(issues("BadgeLabel").is_empty());
+    }
+
+    #[test]
+    fn test_variant_rule() {
+        let (findings, _) = check_fixture();
+        // Default may use the base classes only, Secondary is matched.
+        assert_eq!(
+            findings_of(&findings, "variant-coverage"),
+            [(10, Level::Error, "BadgeVariant::Outline is not mapped to any classes.".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_accessibility_rules() {
+        let (findings, reports) = check_fixture();
+        assert_eq!(
+            findings_of(&findings, "aria-attribute"),
+            [(34, Level::Error, "aria-labeled on <div> is not an ARIA attribute.".to_string())]
+        );
+        assert_eq!(findings_of(&findings, "img-alt"), [(35, Level::Error, "<img> has no alt attribute.".to_string())]);
+        assert_eq!(
+            findings_of(&findings, "click-role"),
+            [(34, Level::Warning, "<div> handles clicks but has no role.".to_string())]
+        );
+
+        let badge = reports.iter().find(|report| report.name == "Badge").unwrap();
+        assert!(!badge.tests["accessibility"].passed);
+        let label = reports.iter().find(|report| report.name == "BadgeLabel").unwrap();
+        assert!(label.tests["accessibility"].passed);
+    }
+
+    #[test]
+    fn test_tailwind_rule() {
+        let (findings, _) = check_fixture();
+        // Classes of `*_CLASS` constants and of `class` attributes.
+        assert_eq!(
+            findings_of(&findings, "tailwind-class"),
+            [
+                (3, Level::Warning, "bg-primry is not a known Tailwind class.".to_string()),
+                (36, Level::Error, "text_xs is not a valid Tailwind class.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_class() {
+        let validator = class_validator();
+        for class in ["bg-primary", "hover:bg-primary/90", "data-[state=open]:bg-accent", "py-1.5", "-mx-1", "h-[var(--radix)]"] {
+            assert_eq!(validate_class(&validator, class), ValidationResult::Valid, "{class}");
+        }
+        assert!(validate_class(&validator, "bg_primary").is_invalid());
+        assert!(validate_class(&validator, "hover:bg-primry").is_unknown());
+    }
+}
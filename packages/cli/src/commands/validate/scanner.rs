@@ -0,0 +1,684 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::visit::{self, Visit};
+use walkdir::WalkDir;
+
+/// A component crate, or a component vendored into a project, to validate.
+#[derive(Debug, Clone)]
+pub struct ScanUnit {
+    pub name: String,
+    /// Files holding the components.
+    pub sources: Vec<PathBuf>,
+    /// Files holding their tests.
+    pub tests: Vec<PathBuf>,
+    /// Cargo.toml listing the dependencies of the components.
+    pub manifest: Option<PathBuf>,
+    /// Styles the components are implemented in, e.g. `default` and `new_york`.
+    pub themes: Vec<String>,
+}
+
+/// What a scan found in the sources of a unit.
+#[derive(Debug, Default)]
+pub struct CrateScan {
+    pub components: Vec<ComponentInfo>,
+    pub enums: Vec<EnumInfo>,
+    /// `(Enum, Variant)` pairs mapped to classes.
+    pub covered_variants: HashSet<(String, String)>,
+    pub class_strings: Vec<ClassString>,
+    pub elements: Vec<Element>,
+    pub dependencies: Vec<String>,
+    /// Source of the tests, to see which components they mention.
+    pub test_source: String,
+    /// Files that could not be parsed.
+    pub parse_errors: Vec<(PathBuf, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComponentInfo {
+    pub name: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub documented: bool,
+    pub props: Vec<PropInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PropInfo {
+    pub name: String,
+    pub ty: String,
+    pub optional: bool,
+    pub documentation: Option<String>,
+    pub line: usize,
+}
+
+/// A `*Variant` or `*Size` enum.
+#[derive(Debug, Clone)]
+pub struct EnumInfo {
+    pub name: String,
+    pub file: PathBuf,
+    pub variants: Vec<(String, usize)>,
+}
+
+/// Tailwind classes from a `class` attribute, a `*_CLASS` constant or a variant.
+#[derive(Debug, Clone)]
+pub struct ClassString {
+    pub value: String,
+    pub file: PathBuf,
+    pub line: usize,
+    /// Component whose markup the classes are in.
+    pub component: Option<String>,
+}
+
+/// An element of a `view!` macro.
+#[derive(Debug, Clone)]
+pub struct Element {
+    pub tag: String,
+    pub attributes: Vec<String>,
+    pub file: PathBuf,
+    pub line: usize,
+    /// Component whose body the element is rendered in.
+    pub component: Option<String>,
+}
+
+impl Element {
+    pub fn has(&self, attribute: &str) -> bool {
+        self.attributes.iter().any(|name| name == attribute)
+    }
+}
+
+fn is_test_path(path: &Path) -> bool {
+    path.components()
+        .any(|component| component.as_os_str().to_string_lossy().contains("test"))
+}
+
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "rs"))
+        .map(|entry| entry.into_path())
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Unit for the component crate in `dir`, if it is one.
+pub fn crate_unit(dir: &Path) -> Option<ScanUnit> {
+    let src = dir.join("src");
+    if !src.is_dir() || !dir.join("Cargo.toml").is_file() {
+        return None;
+    }
+    let (tests, sources): (Vec<_>, Vec<_>) = rust_files(&src)
+        .into_iter()
+        .partition(|file| is_test_path(file.strip_prefix(&src).unwrap_or(file)));
+    let tests = tests.into_iter().chain(rust_files(&dir.join("tests"))).collect();
+
+    let mut themes = Vec::new();
+    for (file, theme) in [("default.rs", "default"), ("new_york.rs", "new_york")] {
+        if src.join(file).is_file() {
+            themes.push(theme.to_string());
+        }
+    }
+    if themes.is_empty() {
+        themes.push("default".to_string());
+    }
+
+    Some(ScanUnit {
+        name: dir.file_name()?.to_string_lossy().into_owned(),
+        sources,
+        tests,
+        manifest: Some(dir.join("Cargo.toml")),
+        themes,
+    })
+}
+
+/// Units for the components vendored into the project at `root`.
+fn vendored_units(root: &Path, config: &toml::Value) -> Vec<ScanUnit> {
+    let components_dir = config
+        .get("components_dir")
+        .and_then(|dir| dir.as_str())
+        .unwrap_or("src/components/ui");
+    let theme = match config.get("style").and_then(|style| style.as_str()) {
+        Some("new-york") => "new_york",
+        _ => "default",
+    };
+    let manifest = Some(root.join("Cargo.toml")).filter(|manifest| manifest.is_file());
+    let unit = |name: String, sources: Vec<PathBuf>| ScanUnit {
+        name,
+        sources,
+        tests: Vec::new(),
+        manifest: manifest.clone(),
+        themes: vec![theme.to_string()],
+    };
+
+    // Components added with `add` list their files, others are one file each.
+    if let Some(components) = config.get("components").and_then(|components| components.as_table()) {
+        return components
+            .iter()
+            .map(|(name, component)| {
+                let files = component.get("files").and_then(|files| files.as_table());
+                let sources = files
+                    .into_iter()
+                    .flat_map(|files| files.keys())
+                    .map(|file| root.join(file))
+                    .filter(|file| file.extension().is_some_and(|ext| ext == "rs") && file.is_file())
+                    .collect();
+                unit(name.clone(), sources)
+            })
+            .collect();
+    }
+    rust_files(&root.join(components_dir))
+        .into_iter()
+        .filter(|file| file.file_stem().is_some_and(|stem| stem != "mod"))
+        .map(|file| {
+            let name = file.file_stem().unwrap_or_default().to_string_lossy().replace('_', "-");
+            unit(name, vec![file])
+        })
+        .collect()
+}
+
+/// Finds the units under `root`: the component crates of this repository, a
+/// directory of component crates, a single crate, or a project with a
+/// `components.toml`.
+pub fn discover(root: &Path) -> Result<Vec<ScanUnit>> {
+    let config_path = root.join("components.toml");
+    if config_path.is_file() {
+        let content = fs::read_to_string(&config_path)?;
+        let config = toml::from_str(&content).with_context(|| format!("Invalid configuration in {}.", config_path.display()))?;
+        return Ok(vendored_units(root, &config));
+    }
+    let crates_dir = root.join("packages").join("leptos");
+    if crates_dir.is_dir() {
+        return crate_units(&crates_dir);
+    }
+    if let Some(unit) = crate_unit(root) {
+        return Ok(vec![unit]);
+    }
+    crate_units(root)
+}
+
+fn crate_units(dir: &Path) -> Result<Vec<ScanUnit>> {
+    let dirs = fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect::<BTreeSet<_>>();
+    Ok(dirs.iter().filter_map(|dir| crate_unit(dir)).collect())
+}
+
+/// Reads and parses the sources of `unit`.
+pub fn scan(unit: &ScanUnit) -> Result<CrateScan> {
+    let mut scan = CrateScan::default();
+    for file in &unit.sources {
+        let source = fs::read_to_string(file)?;
+        match syn::parse_file(&source) {
+            Ok(ast) => FileVisitor { file, scan: &mut scan, component: None, self_ty: None }.visit_file(&ast),
+            Err(error) => scan.parse_errors.push((file.clone(), error.to_string())),
+        }
+    }
+    for file in &unit.tests {
+        scan.test_source.push_str(&fs::read_to_string(file)?);
+    }
+    if let Some(manifest) = &unit.manifest {
+        let manifest = fs::read_to_string(manifest)?.parse::<toml::Table>()?;
+        if let Some(dependencies) = manifest.get("dependencies").and_then(|dependencies| dependencies.as_table()) {
+            scan.dependencies = dependencies.keys().cloned().collect();
+        }
+    }
+    Ok(scan)
+}
+
+fn line(span: proc_macro2::Span) -> usize {
+    span.start().line
+}
+
+fn has_attr(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident(name))
+}
+
+fn is_cfg_test(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg") && attr.meta.to_token_stream().to_string().contains("test")
+    })
+}
+
+fn doc(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(doc), .. }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+fn is_variant_enum(name: &str) -> bool {
+    name.ends_with("Variant") || name.ends_with("Size")
+}
+
+/// Whether a string looks like a list of classes rather than a plain word.
+fn looks_like_classes(value: &str) -> bool {
+    value.contains(['-', ' ', ':'])
+}
+
+struct FileVisitor<'a> {
+    file: &'a Path,
+    scan: &'a mut CrateScan,
+    /// Component whose body is being visited.
+    component: Option<String>,
+    /// Type of the impl block being visited, what `Self` refers to.
+    self_ty: Option<String>,
+}
+
+impl FileVisitor<'_> {
+    /// `(Enum, Variant)` pairs a match arm pattern names.
+    fn pattern_variants(&self, pat: &syn::Pat, variants: &mut Vec<(String, String)>) {
+        let path = match pat {
+            syn::Pat::Or(or) => {
+                for case in &or.cases {
+                    self.pattern_variants(case, variants);
+                }
+                return;
+            }
+            syn::Pat::Path(path) => &path.path,
+            syn::Pat::TupleStruct(tuple) => {
+                // e.g. `Some(ButtonVariant::Ghost)`.
+                for elem in &tuple.elems {
+                    self.pattern_variants(elem, variants);
+                }
+                &tuple.path
+            }
+            syn::Pat::Tuple(tuple) => {
+                for elem in &tuple.elems {
+                    self.pattern_variants(elem, variants);
+                }
+                return;
+            }
+            syn::Pat::Reference(reference) => {
+                self.pattern_variants(&reference.pat, variants);
+                return;
+            }
+            syn::Pat::Struct(pat) => &pat.path,
+            syn::Pat::Ident(ident) => {
+                if let Some((_, sub)) = &ident.subpat {
+                    self.pattern_variants(sub, variants);
+                }
+                return;
+            }
+            _ => return,
+        };
+        let segments = path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+        if let [.., ty, variant] = segments.as_slice() {
+            let ty = match (ty.as_str(), &self.self_ty) {
+                ("Self", Some(self_ty)) => self_ty.clone(),
+                _ => ty.clone(),
+            };
+            if is_variant_enum(&ty) {
+                variants.push((ty, variant.clone()));
+            }
+        }
+    }
+
+    fn push_elements(&mut self, tokens: TokenStream, markup: bool) {
+        let mut elements = Vec::new();
+        if markup {
+            scan_markup(tokens, &mut elements);
+        } else {
+            scan_rust(tokens, &mut elements);
+        }
+        for (element, classes) in elements {
+            for (value, line) in classes {
+                self.scan.class_strings.push(ClassString {
+                    value,
+                    file: self.file.to_path_buf(),
+                    line,
+                    component: self.component.clone(),
+                });
+            }
+            self.scan.elements.push(Element {
+                file: self.file.to_path_buf(),
+                component: self.component.clone(),
+                ..element
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FileVisitor<'_> {
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        if !is_cfg_test(&item.attrs) {
+            visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        if has_attr(&item.attrs, "test") || is_cfg_test(&item.attrs) {
+            return;
+        }
+        if !has_attr(&item.attrs, "component") {
+            visit::visit_item_fn(self, item);
+            return;
+        }
+
+        let props = item
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                syn::FnArg::Typed(arg) => Some(arg),
+                syn::FnArg::Receiver(_) => None,
+            })
+            .filter_map(|arg| {
+                let syn::Pat::Ident(pat) = arg.pat.as_ref() else {
+                    return None;
+                };
+                let ty = arg.ty.to_token_stream().to_string();
+                let optional = ty.starts_with("Option <")
+                    || arg.attrs.iter().any(|attr| {
+                        attr.path().is_ident("prop") && {
+                            let options = attr.meta.to_token_stream().to_string();
+                            options.contains("optional") || options.contains("default")
+                        }
+                    });
+                Some(PropInfo {
+                    name: pat.ident.to_string(),
+                    ty,
+                    optional,
+                    documentation: doc(&arg.attrs),
+                    line: line(pat.ident.span()),
+                })
+            })
+            .collect();
+
+        let name = item.sig.ident.to_string();
+        self.scan.components.push(ComponentInfo {
+            name: name.clone(),
+            file: self.file.to_path_buf(),
+            line: line(item.sig.ident.span()),
+            documented: doc(&item.attrs).is_some(),
+            props,
+        });
+
+        let outer = self.component.replace(name);
+        visit::visit_block(self, &item.block);
+        self.component = outer;
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        if is_cfg_test(&item.attrs) {
+            return;
+        }
+        let self_ty = match item.self_ty.as_ref() {
+            syn::Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+            _ => None,
+        };
+        let outer = std::mem::replace(&mut self.self_ty, self_ty);
+        visit::visit_item_impl(self, item);
+        self.self_ty = outer;
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
+        let name = item.ident.to_string();
+        if !is_variant_enum(&name) {
+            return;
+        }
+        let mut variants = Vec::new();
+        for variant in &item.variants {
+            let variant_name = variant.ident.to_string();
+            // tailwind_fuse variants carry their classes: `#[tw(class = "...")]`.
+            for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("tw")) {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("class") {
+                        let value = meta.value()?.parse::<syn::LitStr>()?;
+                        self.scan.class_strings.push(ClassString {
+                            value: value.value(),
+                            file: self.file.to_path_buf(),
+                            line: line(value.span()),
+                            component: None,
+                        });
+                        self.scan.covered_variants.insert((name.clone(), variant_name.clone()));
+                    }
+                    Ok(())
+                });
+            }
+            variants.push((variant_name, line(variant.ident.span())));
+        }
+        self.scan.enums.push(EnumInfo { name, file: self.file.to_path_buf(), variants });
+    }
+
+    fn visit_item_const(&mut self, item: &'ast syn::ItemConst) {
+        let name = item.ident.to_string();
+        if name.ends_with("_CLASS") || name.ends_with("_CLASSES") {
+            if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }) = item.expr.as_ref() {
+                self.scan.class_strings.push(ClassString {
+                    value: value.value(),
+                    file: self.file.to_path_buf(),
+                    line: line(value.span()),
+                    component: None,
+                });
+            }
+        }
+        visit::visit_item_const(self, item);
+    }
+
+    fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+        let mut variants = Vec::new();
+        self.pattern_variants(&arm.pat, &mut variants);
+        if !variants.is_empty() {
+            let mut literals = Vec::new();
+            collect_str_literals(arm.body.to_token_stream(), &mut literals);
+            let classes = literals
+                .into_iter()
+                .filter(|(value, _)| looks_like_classes(value))
+                .collect::<Vec<_>>();
+            if !classes.is_empty() {
+                self.scan.covered_variants.extend(variants);
+                for (value, line) in classes {
+                    self.scan.class_strings.push(ClassString {
+                        value,
+                        file: self.file.to_path_buf(),
+                        line,
+                        component: None,
+                    });
+                }
+            }
+        }
+        visit::visit_arm(self, arm);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        let markup = mac.path.is_ident("view");
+        self.push_elements(mac.tokens.clone(), markup);
+    }
+}
+
+fn collect_str_literals(tokens: TokenStream, literals: &mut Vec<(String, usize)>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => collect_str_literals(group.stream(), literals),
+            TokenTree::Literal(literal) => {
+                if let Ok(syn::Lit::Str(value)) = syn::parse2::<syn::Lit>(literal.to_token_stream()) {
+                    literals.push((value.value(), line(literal.span())));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An element and the literal classes of its `class` attributes.
+type ScannedElement = (Element, Vec<(String, usize)>);
+
+fn is_punct(token: Option<&TokenTree>, char: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == char)
+}
+
+/// Whether an attribute name joiner, `-` in `aria-label` or the `:` in
+/// `on:click`, is at `index` rather than a path separator.
+fn is_name_joiner(tokens: &[TokenTree], index: usize) -> bool {
+    let joiner = match tokens.get(index) {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '-' => true,
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+            punct.spacing() == Spacing::Alone && !is_punct(tokens.get(index.wrapping_sub(1)), ':')
+        }
+        _ => false,
+    };
+    joiner && matches!(tokens.get(index + 1), Some(TokenTree::Ident(_)))
+}
+
+/// Whether an attribute starts at `index`, a name followed by `=`, another
+/// attribute or the end of the tag.
+fn is_attribute_start(tokens: &[TokenTree], index: usize) -> bool {
+    if !matches!(tokens.get(index), Some(TokenTree::Ident(_))) {
+        return false;
+    }
+    let mut end = index + 1;
+    while is_name_joiner(tokens, end) {
+        end += 2;
+    }
+    end > index + 1 || is_punct(tokens.get(end), '=')
+}
+
+/// Elements of `view!` markup, recursing into the Rust blocks it holds.
+fn scan_markup(tokens: TokenStream, elements: &mut Vec<ScannedElement>) {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut index = 0;
+    while index < tokens.len() {
+        match (&tokens[index], tokens.get(index + 1)) {
+            (TokenTree::Punct(punct), Some(TokenTree::Ident(tag))) if punct.as_char() == '<' => {
+                index = scan_element(&tokens, index + 1, tag.to_string(), line(tag.span()), elements);
+            }
+            (TokenTree::Group(group), _) => {
+                scan_rust(group.stream(), elements);
+                index += 1;
+            }
+            _ => index += 1,
+        }
+    }
+}
+
+/// Scans the attributes of the element whose tag is at `start`, returning
+/// the index after its opening tag.
+fn scan_element(
+    tokens: &[TokenTree],
+    start: usize,
+    tag: String,
+    tag_line: usize,
+    elements: &mut Vec<ScannedElement>,
+) -> usize {
+    let mut attributes = Vec::new();
+    let mut classes = Vec::new();
+    // Whether the tokens are an unbraced value like `move || open.get()`.
+    let mut in_value = false;
+    let mut index = start + 1;
+    while index < tokens.len() {
+        match &tokens[index] {
+            TokenTree::Punct(punct) if punct.as_char() == '>' => {
+                index += 1;
+                break;
+            }
+            TokenTree::Group(group) => {
+                scan_rust(group.stream(), elements);
+                index += 1;
+            }
+            TokenTree::Ident(ident) if !in_value || is_attribute_start(tokens, index) => {
+                let mut name = ident.to_string();
+                in_value = false;
+                index += 1;
+                while is_name_joiner(tokens, index) {
+                    name.push_str(&tokens[index].to_string());
+                    name.push_str(&tokens[index + 1].to_string());
+                    index += 2;
+                }
+                if is_punct(tokens.get(index), '=') {
+                    index += 1;
+                    match tokens.get(index) {
+                        Some(TokenTree::Literal(literal)) => {
+                            if name == "class" {
+                                if let Ok(syn::Lit::Str(value)) = syn::parse2::<syn::Lit>(literal.to_token_stream()) {
+                                    classes.push((value.value(), line(literal.span())));
+                                }
+                            }
+                            index += 1;
+                        }
+                        Some(TokenTree::Group(_)) => {}
+                        _ => in_value = true,
+                    }
+                }
+                if let Some(class) = name.strip_prefix("class:") {
+                    classes.push((class.to_string(), tag_line));
+                }
+                attributes.push(name);
+            }
+            _ => index += 1,
+        }
+    }
+
+    elements.push((
+        Element { tag, attributes, file: PathBuf::new(), line: tag_line, component: None },
+        classes,
+    ));
+    index
+}
+
+/// Elements of the `view!` macros in Rust code.
+fn scan_rust(tokens: TokenStream, elements: &mut Vec<ScannedElement>) {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    for (index, token) in tokens.iter().enumerate() {
+        let TokenTree::Group(group) = token else {
+            continue;
+        };
+        let is_view = index >= 2
+            && is_punct(tokens.get(index - 1), '!')
+            && matches!(&tokens[index - 2], TokenTree::Ident(ident) if ident == "view");
+        if is_view && group.delimiter() != Delimiter::None {
+            scan_markup(group.stream(), elements);
+        } else {
+            scan_rust(group.stream(), elements);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_markup() {
+        let tokens: TokenStream = r#"
+            <div class="flex gap-2" aria-label=move || label.get() on:click=handle_click>
+                <img hidden src=src/>
+                {move || view! { <span class:hidden=true role="status">"Loading"</span> }}
+            </div>
+        "#
+        .parse()
+        .unwrap();
+        let mut elements = Vec::new();
+        scan_markup(tokens, &mut elements);
+
+        let summary = elements
+            .iter()
+            .map(|(element, classes)| (element.tag.as_str(), element.attributes.clone(), classes.iter().map(|(class, _)| class.clone()).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "div",
+                    vec!["class".to_string(), "aria-label".to_string(), "on:click".to_string()],
+                    vec!["flex gap-2".to_string()]
+                ),
+                ("img", vec!["hidden".to_string(), "src".to_string()], vec![]),
+                ("span", vec!["class:hidden".to_string(), "role".to_string()], vec!["hidden".to_string()]),
+            ]
+        );
+    }
+}
@@ -6,7 +6,7 @@ use std::path::PathBuf;
 mod commands;
 mod utils;
 
-use commands::{add, init, validate, list};
+use commands::{add, init, validate, list, status};
 
 #[derive(Parser)]
 #[command(name = "rust-shadcn")]
@@ -30,23 +30,36 @@ enum Commands {
         #[arg(long, default_value = "leptos")]
         framework: String,
         
-        /// Theme to use
+        /// Theme to use: default or new-york
         #[arg(long, default_value = "default")]
         theme: String,
+
+        /// Base color of the theme
+        #[arg(long, default_value = "neutral")]
+        base_color: String,
     },
     
-    /// Add a component to your project
+    /// Add components and the components they depend on to your project
     Add {
-        /// Component name to add
-        component: String,
+        /// Components to add: names, @namespace/names, or URLs or paths of registry items
+        #[arg(required = true)]
+        components: Vec<String>,
         
         /// Framework to use (currently only Leptos supported)
         #[arg(long, default_value = "leptos")]
         framework: String,
         
         /// Project directory
-        #[arg(default_value = ".")]
+        #[arg(long, default_value = ".")]
         path: PathBuf,
+        
+        /// Replace component files that exist with other content
+        #[arg(long)]
+        overwrite: bool,
+        
+        /// Show what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     
     /// List available components
@@ -59,12 +72,20 @@ enum Commands {
         #[arg(long)]
         completed: bool,
         
-        /// Show only missing components
+        /// Show only missing components, including placeholders
         #[arg(long)]
         missing: bool,
+
+        /// Workspace directory, or any directory inside it
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "table")]
+        format: status::OutputFormat,
     },
     
-    /// Validate component quality and consistency
+    /// Validate component props, variants, accessibility and Tailwind classes
     Validate {
         /// Component name to validate (or 'all' for all components)
         #[arg(default_value = "all")]
@@ -73,6 +94,18 @@ enum Commands {
         /// Project directory
         #[arg(default_value = ".")]
         path: PathBuf,
+
+        /// Report format
+        #[arg(long, value_enum, default_value = "text")]
+        format: validate::ReportFormat,
+
+        /// Write the report to a file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Fail on warnings as well as errors
+        #[arg(long)]
+        strict: bool,
     },
     
     /// Check component status and completion
@@ -80,6 +113,14 @@ enum Commands {
         /// Show detailed status information
         #[arg(long)]
         detailed: bool,
+
+        /// Workspace directory, or any directory inside it
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "table")]
+        format: status::OutputFormat,
     },
 }
 
@@ -94,7 +135,7 @@ async fn main() -> anyhow::Result<()> {
         .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏");
     
     match &cli.command {
-        Commands::Init { path, framework, theme } => {
+        Commands::Init { path, framework, theme, base_color } => {
             if framework != "leptos" {
                 eprintln!("{}", style("⚠️  Warning: Only Leptos framework is currently supported").yellow());
             }
@@ -103,60 +144,60 @@ async fn main() -> anyhow::Result<()> {
             pb.set_style(progress_style.clone());
             pb.set_message("Initializing Leptos shadcn/ui project...");
             
-            init::init_project(path, framework, theme).await?;
+            init::init_project(path, theme, base_color).await?;
             
             pb.finish_with_message("✅ Project initialized successfully!");
             println!("\n🎉 Your Leptos shadcn/ui project is ready!");
             println!("📁 Project directory: {}", path.display());
             println!("🔧 Framework: {}", framework);
-            println!("🎨 Theme: {}", theme);
+            println!("🎨 Theme: {} ({})", theme, base_color);
             println!("\nNext steps:");
             println!("  rust-shadcn add button --framework leptos");
             println!("  rust-shadcn add card --framework leptos");
         }
         
-        Commands::Add { component, framework, path } => {
+        Commands::Add { components, framework, path, overwrite, dry_run } => {
             if framework != "leptos" {
                 eprintln!("{}", style("⚠️  Warning: Only Leptos framework is currently supported").yellow());
             }
             
-            let pb = ProgressBar::new_spinner();
-            pb.set_style(progress_style.clone());
-            pb.set_message(format!("Adding {} component...", component));
-            
-            add::add_component(component, framework, path.clone()).await?;
+            add::add_components(components, path.clone(), *overwrite, *dry_run).await?;
             
-            pb.finish_with_message(format!("✅ {} component added successfully!", component));
-            println!("\n📦 Component '{}' has been added to your project", component);
-            println!("📁 Location: {}/src/components/{}", path.display(), component);
-            println!("\nTo use the component:");
-            println!("  use crate::components::{}::{};", component, component);
+            if !dry_run {
+                println!("\n📦 Added {} to your project", components.join(", "));
+            }
         }
         
-        Commands::List { framework, completed, missing } => {
+        Commands::List { framework, completed, missing, path, format } => {
             if framework != "leptos" {
                 eprintln!("{}", style("⚠️  Warning: Only Leptos framework is currently supported").yellow());
             }
             
-            list::list_components(framework, *completed, *missing).await?;
+            list::list_components(framework, path, *completed, *missing, *format).await?;
         }
         
-        Commands::Validate { component, path } => {
+        Commands::Validate { component, path, format, output, strict } => {
             let pb = ProgressBar::new_spinner();
             pb.set_style(progress_style.clone());
             pb.set_message("Validating components...");
             
-            validate::validate_components(component, path.clone()).await?;
+            let result = validate::validate_components(component, path.clone(), *format, output.clone(), *strict).await;
             
-            pb.finish_with_message("✅ Component validation completed!");
+            match result {
+                Ok(()) => pb.finish_with_message("✅ Component validation completed!"),
+                Err(error) => {
+                    pb.finish_with_message("❌ Component validation failed!");
+                    return Err(error);
+                }
+            }
         }
         
-        Commands::Status { detailed } => {
+        Commands::Status { detailed, path, format } => {
             let pb = ProgressBar::new_spinner();
             pb.set_style(progress_style.clone());
             pb.set_message("Checking component status...");
             
-            let status = commands::status::get_status(*detailed).await?;
+            let status = status::get_status(path, *detailed, *format).await?;
             pb.finish_with_message("✅ Status check completed!");
             
             println!("{}", status);
@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+
+const BADGE_CLASS: &str = "inline-flex items-center rounded-md px-2.5 text-xs bg-primry";
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum BadgeVariant {
+    #[default]
+    Default,
+    Secondary,
+    Outline,
+}
+
+impl BadgeVariant {
+    fn class(self) -> &'static str {
+        match self {
+            Self::Default => "bg-primary text-primary-foreground",
+            Self::Secondary => "bg-secondary text-secondary-foreground",
+            _ => "",
+        }
+    }
+}
+
+/// A badge, missing the id and style props
+#[component]
+pub fn Badge(
+    #[prop(into, optional)] variant: MaybeProp<BadgeVariant>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] onDismiss: Option<Callback<()>>,
+    #[prop(optional)] dismissed: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    let _ = (class, onDismiss, dismissed);
+    view! {
+        <div class=BADGE_CLASS data-variant=move || variant.get().unwrap_or_default().class() aria-labeled="badge" on:click=move |_| {}>
+            <img src="badge.png" />
+            <span class="ml-1 text_xs" aria-hidden="true">{children()}</span>
+        </div>
+    }
+}
+
+/// Provides the badge context, rendering no markup of its own
+#[component]
+pub fn BadgeProvider(children: Children) -> impl IntoView {
+    provide_context(BadgeVariant::Default);
+    children()
+}
+
+/// A complete badge
+#[component]
+pub fn BadgeLabel(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] style: MaybeProp<String>,
+    #[prop(optional)] on_click: Option<Callback<()>>,
+) -> impl IntoView {
+    let _ = on_click;
+    view! { <label class=move || class.get() id=move || id.get() style=move || style.get()>"Label"</label> }
+}
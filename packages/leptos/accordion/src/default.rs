@@ -1,8 +1,15 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use leptos::html::Div;
 use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos_node_ref::AnyNodeRef;
+use leptos_shadcn_utils::collapsible::{hidden_attr, use_collapsible_content};
 use web_sys::MouseEvent;
 use web_sys::KeyboardEvent;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AccordionType {
     Single,
     Multiple,
@@ -14,7 +21,7 @@ impl Default for AccordionType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AccordionOrientation {
     Vertical,
     Horizontal,
@@ -26,6 +33,185 @@ impl Default for AccordionOrientation {
     }
 }
 
+impl AccordionOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccordionOrientation::Vertical => "vertical",
+            AccordionOrientation::Horizontal => "horizontal",
+        }
+    }
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Open items after toggling `item`
+pub fn toggled_value(current: &[String], item: &str, r#type: AccordionType, collapsible: bool) -> Vec<String> {
+    let is_open = current.iter().any(|value| value == item);
+    match r#type {
+        AccordionType::Single if is_open => {
+            if collapsible {
+                Vec::new()
+            } else {
+                current.to_vec()
+            }
+        }
+        AccordionType::Single => vec![item.to_string()],
+        AccordionType::Multiple if is_open => current.iter().filter(|value| *value != item).cloned().collect(),
+        AccordionType::Multiple => {
+            let mut value = current.to_vec();
+            value.push(item.to_string());
+            value
+        }
+    }
+}
+
+/// Position among `count` enabled triggers to move focus to for `key`, from
+/// `current`. Arrow keys follow the orientation and wrap around.
+pub fn navigation_target(key: &str, current: usize, count: usize, orientation: AccordionOrientation) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let (previous, next) = match orientation {
+        AccordionOrientation::Vertical => ("ArrowUp", "ArrowDown"),
+        AccordionOrientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+    };
+    match key {
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        key if key == next => Some((current + 1) % count),
+        key if key == previous => Some((current + count - 1) % count),
+        _ => None,
+    }
+}
+
+/// Trigger registered for keyboard navigation
+#[derive(Clone, Copy)]
+struct RegisteredTrigger {
+    id: usize,
+    node_ref: AnyNodeRef,
+    disabled: Signal<bool>,
+}
+
+/// State shared by the items of an [`Accordion`]
+#[derive(Clone, Copy)]
+pub struct AccordionContext {
+    pub r#type: Signal<AccordionType>,
+    pub orientation: Signal<AccordionOrientation>,
+    pub collapsible: Signal<bool>,
+    pub disabled: Signal<bool>,
+    pub value: RwSignal<Vec<String>>,
+    on_value_change: Option<Callback<Vec<String>>>,
+    /// Triggers in document order
+    triggers: StoredValue<Vec<RegisteredTrigger>>,
+}
+
+impl AccordionContext {
+    /// The enclosing accordion. Without one (or without a reactive owner to
+    /// look it up from) the part gets a standalone single accordion.
+    fn current() -> Self {
+        use_context::<Self>().unwrap_or_else(|| Self {
+            r#type: Signal::default(),
+            orientation: Signal::default(),
+            collapsible: Signal::default(),
+            disabled: Signal::default(),
+            value: RwSignal::new(Vec::new()),
+            on_value_change: None,
+            triggers: StoredValue::new(Vec::new()),
+        })
+    }
+
+    fn set_value(self, value: Vec<String>) {
+        self.value.set(value.clone());
+        if let Some(on_value_change) = self.on_value_change {
+            on_value_change.run(value);
+        }
+    }
+
+    /// Opens or closes `item`, following the accordion type
+    pub fn toggle(self, item: &str) {
+        let value = self.value.with_untracked(|value| {
+            toggled_value(value, item, self.r#type.get_untracked(), self.collapsible.get_untracked())
+        });
+        self.set_value(value);
+    }
+
+    /// Opens `item` if it is closed
+    pub fn open(self, item: &str) {
+        if !self.value.with_untracked(|value| value.iter().any(|value| value == item)) {
+            self.toggle(item);
+        }
+    }
+
+    fn register_trigger(self, node_ref: AnyNodeRef, disabled: Signal<bool>) -> usize {
+        let id = next_id();
+        self.triggers.update_value(|triggers| triggers.push(RegisteredTrigger { id, node_ref, disabled }));
+        id
+    }
+
+    fn unregister_trigger(self, id: usize) {
+        self.triggers.update_value(|triggers| triggers.retain(|trigger| trigger.id != id));
+    }
+
+    /// Moves focus from trigger `id` to another enabled trigger. Returns
+    /// whether `key` was a navigation key.
+    fn focus_sibling(self, id: usize, key: &str) -> bool {
+        let enabled = self.triggers.with_value(|triggers| {
+            triggers
+                .iter()
+                .filter(|trigger| !trigger.disabled.get_untracked())
+                .copied()
+                .collect::<Vec<_>>()
+        });
+        let Some(current) = enabled.iter().position(|trigger| trigger.id == id) else {
+            return false;
+        };
+        let Some(target) = navigation_target(key, current, enabled.len(), self.orientation.get_untracked()) else {
+            return false;
+        };
+
+        if let Some(element) = enabled[target]
+            .node_ref
+            .get_untracked()
+            .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            let _ = element.focus();
+        }
+        true
+    }
+}
+
+/// State of one [`AccordionItem`], shared by its trigger and content
+#[derive(Clone)]
+pub struct AccordionItemContext {
+    pub value: String,
+    pub disabled: Signal<bool>,
+    pub expanded: Signal<bool>,
+    trigger_id: String,
+    content_id: String,
+}
+
+impl AccordionItemContext {
+    fn new(context: AccordionContext, value: String, disabled: Signal<bool>) -> Self {
+        let id = next_id();
+        let item_value = value.clone();
+        Self {
+            disabled: Signal::derive(move || disabled.get() || context.disabled.get()),
+            expanded: Signal::derive(move || context.value.with(|value| value.contains(&item_value))),
+            trigger_id: format!("accordion-trigger-{id}"),
+            content_id: format!("accordion-content-{id}"),
+            value,
+        }
+    }
+
+    fn current(context: AccordionContext) -> Self {
+        use_context::<Self>().unwrap_or_else(|| Self::new(context, String::new(), Signal::default()))
+    }
+}
+
 #[component]
 pub fn Accordion(
     #[prop(into, optional)] r#type: Signal<AccordionType>,
@@ -45,24 +231,24 @@ pub fn Accordion(
         }
     });
 
-    provide_context(r#type);
-    provide_context(orientation);
-    provide_context(collapsible);
-    provide_context(disabled);
-    provide_context(value);
-    provide_context(on_value_change);
+    provide_context(AccordionContext {
+        r#type,
+        orientation,
+        collapsible,
+        disabled,
+        value,
+        on_value_change,
+        triggers: StoredValue::new(Vec::new()),
+    });
 
     let computed_class = Signal::derive(move || {
-        format!("w-full {}", class.get().unwrap_or_default())
+        tw_merge!("w-full", class.get().unwrap_or_default())
     });
 
     view! {
         <div
             class=move || computed_class.get()
-            data-orientation=move || match orientation.get() {
-                AccordionOrientation::Vertical => "vertical",
-                AccordionOrientation::Horizontal => "horizontal",
-            }
+            data-orientation=move || orientation.get().as_str()
         >
             {children.map(|c| c())}
         </div>
@@ -76,19 +262,14 @@ pub fn AccordionItem(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let accordion_disabled = expect_context::<Signal<bool>>();
-    let accordion_value = expect_context::<RwSignal<Vec<String>>>();
-    
-    let is_disabled = Signal::derive(move || disabled.get() || accordion_disabled.get());
-    let value_clone = value.clone();
-    let is_expanded = Signal::derive(move || accordion_value.get().contains(&value_clone));
-
-    provide_context(value.clone());
-    provide_context(is_disabled);
-    provide_context(is_expanded);
+    let context = AccordionContext::current();
+    let item = AccordionItemContext::new(context, value, disabled);
+    let is_expanded = item.expanded;
+    let is_disabled = item.disabled;
+    provide_context(item);
 
     let computed_class = Signal::derive(move || {
-        format!("border-b {}", class.get().unwrap_or_default())
+        tw_merge!("border-b", class.get().unwrap_or_default())
     });
 
     view! {
@@ -96,6 +277,7 @@ pub fn AccordionItem(
             class=move || computed_class.get()
             data-state=move || if is_expanded.get() { "open" } else { "closed" }
             data-disabled=move || is_disabled.get()
+            data-orientation=move || context.orientation.get().as_str()
         >
             {children.map(|c| c())}
         </div>
@@ -108,116 +290,53 @@ pub fn AccordionTrigger(
     #[prop(into, optional)] as_child: Option<Callback<AccordionTriggerChildProps>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let item_value = expect_context::<String>();
-    let is_disabled = expect_context::<Signal<bool>>();
-    let is_expanded = expect_context::<Signal<bool>>();
-    let accordion_type = expect_context::<Signal<AccordionType>>();
-    let accordion_value = expect_context::<RwSignal<Vec<String>>>();
-    let on_value_change = expect_context::<Option<Callback<Vec<String>>>>();
-    let collapsible = expect_context::<Signal<bool>>();
-
-    // Toggle logic extracted so it can be used by both click and keydown without moving closures
-    let toggle = {
-        let accordion_value = accordion_value.clone();
-        let on_value_change = on_value_change.clone();
-        let item_value = item_value.clone();
-        move || {
-            if is_disabled.get() {
-                return;
-            }
+    let context = AccordionContext::current();
+    let item = AccordionItemContext::current(context);
+    let is_disabled = item.disabled;
+    let is_expanded = item.expanded;
+    let item_value = StoredValue::new(item.value.clone());
 
-            let mut current_value = accordion_value.get();
-            
-            match accordion_type.get() {
-                AccordionType::Single => {
-                    if current_value.contains(&item_value) {
-                        if collapsible.get() {
-                            current_value.clear();
-                        }
-                    } else {
-                        current_value.clear();
-                        current_value.push(item_value.clone());
-                    }
-                }
-                AccordionType::Multiple => {
-                    if let Some(index) = current_value.iter().position(|v| v == &item_value) {
-                        current_value.remove(index);
-                    } else {
-                        current_value.push(item_value.clone());
-                    }
-                }
-            }
+    let node_ref = AnyNodeRef::new();
+    let trigger_id = context.register_trigger(node_ref, is_disabled);
+    on_cleanup(move || context.unregister_trigger(trigger_id));
 
-            accordion_value.set(current_value.clone());
-            if let Some(callback) = &on_value_change {
-                callback.run(current_value);
-            }
+    let toggle = move || {
+        if !is_disabled.get_untracked() {
+            item_value.with_value(|value| context.toggle(value));
         }
     };
 
-    let handle_click = {
-        let toggle = toggle.clone();
-        move |_: MouseEvent| {
-            toggle();
-        }
-    };
+    let handle_click = move |_: MouseEvent| toggle();
 
-    let handle_keydown = {
-        let toggle = toggle.clone();
-        move |e: KeyboardEvent| {
-            match e.key().as_str() {
-                "Enter" | " " => {
+    let handle_keydown = move |e: KeyboardEvent| {
+        match e.key().as_str() {
+            "Enter" | " " => {
+                e.prevent_default();
+                toggle();
+            }
+            key => {
+                if context.focus_sibling(trigger_id, key) {
                     e.prevent_default();
-                    toggle();
                 }
-                _ => {}
             }
         }
     };
 
     let computed_class = Signal::derive(move || {
-        format!(
-            "flex flex-1 items-center justify-between py-4 font-medium transition-all hover:underline [&[data-state=open]>svg]:rotate-180 {}",
-            class.get().unwrap_or_default()
+        tw_merge!(
+            "flex flex-1 items-center justify-between py-4 font-medium transition-all hover:underline [&[data-state=open]>svg]:rotate-180",
+            class.get().unwrap_or_default(),
         )
     });
 
     if let Some(as_child) = as_child {
         let child_props = AccordionTriggerChildProps {
             class: computed_class.get(),
-            onclick: Some(Callback::new(move |_| {
-                if is_disabled.get() {
-                    return;
-                }
-
-                let mut current_value = accordion_value.get();
-                
-                match accordion_type.get() {
-                    AccordionType::Single => {
-                        if current_value.contains(&item_value) {
-                            if collapsible.get() {
-                                current_value.clear();
-                            }
-                        } else {
-                            current_value.clear();
-                            current_value.push(item_value.clone());
-                        }
-                    }
-                    AccordionType::Multiple => {
-                        if let Some(index) = current_value.iter().position(|v| v == &item_value) {
-                            current_value.remove(index);
-                        } else {
-                            current_value.push(item_value.clone());
-                        }
-                    }
-                }
-
-                accordion_value.set(current_value.clone());
-                if let Some(callback) = &on_value_change {
-                    callback.run(current_value);
-                }
-            })),
-            onkeydown: Some(Callback::new(move |e| handle_keydown(e))),
+            node_ref,
+            id: item.trigger_id,
+            controls: item.content_id,
+            onclick: Some(Callback::new(move |_| toggle())),
+            onkeydown: Some(Callback::new(handle_keydown)),
             disabled: is_disabled.get(),
             expanded: is_expanded.get(),
         };
@@ -225,10 +344,14 @@ pub fn AccordionTrigger(
     } else {
         view! {
             <button
+                node_ref=node_ref
+                id=item.trigger_id
                 class=move || computed_class.get()
                 data-state=move || if is_expanded.get() { "open" } else { "closed" }
+                data-orientation=move || context.orientation.get().as_str()
                 disabled=is_disabled
                 aria-expanded=move || is_expanded.get()
+                aria-controls=item.content_id
                 on:click=handle_click
                 on:keydown=handle_keydown
             >
@@ -252,6 +375,11 @@ pub fn AccordionTrigger(
 #[derive(Debug, Clone)]
 pub struct AccordionTriggerChildProps {
     pub class: String,
+    /// Must be attached to the rendered element for arrow-key navigation
+    pub node_ref: AnyNodeRef,
+    pub id: String,
+    /// Id of the content, for `aria-controls`
+    pub controls: String,
     pub onclick: Option<Callback<()>>,
     pub onkeydown: Option<Callback<KeyboardEvent>>,
     pub disabled: bool,
@@ -261,28 +389,84 @@ pub struct AccordionTriggerChildProps {
 #[component]
 pub fn AccordionContent(
     #[prop(into, optional)] class: MaybeProp<String>,
+    /// Keep the content in the document while closed, e.g. for SSR and SEO
     #[prop(into, optional)] force_mount: Signal<bool>,
-    #[prop(optional)] children: Option<Children>,
+    /// Keep closed content findable with find-in-page, which opens the item
+    #[prop(into, optional)] hidden_until_found: Signal<bool>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
-    let is_expanded = expect_context::<Signal<bool>>();
+    let context = AccordionContext::current();
+    let item = AccordionItemContext::current(context);
+    let is_expanded = item.expanded;
+    let item_value = StoredValue::new(item.value.clone());
+
+    let content_ref = NodeRef::<Div>::new();
+    let measure_ref = NodeRef::<Div>::new();
+    let state = use_collapsible_content(
+        content_ref,
+        measure_ref,
+        is_expanded,
+        Callback::new(move |_| item_value.with_value(|value| context.open(value))),
+    );
 
     let computed_class = Signal::derive(move || {
-        format!(
-            "overflow-hidden text-sm transition-all data-[state=closed]:animate-accordion-up data-[state=open]:animate-accordion-down {}",
-            class.get().unwrap_or_default()
+        tw_merge!(
+            "overflow-hidden text-sm transition-all data-[state=closed]:animate-accordion-up data-[state=open]:animate-accordion-down",
+            class.get().unwrap_or_default(),
         )
     });
 
-    let _should_render = Signal::derive(move || force_mount.get() || is_expanded.get());
+    let should_render = Signal::derive(move || force_mount.get() || hidden_until_found.get() || state.present.get());
 
     view! {
         <div
+            node_ref=content_ref
+            id=item.content_id
+            role="region"
+            aria-labelledby=item.trigger_id
             class=move || computed_class.get()
+            style=move || state.size.get().map(|size| size.css_vars("accordion"))
+            hidden=move || hidden_attr(state.present.get(), hidden_until_found.get())
             data-state=move || if is_expanded.get() { "open" } else { "closed" }
+            data-orientation=move || context.orientation.get().as_str()
+            on:animationend=move |_| state.on_animation_end()
         >
-            <div class="pb-4 pt-0">
-                {children.map(|c| c())}
+            <div node_ref=measure_ref class="pb-4 pt-0">
+                {move || should_render.get().then(|| children.clone().map(|c| c()))}
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn test_toggled_value() {
+        assert_eq!(toggled_value(&values(&["a"]), "b", AccordionType::Single, false), values(&["b"]));
+        // A single accordion only closes its open item when collapsible
+        assert_eq!(toggled_value(&values(&["a"]), "a", AccordionType::Single, false), values(&["a"]));
+        assert_eq!(toggled_value(&values(&["a"]), "a", AccordionType::Single, true), values(&[]));
+        assert_eq!(toggled_value(&values(&["a"]), "b", AccordionType::Multiple, false), values(&["a", "b"]));
+        assert_eq!(toggled_value(&values(&["a", "b"]), "a", AccordionType::Multiple, false), values(&["b"]));
+    }
+
+    #[test]
+    fn test_navigation_target() {
+        let vertical = AccordionOrientation::Vertical;
+        assert_eq!(navigation_target("ArrowDown", 0, 3, vertical), Some(1));
+        assert_eq!(navigation_target("ArrowDown", 2, 3, vertical), Some(0));
+        assert_eq!(navigation_target("ArrowUp", 0, 3, vertical), Some(2));
+        assert_eq!(navigation_target("Home", 2, 3, vertical), Some(0));
+        assert_eq!(navigation_target("End", 0, 3, vertical), Some(2));
+        // Arrows across the orientation are ignored
+        assert_eq!(navigation_target("ArrowRight", 0, 3, vertical), None);
+        assert_eq!(navigation_target("ArrowRight", 0, 3, AccordionOrientation::Horizontal), Some(1));
+        assert_eq!(navigation_target("End", 0, 0, vertical), None);
+    }
+}
@@ -4,11 +4,17 @@ mod tdd_tests {
     use crate::default::{Accordion, AccordionItem, AccordionTrigger, AccordionContent, AccordionType, AccordionOrientation};
     use std::sync::{Arc, Mutex};
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     // ===== TDD ENHANCED TESTS - GREEN PHASE =====
     // These tests now implement real functionality and verify actual behavior
 
     #[test]
     fn test_accordion_basic_rendering() {
+        init_executor();
         let _accordion_view = view! {
             <Accordion>
                 <AccordionItem value="item1">
@@ -21,6 +27,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_item_component() {
+        init_executor();
         let _item_view = view! {
             <AccordionItem value="test-item">
                 <AccordionTrigger>"Test Item"</AccordionTrigger>
@@ -38,6 +45,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_content_component() {
+        init_executor();
         let _content_view = view! {
             <AccordionContent>"Content"</AccordionContent>
         };
@@ -45,6 +53,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_single_type() {
+        init_executor();
         let accordion_type = Signal::stored(AccordionType::Single);
         let _accordion_view = view! {
             <Accordion r#type=accordion_type>
@@ -59,6 +68,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_multiple_type() {
+        init_executor();
         let accordion_type = Signal::stored(AccordionType::Multiple);
         let _accordion_view = view! {
             <Accordion r#type=accordion_type>
@@ -73,6 +83,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_vertical_orientation() {
+        init_executor();
         let orientation = Signal::stored(AccordionOrientation::Vertical);
         let _accordion_view = view! {
             <Accordion orientation=orientation>
@@ -87,6 +98,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_horizontal_orientation() {
+        init_executor();
         let orientation = Signal::stored(AccordionOrientation::Horizontal);
         let _accordion_view = view! {
             <Accordion orientation=orientation>
@@ -101,6 +113,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_collapsible_property() {
+        init_executor();
         let collapsible = Signal::stored(true);
         let _accordion_view = view! {
             <Accordion collapsible=collapsible>
@@ -115,6 +128,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_disabled_state() {
+        init_executor();
         let disabled = Signal::stored(true);
         let _accordion_view = view! {
             <Accordion disabled=disabled>
@@ -129,6 +143,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_item_disabled() {
+        init_executor();
         let item_disabled = Signal::stored(true);
         let _item_view = view! {
             <AccordionItem value="item1" disabled=item_disabled>
@@ -141,6 +156,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_value_management() {
+        init_executor();
         let value = RwSignal::new(vec!["item1".to_string()]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -155,6 +171,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_default_value() {
+        init_executor();
         let default_value = vec!["item1".to_string(), "item2".to_string()];
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
@@ -174,6 +191,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_value_change_callback() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let callback_called = Arc::new(Mutex::new(false));
         let callback_called_clone = callback_called.clone();
@@ -195,6 +213,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_custom_styling() {
+        init_executor();
         let custom_class = "custom-accordion-class";
         let _accordion_view = view! {
             <Accordion class=custom_class>
@@ -209,6 +228,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_multiple_items() {
+        init_executor();
         let _accordion_view = view! {
             <Accordion>
                 <AccordionItem value="item1">
@@ -229,6 +249,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_click_handling() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -242,6 +263,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_keyboard_navigation() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -255,6 +277,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_accessibility_features() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -268,6 +291,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_aria_attributes() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -281,6 +305,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_animation_support() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -294,6 +319,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_force_mount() {
+        init_executor();
         let force_mount = Signal::stored(true);
         let _content_view = view! {
             <AccordionContent force_mount=force_mount>"Content"</AccordionContent>
@@ -303,6 +329,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_as_child_prop() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -316,6 +343,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_state_management() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -329,6 +357,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_context_management() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -342,6 +371,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_integration_scenarios() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value r#type=Signal::stored(AccordionType::Multiple) collapsible=Signal::stored(true)>
@@ -359,6 +389,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_complete_workflow() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let callback_called = Arc::new(Mutex::new(false));
         let callback_called_clone = callback_called.clone();
@@ -385,6 +416,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_error_handling() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -398,6 +430,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_memory_management() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -411,6 +444,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_performance_comprehensive() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -424,6 +458,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_responsive_design() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value orientation=Signal::stored(AccordionOrientation::Vertical)>
@@ -437,6 +472,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_theme_switching() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value class="theme-light">
@@ -450,6 +486,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_validation_comprehensive() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -463,6 +500,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_accessibility_comprehensive() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value>
@@ -476,6 +514,7 @@ mod tdd_tests {
 
     #[test]
     fn test_accordion_advanced_interactions() {
+        init_executor();
         let value = RwSignal::new(vec![]);
         let _accordion_view = view! {
             <Accordion value=value r#type=Signal::stored(AccordionType::Multiple)>
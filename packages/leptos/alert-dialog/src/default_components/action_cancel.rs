@@ -6,6 +6,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn AlertDialogAction(
@@ -24,7 +25,7 @@ pub fn AlertDialogAction(
 
     view! {
         <button
-            class=move || format!("inline-flex h-10 items-center justify-center rounded-md bg-primary px-4 py-2 text-sm font-semibold text-primary-foreground ring-offset-background transition-colors hover:bg-primary/90 focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("inline-flex h-10 items-center justify-center rounded-md bg-primary px-4 py-2 text-sm font-semibold text-primary-foreground ring-offset-background transition-colors hover:bg-primary/90 focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50", class.get().unwrap_or_default())
             on:click=handle_click
         >
             {children.map(|c| c())}
@@ -49,7 +50,7 @@ pub fn AlertDialogCancel(
 
     view! {
         <button
-            class=move || format!("inline-flex h-10 items-center justify-center rounded-md border border-input bg-background px-4 py-2 text-sm font-semibold ring-offset-background transition-colors hover:bg-accent hover:text-accent-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("inline-flex h-10 items-center justify-center rounded-md border border-input bg-background px-4 py-2 text-sm font-semibold ring-offset-background transition-colors hover:bg-accent hover:text-accent-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50", class.get().unwrap_or_default())
             on:click=handle_click
         >
             {children.map(|c| c())}
@@ -1,20 +1,50 @@
 //! AlertDialog content component
 //! 
 //! This module contains the AlertDialogContent component for the main
-//! alert dialog content area.
+//! alert dialog content area. Alert dialogs are modal but, unlike dialogs,
+//! are not dismissed by pressing outside the content.
 
+use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_utils::modal::{use_modal, ModalEvent, ModalOptions};
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn AlertDialogContent(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
-    #[prop(optional)] children: Option<Children>,
+    /// Runs before focus moves into the content; prevent it to focus something else
+    #[prop(optional)] on_open_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs before focus returns to the trigger; prevent it to focus something else
+    #[prop(optional)] on_close_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs on Escape; prevent it to keep the alert dialog open
+    #[prop(optional)] on_escape_key_down: Option<Callback<ModalEvent>>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     let open = expect_context::<RwSignal<bool>>();
+    let on_open_change = expect_context::<Option<Callback<bool>>>();
+    let content_ref = NodeRef::<Div>::new();
+
+    use_modal(
+        content_ref,
+        open.into(),
+        Callback::new(move |_| {
+            open.set(false);
+            if let Some(callback) = &on_open_change {
+                callback.run(false);
+            }
+        }),
+        ModalOptions {
+            on_open_auto_focus,
+            on_close_auto_focus,
+            on_escape_key_down,
+            dismiss_on_interact_outside: Signal::stored(false),
+            ..ModalOptions::default()
+        },
+    );
 
     let handle_click = move |e: MouseEvent| {
         e.stop_propagation();
@@ -26,16 +56,21 @@ pub fn AlertDialogContent(
             fallback=|| view! { <div></div> }
         >
             <div
-                class=move || format!("fixed inset-0 z-50 flex items-center justify-center bg-background/80 backdrop-blur-sm {}", class.get().unwrap_or_default())
+                class=move || tw_merge!("fixed inset-0 z-50 flex items-center justify-center bg-background/80 backdrop-blur-sm", class.get().unwrap_or_default())
                 id=move || id.get().unwrap_or_default()
                 style=move || style.get().unwrap_or_default()
                 on:click=handle_click
-                role="alertdialog"
-                aria-modal="true"
             >
-                <div class="fixed inset-0 z-50 bg-background/80 backdrop-blur-sm" on:click=move |_| open.set(false)>
-                    <div class="relative z-50 grid w-full max-w-lg gap-4 border bg-background p-6 shadow-lg sm:rounded-lg" on:click=handle_click>
-                        {children.map(|c| c())}
+                <div class="fixed inset-0 z-50 bg-background/80 backdrop-blur-sm">
+                    <div
+                        node_ref=content_ref
+                        class="relative z-50 grid w-full max-w-lg gap-4 border bg-background p-6 shadow-lg sm:rounded-lg"
+                        on:click=handle_click
+                        role="alertdialog"
+                        aria-modal="true"
+                        tabindex="-1"
+                    >
+                        {children.clone().map(|c| c())}
                     </div>
                 </div>
             </div>
@@ -5,6 +5,7 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn AlertDialogTitle(
@@ -15,7 +16,7 @@ pub fn AlertDialogTitle(
 ) -> impl IntoView {
     view! {
         <h2
-            class=move || format!("text-lg font-semibold {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("text-lg font-semibold", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
         >
@@ -33,7 +34,7 @@ pub fn AlertDialogDescription(
 ) -> impl IntoView {
     view! {
         <p
-            class=move || format!("text-sm text-muted-foreground {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("text-sm text-muted-foreground", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
         >
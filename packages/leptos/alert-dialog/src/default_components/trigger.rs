@@ -6,6 +6,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn AlertDialogTrigger(
@@ -24,7 +25,7 @@ pub fn AlertDialogTrigger(
 
     view! {
         <button
-            class=move || format!("alert-dialog-trigger {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("alert-dialog-trigger", class.get().unwrap_or_default())
             on:click=handle_click
         >
             {children.map(|c| c())}
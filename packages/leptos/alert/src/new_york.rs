@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const ALERT_CLASS: &str = "relative w-full rounded-lg border p-4";
 const ALERT_TITLE_CLASS: &str = "mb-1 font-medium leading-none tracking-tight";
@@ -47,7 +48,7 @@ pub fn Alert(
             AlertVariant::Warning => "border-yellow-500/50 text-yellow-600 dark:text-yellow-400",
         };
         
-        format!("{} {} {}", ALERT_CLASS, variant_class, class.get().unwrap_or_default())
+        tw_merge!(ALERT_CLASS, variant_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -69,7 +70,7 @@ pub fn AlertTitle(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", ALERT_TITLE_CLASS, class.get().unwrap_or_default())
+        tw_merge!(ALERT_TITLE_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -91,7 +92,7 @@ pub fn AlertDescription(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", ALERT_DESCRIPTION_CLASS, class.get().unwrap_or_default())
+        tw_merge!(ALERT_DESCRIPTION_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -3,6 +3,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use leptos_shadcn_signal_management::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// Signal-managed aspect-ratio state
 #[derive(Debug, Clone, PartialEq)]
@@ -44,12 +45,12 @@ pub fn SignalManagedAspectRatio(
         let hover_class = if state.is_hovered { "hover" } else { "" };
         let focus_class = if state.is_focused { "focus" } else { "" };
         
-        format!("{} {} {} {} {}", 
-            base_class, 
-            active_class, 
-            hover_class, 
+        tw_merge!(
+            base_class,
+            active_class,
+            hover_class,
             focus_class,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
 
@@ -128,12 +129,12 @@ pub fn EnhancedAspectRatio(
         let hover_class = if state.is_hovered { "hover:shadow-md" } else { "" };
         let focus_class = if state.is_focused { "focus:ring-2 focus:ring-ring" } else { "" };
         
-        format!("{} {} {} {} {}", 
-            base_class, 
-            active_class, 
-            hover_class, 
+        tw_merge!(
+            base_class,
+            active_class,
+            hover_class,
             focus_class,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
 
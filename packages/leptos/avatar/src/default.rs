@@ -1,10 +1,96 @@
+use std::time::Duration;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
 use leptos::prelude::*;
 use leptos_style::Style;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
 
 const AVATAR_CLASS: &str = "relative flex h-10 w-10 shrink-0 overflow-hidden rounded-full";
 const AVATAR_IMAGE_CLASS: &str = "aspect-square h-full w-full";
 const AVATAR_FALLBACK_CLASS: &str = "flex h-full w-full items-center justify-center rounded-full bg-muted";
 const AVATAR_GROUP_CLASS: &str = "flex -space-x-2";
+const AVATAR_OVERFLOW_CLASS: &str = "relative flex h-10 w-10 shrink-0 items-center justify-center rounded-full bg-muted text-sm font-medium ring-2 ring-background";
+
+/// Loading state of an [`AvatarImage`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageLoadingStatus {
+    /// No image has been requested yet
+    #[default]
+    Idle,
+    Loading,
+    Loaded,
+    /// The image failed to load or has no source
+    Error,
+}
+
+impl ImageLoadingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageLoadingStatus::Idle => "idle",
+            ImageLoadingStatus::Loading => "loading",
+            ImageLoadingStatus::Loaded => "loaded",
+            ImageLoadingStatus::Error => "error",
+        }
+    }
+}
+
+/// Whether the fallback is shown: once its delay has passed and for as long
+/// as the image is not loaded
+pub fn fallback_visible(status: ImageLoadingStatus, delay_elapsed: bool) -> bool {
+    delay_elapsed && status != ImageLoadingStatus::Loaded
+}
+
+/// Number of avatars hidden behind the "+N" indicator of a group
+pub fn overflow_count(total: usize, max: Option<usize>) -> usize {
+    max.map_or(0, |max| total.saturating_sub(max))
+}
+
+/// Image loading status shared by the parts of an [`Avatar`]
+#[derive(Clone, Copy)]
+struct AvatarContext {
+    status: RwSignal<ImageLoadingStatus>,
+}
+
+impl AvatarContext {
+    /// The enclosing avatar, or a standalone one outside of it (or without a
+    /// reactive owner to look it up from)
+    fn current() -> Self {
+        use_context::<Self>().unwrap_or_else(|| Self {
+            status: RwSignal::new(ImageLoadingStatus::Idle),
+        })
+    }
+}
+
+/// Avatars of an [`AvatarGroup`], in document order
+#[derive(Clone, Copy)]
+struct AvatarGroupContext {
+    max: MaybeProp<usize>,
+    next_id: StoredValue<usize>,
+    avatars: RwSignal<Vec<usize>>,
+}
+
+impl AvatarGroupContext {
+    fn register(self) -> usize {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.avatars.update(|avatars| avatars.push(id));
+        id
+    }
+
+    fn unregister(self, id: usize) {
+        self.avatars.update(|avatars| avatars.retain(|avatar| *avatar != id));
+    }
+
+    /// Whether avatar `id` is within the group's maximum
+    fn is_visible(&self, id: usize) -> bool {
+        let index = self.avatars.with(|avatars| avatars.iter().position(|avatar| *avatar == id));
+        match (index, self.max.get()) {
+            (Some(index), Some(max)) => index < max,
+            _ => true,
+        }
+    }
+}
 
 #[component]
 pub fn Avatar(
@@ -13,15 +99,33 @@ pub fn Avatar(
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    let context = AvatarContext {
+        status: RwSignal::new(ImageLoadingStatus::Idle),
+    };
+    provide_context(context);
+
+    // Inside a group, avatars beyond its maximum are hidden
+    let group = use_context::<AvatarGroupContext>();
+    let group_id = group.map(|group| {
+        let group_id = group.register();
+        on_cleanup(move || group.unregister(group_id));
+        group_id
+    });
+    let is_visible = move || group.zip(group_id).is_none_or(|(group, group_id)| group.is_visible(group_id));
+
     let computed_class = Signal::derive(move || {
-        format!("{} {}", AVATAR_CLASS, class.get().unwrap_or_default())
+        tw_merge!(AVATAR_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
         <div
             class=computed_class
             id=id.get().unwrap_or_default()
-            style=move || style.get().to_string()
+            style=move || {
+                let style = style.get().to_string();
+                if is_visible() { style } else { format!("{style} display: none;") }
+            }
+            data-status=move || context.status.get().as_str()
         >
             {children.map(|c| c())}
         </div>
@@ -30,43 +134,126 @@ pub fn Avatar(
 
 #[component]
 pub fn AvatarImage(
-    #[prop(into)] src: String,
+    #[prop(into)] src: Signal<String>,
     #[prop(into, optional)] alt: MaybeProp<String>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
+    /// Called whenever the image starts loading, loads or fails
+    #[prop(into, optional)] on_loading_status_change: Option<Callback<ImageLoadingStatus>>,
 ) -> impl IntoView {
+    let context = AvatarContext::current();
+
+    let set_status = move |status: ImageLoadingStatus| {
+        if context.status.get_untracked() != status {
+            context.status.set(status);
+            if let Some(on_loading_status_change) = on_loading_status_change {
+                on_loading_status_change.run(status);
+            }
+        }
+    };
+
+    // The image is preloaded off-document and only rendered once it has
+    // loaded, so a broken image never shows
+    let loader = StoredValue::new_local(None::<(web_sys::HtmlImageElement, Closure<dyn FnMut()>, Closure<dyn FnMut()>)>);
+    let stop_loading = move || {
+        if let Some((image, _, _)) = loader.try_update_value(Option::take).flatten() {
+            image.set_onload(None);
+            image.set_onerror(None);
+        }
+    };
+
+    Effect::new(move |_| {
+        let src = src.get();
+        stop_loading();
+        if src.is_empty() {
+            set_status(ImageLoadingStatus::Error);
+            return;
+        }
+        let Ok(image) = web_sys::HtmlImageElement::new() else {
+            set_status(ImageLoadingStatus::Error);
+            return;
+        };
+
+        set_status(ImageLoadingStatus::Loading);
+        let on_load: Closure<dyn FnMut()> = Closure::new(move || set_status(ImageLoadingStatus::Loaded));
+        let on_error: Closure<dyn FnMut()> = Closure::new(move || set_status(ImageLoadingStatus::Error));
+        image.set_onload(Some(on_load.as_ref().unchecked_ref()));
+        image.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        image.set_src(&src);
+        loader.set_value(Some((image, on_load, on_error)));
+    });
+    on_cleanup(stop_loading);
+
     let computed_class = Signal::derive(move || {
-        format!("{} {}", AVATAR_IMAGE_CLASS, class.get().unwrap_or_default())
+        tw_merge!(AVATAR_IMAGE_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
-        <img
-            class=computed_class
-            id=id.get().unwrap_or_default()
-            style=move || style.get().to_string()
-            src=src
-            alt=alt.get().unwrap_or_default()
-        />
+        <Show when=move || context.status.get() == ImageLoadingStatus::Loaded>
+            <img
+                class=computed_class
+                id=id.get().unwrap_or_default()
+                style=move || style.get().to_string()
+                src=move || src.get()
+                alt=move || alt.get().unwrap_or_default()
+            />
+        </Show>
     }
 }
 
 #[component]
 pub fn AvatarFallback(
+    /// Wait this long before showing the fallback, so it does not flash
+    /// while a fast image loads
+    #[prop(into, optional)] delay_ms: MaybeProp<u32>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    let context = AvatarContext::current();
+
+    let delay_ms = delay_ms.get_untracked();
+    let delay_elapsed = RwSignal::new(delay_ms.is_none());
+    if let Some(delay_ms) = delay_ms {
+        let timeout = StoredValue::new(None::<TimeoutHandle>);
+        Effect::new(move |_| {
+            let handle = set_timeout_with_handle(
+                move || delay_elapsed.set(true),
+                Duration::from_millis(delay_ms.into()),
+            );
+            timeout.set_value(handle.ok());
+        });
+        on_cleanup(move || {
+            if let Some(handle) = timeout.try_update_value(Option::take).flatten() {
+                handle.clear();
+            }
+        });
+    }
+
     let computed_class = Signal::derive(move || {
-        format!("{} {}", AVATAR_FALLBACK_CLASS, class.get().unwrap_or_default())
+        tw_merge!(AVATAR_FALLBACK_CLASS, class.get().unwrap_or_default())
+    });
+
+    // Rendered once and hidden with an inline style, which wins over the
+    // `flex` class where the `hidden` attribute would not
+    let is_visible = Signal::derive(move || fallback_visible(context.status.get(), delay_elapsed.get()));
+    let computed_style = Signal::derive(move || {
+        let style = style.get().to_string();
+        if is_visible.get() {
+            style
+        } else {
+            format!("{style}; display: none;")
+        }
     });
 
     view! {
         <div
             class=computed_class
             id=id.get().unwrap_or_default()
-            style=move || style.get().to_string()
+            style=computed_style
+            aria-hidden=move || (!is_visible.get()).then_some("true")
         >
             {children.map(|c| c())}
         </div>
@@ -75,13 +262,24 @@ pub fn AvatarFallback(
 
 #[component]
 pub fn AvatarGroup(
+    /// Number of avatars shown before the rest collapse into a "+N" indicator
+    #[prop(into, optional)] max: MaybeProp<usize>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    let group = AvatarGroupContext {
+        max,
+        next_id: StoredValue::new(0),
+        avatars: RwSignal::new(Vec::new()),
+    };
+    provide_context(group);
+
+    let hidden = move || overflow_count(group.avatars.with(Vec::len), max.get());
+
     let computed_class = Signal::derive(move || {
-        format!("{} {}", AVATAR_GROUP_CLASS, class.get().unwrap_or_default())
+        tw_merge!(AVATAR_GROUP_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -91,6 +289,45 @@ pub fn AvatarGroup(
             style=move || style.get().to_string()
         >
             {children.map(|c| c())}
+            <Show when=move || hidden() != 0>
+                <div class=AVATAR_OVERFLOW_CLASS aria-label=move || format!("{} more", hidden())>
+                    {move || format!("+{}", hidden())}
+                </div>
+            </Show>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_visible_until_loaded() {
+        assert!(!fallback_visible(ImageLoadingStatus::Loading, false));
+        assert!(fallback_visible(ImageLoadingStatus::Idle, true));
+        assert!(fallback_visible(ImageLoadingStatus::Loading, true));
+        assert!(fallback_visible(ImageLoadingStatus::Error, true));
+        assert!(!fallback_visible(ImageLoadingStatus::Loaded, true));
+    }
+
+    #[test]
+    fn test_group_overflow() {
+        assert_eq!(overflow_count(5, Some(3)), 2);
+        assert_eq!(overflow_count(2, Some(3)), 0);
+        assert_eq!(overflow_count(5, None), 0);
+
+        let group = AvatarGroupContext {
+            max: Some(2).into(),
+            next_id: StoredValue::new(0),
+            avatars: RwSignal::new(Vec::new()),
+        };
+        let ids = [group.register(), group.register(), group.register()];
+        assert!(group.is_visible(ids[1]));
+        assert!(!group.is_visible(ids[2]));
+
+        // Removing a visible avatar lets the next one in
+        group.unregister(ids[0]);
+        assert!(group.is_visible(ids[2]));
+    }
+}
@@ -4,7 +4,7 @@ pub mod signal_managed;
 pub mod default;
 pub mod new_york;
 
-pub use default::{Avatar, AvatarImage, AvatarFallback, AvatarGroup};
+pub use default::{Avatar, AvatarImage, AvatarFallback, AvatarGroup, ImageLoadingStatus};
 pub use new_york::{Avatar as AvatarNewYork, AvatarImage as AvatarImageNewYork, AvatarFallback as AvatarFallbackNewYork, AvatarGroup as AvatarGroupNewYork};
 
 mod tests;
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const BADGE_CLASS: &str = "inline-flex items-center rounded-full border px-2.5 py-0.5 text-xs font-semibold transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2";
 
@@ -45,7 +46,7 @@ pub fn Badge(
             BadgeVariant::Outline => "text-foreground",
         };
         
-        format!("{} {} {}", BADGE_CLASS, variant_class, class.get().unwrap_or_default())
+        tw_merge!(BADGE_CLASS, variant_class, class.get().unwrap_or_default())
     });
 
     view! {
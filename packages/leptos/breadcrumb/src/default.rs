@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const BREADCRUMB_CLASS: &str = "";
 const BREADCRUMB_LIST_CLASS: &str = "flex flex-wrap items-center gap-1.5 break-words text-sm text-muted-foreground sm:gap-2.5";
@@ -8,16 +8,14 @@ const BREADCRUMB_LINK_CLASS: &str = "transition-colors hover:text-foreground";
 const BREADCRUMB_PAGE_CLASS: &str = "font-normal text-foreground";
 const BREADCRUMB_SEPARATOR_CLASS: &str = "[&>svg]:size-3.5";
 const BREADCRUMB_ELLIPSIS_CLASS: &str = "flex h-9 w-9 items-center justify-center";
+const BREADCRUMB_MENU_CLASS: &str = "absolute left-0 top-full z-50 mt-1 flex min-w-[8rem] flex-col gap-1 rounded-md border bg-popover p-1 text-popover-foreground shadow-md [&_a]:rounded-sm [&_a]:px-2 [&_a]:py-1.5 [&_a:hover]:bg-accent";
 
 #[component]
 pub fn Breadcrumb(
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_CLASS, class.get().unwrap_or_default());
     
     view! {
         <nav 
@@ -34,10 +32,7 @@ pub fn BreadcrumbList(
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_LIST_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_LIST_CLASS, class.get().unwrap_or_default());
     
     view! {
         <ol class={merged_class}>
@@ -51,10 +46,7 @@ pub fn BreadcrumbItem(
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_ITEM_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_ITEM_CLASS, class.get().unwrap_or_default());
     
     view! {
         <li class={merged_class}>
@@ -65,15 +57,12 @@ pub fn BreadcrumbItem(
 
 #[component]
 pub fn BreadcrumbLink(
-    #[prop(optional)] href: MaybeProp<String>,
+    #[prop(into, optional)] href: MaybeProp<String>,
     #[prop(optional)] class: MaybeProp<String>,
     #[prop(optional)] as_child: MaybeProp<bool>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_LINK_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_LINK_CLASS, class.get().unwrap_or_default());
     
     let is_as_child = as_child.get().unwrap_or(false);
     
@@ -97,10 +86,7 @@ pub fn BreadcrumbPage(
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_PAGE_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_PAGE_CLASS, class.get().unwrap_or_default());
     
     view! {
         <span 
@@ -119,10 +105,7 @@ pub fn BreadcrumbSeparator(
     #[prop(optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_SEPARATOR_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(BREADCRUMB_SEPARATOR_CLASS, class.get().unwrap_or_default());
     
     view! {
         <li role="presentation" aria-hidden="true" class={merged_class}>
@@ -154,31 +137,70 @@ pub fn BreadcrumbSeparator(
 #[component]
 pub fn BreadcrumbEllipsis(
     #[prop(optional)] class: MaybeProp<String>,
+    /// Collapsed items. When given, the ellipsis becomes a button opening a
+    /// menu with them.
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        BREADCRUMB_ELLIPSIS_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
+    let merged_class = tw_merge!(BREADCRUMB_ELLIPSIS_CLASS, class.get().unwrap_or_default());
+
+    let icon = || view! {
+        <svg 
+            width="15" 
+            height="15" 
+            viewBox="0 0 15 15" 
+            fill="none" 
+            xmlns="http://www.w3.org/2000/svg"
+        >
+            <path 
+                d="M3 6.5a1.5 1.5 0 1 1 0 3 1.5 1.5 0 0 1 0-3ZM7.5 6.5a1.5 1.5 0 1 1 0 3 1.5 1.5 0 0 1 0-3ZM13.5 8a1.5 1.5 0 1 0-3 0 1.5 1.5 0 0 0 3 0Z" 
+                fill="currentColor"
+            />
+        </svg>
+    };
+
+    let Some(children) = children else {
+        return view! {
+            <span 
+                role="presentation" 
+                aria-hidden="true"
+                class={merged_class}
+            >
+                {icon()}
+                <span class="sr-only">"More"</span>
+            </span>
+        }.into_any();
+    };
+
+    let open = RwSignal::new(false);
+
     view! {
-        <span 
-            role="presentation" 
-            aria-hidden="true"
-            class={merged_class}
+        <span
+            class="relative"
+            on:keydown=move |e: leptos::ev::KeyboardEvent| {
+                if e.key() == "Escape" {
+                    open.set(false);
+                }
+            }
         >
-            <svg 
-                width="15" 
-                height="15" 
-                viewBox="0 0 15 15" 
-                fill="none" 
-                xmlns="http://www.w3.org/2000/svg"
+            <button
+                type="button"
+                class={merged_class}
+                aria-haspopup="menu"
+                aria-expanded=move || open.get().to_string()
+                on:click=move |_| open.update(|open| *open = !*open)
             >
-                <path 
-                    d="M3 6.5a1.5 1.5 0 1 1 0 3 1.5 1.5 0 0 1 0-3ZM7.5 6.5a1.5 1.5 0 1 1 0 3 1.5 1.5 0 0 1 0-3ZM13.5 8a1.5 1.5 0 1 0-3 0 1.5 1.5 0 0 0 3 0Z" 
-                    fill="currentColor"
-                />
-            </svg>
-            <span class="sr-only">"More"</span>
+                {icon()}
+                <span class="sr-only">"Show more"</span>
+            </button>
+            <Show when=move || open.get()>
+                <div
+                    role="menu"
+                    class=BREADCRUMB_MENU_CLASS
+                    on:click=move |_| open.set(false)
+                >
+                    {children.clone()()}
+                </div>
+            </Show>
         </span>
-    }
-}
\ No newline at end of file
+    }.into_any()
+}
@@ -7,9 +7,18 @@ pub use default::*;
 #[cfg(feature = "new_york")]
 mod new_york;
 
-#[cfg(not(feature = "new_york"))]
+// The New York theme re-exports the default implementation
 mod default;
 
+pub mod trail;
+pub use trail::{BreadcrumbLabel, BreadcrumbRoute, RouteParams};
+
+#[cfg(feature = "router")]
+pub mod router;
+
+#[cfg(feature = "router")]
+pub use router::AutoBreadcrumb;
+
 mod tests;
 
 // Signal-managed module and exports
@@ -0,0 +1,98 @@
+//! Breadcrumbs derived from the current route
+//!
+//! Enabled with the `router` feature. [`AutoBreadcrumb`] must be rendered
+//! inside a `<Router>`:
+//!
+//! ```ignore
+//! let routes = vec![
+//!     BreadcrumbRoute::new("/", "Home"),
+//!     BreadcrumbRoute::new("/users", "Users"),
+//!     BreadcrumbRoute::with_async_label("/users/:id", |params| async move {
+//!         fetch_user_name(&params["id"]).await
+//!     }),
+//! ];
+//! view! { <AutoBreadcrumb routes=routes max_items=4 /> }
+//! ```
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+use crate::trail::{BreadcrumbLabel, BreadcrumbRoute, TrailItem, breadcrumb_trail, collapsed_range};
+use crate::{Breadcrumb, BreadcrumbEllipsis, BreadcrumbItem, BreadcrumbLink, BreadcrumbList, BreadcrumbPage, BreadcrumbSeparator};
+
+/// Label of a trail item. Async labels show the path segment until they
+/// resolve, which also means they are never awaited during SSR.
+fn item_label(routes: StoredValue<Vec<BreadcrumbRoute>>, item: &TrailItem) -> AnyView {
+    match routes.with_value(|routes| routes[item.route].label.clone()) {
+        BreadcrumbLabel::Static(label) => label.into_any(),
+        BreadcrumbLabel::Async(label) => {
+            let params = item.params.clone();
+            let fallback = item.segment().to_string();
+            let resource = LocalResource::new(move || label(params.clone()));
+            (move || resource.get().unwrap_or_else(|| fallback.clone())).into_any()
+        }
+    }
+}
+
+#[component]
+pub fn AutoBreadcrumb(
+    /// Route patterns, such as `/users/:id`, that get an item in the trail
+    #[prop(into)] routes: Vec<BreadcrumbRoute>,
+    /// Collapse the middle of longer trails into an ellipsis menu
+    #[prop(into, optional)] max_items: MaybeProp<usize>,
+    #[prop(optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let location = use_location();
+    let routes = StoredValue::new(routes);
+    let trail = Memo::new(move |_| routes.with_value(|routes| breadcrumb_trail(&location.pathname.get(), routes)));
+
+    view! {
+        <Breadcrumb class=class>
+            <BreadcrumbList>
+                {move || {
+                    let trail = trail.get();
+                    let collapsed = max_items.get().and_then(|max_items| collapsed_range(trail.len(), max_items));
+
+                    trail
+                        .clone()
+                        .into_iter()
+                        .enumerate()
+                        .filter_map(|(index, item)| {
+                            let separator = (index > 0).then(|| view! { <BreadcrumbSeparator /> });
+                            match &collapsed {
+                                Some(range) if index == range.start => {
+                                    let hidden = trail[range.clone()].to_vec();
+                                    Some(view! {
+                                        {separator}
+                                        <BreadcrumbItem>
+                                            <BreadcrumbEllipsis>
+                                                {hidden.clone().into_iter().map(|item| view! {
+                                                    <BreadcrumbLink href=item.href.clone() attr:role="menuitem">
+                                                        {item_label(routes, &item)}
+                                                    </BreadcrumbLink>
+                                                }).collect_view()}
+                                            </BreadcrumbEllipsis>
+                                        </BreadcrumbItem>
+                                    }.into_any())
+                                }
+                                Some(range) if range.contains(&index) => None,
+                                _ => Some(view! {
+                                    {separator}
+                                    <BreadcrumbItem>
+                                        {if item.current {
+                                            view! { <BreadcrumbPage>{item_label(routes, &item)}</BreadcrumbPage> }.into_any()
+                                        } else {
+                                            view! {
+                                                <BreadcrumbLink href=item.href.clone()>{item_label(routes, &item)}</BreadcrumbLink>
+                                            }.into_any()
+                                        }}
+                                    </BreadcrumbItem>
+                                }.into_any()),
+                            }
+                        })
+                        .collect_view()
+                }}
+            </BreadcrumbList>
+        </Breadcrumb>
+    }
+}
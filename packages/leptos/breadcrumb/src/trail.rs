@@ -0,0 +1,164 @@
+//! Breadcrumb trails derived from a URL path
+//!
+//! A trail has one item for every prefix of the path that matches one of the
+//! given routes, e.g. `/users/42/settings` with routes for `/users`,
+//! `/users/:id` and `/users/:id/settings` gives three items.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Parameters captured by the `:name` segments of a route
+pub type RouteParams = HashMap<String, String>;
+
+type AsyncLabel = Arc<dyn Fn(RouteParams) -> Pin<Box<dyn Future<Output = String>>> + Send + Sync>;
+
+/// How the label of a breadcrumb item is obtained
+#[derive(Clone)]
+pub enum BreadcrumbLabel {
+    Static(String),
+    /// Computed from the route parameters, e.g. by fetching the name of the
+    /// user in `/users/:id`. The raw path segment is shown until it resolves.
+    Async(AsyncLabel),
+}
+
+/// A route pattern, such as `/users/:id`, and the label of its breadcrumb item
+#[derive(Clone)]
+pub struct BreadcrumbRoute {
+    pub path: String,
+    pub label: BreadcrumbLabel,
+}
+
+impl BreadcrumbRoute {
+    pub fn new(path: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            label: BreadcrumbLabel::Static(label.into()),
+        }
+    }
+
+    pub fn with_async_label<Fut>(path: impl Into<String>, label: impl Fn(RouteParams) -> Fut + Send + Sync + 'static) -> Self
+    where
+        Fut: Future<Output = String> + 'static,
+    {
+        Self {
+            path: path.into(),
+            label: BreadcrumbLabel::Async(Arc::new(move |params| Box::pin(label(params)))),
+        }
+    }
+}
+
+/// One item of a trail
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailItem {
+    pub href: String,
+    /// Index of the matching route
+    pub route: usize,
+    pub params: RouteParams,
+    /// Whether this is the page itself rather than one of its parents
+    pub current: bool,
+}
+
+impl TrailItem {
+    /// Last segment of the href, shown while an async label loads
+    pub fn segment(&self) -> &str {
+        self.href.rsplit('/').next().filter(|segment| !segment.is_empty()).unwrap_or("/")
+    }
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Parameters of `path` if it matches route `pattern`
+pub fn match_route(pattern: &str, path: &str) -> Option<RouteParams> {
+    let (pattern, path) = (segments(pattern), segments(path));
+    if pattern.len() != path.len() {
+        return None;
+    }
+
+    let mut params = RouteParams::new();
+    for (expected, actual) in pattern.into_iter().zip(path) {
+        match expected.strip_prefix(':') {
+            Some(name) => {
+                params.insert(name.to_string(), actual.to_string());
+            }
+            None if expected == actual => {}
+            None => return None,
+        }
+    }
+    Some(params)
+}
+
+/// Trail for `pathname`, from the root to the page itself. Prefixes without a
+/// matching route are left out; the first matching route wins.
+pub fn breadcrumb_trail(pathname: &str, routes: &[BreadcrumbRoute]) -> Vec<TrailItem> {
+    let path = pathname.split(['?', '#']).next().unwrap_or_default();
+    let segments = segments(path);
+
+    (0..=segments.len())
+        .filter_map(|len| {
+            let href = format!("/{}", segments[..len].join("/"));
+            routes.iter().enumerate().find_map(|(route, candidate)| {
+                match_route(&candidate.path, &href).map(|params| TrailItem {
+                    href: href.clone(),
+                    route,
+                    params,
+                    current: len == segments.len(),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Items of a trail of `len` items hidden behind an ellipsis so that at most
+/// `max_items` entries are shown, counting the ellipsis. The first item and
+/// the ones closest to the current page stay visible.
+pub fn collapsed_range(len: usize, max_items: usize) -> Option<Range<usize>> {
+    if len <= max_items.max(1) {
+        return None;
+    }
+    let after = max_items.saturating_sub(2).max(1);
+    Some(1..len - after).filter(|range| !range.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes() -> Vec<BreadcrumbRoute> {
+        vec![
+            BreadcrumbRoute::new("/", "Home"),
+            BreadcrumbRoute::new("/users", "Users"),
+            BreadcrumbRoute::with_async_label("/users/:id", |params| async move { format!("User {}", params["id"]) }),
+            BreadcrumbRoute::new("/users/:id/settings", "Settings"),
+        ]
+    }
+
+    #[test]
+    fn test_breadcrumb_trail() {
+        let trail = breadcrumb_trail("/users/42/settings?tab=profile", &routes());
+        let hrefs = trail.iter().map(|item| item.href.as_str()).collect::<Vec<_>>();
+        assert_eq!(hrefs, ["/", "/users", "/users/42", "/users/42/settings"]);
+        assert_eq!(trail[2].route, 2);
+        assert_eq!(trail[2].params["id"], "42");
+        assert_eq!(trail[2].segment(), "42");
+        assert!(trail[3].current && !trail[2].current);
+
+        // Unmapped prefixes are skipped
+        let trail = breadcrumb_trail("/users/42/posts/7", &routes());
+        assert_eq!(trail.len(), 3);
+        assert!(!trail[2].current);
+        assert_eq!(match_route("/users/:id", "/teams/42"), None);
+    }
+
+    #[test]
+    fn test_collapsed_range() {
+        assert_eq!(collapsed_range(4, 4), None);
+        assert_eq!(collapsed_range(6, 3), Some(1..5));
+        assert_eq!(collapsed_range(6, 4), Some(1..4));
+        assert_eq!(collapsed_range(3, 2), Some(1..2));
+    }
+}
@@ -1,6 +1,7 @@
 use leptos::prelude::*;
 use leptos::ev::{MouseEvent, KeyboardEvent};
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const BUTTON_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
 
@@ -125,7 +126,7 @@ pub fn Button(
         };
         
         let loading_class = if loading.get() { " opacity-50 cursor-not-allowed" } else { "" };
-        format!("{}{} {} {} {}", BUTTON_CLASS, loading_class, variant_class, size_class, class.get().unwrap_or_default())
+        tw_merge!(BUTTON_CLASS, loading_class, variant_class, size_class, class.get().unwrap_or_default())
     });
 
     // Implement as_child functionality using conditional rendering
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const BUTTON_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
 
@@ -108,7 +109,7 @@ pub fn Button(
             ButtonSize::Icon => "h-10 w-10",
         };
         
-        format!("{} {} {} {}", BUTTON_CLASS, variant_class, size_class, class.get().unwrap_or_default())
+        tw_merge!(BUTTON_CLASS, variant_class, size_class, class.get().unwrap_or_default())
     });
 
     // Implement as_child functionality using conditional rendering
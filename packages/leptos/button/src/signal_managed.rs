@@ -3,6 +3,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use leptos_shadcn_signal_management::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const BUTTON_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
 
@@ -85,13 +86,13 @@ pub fn SignalManagedButton(
         let loading_class = if state.loading { "loading" } else { "" };
         let disabled_class = if state.disabled { "disabled" } else { "" };
         
-        format!("{} {} {} {} {} {}", 
-            BUTTON_CLASS, 
-            variant_class, 
-            size_class, 
+        tw_merge!(
+            BUTTON_CLASS,
+            variant_class,
+            size_class,
             loading_class,
             disabled_class,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
     
@@ -219,11 +220,11 @@ pub fn EnhancedButton(
             ButtonSize::Icon => "h-10 w-10",
         };
         
-        format!("{} {} {} {}", 
-            BUTTON_CLASS, 
-            variant_class, 
-            size_class, 
-            class.get().unwrap_or_default()
+        tw_merge!(
+            BUTTON_CLASS,
+            variant_class,
+            size_class,
+            class.get().unwrap_or_default(),
         )
     });
     
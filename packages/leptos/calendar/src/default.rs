@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use js_sys::Date;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const CALENDAR_GRID_CLASS: &str = "grid w-full grid-cols-7 gap-px";
 const CALENDAR_HEADER_CLASS: &str = "grid w-full grid-cols-7 gap-px";
@@ -71,7 +72,7 @@ pub fn Calendar(
     };
     
     let computed_class = Signal::derive(move || {
-        format!("w-full {}", class.get().unwrap_or_default())
+        tw_merge!("w-full", class.get().unwrap_or_default())
     });
     
     view! {
@@ -1,6 +1,7 @@
 use leptos::prelude::*;
 use leptos::ev::{MouseEvent, KeyboardEvent};
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const CARD_CLASS: &str = "rounded-lg border bg-card text-card-foreground shadow-sm";
 
@@ -37,7 +38,7 @@ pub fn Card(
         };
         let interactive_class = if interactive.get() { " cursor-pointer hover:shadow-md transition-shadow" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{}{} {}", base_class, variant_class, interactive_class, custom_class)
+        tw_merge!(base_class, variant_class, interactive_class, custom_class)
     });
 
     view! {
@@ -61,7 +62,7 @@ pub fn CardHeader(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_HEADER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_HEADER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -84,7 +85,7 @@ pub fn CardTitle(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_TITLE_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_TITLE_CLASS, class.get().unwrap_or_default())
     });
 
     // Default to h2 for semantic structure
@@ -107,7 +108,7 @@ pub fn CardDescription(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -129,7 +130,7 @@ pub fn CardContent(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_CONTENT_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_CONTENT_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -151,7 +152,7 @@ pub fn CardFooter(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_FOOTER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_FOOTER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -188,7 +189,7 @@ pub fn InteractiveCard(
         };
         let interactive_class = " cursor-pointer hover:shadow-md transition-shadow focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2";
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{}{} {}", base_class, variant_class, interactive_class, custom_class)
+        tw_merge!(base_class, variant_class, interactive_class, custom_class)
     });
 
     let handle_click = move |_: MouseEvent| {
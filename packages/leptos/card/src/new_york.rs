@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const CARD_CLASS: &str = "rounded-lg border bg-card text-card-foreground shadow-sm";
 const CARD_HEADER_CLASS: &str = "flex flex-col space-y-1.5 p-6";
@@ -16,7 +17,7 @@ pub fn Card(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -38,7 +39,7 @@ pub fn CardHeader(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_HEADER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_HEADER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -60,7 +61,7 @@ pub fn CardTitle(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_TITLE_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_TITLE_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -82,7 +83,7 @@ pub fn CardDescription(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -104,7 +105,7 @@ pub fn CardContent(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_CONTENT_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_CONTENT_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -126,7 +127,7 @@ pub fn CardFooter(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CARD_FOOTER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_FOOTER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -3,6 +3,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use leptos_shadcn_signal_management::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const CARD_CLASS: &str = "rounded-lg border bg-card text-card-foreground shadow-sm";
 pub const CARD_HEADER_CLASS: &str = "flex flex-col space-y-1.5 p-6";
@@ -53,12 +54,12 @@ pub fn SignalManagedCard(
         let focus_class = if state.is_focused { "focus:ring-2 focus:ring-ring" } else { "" };
         let selected_class = if state.is_selected { "ring-2 ring-primary" } else { "" };
         
-        format!("{} {} {} {} {}", 
-            base_class, 
-            hover_class, 
-            focus_class, 
+        tw_merge!(
+            base_class,
+            hover_class,
+            focus_class,
             selected_class,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
 
@@ -137,12 +138,12 @@ pub fn EnhancedCard(
         let focus_class = if state.is_focused { "focus:ring-2 focus:ring-ring" } else { "" };
         let selected_class = if state.is_selected { "ring-2 ring-primary" } else { "" };
         
-        format!("{} {} {} {} {}", 
-            base_class, 
-            hover_class, 
-            focus_class, 
+        tw_merge!(
+            base_class,
+            hover_class,
+            focus_class,
             selected_class,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
 
@@ -232,7 +233,7 @@ pub fn SignalManagedCardHeader(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = ArcMemo::new(move |_| {
-        format!("{} {}", CARD_HEADER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_HEADER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -255,7 +256,7 @@ pub fn SignalManagedCardTitle(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = ArcMemo::new(move |_| {
-        format!("{} {}", CARD_TITLE_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_TITLE_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -278,7 +279,7 @@ pub fn SignalManagedCardDescription(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = ArcMemo::new(move |_| {
-        format!("{} {}", CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_DESCRIPTION_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -301,7 +302,7 @@ pub fn SignalManagedCardContent(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = ArcMemo::new(move |_| {
-        format!("{} {}", CARD_CONTENT_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_CONTENT_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -324,7 +325,7 @@ pub fn SignalManagedCardFooter(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = ArcMemo::new(move |_| {
-        format!("{} {}", CARD_FOOTER_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CARD_FOOTER_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
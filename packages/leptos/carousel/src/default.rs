@@ -1,5 +1,14 @@
+use std::time::Duration;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use leptos::ev;
+use leptos::html::Div;
 use leptos::prelude::*;
-use web_sys::MouseEvent;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys;
+use web_sys::{KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::engine::{self, CarouselLayout, CarouselOptions, CarouselPlugin, VelocityTracker};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CarouselOrientation {
@@ -20,37 +29,143 @@ pub struct CarouselApi {
     pub scroll_next: Callback<()>,
     pub can_scroll_prev: Signal<bool>,
     pub can_scroll_next: Signal<bool>,
+    /// Index of the current snap
+    pub selected_index: Signal<usize>,
+    pub snap_count: Signal<usize>,
+    pub slides_in_view: Signal<Vec<usize>>,
+    /// Starts or resumes the autoplay plugin
+    pub play: Callback<()>,
+    pub stop: Callback<()>,
+    pub is_playing: Signal<bool>,
+}
+
+/// Engine state shared by the carousel parts
+#[derive(Clone, Copy)]
+pub struct CarouselContext {
+    pub orientation: Signal<CarouselOrientation>,
+    pub slide_count: RwSignal<usize>,
+    pub layout: Memo<CarouselLayout>,
+    pub looped: Memo<bool>,
+    pub snap_points: Memo<Vec<f64>>,
+    /// Current position in slides; looped carousels briefly leave `0..slide_count`
+    pub position: RwSignal<f64>,
+    /// Pixels dragged since the pointer went down
+    pub drag_offset: RwSignal<f64>,
+    pub is_dragging: RwSignal<bool>,
+    /// Whether the next position change is transitioned
+    pub animate: RwSignal<bool>,
+    pub reduced_motion: RwSignal<bool>,
+    pub draggable: bool,
+    pub duration: Duration,
+    pub viewport_ref: NodeRef<Div>,
+    is_playing: RwSignal<bool>,
+    stop_on_interaction: bool,
+}
+
+impl CarouselContext {
+    /// Called on user navigation; stops autoplay when configured to
+    pub fn notify_interaction(&self) {
+        if self.stop_on_interaction {
+            self.is_playing.set(false);
+        }
+    }
+
+    /// Viewport size along the scroll axis, in pixels
+    fn viewport_size(&self) -> Option<f64> {
+        let viewport = self.viewport_ref.get_untracked()?;
+        let size = match self.orientation.get_untracked() {
+            CarouselOrientation::Horizontal => viewport.client_width(),
+            CarouselOrientation::Vertical => viewport.client_height(),
+        } as f64;
+        (size > 0.0).then_some(size)
+    }
 }
 
+/// Embla-style carousel.
+///
+/// Slides move with a CSS transform on the track. `opts` controls looping,
+/// slides per view (with breakpoints) and dragging; `plugins` adds autoplay,
+/// which pauses on hover and focus and never runs under reduced motion.
 #[component]
 pub fn Carousel(
     #[prop(into, optional)] orientation: MaybeProp<Signal<CarouselOrientation>>,
     #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] opts: CarouselOptions,
+    #[prop(optional)] plugins: Vec<CarouselPlugin>,
+    /// Receives the carousel API once created
+    #[prop(into, optional)] set_api: Option<Callback<CarouselApi>>,
+    /// Called with the snap index whenever the selection changes
+    #[prop(into, optional)] on_select: Option<Callback<usize>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let selected_index: RwSignal<usize> = RwSignal::new(0);
     let total_items: RwSignal<usize> = RwSignal::new(0);
-    
-    let can_scroll_prev = Signal::derive(move || selected_index.get() > 0);
-    let can_scroll_next = Signal::derive(move || selected_index.get() < total_items.get().saturating_sub(1_usize));
-    
+    let viewport_width = RwSignal::new(0.0_f64);
+    let reduced_motion = RwSignal::new(false);
+
+    // Provide default orientation if none is provided
+    let orientation_signal = orientation.get().unwrap_or_else(|| Signal::derive(CarouselOrientation::default));
+
+    let draggable = opts.draggable;
+    let duration = opts.duration;
+    let loop_mode = opts.loop_mode;
+    let layout = Memo::new(move |_| opts.resolve(viewport_width.get()));
+    let looped = Memo::new(move |_| engine::can_loop(total_items.get(), &layout.get(), loop_mode));
+    let snap_points = Memo::new(move |_| engine::snap_points(total_items.get(), &layout.get(), looped.get()));
+
+    let autoplay = plugins
+        .into_iter()
+        .map(|plugin| match plugin {
+            CarouselPlugin::Autoplay(options) => options,
+        })
+        .next();
+    let is_playing = RwSignal::new(autoplay.is_some());
+
+    let context = CarouselContext {
+        orientation: orientation_signal,
+        slide_count: total_items,
+        layout,
+        looped,
+        snap_points,
+        position: RwSignal::new(0.0),
+        drag_offset: RwSignal::new(0.0),
+        is_dragging: RwSignal::new(false),
+        animate: RwSignal::new(false),
+        reduced_motion,
+        draggable,
+        duration,
+        viewport_ref: NodeRef::new(),
+        is_playing,
+        stop_on_interaction: autoplay.as_ref().is_some_and(|options| options.stop_on_interaction),
+    };
+
+    let snap_count = Signal::derive(move || snap_points.with(|points| points.len()));
+    let can_scroll_prev = Signal::derive(move || looped.get() || selected_index.get() > 0);
+    let can_scroll_next = Signal::derive(move || looped.get() || selected_index.get() < snap_count.get().saturating_sub(1_usize));
+
     let scroll_to = Callback::new(move |index: usize| {
+        let Some(point) = snap_points.with_untracked(|points| points.get(index).copied()) else {
+            return;
+        };
+        let instant = reduced_motion.get_untracked();
+        let target = if looped.get_untracked() && !instant {
+            engine::loop_target(context.position.get_untracked(), point, total_items.get_untracked())
+        } else {
+            point
+        };
+        context.animate.set(!instant);
+        context.position.set(target);
         selected_index.set(index);
     });
     
     let scroll_prev = Callback::new(move |_| {
-        let current = selected_index.get();
-        if current > 0 {
-            selected_index.set(current - 1);
-        }
+        let previous = engine::step_snap(selected_index.get_untracked(), -1, snap_count.get_untracked(), looped.get_untracked());
+        scroll_to.run(previous);
     });
     
     let scroll_next = Callback::new(move |_| {
-        let current = selected_index.get();
-        let total = total_items.get();
-        if current < total.saturating_sub(1_usize) {
-            selected_index.set(current + 1);
-        }
+        let next = engine::step_snap(selected_index.get_untracked(), 1, snap_count.get_untracked(), looped.get_untracked());
+        scroll_to.run(next);
     });
     
     let api = CarouselApi {
@@ -59,56 +174,284 @@ pub fn Carousel(
         scroll_next,
         can_scroll_prev,
         can_scroll_next,
+        selected_index: selected_index.into(),
+        snap_count,
+        slides_in_view: Signal::derive(move || {
+            engine::slides_in_view(context.position.get(), &layout.get(), total_items.get(), looped.get())
+        }),
+        play: Callback::new(move |_| is_playing.set(true)),
+        stop: Callback::new(move |_| is_playing.set(false)),
+        is_playing: is_playing.into(),
     };
+    if let Some(set_api) = set_api {
+        set_api.run(api.clone());
+    }
     
-    // Provide default orientation if none is provided
-    let orientation_signal = orientation.get().unwrap_or_else(|| Signal::derive(|| CarouselOrientation::default()));
     provide_context(orientation_signal);
+    provide_context(context);
     provide_context(api);
-    provide_context(selected_index);
-    provide_context(total_items);
+
+    Effect::new(move |previous: Option<usize>| {
+        let selected = selected_index.get();
+        if previous.is_some_and(|previous| previous != selected)
+            && let Some(on_select) = on_select
+        {
+            on_select.run(selected);
+        }
+        selected
+    });
+
+    // Keep the selection valid when breakpoints or slides change the snaps
+    Effect::new(move |_| {
+        let count = snap_count.get();
+        let selected = selected_index.get_untracked();
+        let target = if selected >= count { count.saturating_sub(1) } else { selected };
+        if let Some(point) = snap_points.with(|points| points.get(target).copied()) {
+            context.animate.set(false);
+            context.position.set(point);
+            selected_index.set(target);
+        }
+    });
+
+    // Track the viewport width for breakpoints and the reduced motion preference
+    Effect::new(move |_| {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        if let Ok(Some(query)) = window.match_media("(prefers-reduced-motion: reduce)") {
+            reduced_motion.set(query.matches());
+        }
+        let read_width = move || {
+            if let Some(width) = web_sys::window().and_then(|window| window.inner_width().ok()).and_then(|width| width.as_f64()) {
+                viewport_width.set(width);
+            }
+        };
+        read_width();
+        let listener = window_event_listener(ev::resize, move |_| read_width());
+        on_cleanup(move || listener.remove());
+    });
+
+    let hovered = RwSignal::new(false);
+    let focused = RwSignal::new(false);
+    if let Some(options) = autoplay {
+        let timer = StoredValue::new(None::<TimeoutHandle>);
+        let clear_timer = move || {
+            if let Some(handle) = timer.get_value() {
+                handle.clear();
+                timer.set_value(None);
+            }
+        };
+
+        // Re-armed on every selection change so each slide gets the full delay
+        Effect::new(move |_| {
+            selected_index.track();
+            clear_timer();
+            let paused = (options.pause_on_hover && hovered.get())
+                || (options.pause_on_focus && focused.get())
+                || reduced_motion.get();
+            if !is_playing.get() || paused || snap_count.get() < 2 {
+                return;
+            }
+            let advance = move || {
+                if can_scroll_next.get_untracked() {
+                    scroll_next.run(());
+                } else {
+                    scroll_to.run(0);
+                }
+            };
+            if let Ok(handle) = set_timeout_with_handle(advance, options.delay) {
+                timer.set_value(Some(handle));
+            }
+        });
+        on_cleanup(clear_timer);
+    }
+
+    let handle_key_down = move |evt: KeyboardEvent| {
+        let (prev_key, next_key) = match orientation_signal.get_untracked() {
+            CarouselOrientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+            CarouselOrientation::Vertical => ("ArrowUp", "ArrowDown"),
+        };
+        let key = evt.key();
+        if key == prev_key {
+            evt.prevent_default();
+            context.notify_interaction();
+            scroll_prev.run(());
+        } else if key == next_key {
+            evt.prevent_default();
+            context.notify_interaction();
+            scroll_next.run(());
+        }
+    };
     
     let computed_class = Signal::derive(move || {
-        format!("relative w-full {}", class.get().unwrap_or_default())
+        tw_merge!("relative w-full", class.get().unwrap_or_default())
     });
     
     view! {
-        <div class=computed_class>
+        <div
+            class=computed_class
+            role="region"
+            aria-roledescription="carousel"
+            on:keydown=handle_key_down
+            on:mouseenter=move |_| hovered.set(true)
+            on:mouseleave=move |_| hovered.set(false)
+            on:focusin=move |_| focused.set(true)
+            on:focusout=move |_| focused.set(false)
+        >
             {children.map(|c| c())}
         </div>
     }
 }
 
+/// Pointer drag in progress on a [`CarouselContent`]
+struct DragState {
+    pointer_id: i32,
+    origin: f64,
+    tracker: VelocityTracker,
+    captured: bool,
+}
+
 #[component]
 pub fn CarouselContent(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let orientation = expect_context::<Signal<CarouselOrientation>>();
-    let total_items = expect_context::<RwSignal<usize>>();
-    
-    // Update total items count when children change without moving children into the Effect
-    let has_any_children = children.is_some();
-    Effect::new(move |_| {
-        if has_any_children {
-            // This is a simplified approach - in a real implementation you'd count actual children
-            total_items.set(1);
+    let context = expect_context::<CarouselContext>();
+    let api = expect_context::<CarouselApi>();
+    let drag = StoredValue::new(None::<DragState>);
+
+    let coordinate = move |evt: &PointerEvent| match orientation.get_untracked() {
+        CarouselOrientation::Horizontal => evt.client_x() as f64,
+        CarouselOrientation::Vertical => evt.client_y() as f64,
+    };
+
+    let handle_pointer_down = move |evt: PointerEvent| {
+        if !context.draggable || evt.button() != 0 || !evt.is_primary() {
+            return;
         }
-    });
-    
+        let mut tracker = VelocityTracker::new();
+        tracker.push(evt.time_stamp(), coordinate(&evt));
+        drag.set_value(Some(DragState {
+            pointer_id: evt.pointer_id(),
+            origin: coordinate(&evt),
+            tracker,
+            captured: false,
+        }));
+    };
+
+    let handle_pointer_move = move |evt: PointerEvent| {
+        let offset = drag.try_update_value(|state| {
+            let state = state.as_mut().filter(|state| state.pointer_id == evt.pointer_id())?;
+            let offset = coordinate(&evt) - state.origin;
+            state.tracker.push(evt.time_stamp(), coordinate(&evt));
+            if !state.captured && offset.abs() > engine::DRAG_THRESHOLD {
+                state.captured = true;
+                if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+                    let _ = target.set_pointer_capture(evt.pointer_id());
+                }
+            }
+            state.captured.then_some(offset)
+        });
+        if let Some(Some(offset)) = offset {
+            if !context.is_dragging.get_untracked() {
+                context.is_dragging.set(true);
+                context.notify_interaction();
+            }
+            context.drag_offset.set(offset);
+        }
+    };
+
+    let handle_pointer_up = move |evt: PointerEvent| {
+        let Some(state) = drag.try_update_value(|state| state.take()).flatten() else {
+            return;
+        };
+        if !state.captured {
+            return;
+        }
+        if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+            let _ = target.release_pointer_capture(state.pointer_id);
+        }
+
+        let offset = context.drag_offset.get_untracked();
+        let slide_size = context
+            .viewport_size()
+            .map(|size| size / context.layout.get_untracked().slides_per_view)
+            .unwrap_or(0.0);
+        let position = context.position.get_untracked();
+        let released_at = engine::project_release(position, offset, 0.0, slide_size);
+        let projected = engine::project_release(position, offset, state.tracker.velocity(), slide_size);
+        let snap = context.snap_points.with_untracked(|points| {
+            engine::closest_snap(points, projected, context.slide_count.get_untracked(), context.looped.get_untracked())
+        });
+
+        // Settle from where the slides were released rather than jumping back first
+        context.animate.set(false);
+        context.position.set(released_at);
+        context.drag_offset.set(0.0);
+        context.is_dragging.set(false);
+        api.scroll_to.run(snap);
+    };
+
+    // Looped transitions may end outside the slide range; wrap back without animating
+    let handle_transition_end = move |_| {
+        let count = context.slide_count.get_untracked();
+        let position = context.position.get_untracked();
+        let wrapped = engine::wrap_position(position, count);
+        if context.looped.get_untracked() && (wrapped - position).abs() > f64::EPSILON {
+            context.animate.set(false);
+            context.position.set(wrapped);
+        }
+    };
+
     let computed_class = Signal::derive(move || {
         let position_class = match orientation.get() {
             CarouselOrientation::Horizontal => "flex",
             CarouselOrientation::Vertical => "flex flex-col",
         };
         
-        format!("{} w-full {}", position_class, class.get().unwrap_or_default())
+        tw_merge!(position_class, "w-full", class.get().unwrap_or_default())
     });
+
+    let track_style = move || {
+        let offset = format!(
+            "calc({}% + {}px)",
+            -context.position.get() * 100.0 / context.layout.get().slides_per_view,
+            context.drag_offset.get()
+        );
+        let transform = match orientation.get() {
+            CarouselOrientation::Horizontal => format!("translate3d({}, 0, 0)", offset),
+            CarouselOrientation::Vertical => format!("translate3d(0, {}, 0)", offset),
+        };
+        let transition = if context.animate.get() && !context.is_dragging.get() && !context.reduced_motion.get() {
+            format!("transform {}ms ease-out", context.duration.as_millis())
+        } else {
+            "none".to_string()
+        };
+        format!("transform: {}; transition: {};", transform, transition)
+    };
+
+    // Let the browser keep scrolling along the other axis
+    let touch_action = move || match orientation.get() {
+        CarouselOrientation::Horizontal => "touch-action: pan-y;",
+        CarouselOrientation::Vertical => "touch-action: pan-x;",
+    };
     
     let rendered_children = children.map(|c| c());
     view! {
-        <div class=computed_class>
-            {rendered_children}
+        <div
+            node_ref=context.viewport_ref
+            class="overflow-hidden"
+            style=touch_action
+            on:pointerdown=handle_pointer_down
+            on:pointermove=handle_pointer_move
+            on:pointerup=handle_pointer_up
+            on:pointercancel=handle_pointer_up
+            on:dragstart=|evt| evt.prevent_default()
+        >
+            <div class=computed_class style=track_style on:transitionend=handle_transition_end>
+                {rendered_children}
+            </div>
         </div>
     }
 }
@@ -119,6 +462,14 @@ pub fn CarouselItem(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let orientation = expect_context::<Signal<CarouselOrientation>>();
+    let context = expect_context::<CarouselContext>();
+
+    // Slides register in render order
+    let mut index = 0;
+    context.slide_count.update(|count| {
+        index = *count;
+        *count += 1;
+    });
     
     let computed_class = Signal::derive(move || {
         let position_class = match orientation.get() {
@@ -126,16 +477,107 @@ pub fn CarouselItem(
             CarouselOrientation::Vertical => "min-h-0 shrink-0 grow-0 basis-full",
         };
         
-        format!("{} {}", position_class, class.get().unwrap_or_default())
+        tw_merge!(position_class, class.get().unwrap_or_default())
     });
+
+    let item_style = move || {
+        let basis = format!("flex-basis: {}%;", 100.0 / context.layout.get().slides_per_view);
+        if !context.looped.get() {
+            return basis;
+        }
+        let shift = engine::loop_shift(index, context.position.get(), context.slide_count.get()) * 100.0;
+        match orientation.get() {
+            CarouselOrientation::Horizontal => format!("{} transform: translate3d({}%, 0, 0);", basis, shift),
+            CarouselOrientation::Vertical => format!("{} transform: translate3d(0, {}%, 0);", basis, shift),
+        }
+    };
     
     view! {
-        <div class=computed_class>
+        <div
+            class=computed_class
+            style=item_style
+            role="group"
+            aria-roledescription="slide"
+            aria-label=move || format!("{} of {}", index + 1, context.slide_count.get())
+        >
             {children.map(|c| c())}
         </div>
     }
 }
 
+/// One dot per snap, highlighting the current one
+#[component]
+pub fn CarouselDots(
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<CarouselContext>();
+    let api = expect_context::<CarouselApi>();
+
+    let computed_class = Signal::derive(move || {
+        tw_merge!("flex items-center justify-center gap-2 py-2", class.get().unwrap_or_default())
+    });
+
+    view! {
+        <div class=computed_class role="tablist" aria-label="Choose slide">
+            {move || (0..api.snap_count.get()).map(|snap| {
+                let is_selected = move || api.selected_index.get() == snap;
+                view! {
+                    <button
+                        type="button"
+                        role="tab"
+                        class=move || format!(
+                            "h-2 w-2 rounded-full transition-colors {}",
+                            if is_selected() { "bg-primary" } else { "bg-muted-foreground/30 hover:bg-muted-foreground/50" }
+                        )
+                        aria-selected=move || is_selected().to_string()
+                        aria-label=format!("Go to slide {}", snap + 1)
+                        data-selected=move || is_selected().then_some("")
+                        on:click=move |_| {
+                            context.notify_interaction();
+                            api.scroll_to.run(snap);
+                        }
+                    />
+                }
+            }).collect_view()}
+        </div>
+    }
+}
+
+/// Thumbnail button that brings slide `index` into view
+#[component]
+pub fn CarouselThumbnail(
+    index: usize,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let context = expect_context::<CarouselContext>();
+    let api = expect_context::<CarouselApi>();
+
+    let snap = move || context.snap_points.with(|points| engine::snap_for_slide(points, index));
+    let is_selected = move || api.selected_index.get() == snap();
+
+    let computed_class = Signal::derive(move || {
+        let state_class = if is_selected() { "opacity-100 ring-2 ring-ring" } else { "opacity-50 hover:opacity-75" };
+        tw_merge!("overflow-hidden rounded-md transition-opacity", state_class, class.get().unwrap_or_default())
+    });
+
+    view! {
+        <button
+            type="button"
+            class=computed_class
+            aria-label=format!("Go to slide {}", index + 1)
+            aria-current=move || is_selected().then_some("true")
+            data-selected=move || is_selected().then_some("")
+            on:click=move |_| {
+                context.notify_interaction();
+                api.scroll_to.run(snap());
+            }
+        >
+            {children.map(|c| c())}
+        </button>
+    }
+}
+
 #[component]
 pub fn CarouselPrevious(
     #[prop(into, optional)] class: MaybeProp<String>,
@@ -145,9 +587,11 @@ pub fn CarouselPrevious(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let orientation = expect_context::<Signal<CarouselOrientation>>();
+    let context = expect_context::<CarouselContext>();
     let api = expect_context::<CarouselApi>();
 
     let handle_click = move |_: MouseEvent| {
+        context.notify_interaction();
         api.scroll_prev.run(());
         if let Some(callback) = &on_click {
             callback.run(());
@@ -160,10 +604,10 @@ pub fn CarouselPrevious(
             CarouselOrientation::Vertical => "absolute h-8 w-8 rounded-full left-1/2 top-12 -translate-x-1/2 -rotate-90",
         };
         
-        format!(
-            "{} inline-flex items-center justify-center whitespace-nowrap text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 border border-input bg-background hover:bg-accent hover:text-accent-foreground {}",
+        tw_merge!(
             position_class,
-            class.get().unwrap_or_default()
+            "inline-flex items-center justify-center whitespace-nowrap text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 border border-input bg-background hover:bg-accent hover:text-accent-foreground",
+            class.get().unwrap_or_default(),
         )
     });
 
@@ -200,9 +644,11 @@ pub fn CarouselNext(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let orientation = expect_context::<Signal<CarouselOrientation>>();
+    let context = expect_context::<CarouselContext>();
     let api = expect_context::<CarouselApi>();
 
     let handle_click = move |_: MouseEvent| {
+        context.notify_interaction();
         api.scroll_next.run(());
         if let Some(callback) = &on_click {
             callback.run(());
@@ -215,10 +661,10 @@ pub fn CarouselNext(
             CarouselOrientation::Vertical => "absolute h-8 w-8 rounded-full left-1/2 bottom-12 -translate-x-1/2 rotate-90",
         };
         
-        format!(
-            "{} inline-flex items-center justify-center whitespace-nowrap text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 border border-input bg-background hover:bg-accent hover:text-accent-foreground {}",
+        tw_merge!(
             position_class,
-            class.get().unwrap_or_default()
+            "inline-flex items-center justify-center whitespace-nowrap text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 border border-input bg-background hover:bg-accent hover:text-accent-foreground",
+            class.get().unwrap_or_default(),
         )
     });
 
@@ -0,0 +1,371 @@
+//! Slide positioning math for the Carousel components
+//!
+//! Positions are measured in slides from the start of the first slide, so
+//! none of this depends on rendered sizes; the components convert pixels at
+//! the edges. Everything here is pure and tested without a DOM.
+
+use std::time::Duration;
+
+/// Tolerance for comparing slide positions
+const EPSILON: f64 = 1e-6;
+
+/// Pointer travel, in pixels, before a press turns into a drag
+pub const DRAG_THRESHOLD: f64 = 5.0;
+
+/// How far a release keeps travelling: release velocity (px/ms) times this
+pub const MOMENTUM_FACTOR: f64 = 200.0;
+
+/// Window over which drag velocity is measured
+const VELOCITY_WINDOW_MS: f64 = 100.0;
+
+/// Responsive override applied from a viewport width upwards
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarouselBreakpoint {
+    pub min_width: f64,
+    pub slides_per_view: f64,
+    pub slides_to_scroll: usize,
+}
+
+/// Slides shown and moved per step at the current viewport width
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CarouselLayout {
+    pub slides_per_view: f64,
+    pub slides_to_scroll: usize,
+}
+
+/// Engine options for a [`Carousel`](crate::Carousel)
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarouselOptions {
+    /// Wraps from the last slide back to the first
+    pub loop_mode: bool,
+    pub slides_per_view: f64,
+    pub slides_to_scroll: usize,
+    /// Overrides by minimum viewport width; the widest matching one wins
+    pub breakpoints: Vec<CarouselBreakpoint>,
+    pub draggable: bool,
+    /// Length of the slide transition
+    pub duration: Duration,
+}
+
+impl Default for CarouselOptions {
+    fn default() -> Self {
+        Self {
+            loop_mode: false,
+            slides_per_view: 1.0,
+            slides_to_scroll: 1,
+            breakpoints: Vec::new(),
+            draggable: true,
+            duration: Duration::from_millis(300),
+        }
+    }
+}
+
+impl CarouselOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_loop(mut self, loop_mode: bool) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    pub fn with_slides_per_view(mut self, slides_per_view: f64) -> Self {
+        self.slides_per_view = slides_per_view;
+        self
+    }
+
+    pub fn with_slides_to_scroll(mut self, slides_to_scroll: usize) -> Self {
+        self.slides_to_scroll = slides_to_scroll;
+        self
+    }
+
+    pub fn with_breakpoint(mut self, min_width: f64, slides_per_view: f64, slides_to_scroll: usize) -> Self {
+        self.breakpoints.push(CarouselBreakpoint {
+            min_width,
+            slides_per_view,
+            slides_to_scroll,
+        });
+        self
+    }
+
+    pub fn with_draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Layout for a viewport `width` pixels wide
+    pub fn resolve(&self, width: f64) -> CarouselLayout {
+        let (slides_per_view, slides_to_scroll) = self
+            .breakpoints
+            .iter()
+            .filter(|breakpoint| breakpoint.min_width <= width)
+            .max_by(|a, b| a.min_width.total_cmp(&b.min_width))
+            .map(|breakpoint| (breakpoint.slides_per_view, breakpoint.slides_to_scroll))
+            .unwrap_or((self.slides_per_view, self.slides_to_scroll));
+
+        CarouselLayout {
+            slides_per_view: slides_per_view.max(1.0),
+            slides_to_scroll: slides_to_scroll.max(1),
+        }
+    }
+}
+
+/// Autoplay plugin settings
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoplayOptions {
+    pub delay: Duration,
+    /// Stops autoplay for good once the user drags or navigates
+    pub stop_on_interaction: bool,
+    pub pause_on_hover: bool,
+    pub pause_on_focus: bool,
+}
+
+impl Default for AutoplayOptions {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(4000),
+            stop_on_interaction: true,
+            pause_on_hover: true,
+            pause_on_focus: true,
+        }
+    }
+}
+
+/// Optional behaviour added to a carousel
+#[derive(Clone, Debug, PartialEq)]
+pub enum CarouselPlugin {
+    Autoplay(AutoplayOptions),
+}
+
+/// Looping only makes sense when there are more slides than fit in view
+pub fn can_loop(slide_count: usize, layout: &CarouselLayout, loop_mode: bool) -> bool {
+    loop_mode && slide_count as f64 > layout.slides_per_view
+}
+
+/// Positions the carousel can settle at.
+///
+/// Without looping the last snap is aligned so the final slide sits at the
+/// end of the view, even when that breaks the `slides_to_scroll` rhythm.
+pub fn snap_points(slide_count: usize, layout: &CarouselLayout, looped: bool) -> Vec<f64> {
+    let step = layout.slides_to_scroll as f64;
+    let mut points = Vec::new();
+
+    if looped {
+        let mut point = 0.0;
+        while point < slide_count as f64 - EPSILON {
+            points.push(point);
+            point += step;
+        }
+        return points;
+    }
+
+    let max_start = (slide_count as f64 - layout.slides_per_view).max(0.0);
+    let mut point = 0.0;
+    while point < max_start - EPSILON {
+        points.push(point);
+        point += step;
+    }
+    points.push(max_start);
+    points
+}
+
+/// Wraps a looped position into `0..slide_count`
+pub fn wrap_position(position: f64, slide_count: usize) -> f64 {
+    if slide_count == 0 {
+        0.0
+    } else {
+        position.rem_euclid(slide_count as f64)
+    }
+}
+
+/// Distance between two positions, the short way round when looped
+fn distance(a: f64, b: f64, slide_count: usize, looped: bool) -> f64 {
+    if looped && slide_count > 0 {
+        let d = (a - b).rem_euclid(slide_count as f64);
+        d.min(slide_count as f64 - d)
+    } else {
+        (a - b).abs()
+    }
+}
+
+/// Copy of `point` (shifted by whole loops) closest to `current`, so looped
+/// transitions take the short way round
+pub fn loop_target(current: f64, point: f64, slide_count: usize) -> f64 {
+    if slide_count == 0 {
+        return point;
+    }
+    let count = slide_count as f64;
+    point + ((current - point) / count).round() * count
+}
+
+/// Snap closest to `position`
+pub fn closest_snap(points: &[f64], position: f64, slide_count: usize, looped: bool) -> usize {
+    let mut closest = 0;
+    let mut closest_distance = f64::INFINITY;
+    for (index, point) in points.iter().enumerate() {
+        let d = distance(*point, position, slide_count, looped);
+        if d < closest_distance - EPSILON {
+            closest = index;
+            closest_distance = d;
+        }
+    }
+    closest
+}
+
+/// Snap that brings `slide` into view
+pub fn snap_for_slide(points: &[f64], slide: usize) -> usize {
+    points
+        .iter()
+        .rposition(|point| *point <= slide as f64 + EPSILON)
+        .unwrap_or(0)
+}
+
+/// Snap `delta` steps away from `current`, wrapping when looped
+pub fn step_snap(current: usize, delta: isize, snap_count: usize, looped: bool) -> usize {
+    if snap_count == 0 {
+        return 0;
+    }
+    let target = current as isize + delta;
+    if looped {
+        target.rem_euclid(snap_count as isize) as usize
+    } else {
+        target.clamp(0, snap_count as isize - 1) as usize
+    }
+}
+
+/// Whole-loop offset, in slides, applied to `slide` so that looped slides
+/// fill the view on both sides of `position`
+pub fn loop_shift(slide: usize, position: f64, slide_count: usize) -> f64 {
+    if slide_count == 0 {
+        return 0.0;
+    }
+    // Keep one slide before the view so dragging backwards shows a neighbour
+    let start = position.floor() - 1.0;
+    let shifted = start + (slide as f64 - start).rem_euclid(slide_count as f64);
+    shifted - slide as f64
+}
+
+/// Slides at least partly visible at `position`
+pub fn slides_in_view(position: f64, layout: &CarouselLayout, slide_count: usize, looped: bool) -> Vec<usize> {
+    (0..slide_count)
+        .filter(|slide| {
+            let start = *slide as f64 + if looped { loop_shift(*slide, position, slide_count) } else { 0.0 };
+            start + 1.0 > position + EPSILON && start < position + layout.slides_per_view - EPSILON
+        })
+        .collect()
+}
+
+/// Pointer velocity over the last few samples of a drag
+#[derive(Clone, Debug, Default)]
+pub struct VelocityTracker {
+    /// (timestamp in ms, position in px)
+    samples: Vec<(f64, f64)>,
+}
+
+impl VelocityTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, time: f64, position: f64) {
+        self.samples.push((time, position));
+        self.samples.retain(|(sample_time, _)| time - sample_time <= VELOCITY_WINDOW_MS);
+    }
+
+    /// Pixels per millisecond, positive towards larger coordinates
+    pub fn velocity(&self) -> f64 {
+        match (self.samples.first(), self.samples.last()) {
+            (Some((start_time, start)), Some((end_time, end))) if end_time - start_time > EPSILON => {
+                (end - start) / (end_time - start_time)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Position a released drag settles towards, before snapping.
+///
+/// `offset` and `velocity` are in pixels along the axis; dragging towards
+/// larger coordinates moves back through the slides.
+pub fn project_release(position: f64, offset: f64, velocity: f64, slide_size: f64) -> f64 {
+    if slide_size <= 0.0 {
+        return position;
+    }
+    position - (offset + velocity * MOMENTUM_FACTOR) / slide_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(slides_per_view: f64, slides_to_scroll: usize) -> CarouselLayout {
+        CarouselLayout {
+            slides_per_view,
+            slides_to_scroll,
+        }
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let options = CarouselOptions::new()
+            .with_breakpoint(640.0, 2.0, 2)
+            .with_breakpoint(1024.0, 3.0, 1);
+        assert_eq!(options.resolve(320.0), layout(1.0, 1));
+        assert_eq!(options.resolve(800.0), layout(2.0, 2));
+        assert_eq!(options.resolve(1280.0), layout(3.0, 1));
+    }
+
+    #[test]
+    fn test_snap_points() {
+        assert_eq!(snap_points(5, &layout(1.0, 1), false), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        // The last snap aligns the final slide with the end of the view
+        assert_eq!(snap_points(5, &layout(2.0, 2), false), vec![0.0, 2.0, 3.0]);
+        assert_eq!(snap_points(2, &layout(3.0, 1), false), vec![0.0]);
+        assert_eq!(snap_points(5, &layout(2.0, 2), true), vec![0.0, 2.0, 4.0]);
+        assert!(!can_loop(3, &layout(3.0, 1), true));
+    }
+
+    #[test]
+    fn test_loop_navigation() {
+        assert_eq!(step_snap(4, 1, 5, true), 0);
+        assert_eq!(step_snap(0, -1, 5, true), 4);
+        assert_eq!(step_snap(4, 1, 5, false), 4);
+        // Going from the last slide to the first keeps moving forward
+        assert_eq!(loop_target(4.0, 0.0, 5), 5.0);
+        assert_eq!(loop_target(0.0, 4.0, 5), -1.0);
+        assert_eq!(wrap_position(5.0, 5), 0.0);
+        assert_eq!(closest_snap(&[0.0, 1.0, 2.0, 3.0, 4.0], 4.6, 5, true), 0);
+    }
+
+    #[test]
+    fn test_loop_shift_fills_both_sides() {
+        // Slide 2 of 3 sits before slide 0 so it can be dragged in
+        assert_eq!(loop_shift(2, 0.0, 3), -3.0);
+        assert_eq!(loop_shift(1, 0.0, 3), 0.0);
+        assert_eq!(loop_shift(0, 2.0, 3), 3.0);
+        assert_eq!(slides_in_view(2.5, &layout(1.0, 1), 3, true), vec![0, 2]);
+        assert_eq!(slides_in_view(1.0, &layout(2.0, 1), 4, false), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_drag_release_projection() {
+        let mut tracker = VelocityTracker::new();
+        tracker.push(0.0, 100.0);
+        tracker.push(50.0, 50.0);
+        tracker.push(100.0, 0.0);
+        assert_eq!(tracker.velocity(), -1.0);
+
+        // Dragged half a slide left plus momentum of one more slide
+        let projected = project_release(1.0, -100.0, tracker.velocity(), 200.0);
+        assert_eq!(projected, 2.5);
+        assert_eq!(closest_snap(&[0.0, 1.0, 2.0, 3.0], projected, 4, false), 2);
+        assert_eq!(snap_for_slide(&[0.0, 2.0, 3.0], 1), 0);
+        assert_eq!(snap_for_slide(&[0.0, 2.0, 3.0], 4), 2);
+    }
+}
@@ -1,13 +1,15 @@
 //! Leptos port of shadcn/ui carousel
 
 pub mod signal_managed;
+pub mod engine;
 pub mod default;
 pub mod new_york;
 
 pub use default::{
     Carousel, CarouselContent, CarouselItem, CarouselPrevious, CarouselNext,
-    CarouselOrientation, CarouselApi,
+    CarouselOrientation, CarouselApi, CarouselContext, CarouselDots, CarouselThumbnail,
 };
+pub use engine::{AutoplayOptions, CarouselBreakpoint, CarouselLayout, CarouselOptions, CarouselPlugin};
 
 pub use new_york::{
     Carousel as CarouselNewYork,
@@ -17,6 +19,8 @@ pub use new_york::{
     CarouselNext as CarouselNextNewYork,
     CarouselOrientation as CarouselOrientationNewYork,
     CarouselApi as CarouselApiNewYork,
+    CarouselDots as CarouselDotsNewYork,
+    CarouselThumbnail as CarouselThumbnailNewYork,
 };
 
 mod tests;
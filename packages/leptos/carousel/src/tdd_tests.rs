@@ -5,12 +5,18 @@ use crate::*;
 mod tdd_tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     // ===== TDD ENHANCED TESTS - GREEN PHASE =====
     // These tests now implement real functionality and verify actual behavior
 
     // Basic Rendering Tests
     #[test]
     fn test_carousel_basic_rendering() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Basic carousel content"</div>
@@ -21,6 +27,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_with_orientation() {
+        init_executor();
         let orientation = RwSignal::new(CarouselOrientation::Horizontal);
         let _carousel_view = view! {
             <Carousel orientation=MaybeProp::from(<RwSignal<CarouselOrientation> as Into<Signal<CarouselOrientation>>>::into(orientation))>
@@ -31,6 +38,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_with_class() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("custom-carousel")>
                 <div>"Classed carousel content"</div>
@@ -40,6 +48,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_content_basic() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Content Item"</div>
@@ -49,6 +58,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_content_with_class() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div class="custom-content">"Content with Class"</div>
@@ -58,6 +68,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_item_basic() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Basic Item"</div>
@@ -67,6 +78,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_item_with_class() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div class="custom-item">"Item with Class"</div>
@@ -76,6 +88,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_previous_basic() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -86,6 +99,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_next_basic() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -97,6 +111,7 @@ mod tdd_tests {
     // Orientation Tests
     #[test]
     fn test_carousel_horizontal_orientation() {
+        init_executor();
         let orientation = RwSignal::new(CarouselOrientation::Horizontal);
         let _carousel_view = view! {
             <Carousel orientation=MaybeProp::from(<RwSignal<CarouselOrientation> as Into<Signal<CarouselOrientation>>>::into(orientation))>
@@ -110,6 +125,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_vertical_orientation() {
+        init_executor();
         let orientation = RwSignal::new(CarouselOrientation::Vertical);
         let _carousel_view = view! {
             <Carousel orientation=MaybeProp::from(<RwSignal<CarouselOrientation> as Into<Signal<CarouselOrientation>>>::into(orientation))>
@@ -124,6 +140,7 @@ mod tdd_tests {
     // Multiple Items Tests
     #[test]
     fn test_carousel_multiple_items() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -137,6 +154,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_many_items() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -153,6 +171,7 @@ mod tdd_tests {
     // Navigation Tests
     #[test]
     fn test_carousel_navigation_buttons() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -165,6 +184,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_previous_with_callback() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -176,6 +196,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_next_with_callback() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -188,6 +209,7 @@ mod tdd_tests {
     // Complex Content Tests
     #[test]
     fn test_carousel_complex_items() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>
@@ -206,6 +228,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_with_images() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>
@@ -223,6 +246,7 @@ mod tdd_tests {
     // State Management Tests
     #[test]
     fn test_carousel_state_management() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"State Item 1"</div>
@@ -233,6 +257,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_context_management() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("context-carousel")>
                 <div>"Context Item"</div>
@@ -243,6 +268,7 @@ mod tdd_tests {
     // Animation and Transitions Tests
     #[test]
     fn test_carousel_animations() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("animate-in fade-in-0")>
                 <div>"Animated Item"</div>
@@ -252,6 +278,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_content_placeholder() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div class="content-placeholder">"Placeholder Item"</div>
@@ -262,6 +289,7 @@ mod tdd_tests {
     // Accessibility Tests
     #[test]
     fn test_carousel_accessibility() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("focus-visible:ring-2")>
                 <div>"Accessible Item"</div>
@@ -273,6 +301,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_accessibility_comprehensive() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("focus-visible:outline-none focus-visible:ring-2")>
                 <div>"Comprehensive Accessible Item"</div>
@@ -285,6 +314,7 @@ mod tdd_tests {
     // Keyboard Navigation Tests
     #[test]
     fn test_carousel_keyboard_navigation() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("keyboard-navigable")>
                 <div>"Keyboard Navigable Item"</div>
@@ -296,6 +326,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_focus_management() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("focus-managed")>
                 <div>"Focus Managed Item"</div>
@@ -308,6 +339,7 @@ mod tdd_tests {
     // Advanced Interactions Tests
     #[test]
     fn test_carousel_advanced_interactions() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("advanced-interactions")>
                 <div>"Advanced Interactions Item"</div>
@@ -320,6 +352,7 @@ mod tdd_tests {
     // Form Integration Tests
     #[test]
     fn test_carousel_form_integration() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("form-integration-carousel")>
                 <div>"Form Integration Item"</div>
@@ -329,6 +362,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_error_handling() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("error-handling")>
                 <div>"Error Handling Item"</div>
@@ -338,6 +372,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_validation_comprehensive() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("validated-carousel")>
                 <div>"Validated Item"</div>
@@ -348,6 +383,7 @@ mod tdd_tests {
     // Integration Tests
     #[test]
     fn test_carousel_integration_scenarios() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("integration-carousel")>
                 <div>"Integration Item"</div>
@@ -357,6 +393,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_complete_workflow() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("workflow-carousel")>
                 <div>"Workflow Item"</div>
@@ -369,6 +406,7 @@ mod tdd_tests {
     // Edge Cases and Error Handling
     #[test]
     fn test_carousel_edge_cases() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>""</div>
@@ -378,6 +416,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_empty_content() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div></div>
@@ -387,6 +426,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_single_item() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Single Item"</div>
@@ -399,6 +439,7 @@ mod tdd_tests {
     // Performance Tests
     #[test]
     fn test_carousel_performance() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Performance Item"</div>
@@ -409,6 +450,7 @@ mod tdd_tests {
     // Integration with other components
     #[test]
     fn test_carousel_with_label() {
+        init_executor();
         let _carousel_view = view! {
             <div>
                 <label>"Carousel Label"</label>
@@ -421,6 +463,7 @@ mod tdd_tests {
 
     #[test]
     fn test_carousel_with_form() {
+        init_executor();
         let _carousel_view = view! {
             <form>
                 <Carousel>
@@ -433,6 +476,7 @@ mod tdd_tests {
     // API Tests
     #[test]
     fn test_carousel_api_usage() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"API Item"</div>
@@ -443,6 +487,7 @@ mod tdd_tests {
     // Navigation State Tests
     #[test]
     fn test_carousel_navigation_state() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel>
                 <div>"Item 1"</div>
@@ -456,6 +501,7 @@ mod tdd_tests {
     // Custom Styling Tests
     #[test]
     fn test_carousel_custom_styling() {
+        init_executor();
         let _carousel_view = view! {
             <Carousel class=MaybeProp::from("custom-carousel-style")>
                 <div class="custom-content-style">
@@ -470,6 +516,7 @@ mod tdd_tests {
     // Combined Props Tests
     #[test]
     fn test_carousel_combined_props() {
+        init_executor();
         let orientation = RwSignal::new(CarouselOrientation::Vertical);
         let _carousel_view = view! {
             <Carousel 
@@ -1,6 +1,7 @@
 use leptos::{ev::Event, prelude::*};
 use leptos_style::Style;
 use leptos::wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const CHECKBOX_CLASS: &str = "h-4 w-4 shrink-0 rounded-sm border border-primary ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 data-[state=checked]:bg-primary data-[state=checked]:text-primary-foreground";
 
@@ -25,7 +26,7 @@ pub fn Checkbox(
     };
 
     let computed_class = Signal::derive(move || {
-        format!("{} {}", CHECKBOX_CLASS, class.get().unwrap_or_default())
+        tw_merge!(CHECKBOX_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -1,7 +1,58 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use leptos::html::Div;
 use leptos::prelude::*;
+use leptos_shadcn_utils::collapsible::{hidden_attr, use_collapsible_content};
 use web_sys::MouseEvent;
 use web_sys::KeyboardEvent;
 
+static NEXT_CONTENT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// State shared by the parts of a [`Collapsible`]
+#[derive(Clone, Copy)]
+pub struct CollapsibleContext {
+    pub open: RwSignal<bool>,
+    pub disabled: Signal<bool>,
+    on_open_change: Option<Callback<bool>>,
+    content_id: usize,
+}
+
+impl CollapsibleContext {
+    fn new(open: RwSignal<bool>, disabled: Signal<bool>, on_open_change: Option<Callback<bool>>) -> Self {
+        Self {
+            open,
+            disabled,
+            on_open_change,
+            content_id: NEXT_CONTENT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// The enclosing collapsible. Without one (or without a reactive owner to
+    /// look it up from) the part gets a standalone closed collapsible.
+    fn current() -> Self {
+        use_context::<Self>().unwrap_or_else(|| Self::new(RwSignal::new(false), Signal::default(), None))
+    }
+
+    fn content_id(&self) -> String {
+        format!("collapsible-content-{}", self.content_id)
+    }
+
+    pub fn set_open(self, open: bool) {
+        self.open.set(open);
+        if let Some(on_open_change) = self.on_open_change {
+            on_open_change.run(open);
+        }
+    }
+
+    /// Flips the open state unless the collapsible is disabled
+    pub fn toggle(self) {
+        if !self.disabled.get_untracked() {
+            self.set_open(!self.open.get_untracked());
+        }
+    }
+}
+
 #[component]
 pub fn Collapsible(
     #[prop(into, optional)] open: RwSignal<bool>,
@@ -18,16 +69,18 @@ pub fn Collapsible(
         }
     });
 
-    provide_context(open);
-    provide_context(disabled);
-    provide_context(on_open_change);
+    provide_context(CollapsibleContext::new(open, disabled, on_open_change));
 
     let computed_class = Signal::derive(move || {
-        format!("w-full {}", class.get().unwrap_or_default())
+        tw_merge!("w-full", class.get().unwrap_or_default())
     });
 
     view! {
-        <div class=computed_class>
+        <div
+            class=computed_class
+            data-state=move || if open.get() { "open" } else { "closed" }
+            data-disabled=move || disabled.get()
+        >
             {children.map(|c| c())}
         </div>
     }
@@ -39,68 +92,35 @@ pub fn CollapsibleTrigger(
     #[prop(into, optional)] as_child: Option<Callback<CollapsibleTriggerChildProps>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let open = expect_context::<RwSignal<bool>>();
-    let disabled = expect_context::<Signal<bool>>();
-    let on_open_change = expect_context::<Option<Callback<bool>>>();
-
-    let toggle = {
-        let open = open.clone();
-        let on_open_change = on_open_change.clone();
-        move || {
-            if disabled.get() {
-                return;
-            }
+    let context = CollapsibleContext::current();
+    let open = context.open;
+    let disabled = context.disabled;
 
-            let new_open = !open.get();
-            open.set(new_open);
-            if let Some(callback) = &on_open_change {
-                callback.run(new_open);
-            }
-        }
-    };
-
-    let handle_click = {
-        let toggle = toggle.clone();
-        move |_: MouseEvent| {
-            toggle();
-        }
-    };
+    let handle_click = move |_: MouseEvent| context.toggle();
 
-    let handle_keydown = {
-        let toggle = toggle.clone();
-        move |e: KeyboardEvent| {
-            match e.key().as_str() {
-                "Enter" | " " => {
-                    e.prevent_default();
-                    toggle();
-                }
-                _ => {}
+    let handle_keydown = move |e: KeyboardEvent| {
+        match e.key().as_str() {
+            "Enter" | " " => {
+                e.prevent_default();
+                context.toggle();
             }
+            _ => {}
         }
     };
 
     let computed_class = Signal::derive(move || {
-        format!(
-            "flex w-full items-center justify-between py-4 font-medium transition-all hover:underline [&[data-state=open]>svg]:rotate-180 {}",
-            class.get().unwrap_or_default()
+        tw_merge!(
+            "flex w-full items-center justify-between py-4 font-medium transition-all hover:underline [&[data-state=open]>svg]:rotate-180",
+            class.get().unwrap_or_default(),
         )
     });
 
     if let Some(as_child) = as_child {
         let child_props = CollapsibleTriggerChildProps {
             class: class.get().unwrap_or_default(),
-            onclick: Some(Callback::new(move |_| {
-                if disabled.get() {
-                    return;
-                }
-
-                let new_open = !open.get();
-                open.set(new_open);
-                if let Some(callback) = &on_open_change {
-                    callback.run(new_open);
-                }
-            })),
-            onkeydown: Some(Callback::new(move |e| handle_keydown(e))),
+            controls: context.content_id(),
+            onclick: Some(Callback::new(move |_| context.toggle())),
+            onkeydown: Some(Callback::new(handle_keydown)),
             disabled: disabled.get(),
             open: open.get(),
         };
@@ -112,6 +132,7 @@ pub fn CollapsibleTrigger(
                 data-state=move || if open.get() { "open" } else { "closed" }
                 disabled=disabled
                 aria-expanded=move || open.get()
+                aria-controls=context.content_id()
                 on:click=handle_click
                 on:keydown=handle_keydown
             >
@@ -135,6 +156,8 @@ pub fn CollapsibleTrigger(
 #[derive(Debug, Clone)]
 pub struct CollapsibleTriggerChildProps {
     pub class: String,
+    /// Id of the content, for `aria-controls`
+    pub controls: String,
     pub onclick: Option<Callback<()>>,
     pub onkeydown: Option<Callback<KeyboardEvent>>,
     pub disabled: bool,
@@ -144,28 +167,63 @@ pub struct CollapsibleTriggerChildProps {
 #[component]
 pub fn CollapsibleContent(
     #[prop(into, optional)] class: MaybeProp<String>,
+    /// Keep the content in the document while closed, e.g. for SSR and SEO
     #[prop(into, optional)] force_mount: Signal<bool>,
-    #[prop(optional)] children: Option<Children>,
+    /// Keep closed content findable with find-in-page, which opens it
+    #[prop(into, optional)] hidden_until_found: Signal<bool>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
-    let open = expect_context::<RwSignal<bool>>();
+    let context = CollapsibleContext::current();
+    let open = context.open;
+
+    let content_ref = NodeRef::<Div>::new();
+    let measure_ref = NodeRef::<Div>::new();
+    let state = use_collapsible_content(
+        content_ref,
+        measure_ref,
+        open.into(),
+        Callback::new(move |_| context.set_open(true)),
+    );
 
     let computed_class = Signal::derive(move || {
-        format!(
-            "overflow-hidden text-sm transition-all data-[state=closed]:animate-collapsible-up data-[state=open]:animate-collapsible-down {}",
-            class.get().unwrap_or_default()
+        tw_merge!(
+            "overflow-hidden text-sm transition-all data-[state=closed]:animate-collapsible-up data-[state=open]:animate-collapsible-down",
+            class.get().unwrap_or_default(),
         )
     });
 
-    let _should_render = Signal::derive(move || force_mount.get() || open.get());
+    let should_render = Signal::derive(move || force_mount.get() || hidden_until_found.get() || state.present.get());
 
     view! {
         <div
+            node_ref=content_ref
+            id=context.content_id()
             class=computed_class
+            style=move || state.size.get().map(|size| size.css_vars("collapsible"))
+            hidden=move || hidden_attr(state.present.get(), hidden_until_found.get())
             data-state=move || if open.get() { "open" } else { "closed" }
+            on:animationend=move |_| state.on_animation_end()
         >
-            <div>
-                {children.map(|c| c())}
+            <div node_ref=measure_ref>
+                {move || should_render.get().then(|| children.clone().map(|c| c()))}
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_respects_disabled() {
+        let context = CollapsibleContext::new(RwSignal::new(false), Signal::stored(false), None);
+        context.toggle();
+        assert!(context.open.get_untracked());
+
+        let disabled = CollapsibleContext::new(RwSignal::new(false), Signal::stored(true), None);
+        disabled.toggle();
+        assert!(!disabled.open.get_untracked());
+        assert_ne!(context.content_id(), disabled.content_id());
+    }
+}
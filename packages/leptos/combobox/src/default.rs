@@ -2,6 +2,7 @@ use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::{HtmlInputElement, Event, KeyboardEvent, FocusEvent};
 use wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// Props for a combobox option
 #[derive(Clone, Debug)]
@@ -146,11 +147,7 @@ pub fn Combobox(
     // Compute classes
     let computed_class = Signal::derive(move || {
         let base_class = "flex h-10 w-full items-center justify-between rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background placeholder:text-muted-foreground focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -3,6 +3,7 @@ use leptos_style::Style;
 use web_sys::{HtmlInputElement, Event, KeyboardEvent, FocusEvent};
 use wasm_bindgen::JsCast;
 use crate::default::ComboboxOption;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// New York theme Combobox component
 #[component]
@@ -124,11 +125,7 @@ pub fn Combobox(
     // Compute classes (New York theme styling)
     let computed_class = Signal::derive(move || {
         let base_class = "flex h-9 w-full rounded-md border border-input bg-transparent px-3 py-1 text-sm shadow-sm transition-colors file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-ring disabled:cursor-not-allowed disabled:opacity-50";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
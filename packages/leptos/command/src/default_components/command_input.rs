@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos::wasm_bindgen::JsCast;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const COMMAND_INPUT_CLASS: &str = "flex items-center border-b px-3";
 const COMMAND_INPUT_WRAPPER_CLASS: &str = "flex h-10 w-full rounded-md bg-transparent py-3 text-sm outline-none placeholder:text-muted-foreground disabled:cursor-not-allowed disabled:opacity-50";
@@ -14,15 +14,9 @@ pub fn CommandInput(
 ) -> impl IntoView {
     let context = expect_context::<crate::default_components::command_root::CommandContext>();
     
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_INPUT_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_INPUT_CLASS, class.get().unwrap_or_default());
     
-    let input_class = tw_merge!(&format!("{} {}", 
-        COMMAND_INPUT_WRAPPER_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let input_class = tw_merge!(COMMAND_INPUT_WRAPPER_CLASS, class.get().unwrap_or_default());
     
     let handle_input = move |ev: leptos::ev::Event| {
         if let Ok(input_ev) = ev.dyn_into::<leptos::ev::InputEvent>() {
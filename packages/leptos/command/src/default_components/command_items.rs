@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const COMMAND_GROUP_CLASS: &str = "overflow-hidden p-1 text-foreground";
 const COMMAND_GROUP_HEADING_CLASS: &str = "px-2 py-1.5 text-xs font-medium text-muted-foreground";
@@ -14,10 +14,7 @@ pub fn CommandGroup(
     #[prop(optional)] style: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_GROUP_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_GROUP_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div 
@@ -38,10 +35,7 @@ pub fn CommandGroupHeading(
     #[prop(optional)] style: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_GROUP_HEADING_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_GROUP_HEADING_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div 
@@ -67,10 +61,7 @@ pub fn CommandItem(
     let context = expect_context::<crate::default_components::command_root::CommandContext>();
     let is_disabled = disabled.get().unwrap_or(false);
     
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_ITEM_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_ITEM_CLASS, class.get().unwrap_or_default());
     
     let handle_click = move |_| {
         if !is_disabled {
@@ -105,10 +96,7 @@ pub fn CommandShortcut(
     #[prop(optional)] style: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_SHORTCUT_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_SHORTCUT_CLASS, class.get().unwrap_or_default());
     
     view! {
         <span 
@@ -127,10 +115,7 @@ pub fn CommandSeparator(
     #[prop(optional)] id: MaybeProp<String>,
     #[prop(optional)] style: MaybeProp<String>,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_SEPARATOR_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_SEPARATOR_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div 
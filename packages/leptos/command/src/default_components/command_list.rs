@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const COMMAND_LIST_CLASS: &str = "max-h-[300px] overflow-y-auto overflow-x-hidden";
 const COMMAND_EMPTY_CLASS: &str = "py-6 text-center text-sm";
@@ -11,10 +11,7 @@ pub fn CommandList(
     #[prop(optional)] style: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_LIST_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_LIST_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div 
@@ -35,10 +32,7 @@ pub fn CommandEmpty(
     #[prop(optional)] style: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_EMPTY_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_EMPTY_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div 
@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const COMMAND_CLASS: &str = "flex h-full w-full flex-col overflow-hidden rounded-md bg-popover text-popover-foreground";
 
@@ -27,10 +27,7 @@ pub fn Command(
         }
     });
     
-    let merged_class = tw_merge!(&format!("{} {}", 
-        COMMAND_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(COMMAND_CLASS, class.get().unwrap_or_default());
     
     // Create context for child components
     provide_context(CommandContext {
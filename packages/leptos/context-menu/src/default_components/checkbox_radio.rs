@@ -6,6 +6,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn ContextMenuCheckboxItem(
@@ -36,7 +37,7 @@ pub fn ContextMenuCheckboxItem(
         let checked_class = if checked.get() { " checked" } else { "" };
         let disabled_class = if disabled.get().unwrap_or(false) { " disabled" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{}{} {}", base_class, checked_class, disabled_class, custom_class)
+        tw_merge!(base_class, checked_class, disabled_class, custom_class)
     };
 
     view! {
@@ -66,7 +67,7 @@ pub fn ContextMenuRadioGroup(
 
     view! {
         <div
-            class=move || format!("context-menu-radio-group {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("context-menu-radio-group", class.get().unwrap_or_default())
             role="radiogroup"
         >
             {children.map(|c| c())}
@@ -106,7 +107,7 @@ pub fn ContextMenuRadioItem(
         let selected_class = if is_selected.get() { " selected" } else { "" };
         let disabled_class = if disabled.get().unwrap_or(false) { " disabled" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{}{} {}", base_class, selected_class, disabled_class, custom_class)
+        tw_merge!(base_class, selected_class, disabled_class, custom_class)
     };
 
     view! {
@@ -5,6 +5,7 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn ContextMenuLabel(
@@ -15,7 +16,7 @@ pub fn ContextMenuLabel(
 ) -> impl IntoView {
     view! {
         <div
-            class=move || format!("context-menu-label {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("context-menu-label", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
         >
@@ -32,7 +33,7 @@ pub fn ContextMenuSeparator(
 ) -> impl IntoView {
     view! {
         <div
-            class=move || format!("context-menu-separator {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("context-menu-separator", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
             role="separator"
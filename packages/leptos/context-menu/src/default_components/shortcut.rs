@@ -5,6 +5,7 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn ContextMenuShortcut(
@@ -15,7 +16,7 @@ pub fn ContextMenuShortcut(
 ) -> impl IntoView {
     view! {
         <span
-            class=move || format!("context-menu-shortcut {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("context-menu-shortcut", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
         >
@@ -6,6 +6,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn ContextMenuSub(
@@ -45,7 +46,7 @@ pub fn ContextMenuSubTrigger(
         let base_class = "context-menu-sub-trigger";
         let disabled_class = if disabled.get().unwrap_or(false) { " disabled" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{} {}", base_class, disabled_class, custom_class)
+        tw_merge!(base_class, disabled_class, custom_class)
     };
 
     view! {
@@ -80,7 +81,7 @@ pub fn ContextMenuSubContent(
 
     view! {
         <div
-            class=move || format!("context-menu-sub-content {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("context-menu-sub-content", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
             on:click=handle_click
@@ -7,6 +7,7 @@ use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
 use wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn ContextMenuTrigger(
@@ -33,7 +34,7 @@ pub fn ContextMenuTrigger(
 
     view! {
         <div
-            class=move || format!("cursor-pointer {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("cursor-pointer", class.get().unwrap_or_default())
             on:contextmenu=handle_context_menu
             on:click=handle_click
         >
@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 use leptos_shadcn_calendar::{Calendar as CalendarComponent, CalendarDate};
 use leptos_shadcn_button::{Button, ButtonVariant};
 
@@ -46,10 +46,7 @@ pub fn DatePicker(
         )
     };
     
-    let merged_class = tw_merge!(&format!("{} {}", 
-        DATE_PICKER_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(DATE_PICKER_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div class={merged_class}>
@@ -173,10 +170,7 @@ pub fn DatePickerWithRange(
         }
     };
     
-    let merged_class = tw_merge!(&format!("{} {}", 
-        DATE_PICKER_CLASS,
-        class.get().unwrap_or_default()
-    ));
+    let merged_class = tw_merge!(DATE_PICKER_CLASS, class.get().unwrap_or_default());
     
     view! {
         <div class={merged_class}>
@@ -1,7 +1,9 @@
-use leptos::{ev::MouseEvent, prelude::*};
+use leptos::{ev::MouseEvent, html::Div, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
+use leptos_shadcn_utils::modal::{use_modal, ModalEvent, ModalOptions};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 // Dialog Root Provider
 #[component]
@@ -70,7 +72,7 @@ pub fn DialogTrigger(
     let ctx = expect_context::<DialogContextValue>();
     
     let trigger_class = Signal::derive(move || {
-        format!("{}", class.get().unwrap_or_default())
+        tw_merge!(class.get().unwrap_or_default())
     });
 
     let handle_click = Callback::new(move |_: MouseEvent| {
@@ -99,31 +101,59 @@ pub fn DialogTrigger(
 pub fn DialogContent(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
-    #[prop(optional)] children: Option<Children>,
+    /// Runs before focus moves into the content; prevent it to focus something else
+    #[prop(optional)] on_open_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs before focus returns to the trigger; prevent it to focus something else
+    #[prop(optional)] on_close_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs on Escape; prevent it to keep the dialog open
+    #[prop(optional)] on_escape_key_down: Option<Callback<ModalEvent>>,
+    /// Runs on a press outside the content; prevent it to keep the dialog open
+    #[prop(optional)] on_interact_outside: Option<Callback<ModalEvent>>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     let ctx = expect_context::<DialogContextValue>();
-    
+    let content_ref = NodeRef::<Div>::new();
+
+    use_modal(
+        content_ref,
+        ctx.open,
+        Callback::new(move |_| ctx.set_open.run(false)),
+        ModalOptions {
+            on_open_auto_focus,
+            on_close_auto_focus,
+            on_escape_key_down,
+            on_interact_outside,
+            ..ModalOptions::default()
+        },
+    );
+
     let content_class = Signal::derive(move || {
-        format!("fixed inset-0 z-50 flex items-center justify-center bg-background/80 backdrop-blur-sm data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[state=closed]:slide-out-to-left-1/2 data-[state=closed]:slide-out-to-top-[48%] data-[state=open]:slide-in-from-left-1/2 data-[state=open]:slide-in-from-top-[48%] sm:rounded-lg {}", class.get().unwrap_or_default())
+        tw_merge!("fixed left-[50%] top-[50%] z-50 grid w-full max-w-lg translate-x-[-50%] translate-y-[-50%] gap-4 border bg-background p-6 shadow-lg duration-200 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[state=closed]:slide-out-to-left-1/2 data-[state=closed]:slide-out-to-top-[48%] data-[state=open]:slide-in-from-left-1/2 data-[state=open]:slide-in-from-top-[48%] sm:rounded-lg", class.get().unwrap_or_default())
     });
 
-    if ctx.open.get() {
-        view! {
-            <div 
-                class="fixed inset-0 z-50"
-                on:click=move |_| ctx.set_open.run(false)
+    view! {
+        <Show
+            when=move || ctx.open.get()
+            fallback=|| view! { <div></div> }
+        >
+            // Outside the content, so presses on it count as outside interactions
+            <div
+                class="fixed inset-0 z-50 bg-background/80 backdrop-blur-sm data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0"
+                data-state="open"
+                aria-hidden="true"
+            ></div>
+            <div
+                node_ref=content_ref
+                class={content_class}
+                style={move || style.get().to_string()}
+                data-state="open"
+                role="dialog"
+                aria-modal="true"
+                tabindex="-1"
             >
-                <div
-                    class={content_class}
-                    style={move || style.get().to_string()}
-                    on:click=|e: MouseEvent| e.stop_propagation()
-                >
-                    {children.map(|c| c())}
-                </div>
+                {children.clone().map(|c| c())}
             </div>
-        }.into_any()
-    } else {
-        view! { <div></div> }.into_any()
+        </Show>
     }
 }
 
@@ -134,7 +164,7 @@ pub fn DialogHeader(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let header_class = Signal::derive(move || {
-        format!("flex flex-col space-y-1.5 text-center sm:text-left {}", class.get().unwrap_or_default())
+        tw_merge!("flex flex-col space-y-1.5 text-center sm:text-left", class.get().unwrap_or_default())
     });
 
     view! {
@@ -151,7 +181,7 @@ pub fn DialogTitle(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let title_class = Signal::derive(move || {
-        format!("text-lg font-semibold leading-none tracking-tight {}", class.get().unwrap_or_default())
+        tw_merge!("text-lg font-semibold leading-none tracking-tight", class.get().unwrap_or_default())
     });
 
     view! {
@@ -168,7 +198,7 @@ pub fn DialogDescription(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let description_class = Signal::derive(move || {
-        format!("text-sm text-muted-foreground {}", class.get().unwrap_or_default())
+        tw_merge!("text-sm text-muted-foreground", class.get().unwrap_or_default())
     });
 
     view! {
@@ -185,7 +215,7 @@ pub fn DialogFooter(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let footer_class = Signal::derive(move || {
-        format!("flex flex-col-reverse sm:flex-row sm:justify-end sm:space-x-2 {}", class.get().unwrap_or_default())
+        tw_merge!("flex flex-col-reverse sm:flex-row sm:justify-end sm:space-x-2", class.get().unwrap_or_default())
     });
 
     view! {
@@ -204,7 +234,7 @@ pub fn DialogClose(
     let ctx = expect_context::<DialogContextValue>();
     
     let close_class = Signal::derive(move || {
-        format!("{}", class.get().unwrap_or_default())
+        tw_merge!(class.get().unwrap_or_default())
     });
 
     view! {
@@ -5,6 +5,7 @@
 use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::MouseEvent;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn DrawerClose(
@@ -25,7 +26,7 @@ pub fn DrawerClose(
 
     view! {
         <button
-            class=move || format!("drawer-close {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("drawer-close", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
             on:click=handle_click
@@ -1,26 +1,163 @@
 //! Drawer content components
 //! 
 //! This module contains the DrawerContent component for the main drawer content area.
+//! The content follows pointer drags and settles on a snap point or dismisses
+//! the drawer on release. Focus, Escape and outside presses go through the
+//! shared modal stack.
 
+use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_style::Style;
-use web_sys::MouseEvent;
+use leptos_shadcn_utils::modal::{use_modal, ModalEvent, ModalOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{MouseEvent, PointerEvent};
+use super::drag::{self, DragRelease, DrawerDragContext, VelocityTracker};
 use super::types::DrawerDirection;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+/// Pointer press on the content that may turn into a drag
+struct PointerGesture {
+    pointer_id: i32,
+    origin: f64,
+    target: Option<web_sys::Element>,
+    /// `None` until the pointer has moved far enough to decide
+    dragging: Option<bool>,
+    tracker: VelocityTracker,
+}
 
 #[component]
 pub fn DrawerContent(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
-    #[prop(optional)] children: Option<Children>,
+    /// Runs before focus moves into the drawer; prevent it to focus something else
+    #[prop(optional)] on_open_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs before focus returns to the trigger; prevent it to focus something else
+    #[prop(optional)] on_close_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs on Escape; prevent it to keep the drawer open
+    #[prop(optional)] on_escape_key_down: Option<Callback<ModalEvent>>,
+    /// Runs on a press outside the drawer; prevent it to keep the drawer open
+    #[prop(optional)] on_interact_outside: Option<Callback<ModalEvent>>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     let open_state = expect_context::<RwSignal<bool>>();
+    let on_open_change = expect_context::<Option<Callback<bool>>>();
     let direction = expect_context::<Signal<DrawerDirection>>();
+    let drag = expect_context::<DrawerDragContext>();
+    let content_ref = NodeRef::<Div>::new();
+    let gesture = StoredValue::new_local(None::<PointerGesture>);
+
+    let close = move || {
+        open_state.set(false);
+        if let Some(callback) = &on_open_change {
+            callback.run(false);
+        }
+    };
+
+    use_modal(
+        content_ref,
+        open_state.into(),
+        Callback::new(move |_| close()),
+        ModalOptions {
+            on_open_auto_focus,
+            on_close_auto_focus,
+            on_escape_key_down,
+            on_interact_outside,
+            dismiss_on_interact_outside: drag.dismissible,
+        },
+    );
 
     let handle_click = move |e: MouseEvent| {
         e.stop_propagation();
     };
 
+    Effect::new(move |_| {
+        if let Some(content) = content_ref.get() {
+            drag.measure(&content, &direction.get_untracked());
+        }
+    });
+
+    // Pointer position along the drag axis, positive towards closed
+    let closing_coordinate = move |evt: &PointerEvent| {
+        let direction = direction.get_untracked();
+        let coordinate = if direction.is_vertical() { evt.client_y() } else { evt.client_x() } as f64;
+        coordinate * direction.closing_sign()
+    };
+
+    let handle_pointer_down = move |evt: PointerEvent| {
+        if evt.button() != 0 || !evt.is_primary() {
+            return;
+        }
+        if let Some(content) = content_ref.get_untracked() {
+            drag.measure(&content, &direction.get_untracked());
+        }
+        let mut tracker = VelocityTracker::default();
+        tracker.push(evt.time_stamp(), 0.0);
+        gesture.set_value(Some(PointerGesture {
+            pointer_id: evt.pointer_id(),
+            origin: closing_coordinate(&evt),
+            target: evt.target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()),
+            dragging: None,
+            tracker,
+        }));
+    };
+
+    let handle_pointer_move = move |evt: PointerEvent| {
+        let offset = gesture.try_update_value(|gesture| {
+            let gesture = gesture.as_mut().filter(|gesture| gesture.pointer_id == evt.pointer_id())?;
+            let offset = closing_coordinate(&evt) - gesture.origin;
+            gesture.tracker.push(evt.time_stamp(), offset);
+
+            if gesture.dragging.is_none() && offset.abs() > drag::DRAG_THRESHOLD {
+                let (scrollable, scroll_remaining) = content_ref
+                    .get_untracked()
+                    .map(|content| scroll_state(gesture.target.as_ref(), &content, &direction.get_untracked()))
+                    .unwrap_or((false, 0.0));
+                let dragging = drag::should_drag(offset, scrollable, scroll_remaining, drag.is_fully_open());
+                gesture.dragging = Some(dragging);
+                if dragging {
+                    // Restart from here so the drawer does not jump by the threshold
+                    gesture.origin += offset;
+                    if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+                        let _ = target.set_pointer_capture(evt.pointer_id());
+                    }
+                    return Some(0.0);
+                }
+            }
+            gesture.dragging.filter(|dragging| *dragging).map(|_| offset)
+        });
+
+        if let Some(Some(offset)) = offset {
+            evt.prevent_default();
+            drag.is_dragging.set(true);
+            drag.drag_offset.set(offset);
+        }
+    };
+
+    let handle_pointer_up = move |_evt: PointerEvent| {
+        let Some(gesture) = gesture.try_update_value(|gesture| gesture.take()).flatten() else {
+            return;
+        };
+        if gesture.dragging != Some(true) {
+            return;
+        }
+
+        let offset = drag.offset();
+        let release = drag::release(
+            offset,
+            gesture.tracker.velocity(),
+            &drag.snap_offsets(),
+            drag.drawer_size.get_untracked(),
+            drag.dismissible.get_untracked(),
+        );
+        drag.is_dragging.set(false);
+        drag.drag_offset.set(0.0);
+        match release {
+            DragRelease::Snap(index) => drag.active_snap_point.set(index),
+            DragRelease::Dismiss => close(),
+        }
+    };
+
     let content_class = move || {
         let base_class = "fixed z-50 bg-background";
         let direction_class = match direction.get() {
@@ -30,7 +167,24 @@ pub fn DrawerContent(
             DrawerDirection::Right => " right-0 top-0 h-full w-3/4 border-l sm:max-w-sm",
         };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{} {}", base_class, direction_class, custom_class)
+        tw_merge!(base_class, direction_class, custom_class)
+    };
+
+    let content_style = move || {
+        let direction = direction.get();
+        let offset = drag.offset() * direction.closing_sign();
+        let transform = if direction.is_vertical() {
+            format!("translate3d(0, {}px, 0)", offset)
+        } else {
+            format!("translate3d({}px, 0, 0)", offset)
+        };
+        let transition = if drag.is_dragging.get() { "none" } else { "transform 0.5s cubic-bezier(0.32, 0.72, 0, 1)" };
+        format!(
+            "{}; transform: {}; transition: {};",
+            style.get().unwrap_or_default(),
+            transform,
+            transition
+        )
     };
 
     view! {
@@ -39,15 +193,49 @@ pub fn DrawerContent(
             fallback=|| view! { <div></div> }
         >
             <div
+                node_ref=content_ref
                 class=content_class
                 id=move || id.get().unwrap_or_default()
-                style=move || style.get().unwrap_or_default()
+                style=content_style
                 on:click=handle_click
+                on:pointerdown=handle_pointer_down
+                on:pointermove=handle_pointer_move
+                on:pointerup=handle_pointer_up
+                on:pointercancel=handle_pointer_up
                 role="dialog"
                 aria-modal="true"
+                tabindex="-1"
+                data-dragging=move || drag.is_dragging.get().to_string()
             >
-                {children.map(|c| c())}
+                {children.clone().map(|c| c())}
             </div>
         </Show>
     }
 }
+
+/// Whether the pointer went down inside scrollable content, and how far that
+/// content can still scroll in the direction a closing drag scrolls it
+fn scroll_state(target: Option<&web_sys::Element>, content: &web_sys::Element, direction: &DrawerDirection) -> (bool, f64) {
+    let mut element = target.cloned();
+    while let Some(current) = element {
+        let (scroll, scroll_size, client_size) = if direction.is_vertical() {
+            (current.scroll_top(), current.scroll_height(), current.client_height())
+        } else {
+            (current.scroll_left(), current.scroll_width(), current.client_width())
+        };
+        if scroll_size > client_size {
+            // A closing drag towards positive coordinates scrolls back to the start
+            let remaining = if direction.closing_sign() > 0.0 {
+                scroll as f64
+            } else {
+                (scroll_size - client_size - scroll) as f64
+            };
+            return (true, remaining.max(0.0));
+        }
+        if &current == content {
+            break;
+        }
+        element = current.parent_element();
+    }
+    (false, 0.0)
+}
@@ -0,0 +1,272 @@
+//! Drawer drag gestures
+//!
+//! This module contains the drag state shared by the drawer parts and the
+//! pure math behind drag-to-dismiss: snap point offsets, release decisions,
+//! rubber-banding and scroll-versus-drag disambiguation.
+
+use leptos::prelude::*;
+
+use super::types::{DrawerDirection, SnapPoint};
+
+/// Fraction of the drawer that must be dragged past the last snap to dismiss
+pub const CLOSE_THRESHOLD: f64 = 0.25;
+
+/// Release velocity, in px/ms, that counts as a flick
+pub const VELOCITY_THRESHOLD: f64 = 0.4;
+
+/// Pointer travel, in pixels, before a press becomes a drag or a scroll
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+/// Scale of the page behind an open drawer with `should_scale_background`
+pub const BACKGROUND_SCALE: f64 = 0.95;
+
+/// Window over which drag velocity is measured
+const VELOCITY_WINDOW_MS: f64 = 100.0;
+
+/// Where a released drag settles
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DragRelease {
+    /// Index into the snap points (0 when there are none)
+    Snap(usize),
+    Dismiss,
+}
+
+/// Offsets, in pixels from fully open towards closed, at which each snap
+/// point rests. Without snap points the drawer rests fully open.
+pub fn snap_offsets(points: &[SnapPoint], drawer_size: f64, viewport_size: f64) -> Vec<f64> {
+    if points.is_empty() {
+        return vec![0.0];
+    }
+    points
+        .iter()
+        .map(|point| (drawer_size - point.visible_size(viewport_size)).clamp(0.0, drawer_size.max(0.0)))
+        .collect()
+}
+
+/// Decides where a drag released at `offset` with `velocity` (px/ms,
+/// positive towards closed) settles.
+///
+/// Flicks move to the next snap in the flick direction; slow releases go to
+/// the closest snap. Dragging a quarter of the remaining size past the most
+/// closed snap, or flicking closed from it, dismisses the drawer.
+pub fn release(offset: f64, velocity: f64, snaps: &[f64], drawer_size: f64, dismissible: bool) -> DragRelease {
+    let (most_closed, most_closed_offset) = snaps
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, 0.0));
+    let is_flick = velocity.abs() > VELOCITY_THRESHOLD;
+
+    if dismissible {
+        let past_threshold = offset - most_closed_offset > CLOSE_THRESHOLD * (drawer_size - most_closed_offset);
+        let flicked_closed = is_flick && velocity > 0.0 && offset >= most_closed_offset;
+        if past_threshold || flicked_closed {
+            return DragRelease::Dismiss;
+        }
+    }
+
+    let closest_by = |candidates: &mut dyn Iterator<Item = (usize, f64)>| {
+        candidates
+            .min_by(|a, b| (a.1 - offset).abs().total_cmp(&(b.1 - offset).abs()))
+            .map(|(index, _)| index)
+    };
+    let snaps_iter = || snaps.iter().copied().enumerate();
+
+    let target = if is_flick && velocity > 0.0 {
+        closest_by(&mut snaps_iter().filter(|(_, snap)| *snap > offset)).unwrap_or(most_closed)
+    } else if is_flick {
+        closest_by(&mut snaps_iter().filter(|(_, snap)| *snap < offset))
+            .unwrap_or_else(|| closest_by(&mut snaps_iter()).unwrap_or(0))
+    } else {
+        closest_by(&mut snaps_iter()).unwrap_or(0)
+    };
+    DragRelease::Snap(target)
+}
+
+/// Resists dragging past fully open
+pub fn dampen(offset: f64) -> f64 {
+    if offset >= 0.0 {
+        offset
+    } else {
+        -8.0 * (1.0 - offset).ln()
+    }
+}
+
+/// How far the drawer has moved towards closed, from 0 (open) to 1 (closed)
+pub fn drag_progress(offset: f64, drawer_size: f64) -> f64 {
+    if drawer_size <= 0.0 {
+        0.0
+    } else {
+        (offset / drawer_size).clamp(0.0, 1.0)
+    }
+}
+
+/// Scale applied to the page behind the drawer
+pub fn background_scale(progress: f64) -> f64 {
+    BACKGROUND_SCALE + (1.0 - BACKGROUND_SCALE) * progress.clamp(0.0, 1.0)
+}
+
+/// Whether a gesture drags the drawer rather than scrolling the content
+/// under the pointer.
+///
+/// `scroll_remaining` is how far that content can still scroll in the
+/// direction a closing drag would scroll it; a closing drag only moves the
+/// drawer once the content has reached its edge. Opening drags move the
+/// drawer until it reaches its most open snap.
+pub fn should_drag(delta_towards_close: f64, scrollable: bool, scroll_remaining: f64, fully_open: bool) -> bool {
+    if !scrollable {
+        return true;
+    }
+    if delta_towards_close > 0.0 {
+        scroll_remaining <= 0.0
+    } else {
+        !fully_open
+    }
+}
+
+/// Pointer velocity over the last few samples of a drag
+#[derive(Clone, Debug, Default)]
+pub struct VelocityTracker {
+    /// (timestamp in ms, offset in px)
+    samples: Vec<(f64, f64)>,
+}
+
+impl VelocityTracker {
+    pub fn push(&mut self, time: f64, offset: f64) {
+        self.samples.push((time, offset));
+        self.samples.retain(|(sample_time, _)| time - sample_time <= VELOCITY_WINDOW_MS);
+    }
+
+    pub fn velocity(&self) -> f64 {
+        match (self.samples.first(), self.samples.last()) {
+            (Some((start_time, start)), Some((end_time, end))) if end_time > start_time => {
+                (end - start) / (end_time - start_time)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Drag state shared by a drawer's parts
+#[derive(Clone, Copy)]
+pub struct DrawerDragContext {
+    pub snap_points: StoredValue<Vec<SnapPoint>>,
+    /// Index of the snap point the drawer rests at
+    pub active_snap_point: RwSignal<usize>,
+    /// Pixels dragged towards closed from the resting position
+    pub drag_offset: RwSignal<f64>,
+    pub is_dragging: RwSignal<bool>,
+    /// Size of the content along the drag axis, measured when opened
+    pub drawer_size: RwSignal<f64>,
+    pub viewport_size: RwSignal<f64>,
+    pub dismissible: Signal<bool>,
+}
+
+impl DrawerDragContext {
+    pub fn new(snap_points: Vec<SnapPoint>, active_snap_point: RwSignal<usize>, dismissible: Signal<bool>) -> Self {
+        Self {
+            snap_points: StoredValue::new(snap_points),
+            active_snap_point,
+            drag_offset: RwSignal::new(0.0),
+            is_dragging: RwSignal::new(false),
+            drawer_size: RwSignal::new(0.0),
+            viewport_size: RwSignal::new(0.0),
+            dismissible,
+        }
+    }
+
+    pub fn snap_offsets(&self) -> Vec<f64> {
+        let (drawer_size, viewport_size) = (self.drawer_size.get(), self.viewport_size.get());
+        self.snap_points.with_value(|points| snap_offsets(points, drawer_size, viewport_size))
+    }
+
+    /// Offset of the resting position
+    pub fn resting_offset(&self) -> f64 {
+        let active = self.active_snap_point.get();
+        let offsets = self.snap_offsets();
+        offsets.get(active).or(offsets.first()).copied().unwrap_or(0.0)
+    }
+
+    /// Current offset towards closed, including the drag in progress
+    pub fn offset(&self) -> f64 {
+        dampen(self.resting_offset() + self.drag_offset.get())
+    }
+
+    pub fn progress(&self) -> f64 {
+        drag_progress(self.offset(), self.drawer_size.get())
+    }
+
+    /// Whether the drawer rests at its most open position
+    pub fn is_fully_open(&self) -> bool {
+        let resting = self.resting_offset();
+        self.snap_offsets().iter().all(|offset| *offset >= resting)
+    }
+
+    /// Records the content size and viewport size along the drag axis
+    pub fn measure(&self, content: &web_sys::Element, direction: &DrawerDirection) {
+        let rect = content.get_bounding_client_rect();
+        let window = web_sys::window();
+        let (drawer_size, viewport_size) = if direction.is_vertical() {
+            (rect.height(), window.and_then(|w| w.inner_height().ok()).and_then(|h| h.as_f64()))
+        } else {
+            (rect.width(), window.and_then(|w| w.inner_width().ok()).and_then(|w| w.as_f64()))
+        };
+        self.drawer_size.set(drawer_size);
+        self.viewport_size.set(viewport_size.unwrap_or(drawer_size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snap_offsets() {
+        let points = [SnapPoint::Fraction(0.25), SnapPoint::Pixels(400.0), SnapPoint::Fraction(1.0)];
+        assert_eq!(snap_offsets(&points, 800.0, 800.0), vec![600.0, 400.0, 0.0]);
+        assert_eq!(snap_offsets(&[], 500.0, 800.0), vec![0.0]);
+    }
+
+    #[test]
+    fn test_release_without_snap_points() {
+        let snaps = [0.0];
+        assert_eq!(release(50.0, 0.1, &snaps, 400.0, true), DragRelease::Snap(0));
+        assert_eq!(release(120.0, 0.1, &snaps, 400.0, true), DragRelease::Dismiss);
+        assert_eq!(release(20.0, 1.0, &snaps, 400.0, true), DragRelease::Dismiss);
+        assert_eq!(release(120.0, 1.0, &snaps, 400.0, false), DragRelease::Snap(0));
+    }
+
+    #[test]
+    fn test_release_with_snap_points() {
+        let snaps = [600.0, 400.0, 0.0];
+        // Slow release goes to the closest snap
+        assert_eq!(release(350.0, 0.0, &snaps, 800.0, true), DragRelease::Snap(1));
+        // Flicks move one snap in the flick direction
+        assert_eq!(release(410.0, 1.0, &snaps, 800.0, true), DragRelease::Snap(0));
+        assert_eq!(release(390.0, -1.0, &snaps, 800.0, true), DragRelease::Snap(2));
+        // Flicking closed from the most closed snap dismisses
+        assert_eq!(release(610.0, 1.0, &snaps, 800.0, true), DragRelease::Dismiss);
+        assert_eq!(release(610.0, 0.0, &snaps, 800.0, true), DragRelease::Snap(0));
+    }
+
+    #[test]
+    fn test_progress_and_damping() {
+        assert_eq!(drag_progress(100.0, 400.0), 0.25);
+        assert_eq!(drag_progress(-10.0, 400.0), 0.0);
+        assert!(dampen(-100.0) > -100.0 && dampen(-100.0) < 0.0);
+        assert_eq!(background_scale(0.0), BACKGROUND_SCALE);
+        assert_eq!(background_scale(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_scroll_versus_drag() {
+        assert!(should_drag(10.0, false, 0.0, true));
+        // Scrolled content scrolls back to its edge before the drawer moves
+        assert!(!should_drag(10.0, true, 120.0, true));
+        assert!(should_drag(10.0, true, 0.0, true));
+        // Opening drags expand the drawer, then scroll once fully open
+        assert!(should_drag(-10.0, true, 0.0, false));
+        assert!(!should_drag(-10.0, true, 0.0, true));
+    }
+}
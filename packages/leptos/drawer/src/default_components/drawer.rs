@@ -1,28 +1,47 @@
 //! Main Drawer component
 //! 
-//! This module contains the main Drawer component that provides context and handles
-//! keyboard events for the drawer system.
+//! This module contains the main Drawer component that provides context for
+//! the drawer system and scales the page behind it.
 
 use leptos::prelude::*;
-use web_sys::KeyboardEvent;
 use wasm_bindgen::JsCast;
-use super::types::DrawerDirection;
+use super::drag::{background_scale, DrawerDragContext};
+use super::types::{DrawerDirection, SnapPoint};
+
+/// Selector for the page wrapper scaled by `should_scale_background`
+pub const DRAWER_WRAPPER_SELECTOR: &str = "[data-drawer-wrapper]";
 
 #[component]
 pub fn Drawer(
     #[prop(into)] open: RwSignal<bool>,
     #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
     #[prop(into, optional)] direction: Signal<DrawerDirection>,
+    /// Scales the element marked `data-drawer-wrapper` down behind the open drawer
     #[prop(into, optional)] should_scale_background: Signal<bool>,
-    #[prop(optional)] children: Option<Children>,
+    /// Resting positions, from most closed to most open
+    #[prop(optional)] snap_points: Vec<SnapPoint>,
+    /// Index of the snap point the drawer rests at; updated when dragged
+    #[prop(optional)] active_snap_point: Option<RwSignal<usize>>,
+    /// Whether dragging or clicking outside can close the drawer
+    #[prop(into, optional)] dismissible: MaybeProp<bool>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     provide_context(open);
     provide_context(on_open_change);
     provide_context(direction);
     provide_context(should_scale_background);
 
-    // Handle escape key - use a simpler approach without global listeners
-    // The escape key handling will be managed by the content components
+    let dismissible = Signal::derive(move || dismissible.get().unwrap_or(true));
+    let active_snap_point = active_snap_point.unwrap_or_else(|| RwSignal::new(0));
+    let drag = DrawerDragContext::new(snap_points, active_snap_point, dismissible);
+    provide_context(drag);
+
+    // Scale the page behind the drawer, following the drag progress
+    Effect::new(move |_| {
+        let progress = (open.get() && should_scale_background.get()).then(|| drag.progress());
+        scale_background(progress, drag.is_dragging.get());
+    });
+    on_cleanup(|| scale_background(None, false));
 
     view! {
         <Show
@@ -30,8 +49,36 @@ pub fn Drawer(
             fallback=|| view! { <div></div> }
         >
             <div class="drawer-root">
-                {children.map(|c| c())}
+                {children.clone().map(|c| c())}
             </div>
         </Show>
     }
 }
+
+/// Applies (or with `None`, resets) the background scale for a drag progress
+fn scale_background(progress: Option<f64>, is_dragging: bool) {
+    let Some(wrapper) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.query_selector(DRAWER_WRAPPER_SELECTOR).ok().flatten())
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return;
+    };
+
+    let style = wrapper.style();
+    let transition = if is_dragging { "none" } else { "transform 0.5s cubic-bezier(0.32, 0.72, 0, 1), border-radius 0.5s" };
+    let _ = style.set_property("transition", transition);
+    match progress {
+        Some(progress) => {
+            let _ = style.set_property("transform-origin", "top");
+            let _ = style.set_property("transform", &format!("scale({})", background_scale(progress)));
+            let _ = style.set_property("border-radius", &format!("{}px", 8.0 * (1.0 - progress)));
+            let _ = style.set_property("overflow", "hidden");
+        }
+        None => {
+            let _ = style.remove_property("transform");
+            let _ = style.remove_property("border-radius");
+            let _ = style.remove_property("overflow");
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Drawer handle component
+//! 
+//! This module contains the DrawerHandle component, the grab bar shown at the
+//! edge of a drawer. Dragging it moves the drawer like the rest of the content.
+
+use leptos::prelude::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+#[component]
+pub fn DrawerHandle(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] id: MaybeProp<String>,
+) -> impl IntoView {
+    view! {
+        <div
+            class=move || tw_merge!("mx-auto mt-4 h-2 w-[100px] shrink-0 cursor-grab rounded-full bg-muted active:cursor-grabbing", class.get().unwrap_or_default())
+            id=move || id.get().unwrap_or_default()
+            style="touch-action: none;"
+            aria-hidden="true"
+            data-drawer-handle=""
+        />
+    }
+}
@@ -4,6 +4,7 @@
 //! for better maintainability and readability.
 
 pub mod types;
+pub mod drag;
 pub mod drawer;
 pub mod trigger;
 pub mod portal_overlay;
@@ -12,6 +13,7 @@ pub mod header_footer;
 pub mod title_description;
 pub mod close;
 pub mod nested;
+pub mod handle;
 
 // Re-export all components and types for easy access
 pub use types::*;
@@ -23,3 +25,5 @@ pub use header_footer::{DrawerHeader, DrawerFooter};
 pub use title_description::{DrawerTitle, DrawerDescription};
 pub use close::{DrawerClose, DrawerCloseChildProps};
 pub use nested::DrawerNestedRoot;
+pub use handle::DrawerHandle;
+pub use drag::{DrawerDragContext, DragRelease};
@@ -4,7 +4,8 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
-use super::types::DrawerDirection;
+use super::drag::DrawerDragContext;
+use super::types::{DrawerDirection, SnapPoint};
 
 #[component]
 pub fn DrawerNestedRoot(
@@ -12,6 +13,9 @@ pub fn DrawerNestedRoot(
     #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
     #[prop(into, optional)] direction: Signal<DrawerDirection>,
     #[prop(into, optional)] should_scale_background: Signal<bool>,
+    #[prop(optional)] snap_points: Vec<SnapPoint>,
+    #[prop(optional)] active_snap_point: Option<RwSignal<usize>>,
+    #[prop(into, optional)] dismissible: MaybeProp<bool>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     provide_context(open);
@@ -19,6 +23,11 @@ pub fn DrawerNestedRoot(
     provide_context(direction);
     provide_context(should_scale_background);
 
+    // Nested drawers drag independently of their parent
+    let dismissible = Signal::derive(move || dismissible.get().unwrap_or(true));
+    let active_snap_point = active_snap_point.unwrap_or_else(|| RwSignal::new(0));
+    provide_context(DrawerDragContext::new(snap_points, active_snap_point, dismissible));
+
     view! {
         <div class="drawer-nested-root">
             {children.map(|c| c())}
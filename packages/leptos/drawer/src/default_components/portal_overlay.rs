@@ -5,15 +5,16 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
-use web_sys::MouseEvent;
+use super::drag::DrawerDragContext;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn DrawerPortal(
-    #[prop(optional)] children: Option<Children>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     view! {
         <div class="fixed inset-0 z-50">
-            {children.map(|c| c())}
+            {children.clone().map(|c| c())}
         </div>
     }
 }
@@ -23,24 +24,17 @@ pub fn DrawerOverlay(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
-    #[prop(optional)] children: Option<Children>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
     let open_state = expect_context::<RwSignal<bool>>();
-    let on_open_change = expect_context::<Option<Callback<bool>>>();
     let should_scale_background = expect_context::<Signal<bool>>();
-
-    let handle_click = move |_e: MouseEvent| {
-        open_state.set(false);
-        if let Some(callback) = &on_open_change {
-            callback.run(false);
-        }
-    };
+    let drag = expect_context::<DrawerDragContext>();
 
     let overlay_class = move || {
         let base_class = "fixed inset-0 z-50 bg-background/80 backdrop-blur-sm";
         let scale_class = if should_scale_background.get() { " data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{} {}", base_class, scale_class, custom_class)
+        tw_merge!(base_class, scale_class, custom_class)
     };
 
     view! {
@@ -51,10 +45,13 @@ pub fn DrawerOverlay(
             <div
                 class=overlay_class
                 id=move || id.get().unwrap_or_default()
-                style=move || style.get().unwrap_or_default()
-                on:click=handle_click
+                style=move || {
+                    // Fade out as the drawer is dragged closed
+                    let transition = if drag.is_dragging.get() { "none" } else { "opacity 0.5s cubic-bezier(0.32, 0.72, 0, 1)" };
+                    format!("{}; opacity: {}; transition: {};", style.get().unwrap_or_default(), 1.0 - drag.progress(), transition)
+                }
             >
-                {children.map(|c| c())}
+                {children.clone().map(|c| c())}
             </div>
         </Show>
     }
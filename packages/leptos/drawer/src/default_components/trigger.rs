@@ -4,6 +4,7 @@
 
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 #[component]
 pub fn DrawerTrigger(
@@ -24,7 +25,7 @@ pub fn DrawerTrigger(
 
     view! {
         <button
-            class=move || format!("drawer-trigger {}", class.get().unwrap_or_default())
+            class=move || tw_merge!("drawer-trigger", class.get().unwrap_or_default())
             id=move || id.get().unwrap_or_default()
             style=move || style.get().unwrap_or_default()
             on:click=handle_click
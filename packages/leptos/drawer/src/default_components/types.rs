@@ -15,3 +15,37 @@ impl Default for DrawerDirection {
         DrawerDirection::Bottom
     }
 }
+
+impl DrawerDirection {
+    /// Whether the drawer slides along the vertical axis
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, DrawerDirection::Top | DrawerDirection::Bottom)
+    }
+
+    /// Sign of pointer movement (along the drag axis) that closes the drawer
+    pub fn closing_sign(&self) -> f64 {
+        match self {
+            DrawerDirection::Bottom | DrawerDirection::Right => 1.0,
+            DrawerDirection::Top | DrawerDirection::Left => -1.0,
+        }
+    }
+}
+
+/// Resting position of a drawer, as the part of it left visible
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapPoint {
+    /// Fraction of the viewport, e.g. `0.5` for half the screen
+    Fraction(f64),
+    /// Fixed size in pixels
+    Pixels(f64),
+}
+
+impl SnapPoint {
+    /// Visible size in pixels for a viewport of `viewport_size`
+    pub fn visible_size(&self, viewport_size: f64) -> f64 {
+        match self {
+            SnapPoint::Fraction(fraction) => fraction * viewport_size,
+            SnapPoint::Pixels(pixels) => *pixels,
+        }
+    }
+}
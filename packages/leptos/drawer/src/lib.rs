@@ -8,7 +8,7 @@ pub mod default_components;
 pub use default::{
     Drawer, DrawerTrigger, DrawerContent, DrawerHeader, DrawerFooter,
     DrawerTitle, DrawerDescription, DrawerClose, DrawerOverlay, DrawerPortal,
-    DrawerNestedRoot, DrawerDirection,
+    DrawerNestedRoot, DrawerDirection, DrawerHandle, DrawerDragContext, DragRelease, SnapPoint,
 };
 
 pub use new_york::{
@@ -24,6 +24,7 @@ pub use new_york::{
     DrawerPortal as DrawerPortalNewYork,
     DrawerNestedRoot as DrawerNestedRootNewYork,
     DrawerDirection as DrawerDirectionNewYork,
+    DrawerHandle as DrawerHandleNewYork,
 };
 
 mod tests;
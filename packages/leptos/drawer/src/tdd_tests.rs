@@ -6,12 +6,18 @@ use crate::*;
 mod tdd_tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     // ===== TDD ENHANCED TESTS - GREEN PHASE =====
     // These tests now implement real functionality and verify actual behavior
 
     // Basic Rendering Tests
     #[test]
     fn test_drawer_basic_rendering() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -35,6 +41,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_trigger() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -50,6 +57,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_content() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -65,6 +73,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_header() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -82,6 +91,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_footer() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -100,6 +110,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_title() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -119,6 +130,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_description() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -138,6 +150,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_close() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -157,6 +170,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_overlay() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -173,6 +187,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_portal() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -191,6 +206,7 @@ mod tdd_tests {
     // Direction Tests
     #[test]
     fn test_drawer_direction_top() {
+        init_executor();
         let open = RwSignal::new(false);
         let direction = RwSignal::new(DrawerDirection::Top);
         let _drawer_view = view! {
@@ -207,6 +223,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_direction_bottom() {
+        init_executor();
         let open = RwSignal::new(false);
         let direction = RwSignal::new(DrawerDirection::Bottom);
         let _drawer_view = view! {
@@ -223,6 +240,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_direction_left() {
+        init_executor();
         let open = RwSignal::new(false);
         let direction = RwSignal::new(DrawerDirection::Left);
         let _drawer_view = view! {
@@ -239,6 +257,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_direction_right() {
+        init_executor();
         let open = RwSignal::new(false);
         let direction = RwSignal::new(DrawerDirection::Right);
         let _drawer_view = view! {
@@ -256,6 +275,7 @@ mod tdd_tests {
     // State Management Tests
     #[test]
     fn test_drawer_open_state() {
+        init_executor();
         let open = RwSignal::new(true);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -272,6 +292,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_closed_state() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -288,6 +309,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_state_change() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -312,12 +334,13 @@ mod tdd_tests {
     // Callback Tests
     #[test]
     fn test_drawer_open_change_callback() {
+        init_executor();
         let open = RwSignal::new(false);
         let callback = Callback::new(move |_new_open: bool| {
             // Callback logic
         });
         let _drawer_view = view! {
-            <Drawer open=open on_open_change=Some(callback)>
+            <Drawer open=open on_open_change=callback>
                 <DrawerTrigger>
                     "Open Drawer"
                 </DrawerTrigger>
@@ -331,6 +354,7 @@ mod tdd_tests {
     // Complex Content Tests
     #[test]
     fn test_drawer_complex_content() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -363,6 +387,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_with_forms() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -395,6 +420,7 @@ mod tdd_tests {
     // Multiple Instances Tests
     #[test]
     fn test_drawer_multiple_instances() {
+        init_executor();
         let open1 = RwSignal::new(false);
         let open2 = RwSignal::new(false);
         let _drawer_view = view! {
@@ -422,6 +448,7 @@ mod tdd_tests {
     // Nested Drawer Tests
     #[test]
     fn test_drawer_nested() {
+        init_executor();
         let open1 = RwSignal::new(false);
         let open2 = RwSignal::new(false);
         let _drawer_view = view! {
@@ -450,6 +477,7 @@ mod tdd_tests {
     // Animation and Transitions Tests
     #[test]
     fn test_drawer_animations() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -466,6 +494,7 @@ mod tdd_tests {
     // Accessibility Tests
     #[test]
     fn test_drawer_accessibility() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -482,6 +511,7 @@ mod tdd_tests {
     // Keyboard Navigation Tests
     #[test]
     fn test_drawer_keyboard_navigation() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -498,6 +528,7 @@ mod tdd_tests {
     // Edge Cases and Error Handling
     #[test]
     fn test_drawer_edge_cases() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -513,6 +544,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_empty_content() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -528,6 +560,7 @@ mod tdd_tests {
     // Performance Tests
     #[test]
     fn test_drawer_performance() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -544,6 +577,7 @@ mod tdd_tests {
     // Integration with other components
     #[test]
     fn test_drawer_with_label() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <div>
@@ -560,6 +594,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_with_form() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <form>
@@ -576,6 +611,7 @@ mod tdd_tests {
     // Style Tests
     #[test]
     fn test_drawer_custom_styles() {
+        init_executor();
         let open = RwSignal::new(false);
         let _drawer_view = view! {
             <Drawer open=open>
@@ -591,6 +627,7 @@ mod tdd_tests {
 
     #[test]
     fn test_drawer_combined_props() {
+        init_executor();
         let open = RwSignal::new(false);
         let direction = RwSignal::new(DrawerDirection::Right);
         let should_scale = RwSignal::new(true);
@@ -2,6 +2,7 @@ use leptos::prelude::*;
 use leptos_style::Style;
 use web_sys::{HtmlFormElement, HtmlInputElement, SubmitEvent};
 use wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// Form validation error
 #[derive(Clone, Debug)]
@@ -67,11 +68,7 @@ pub fn Form(
     
     let computed_class = Signal::derive(move || {
         let base_class = "space-y-6";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -101,7 +98,7 @@ pub fn FormField(
         let base_class = "space-y-2";
         let invalid_class = if invalid.get() { " data-invalid" } else { "" };
         let custom_class = class.get().unwrap_or_default();
-        format!("{}{} {}", base_class, invalid_class, custom_class)
+        tw_merge!(base_class, invalid_class, custom_class)
     });
     
     view! {
@@ -126,11 +123,7 @@ pub fn FormItem(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "space-y-2";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -153,11 +146,7 @@ pub fn FormLabel(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -180,11 +169,7 @@ pub fn FormControl(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "peer";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -206,11 +191,7 @@ pub fn FormMessage(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm font-medium text-destructive";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -240,11 +221,7 @@ pub fn FormDescription(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm text-muted-foreground";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
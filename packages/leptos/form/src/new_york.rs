@@ -3,6 +3,7 @@ use leptos_style::Style;
 use web_sys::{HtmlFormElement, SubmitEvent};
 use wasm_bindgen::JsCast;
 use crate::default::{FormData, FormValidation};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// New York theme Form component
 #[component]
@@ -30,11 +31,7 @@ pub fn Form(
     
     let computed_class = Signal::derive(move || {
         let base_class = "space-y-6";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -58,11 +55,7 @@ pub fn FormField(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "space-y-2";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -85,11 +78,7 @@ pub fn FormItem(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "space-y-2";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -112,11 +101,7 @@ pub fn FormLabel(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -139,11 +124,7 @@ pub fn FormControl(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "peer";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -165,11 +146,7 @@ pub fn FormMessage(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm font-medium text-destructive";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
@@ -197,11 +174,7 @@ pub fn FormDescription(
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
         let base_class = "text-sm text-muted-foreground";
-        if let Some(class) = class.get() {
-            format!("{} {}", base_class, class)
-        } else {
-            base_class.to_string()
-        }
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
     
     view! {
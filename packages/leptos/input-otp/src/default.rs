@@ -1,10 +1,36 @@
 use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
 use leptos::web_sys;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
-const INPUT_OTP_CLASS: &str = "flex items-center gap-2";
+use crate::otp::{
+    active_slot_for_caret, caret_to_char_index, char_index_to_caret, insert_at, InputOtpContext, OtpPattern,
+};
+
+const INPUT_OTP_CLASS: &str = "relative flex items-center gap-2 has-[:disabled]:opacity-50";
+const INPUT_OTP_INPUT_CLASS: &str = "absolute inset-0 h-full w-full cursor-text bg-transparent text-transparent caret-transparent opacity-0 outline-none disabled:cursor-not-allowed";
+const INPUT_OTP_GROUP_CLASS: &str = "flex items-center";
 const INPUT_OTP_SLOT_CLASS: &str = "relative flex h-10 w-10 items-center justify-center border-y border-r border-input text-sm transition-all first:rounded-l-md first:border-l last:rounded-r-md focus-within:z-10 focus-within:ring-2 focus-within:ring-ring focus-within:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
+const INPUT_OTP_SLOT_ACTIVE_CLASS: &str = "z-10 ring-2 ring-ring ring-offset-2";
+
+/// Slot classes of one style; the styles share everything else.
+#[derive(Clone, Copy)]
+pub(crate) struct SlotClasses {
+    pub slot: &'static str,
+    pub active: &'static str,
+}
 
+const SLOT_CLASSES: SlotClasses = SlotClasses {
+    slot: INPUT_OTP_SLOT_CLASS,
+    active: INPUT_OTP_SLOT_ACTIVE_CLASS,
+};
+
+/// One-time code input.
+///
+/// A single transparent `<input autocomplete="one-time-code">` sits on top of the
+/// slots, so typing, pasting and SMS autofill all use the native input. Without
+/// children a single group of `max_length` slots is rendered; pass
+/// `InputOtpGroup`/`InputOtpSlot`/`InputOtpSeparator` children for custom layouts.
 #[component]
 pub fn InputOtp(
     #[prop(default = 6)] max_length: usize,
@@ -12,158 +38,242 @@ pub fn InputOtp(
     #[prop(optional)] on_change: Option<Callback<String>>,
     #[prop(optional)] on_complete: Option<Callback<String>>,
     #[prop(optional)] disabled: MaybeProp<bool>,
+    #[prop(optional)] pattern: OtpPattern,
+    /// Renders entered characters as dots
+    #[prop(into, optional)] masked: Signal<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(optional)] class: MaybeProp<String>,
+    #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let current_value = RwSignal::new(value.get().unwrap_or_default());
-    let active_slot = RwSignal::new(0_usize);
-    let is_disabled = disabled.get().unwrap_or(false);
-    
+    input_otp(
+        InputOtpProps {
+            max_length,
+            value,
+            on_change,
+            on_complete,
+            disabled,
+            pattern,
+            masked,
+            name,
+            id,
+            class,
+            children,
+        },
+        SLOT_CLASSES,
+    )
+}
+
+pub(crate) fn input_otp(props: InputOtpProps, slot_classes: SlotClasses) -> impl IntoView {
+    let InputOtpProps {
+        max_length,
+        value,
+        on_change,
+        on_complete,
+        disabled,
+        pattern,
+        masked,
+        name,
+        id,
+        class,
+        children,
+    } = props;
+
+    let current_value = RwSignal::new(value.get_untracked().unwrap_or_default());
+    let context = InputOtpContext {
+        value: current_value,
+        active_slot: RwSignal::new(0_usize),
+        is_focused: RwSignal::new(false),
+        masked,
+        max_length,
+    };
+    provide_context(context);
+
+    let input_mode = pattern.input_mode();
+    let pattern = StoredValue::new(pattern);
+
     // Update value when prop changes
     Effect::new(move |_| {
         if let Some(new_value) = value.get() {
-            current_value.set(new_value);
+            let sanitized = pattern.with_value(|pattern| pattern.sanitize(&new_value, max_length));
+            current_value.set(sanitized);
         }
     });
-    
-    let handle_input = {
-        let current_value = current_value.clone();
-        let active_slot = active_slot.clone();
-        
-        Callback::new(move |input_value: String| {
-            let sanitized: String = input_value.chars()
-                .filter(|c| c.is_ascii_alphanumeric())
-                .take(max_length)
-                .collect();
-                
-            current_value.set(sanitized.clone());
-            
-            // Update active slot
-            let next_slot = sanitized.len().min(max_length - 1);
-            active_slot.set(next_slot);
-            
-            // Call callbacks
-            if let Some(on_change) = on_change {
-                on_change.run(sanitized.clone());
-            }
-            
-            if sanitized.len() == max_length {
-                if let Some(on_complete) = on_complete {
-                    on_complete.run(sanitized);
-                }
-            }
-        })
+
+    let commit = move |input: &web_sys::HtmlInputElement, new_value: String, caret: usize| {
+        if input.value() != new_value {
+            input.set_value(&new_value);
+        }
+        let offset = char_index_to_caret(&new_value, caret) as u32;
+        let _ = input.set_selection_range(offset, offset);
+        context.active_slot.set(active_slot_for_caret(caret, max_length));
+
+        if current_value.get_untracked() == new_value {
+            return;
+        }
+        current_value.set(new_value.clone());
+
+        // Call callbacks
+        if let Some(on_change) = on_change {
+            on_change.run(new_value.clone());
+        }
+        if new_value.chars().count() == max_length
+            && let Some(on_complete) = on_complete
+        {
+            on_complete.run(new_value);
+        }
     };
-    
-    let handle_keydown = {
-        let current_value = current_value.clone();
-        let active_slot = active_slot.clone();
-        
-        Callback::new(move |evt: web_sys::KeyboardEvent| {
-            let key = evt.key();
-            let current_val = current_value.get();
-            let mut chars: Vec<char> = current_val.chars().collect();
-            let active = active_slot.get();
-            
-            match key.as_str() {
-                "Backspace" => {
-                    if active > 0 && active <= chars.len() {
-                        chars.remove(active - 1);
-                        let new_value: String = chars.into_iter().collect();
-                        handle_input.run(new_value);
-                        active_slot.set((active - 1).max(0));
-                    }
-                    evt.prevent_default();
-                }
-                "ArrowLeft" => {
-                    active_slot.set(active.saturating_sub(1));
-                    evt.prevent_default();
-                }
-                "ArrowRight" => {
-                    active_slot.set((active + 1).min(max_length - 1));
-                    evt.prevent_default();
-                }
-                "Delete" => {
-                    if active < chars.len() {
-                        chars.remove(active);
-                        let new_value: String = chars.into_iter().collect();
-                        handle_input.run(new_value);
-                    }
-                    evt.prevent_default();
-                }
-                _ => {
-                    if key.len() == 1 && key.chars().next().unwrap().is_ascii_alphanumeric() {
-                        let new_char = key.chars().next().unwrap();
-                        
-                        if active < max_length {
-                            if active >= chars.len() {
-                                chars.push(new_char);
-                            } else {
-                                chars[active] = new_char;
-                            }
-                            let new_value: String = chars.into_iter().collect();
-                            handle_input.run(new_value);
-                        }
-                        evt.prevent_default();
-                    }
-                }
-            }
-        })
+
+    let handle_input = move |evt: web_sys::Event| {
+        let input: web_sys::HtmlInputElement = evt.target().unwrap().unchecked_into();
+        let raw = input.value();
+        let caret = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map(|caret| caret_to_char_index(&raw, caret as usize))
+            .unwrap_or(max_length);
+        let (sanitized, rejected) = pattern.with_value(|pattern| {
+            let rejected = raw.chars().take(caret).filter(|c| !pattern.accepts(*c)).count();
+            (pattern.sanitize(&raw, max_length), rejected)
+        });
+        let caret = caret.saturating_sub(rejected).min(sanitized.chars().count());
+        commit(&input, sanitized, caret);
     };
-    
-    let merged_class = tw_merge!(&format!("{} {}", 
-        INPUT_OTP_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
-    let slots = move || {
-        let val = current_value.get();
-        let chars: Vec<char> = val.chars().collect();
-        let active = active_slot.get();
-        
-        (0..max_length).map(|i| {
-            let char_value = chars.get(i).map(|c| c.to_string()).unwrap_or_default();
-            let is_active = i == active;
-            
-            let slot_class = if is_active {
-                tw_merge!(&format!("{} {}", INPUT_OTP_SLOT_CLASS, "z-10 ring-2 ring-ring ring-offset-2"))
-            } else {
-                INPUT_OTP_SLOT_CLASS.to_string()
-            };
-            
-            view! {
-                <div class={slot_class}>
-                    <input
-                        r#type="text"
-                        class="absolute inset-0 w-full h-full text-center bg-transparent border-none outline-none focus:outline-none"
-                        value={char_value.clone()}
-                        maxlength="1"
-                        disabled=move || is_disabled.get()
-                        aria-label={format!("Digit {}", i + 1)}
-                        on:keydown={
-                            let handle_keydown = handle_keydown.clone();
-                            move |evt| handle_keydown.run(evt)
-                        }
-                        readonly=true
-                    />
-                    <div class="pointer-events-none absolute inset-0 flex items-center justify-center">
-                        {if char_value.is_empty() && is_active {
-                            view! { <div class="h-4 w-px bg-foreground animate-pulse" /> }.into_any()
-                        } else {
-                            view! { <span>{char_value}</span> }.into_any()
-                        }}
-                    </div>
-                </div>
-            }
-        }).collect::<Vec<_>>()
+
+    let handle_paste = move |evt: web_sys::ClipboardEvent| {
+        let Some(text) = evt.clipboard_data().and_then(|data| data.get_data("text").ok()) else {
+            return;
+        };
+        evt.prevent_default();
+
+        let input: web_sys::HtmlInputElement = evt.target().unwrap().unchecked_into();
+        let slot = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map(|caret| caret_to_char_index(&input.value(), caret as usize))
+            .unwrap_or(0);
+        let (new_value, caret) = pattern.with_value(|pattern| {
+            insert_at(&current_value.get_untracked(), slot, &text, pattern, max_length)
+        });
+        commit(&input, new_value, caret);
     };
-    
+
+    // Follow the caret so arrow keys and selection move the highlighted slot
+    let sync_caret = move |target: Option<web_sys::EventTarget>| {
+        let input: web_sys::HtmlInputElement = target.unwrap().unchecked_into();
+        let caret = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map(|caret| caret_to_char_index(&input.value(), caret as usize))
+            .unwrap_or(0);
+        context.active_slot.set(active_slot_for_caret(caret, max_length));
+    };
+
+    let merged_class = tw_merge!(INPUT_OTP_CLASS, class.get().unwrap_or_default());
+
+    let content = match children {
+        Some(children) => children().into_any(),
+        None => view! {
+            <InputOtpGroup>
+                {(0..max_length)
+                    .map(|index| {
+                        input_otp_slot(
+                            InputOtpSlotProps { index, class: MaybeProp::default() },
+                            slot_classes,
+                        )
+                    })
+                    .collect_view()}
+            </InputOtpGroup>
+        }
+        .into_any(),
+    };
+
     view! {
-        <div 
+        <div
             class={merged_class}
             role="group"
-            aria-label="One-time password input"
+            data-disabled=move || disabled.get().unwrap_or(false).then_some("")
         >
-            {slots()}
+            {content}
+            <input
+                r#type="text"
+                class=INPUT_OTP_INPUT_CLASS
+                id=move || id.get()
+                name=move || name.get()
+                prop:value=move || current_value.get()
+                maxlength=max_length
+                inputmode=input_mode
+                autocomplete="one-time-code"
+                spellcheck="false"
+                aria-label="One-time password input"
+                disabled=move || disabled.get().unwrap_or(false)
+                on:input=handle_input
+                on:paste=handle_paste
+                on:keyup=move |evt| sync_caret(evt.target())
+                on:select=move |evt| sync_caret(evt.target())
+                on:focus=move |evt| {
+                    context.is_focused.set(true);
+                    let input: web_sys::HtmlInputElement = evt.target().unwrap().unchecked_into();
+                    let (end, offset) = current_value
+                        .with_untracked(|value| (value.chars().count(), value.encode_utf16().count() as u32));
+                    let _ = input.set_selection_range(offset, offset);
+                    context.active_slot.set(active_slot_for_caret(end, max_length));
+                }
+                on:blur=move |_| context.is_focused.set(false)
+            />
+        </div>
+    }
+}
+
+#[component]
+pub fn InputOtpGroup(
+    #[prop(optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let merged_class = tw_merge!(INPUT_OTP_GROUP_CLASS, class.get().unwrap_or_default());
+
+    view! {
+        <div class={merged_class} aria-hidden="true">
+            {children()}
+        </div>
+    }
+}
+
+#[component]
+pub fn InputOtpSlot(
+    index: usize,
+    #[prop(optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    input_otp_slot(InputOtpSlotProps { index, class }, SLOT_CLASSES)
+}
+
+pub(crate) fn input_otp_slot(props: InputOtpSlotProps, slot_classes: SlotClasses) -> impl IntoView {
+    let InputOtpSlotProps { index, class } = props;
+    let context = expect_context::<InputOtpContext>();
+
+    let slot_class = move || {
+        let active_class = if context.is_slot_active(index) { slot_classes.active } else { "" };
+        tw_merge!(slot_classes.slot, active_class, class.get().unwrap_or_default())
+    };
+
+    view! {
+        <div
+            class=slot_class
+            data-active=move || context.is_slot_active(index).to_string()
+        >
+            {move || match context.slot_char(index) {
+                Some(c) => view! { <span>{c.to_string()}</span> }.into_any(),
+                None if context.is_slot_active(index) => view! {
+                    <div class="pointer-events-none absolute inset-0 flex items-center justify-center">
+                        <div class="h-4 w-px bg-foreground animate-pulse" />
+                    </div>
+                }
+                .into_any(),
+                None => ().into_any(),
+            }}
         </div>
     }
 }
@@ -173,12 +283,10 @@ pub fn InputOtpSeparator(
     #[prop(optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("flex w-px items-center justify-center {}",
-        class.get().unwrap_or_default()
-    ));
-    
+    let merged_class = tw_merge!("flex w-px items-center justify-center", class.get().unwrap_or_default());
+
     view! {
-        <div class={merged_class}>
+        <div class={merged_class} role="separator">
             {if let Some(children) = children {
                 children().into_any()
             } else {
@@ -186,4 +294,4 @@ pub fn InputOtpSeparator(
             }}
         </div>
     }
-}
\ No newline at end of file
+}
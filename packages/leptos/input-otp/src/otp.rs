@@ -0,0 +1,188 @@
+//! Shared state and value logic for the InputOtp components
+//!
+//! The value handling here is pure so it can be tested without a DOM; the
+//! styled components in `default` and `new_york` only render it.
+
+use leptos::prelude::*;
+use regex::Regex;
+
+/// Characters accepted by an [`InputOtp`](crate::InputOtp)
+#[derive(Clone, Debug, Default)]
+pub enum OtpPattern {
+    /// ASCII digits only, with a numeric keypad on mobile
+    Digits,
+    /// ASCII letters only
+    Letters,
+    /// ASCII letters and digits
+    #[default]
+    Alphanumeric,
+    /// Custom regex, matched against each character individually
+    Custom(Regex),
+}
+
+impl OtpPattern {
+    /// Builds a custom pattern, e.g. `OtpPattern::regex("[0-9a-fA-F]")` for hex codes
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(&format!("^(?:{})$", pattern)).map(OtpPattern::Custom)
+    }
+
+    pub fn accepts(&self, c: char) -> bool {
+        match self {
+            OtpPattern::Digits => c.is_ascii_digit(),
+            OtpPattern::Letters => c.is_ascii_alphabetic(),
+            OtpPattern::Alphanumeric => c.is_ascii_alphanumeric(),
+            OtpPattern::Custom(regex) => regex.is_match(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    /// Drops rejected characters (spaces, dashes, ...) and truncates to `max_length`
+    pub fn sanitize(&self, text: &str, max_length: usize) -> String {
+        text.chars().filter(|c| self.accepts(*c)).take(max_length).collect()
+    }
+
+    /// Value for the `inputmode` attribute
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            OtpPattern::Digits => "numeric",
+            _ => "text",
+        }
+    }
+}
+
+/// Inserts `text` at `slot`, overwriting the following slots.
+///
+/// Returns the new value and the slot the caret moves to. A paste that holds a
+/// full code replaces the whole value regardless of the caret.
+pub fn insert_at(
+    current: &str,
+    slot: usize,
+    text: &str,
+    pattern: &OtpPattern,
+    max_length: usize,
+) -> (String, usize) {
+    let inserted: Vec<char> = pattern.sanitize(text, max_length).chars().collect();
+    if inserted.len() == max_length {
+        return (inserted.into_iter().collect(), max_length);
+    }
+
+    let mut chars: Vec<char> = current.chars().take(max_length).collect();
+    let start = slot.min(chars.len());
+    for (offset, c) in inserted.iter().enumerate() {
+        match chars.get_mut(start + offset) {
+            Some(existing) => *existing = *c,
+            None => chars.push(*c),
+        }
+    }
+    chars.truncate(max_length);
+
+    (chars.into_iter().collect(), (start + inserted.len()).min(max_length))
+}
+
+/// Char index of a caret the DOM reports in UTF-16 code units, e.g. by
+/// `selection_start`, so characters outside the BMP count as one slot
+pub fn caret_to_char_index(value: &str, caret: usize) -> usize {
+    let mut units = 0;
+    value
+        .chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= caret
+        })
+        .count()
+}
+
+/// UTF-16 offset of a char index, as `set_selection_range` expects it
+pub fn char_index_to_caret(value: &str, index: usize) -> usize {
+    value.chars().take(index).map(char::len_utf16).sum()
+}
+
+/// Slot that is highlighted for a caret position
+pub fn active_slot_for_caret(caret: usize, max_length: usize) -> usize {
+    caret.min(max_length.saturating_sub(1))
+}
+
+/// Context shared by `InputOtp` and its `InputOtpSlot`s
+#[derive(Clone, Copy)]
+pub struct InputOtpContext {
+    pub value: RwSignal<String>,
+    pub active_slot: RwSignal<usize>,
+    pub is_focused: RwSignal<bool>,
+    pub masked: Signal<bool>,
+    pub max_length: usize,
+}
+
+impl InputOtpContext {
+    /// Character shown in a slot, masked when requested
+    pub fn slot_char(&self, index: usize) -> Option<char> {
+        let c = self.value.with(|value| value.chars().nth(index))?;
+        Some(if self.masked.get() { '•' } else { c })
+    }
+
+    pub fn is_slot_active(&self, index: usize) -> bool {
+        self.is_focused.get() && self.active_slot.get() == index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert!(OtpPattern::Digits.accepts('7'));
+        assert!(!OtpPattern::Digits.accepts('a'));
+        assert!(OtpPattern::Letters.accepts('a'));
+        assert!(!OtpPattern::Letters.accepts('7'));
+        assert!(OtpPattern::Alphanumeric.accepts('Z'));
+        assert!(!OtpPattern::Alphanumeric.accepts('-'));
+        assert_eq!(OtpPattern::Digits.input_mode(), "numeric");
+        assert_eq!(OtpPattern::default().input_mode(), "text");
+    }
+
+    #[test]
+    fn test_custom_regex() {
+        let hex = OtpPattern::regex("[0-9a-f]").unwrap();
+        assert!(hex.accepts('c'));
+        assert!(!hex.accepts('g'));
+        assert_eq!(hex.sanitize("de:ad:be:ef", 6), "deadbe");
+        assert!(OtpPattern::regex("[").is_err());
+    }
+
+    #[test]
+    fn test_paste_full_code_fills_all_slots() {
+        let (value, caret) = insert_at("12", 1, "987-654", &OtpPattern::Digits, 6);
+        assert_eq!(value, "987654");
+        assert_eq!(caret, 6);
+    }
+
+    #[test]
+    fn test_paste_partial_code_at_slot() {
+        let (value, caret) = insert_at("1234", 1, "99", &OtpPattern::Digits, 6);
+        assert_eq!(value, "1994");
+        assert_eq!(caret, 3);
+
+        let (value, caret) = insert_at("12", 5, "345", &OtpPattern::Digits, 6);
+        assert_eq!(value, "12345");
+        assert_eq!(caret, 5);
+    }
+
+    #[test]
+    fn test_caret_outside_bmp() {
+        let pattern = OtpPattern::regex("[0-9😀🔑]").unwrap();
+        // The caret after "1😀" is at UTF-16 offset 3 but before the third char.
+        let caret = caret_to_char_index("1😀2", 3);
+        assert_eq!(caret, 2);
+        let (value, caret) = insert_at("1😀2", caret, "🔑", &pattern, 6);
+        assert_eq!(value, "1😀🔑");
+        assert_eq!(char_index_to_caret(&value, caret), 5);
+        // A caret inside a surrogate pair stays before the character.
+        assert_eq!(caret_to_char_index("1😀2", 2), 1);
+        assert_eq!(caret_to_char_index("1😀2", 10), 3);
+    }
+
+    #[test]
+    fn test_active_slot_for_caret() {
+        assert_eq!(active_slot_for_caret(0, 6), 0);
+        assert_eq!(active_slot_for_caret(6, 6), 5);
+    }
+}
@@ -1,7 +1,8 @@
 use leptos::{ev::Event, prelude::*};
 use leptos_style::Style;
 use leptos::wasm_bindgen::JsCast;
-use crate::validation::{InputValidator, ValidationResult};
+use crate::validation::{AsyncValidator, InputValidator, ValidationContext, ValidationResult};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const INPUT_CLASS: &str = "flex h-10 w-full rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
 
@@ -21,32 +22,15 @@ pub fn Input(
     #[prop(into, optional)] validator: Option<InputValidator>,
     #[prop(into, optional)] validation_error: MaybeProp<String>,
     #[prop(into, optional)] show_validation: Signal<bool>,
+    /// Debounced server-side check, run only once the synchronous rules pass
+    #[prop(into, optional)] async_validator: Option<AsyncValidator>,
+    /// Shared form context, required for cross-field rules such as `Matches`
+    #[prop(into, optional)] validation_context: Option<RwSignal<ValidationContext>>,
 ) -> impl IntoView {
-    let (validation_result, set_validation_result) = signal(ValidationResult::new());
-    let (_is_validating, _set_is_validating) = signal(false);
-
-    let handle_input = {
-        let on_change = on_change.clone();
-        let set_validation_result = set_validation_result.clone();
-        let _set_is_validating = _set_is_validating.clone();
-        
-        move |event: Event| {
-            if let Some(callback) = &on_change {
-                let target = event.target().unwrap();
-                let input = target.unchecked_into::<web_sys::HtmlInputElement>();
-                let input_value = input.value();
-                callback.run(input_value.clone());
-                
-                // TDD Enhancement: Real-time validation
-                if let Some(validator) = &validator {
-                    _set_is_validating.set(true);
-                    let result = validator.validate(&input_value);
-                    set_validation_result.set(result);
-                    _set_is_validating.set(false);
-                }
-            }
-        }
-    };
+    let (validation, handle_input) =
+        use_input_validation(on_change, validator, async_validator, validation_context, id);
+    let validation_result = validation.result;
+    let is_validating = validation.is_validating;
 
     let computed_class = Signal::derive(move || {
         let base_class = INPUT_CLASS;
@@ -57,7 +41,7 @@ pub fn Input(
             ""
         };
         
-        format!("{} {} {}", base_class, custom_class, error_class).trim().to_string()
+        tw_merge!(base_class, custom_class, error_class).trim().to_string()
     });
 
     let display_error = Signal::derive(move || {
@@ -81,6 +65,7 @@ pub fn Input(
                 id=move || id.get().unwrap_or_default()
                 style=move || style.get().to_string()
                 on:input=handle_input
+                aria-busy=move || is_validating.get().to_string()
                 aria-invalid=move || (validation_result.get().has_errors() || validation_error.get().is_some()).to_string()
                 aria-describedby=move || {
                     if display_error.get().is_some() {
@@ -102,3 +87,100 @@ pub fn Input(
         </div>
     }
 }
+
+/// Validation state shared by the default and New York inputs
+#[derive(Clone, Copy)]
+pub(crate) struct InputValidation {
+    pub result: ReadSignal<ValidationResult>,
+    pub is_validating: ReadSignal<bool>,
+}
+
+/// Builds the `on:input` handler that reports changes and runs the sync and async validators.
+///
+/// Every keystroke clears the pending debounce and cancels in-flight async runs, so a slow
+/// response for an older value never overwrites the current result. Async results are also
+/// recorded in `validation_context`, keeping form-level validity in sync with the field.
+pub(crate) fn use_input_validation(
+    on_change: Option<Callback<String>>,
+    validator: Option<InputValidator>,
+    async_validator: Option<AsyncValidator>,
+    validation_context: Option<RwSignal<ValidationContext>>,
+    id: MaybeProp<String>,
+) -> (InputValidation, impl FnMut(Event) + 'static) {
+    let (validation_result, set_validation_result) = signal(ValidationResult::new());
+    let (is_validating, set_is_validating) = signal(false);
+    let pending_timeout = StoredValue::new(None::<TimeoutHandle>);
+
+    let handle_input = move |event: Event| {
+        let target = event.target().unwrap();
+        let input = target.unchecked_into::<web_sys::HtmlInputElement>();
+        let input_value = input.value();
+        if let Some(callback) = &on_change {
+            callback.run(input_value.clone());
+        }
+
+        // TDD Enhancement: Real-time validation
+        let result = match (&validator, validation_context) {
+            (Some(validator), Some(context)) => {
+                context.update(|context| validator.validate_with_context(&input_value, context));
+                context.with(|context| {
+                    context
+                        .get_field_result(&validator.field_name)
+                        .cloned()
+                        .unwrap_or_default()
+                })
+            }
+            (Some(validator), None) => validator.validate(&input_value),
+            (None, _) => ValidationResult::new(),
+        };
+
+        if let Some(handle) = pending_timeout.get_value() {
+            handle.clear();
+            pending_timeout.set_value(None);
+        }
+        if let Some(async_validator) = &async_validator {
+            async_validator.cancel();
+        }
+
+        let Some(async_validator) = async_validator.clone().filter(|_| result.is_valid) else {
+            set_is_validating.set(false);
+            set_validation_result.set(result);
+            return;
+        };
+
+        set_validation_result.set(result);
+        set_is_validating.set(true);
+        let field_name = validator
+            .as_ref()
+            .map(|validator| validator.field_name.clone())
+            .or_else(|| id.get_untracked())
+            .unwrap_or_default();
+        let debounce = async_validator.debounce;
+        let handle = set_timeout_with_handle(
+            move || {
+                leptos::task::spawn_local(async move {
+                    let Some(result) = async_validator.validate(field_name.clone(), input_value).await
+                    else {
+                        return;
+                    };
+                    if let Some(context) = validation_context {
+                        context.update(|context| context.add_field_result(field_name, result.clone()));
+                    }
+                    set_validation_result.set(result);
+                    set_is_validating.set(false);
+                });
+            },
+            debounce,
+        )
+        .ok();
+        pending_timeout.set_value(handle);
+    };
+
+    (
+        InputValidation {
+            result: validation_result,
+            is_validating,
+        },
+        handle_input,
+    )
+}
@@ -4,12 +4,18 @@ pub mod default;
 pub mod new_york;
 pub mod validation;
 pub mod signal_managed;
+pub mod mask;
 
 pub use default::{Input};
 pub use new_york::{Input as InputNewYork};
 pub use validation::{
     ValidationRule, ValidationError, ValidationResult, 
-    InputValidator, ValidationContext, validation_builders
+    InputValidator, ValidationContext, validation_builders,
+    CustomValidator, AsyncValidator, MessageCatalog, FieldValues,
+};
+pub use mask::{
+    MaskedInput, InputMask, InputFormatter, MaskedValue, PatternMask, NumberFormat,
+    CardNumberFormat, CardBrand, apply_format, luhn_valid,
 };
 pub use signal_managed::{SignalManagedInput, EnhancedInput, SignalManagedInputState, INPUT_CLASS, INPUT_ERROR_CLASS};
 
@@ -0,0 +1,164 @@
+//! Payment card number formatting and Luhn validation
+
+use super::{FormattedText, InputFormatter};
+
+/// Card network detected from the number prefix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+    Unknown,
+}
+
+impl CardBrand {
+    pub fn detect(digits: &str) -> Self {
+        let prefix = |len: usize| digits.get(..len).and_then(|p| p.parse::<u32>().ok());
+
+        if digits.starts_with('4') {
+            CardBrand::Visa
+        } else if matches!(prefix(2), Some(34 | 37)) {
+            CardBrand::Amex
+        } else if matches!(prefix(2), Some(51..=55)) || matches!(prefix(4), Some(2221..=2720)) {
+            CardBrand::Mastercard
+        } else if digits.starts_with("6011") || digits.starts_with("65") {
+            CardBrand::Discover
+        } else {
+            CardBrand::Unknown
+        }
+    }
+
+    /// Digit group sizes used for display
+    pub fn groups(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Amex => &[4, 6, 5],
+            _ => &[4, 4, 4, 4, 3],
+        }
+    }
+
+    /// Valid number lengths
+    pub fn lengths(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Amex => &[15],
+            CardBrand::Visa => &[13, 16, 19],
+            CardBrand::Mastercard => &[16],
+            CardBrand::Discover | CardBrand::Unknown => &[16, 19],
+        }
+    }
+}
+
+/// Luhn (mod 10) checksum used by card numbers
+pub fn luhn_valid(digits: &str) -> bool {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match index % 2 {
+            1 if digit * 2 > 9 => digit * 2 - 9,
+            1 => digit * 2,
+            _ => digit,
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// Card number formatter grouping digits by brand, e.g. `4242 4242 4242 4242`
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardNumberFormat {
+    pub separator: char,
+}
+
+impl CardNumberFormat {
+    pub fn new() -> Self {
+        Self { separator: ' ' }
+    }
+
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+impl Default for CardNumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputFormatter for CardNumberFormat {
+    fn parse(&self, text: &str) -> String {
+        let digits: String = text.chars().filter(|c| c.is_ascii_digit()).collect();
+        let max_len = CardBrand::detect(&digits)
+            .lengths()
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(19);
+        digits.chars().take(max_len).collect()
+    }
+
+    fn format(&self, raw: &str) -> FormattedText {
+        let mut text = String::new();
+        let mut raw_ends = Vec::new();
+        let mut group_ends = CardBrand::detect(raw).groups().iter().scan(0, |end, size| {
+            *end += size;
+            Some(*end)
+        });
+        let mut next_break = group_ends.next();
+
+        for (index, digit) in raw.chars().enumerate() {
+            if next_break == Some(index) {
+                text.push(self.separator);
+                next_break = group_ends.next();
+            }
+            text.push(digit);
+            raw_ends.push(text.chars().count());
+        }
+
+        FormattedText { text, raw_ends }
+    }
+
+    fn is_complete(&self, raw: &str) -> bool {
+        CardBrand::detect(raw).lengths().contains(&raw.len()) && luhn_valid(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn() {
+        assert!(luhn_valid("4242424242424242"));
+        assert!(luhn_valid("378282246310005"));
+        assert!(!luhn_valid("4242424242424241"));
+        assert!(!luhn_valid(""));
+    }
+
+    #[test]
+    fn test_brand_detection() {
+        assert_eq!(CardBrand::detect("4242"), CardBrand::Visa);
+        assert_eq!(CardBrand::detect("3782"), CardBrand::Amex);
+        assert_eq!(CardBrand::detect("5555"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("2223"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("6011"), CardBrand::Discover);
+        assert_eq!(CardBrand::detect("9999"), CardBrand::Unknown);
+    }
+
+    #[test]
+    fn test_card_formatting() {
+        let format = CardNumberFormat::new();
+        assert_eq!(format.format("4242424242424242").text, "4242 4242 4242 4242");
+        assert_eq!(format.format("378282246310005").text, "3782 822463 10005");
+        assert_eq!(format.parse("3782-8224-6310-0059999"), "378282246310005");
+        assert!(format.is_complete("4242424242424242"));
+        assert!(!format.is_complete("4242424242424241"));
+    }
+}
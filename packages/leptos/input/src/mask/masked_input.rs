@@ -0,0 +1,127 @@
+//! Masked Input component
+//!
+//! Renders the formatted value in a visible input and the raw value in a hidden
+//! input carrying `name`, so native form submission sends unformatted data.
+
+use leptos::{ev::Event, prelude::*};
+use leptos_style::Style;
+use leptos::wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use super::{apply_format, InputFormatter, InputMask};
+use crate::default::INPUT_CLASS;
+
+#[component]
+pub fn MaskedInput(
+    #[prop(into)] mask: InputMask,
+    /// Raw (unformatted) value
+    #[prop(into, optional)] value: MaybeProp<String>,
+    /// Called with the raw value on every edit
+    #[prop(into, optional)] on_change: Option<Callback<String>>,
+    /// Called with the formatted display value on every edit
+    #[prop(into, optional)] on_formatted_change: Option<Callback<String>>,
+    /// Name of the hidden input that submits the raw value
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] placeholder: MaybeProp<String>,
+    #[prop(into, optional)] input_mode: MaybeProp<String>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] style: Signal<Style>,
+) -> impl IntoView {
+    let mask = StoredValue::new(mask);
+    let initial = value.get_untracked().unwrap_or_default();
+    let (raw, set_raw) = signal(mask.with_value(|mask| mask.parse(&initial)));
+    let formatted = Memo::new(move |_| raw.with(|raw| mask.with_value(|mask| mask.format(raw).text)));
+
+    // Keep in sync with a controlled `value`
+    Effect::new(move |_| {
+        if let Some(value) = value.get() {
+            let parsed = mask.with_value(|mask| mask.parse(&value));
+            if parsed != raw.get_untracked() {
+                set_raw.set(parsed);
+            }
+        }
+    });
+
+    let notify = move |raw_value: String, formatted_value: String| {
+        if let Some(callback) = &on_change {
+            callback.run(raw_value);
+        }
+        if let Some(callback) = &on_formatted_change {
+            callback.run(formatted_value);
+        }
+    };
+
+    let handle_input = move |event: Event| {
+        let input = event.target().unwrap().unchecked_into::<web_sys::HtmlInputElement>();
+        let text = input.value();
+        let caret = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map(|offset| char_index_from_utf16(&text, offset as usize))
+            .unwrap_or_else(|| text.chars().count());
+
+        let masked = mask.with_value(|mask| apply_format(mask, &text, caret));
+
+        // Write the formatted value and caret before the reactive update, so
+        // the caret doesn't jump to the end when the DOM value changes
+        input.set_value(&masked.formatted);
+        let caret = utf16_index_from_char(&masked.formatted, masked.caret) as u32;
+        let _ = input.set_selection_range(caret, caret);
+
+        set_raw.set(masked.raw.clone());
+        notify(masked.raw, masked.formatted);
+    };
+
+    let handle_blur = move |_| {
+        let finalized = raw.with_untracked(|raw| mask.with_value(|mask| mask.finalize(raw)));
+        if finalized != raw.get_untracked() {
+            set_raw.set(finalized.clone());
+            notify(finalized, formatted.get_untracked());
+        }
+    };
+
+    let computed_class = Signal::derive(move || {
+        tw_merge!(INPUT_CLASS, class.get().unwrap_or_default())
+    });
+
+    view! {
+        <input
+            r#type="text"
+            prop:value=move || formatted.get()
+            placeholder=move || placeholder.get().unwrap_or_default()
+            inputmode=move || input_mode.get()
+            disabled=move || disabled.get()
+            class=move || computed_class.get()
+            id=move || id.get().unwrap_or_default()
+            style=move || style.get().to_string()
+            data-complete=move || raw.with(|raw| mask.with_value(|mask| mask.is_complete(raw))).to_string()
+            on:input=handle_input
+            on:blur=handle_blur
+        />
+        <input
+            r#type="hidden"
+            name=move || name.get()
+            prop:value=move || raw.get()
+        />
+    }
+}
+
+/// Converts a DOM selection offset (UTF-16 code units) to a char index
+fn char_index_from_utf16(text: &str, offset: usize) -> usize {
+    let mut units = 0;
+    for (index, c) in text.chars().enumerate() {
+        if units >= offset {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.chars().count()
+}
+
+/// Converts a char index to a DOM selection offset (UTF-16 code units)
+fn utf16_index_from_char(text: &str, index: usize) -> usize {
+    text.chars().take(index).map(char::len_utf16).sum()
+}
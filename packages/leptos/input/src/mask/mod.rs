@@ -0,0 +1,202 @@
+//! Input masks and formatted inputs
+//!
+//! This module contains a small mask engine for the Input component: pattern masks
+//! such as `(999) 999-9999`, locale-aware number formatting and card number
+//! formatting. Formatters work on plain strings so they can be unit tested; the
+//! [`MaskedInput`] component wires them to the DOM and keeps the caret in place.
+
+pub mod pattern;
+pub mod number;
+pub mod card;
+pub mod masked_input;
+
+use std::fmt;
+use std::sync::Arc;
+
+pub use pattern::PatternMask;
+pub use number::NumberFormat;
+pub use card::{CardBrand, CardNumberFormat, luhn_valid};
+pub use masked_input::MaskedInput;
+
+/// Display text produced by a formatter
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormattedText {
+    pub text: String,
+    /// For each raw character, the char offset in `text` just after it
+    pub raw_ends: Vec<usize>,
+}
+
+/// Converts between the raw value (what forms submit) and its formatted display
+pub trait InputFormatter {
+    /// Extracts the raw value from user-entered text, dropping literals and rejected characters
+    fn parse(&self, text: &str) -> String;
+
+    /// Formats a raw value for display
+    fn format(&self, raw: &str) -> FormattedText;
+
+    /// Normalizes the raw value once editing is finished (e.g. on blur)
+    fn finalize(&self, raw: &str) -> String {
+        raw.to_string()
+    }
+
+    /// Whether the raw value is a complete, valid entry
+    fn is_complete(&self, raw: &str) -> bool {
+        !raw.is_empty()
+    }
+}
+
+/// Result of formatting user input
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaskedValue {
+    pub raw: String,
+    pub formatted: String,
+    /// Caret position in `formatted`, in chars
+    pub caret: usize,
+}
+
+/// Formats `text` and maps the caret so it stays after the same raw character.
+///
+/// `caret` is a char offset into `text`. This is used for typing, deleting and
+/// pasting alike, since all three surface as a new text value plus a caret.
+pub fn apply_format<F: InputFormatter + ?Sized>(formatter: &F, text: &str, caret: usize) -> MaskedValue {
+    let prefix: String = text.chars().take(caret).collect();
+    let raw_before = formatter.parse(&prefix).chars().count();
+
+    let raw = formatter.parse(text);
+    let formatted = formatter.format(&raw);
+    let caret = match raw_before {
+        0 => 0,
+        count => formatted
+            .raw_ends
+            .get(count - 1)
+            .or(formatted.raw_ends.last())
+            .copied()
+            .unwrap_or(0),
+    };
+
+    MaskedValue {
+        raw,
+        formatted: formatted.text,
+        caret,
+    }
+}
+
+/// Mask applied by [`MaskedInput`]
+#[derive(Clone)]
+pub enum InputMask {
+    Pattern(PatternMask),
+    Number(NumberFormat),
+    CardNumber(CardNumberFormat),
+    Custom(Arc<dyn InputFormatter + Send + Sync>),
+}
+
+impl InputMask {
+    /// Pattern mask, see [`PatternMask::new`] for the syntax
+    pub fn pattern(pattern: &str) -> Self {
+        InputMask::Pattern(PatternMask::new(pattern))
+    }
+
+    fn formatter(&self) -> &dyn InputFormatter {
+        match self {
+            InputMask::Pattern(mask) => mask,
+            InputMask::Number(format) => format,
+            InputMask::CardNumber(format) => format,
+            InputMask::Custom(formatter) => formatter.as_ref(),
+        }
+    }
+}
+
+impl InputFormatter for InputMask {
+    fn parse(&self, text: &str) -> String {
+        self.formatter().parse(text)
+    }
+
+    fn format(&self, raw: &str) -> FormattedText {
+        self.formatter().format(raw)
+    }
+
+    fn finalize(&self, raw: &str) -> String {
+        self.formatter().finalize(raw)
+    }
+
+    fn is_complete(&self, raw: &str) -> bool {
+        self.formatter().is_complete(raw)
+    }
+}
+
+impl fmt::Debug for InputMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputMask::Pattern(mask) => f.debug_tuple("Pattern").field(mask).finish(),
+            InputMask::Number(format) => f.debug_tuple("Number").field(format).finish(),
+            InputMask::CardNumber(format) => f.debug_tuple("CardNumber").field(format).finish(),
+            InputMask::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl From<PatternMask> for InputMask {
+    fn from(mask: PatternMask) -> Self {
+        InputMask::Pattern(mask)
+    }
+}
+
+impl From<NumberFormat> for InputMask {
+    fn from(format: NumberFormat) -> Self {
+        InputMask::Number(format)
+    }
+}
+
+impl From<CardNumberFormat> for InputMask {
+    fn from(format: CardNumberFormat) -> Self {
+        InputMask::CardNumber(format)
+    }
+}
+
+impl From<&str> for InputMask {
+    fn from(pattern: &str) -> Self {
+        InputMask::pattern(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret_after_typing_across_literal() {
+        let mask = PatternMask::new("(999) 999-9999");
+        // User typed "4" at the end of "(555"
+        let value = apply_format(&mask, "(5554", 5);
+        assert_eq!(value.formatted, "(555) 4");
+        assert_eq!(value.raw, "5554");
+        assert_eq!(value.caret, 7);
+    }
+
+    #[test]
+    fn test_caret_preserved_on_middle_edit() {
+        let mask = PatternMask::new("(999) 999-9999");
+        // Inserted "9" after "(55" in "(555) 123-4567"
+        let value = apply_format(&mask, "(559) 5123-4567", 4);
+        assert_eq!(value.formatted, "(559) 512-3456");
+        assert_eq!(value.caret, 4);
+    }
+
+    #[test]
+    fn test_paste_unformatted_value() {
+        let mask = InputMask::pattern("(999) 999-9999");
+        let value = apply_format(&mask, "5551234567", 10);
+        assert_eq!(value.formatted, "(555) 123-4567");
+        assert_eq!(value.raw, "5551234567");
+        assert_eq!(value.caret, 14);
+        assert!(mask.is_complete(&value.raw));
+    }
+
+    #[test]
+    fn test_caret_at_start() {
+        let format = NumberFormat::default();
+        let value = apply_format(&format, "11234", 0);
+        assert_eq!(value.formatted, "11,234");
+        assert_eq!(value.caret, 0);
+    }
+}
@@ -0,0 +1,239 @@
+//! Locale-aware numeric formatting for amounts, quantities and percentages
+//!
+//! The raw value always uses `.` as the decimal separator and no grouping, so a
+//! German `1.234,5` submits as `1234.5`.
+
+use super::{FormattedText, InputFormatter};
+
+/// Numeric formatter with locale separators, precision and bounds
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+    /// Maximum number of decimals; `Some(0)` only allows integers
+    pub precision: Option<usize>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: Some(','),
+            precision: None,
+            min: None,
+            max: None,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    /// Separators for a BCP 47 language tag; unknown tags use `en-US` separators
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or_default();
+        let (decimal, group) = match (language, locale) {
+            (_, "de-CH") => ('.', '\''),
+            ("de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da", _) => (',', '.'),
+            ("fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk", _) => (',', '\u{a0}'),
+            _ => ('.', ','),
+        };
+        Self::new().with_separators(decimal, Some(group))
+    }
+
+    /// Currency amount with a symbol prefix and two decimals
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        Self::new().with_prefix(symbol).with_precision(2)
+    }
+
+    pub fn with_separators(mut self, decimal: char, group: Option<char>) -> Self {
+        self.decimal_separator = decimal;
+        self.group_separator = group;
+        self
+    }
+
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    fn allows_negative(&self) -> bool {
+        self.min.is_none_or(|min| min < 0.0)
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputFormatter for NumberFormat {
+    fn parse(&self, text: &str) -> String {
+        let mut raw = String::new();
+        let mut decimals = None::<usize>;
+
+        for c in text.chars() {
+            if c.is_ascii_digit() {
+                match decimals {
+                    Some(count) if self.precision.is_some_and(|precision| count >= precision) => {}
+                    Some(count) => {
+                        raw.push(c);
+                        decimals = Some(count + 1);
+                    }
+                    None => raw.push(c),
+                }
+            } else if c == self.decimal_separator
+                && decimals.is_none()
+                && self.precision != Some(0)
+            {
+                raw.push('.');
+                decimals = Some(0);
+            } else if c == '-' && raw.is_empty() && self.allows_negative() {
+                raw.push('-');
+            }
+        }
+
+        raw
+    }
+
+    fn format(&self, raw: &str) -> FormattedText {
+        let mut text = String::new();
+        let mut raw_ends = Vec::new();
+        let mut length = 0;
+
+        let (sign, unsigned) = match raw.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", raw),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        if !sign.is_empty() {
+            text.push('-');
+            length += 1;
+            raw_ends.push(length);
+        }
+        if !raw.is_empty() {
+            text.push_str(&self.prefix);
+            length += self.prefix.chars().count();
+        }
+
+        let digits = integer.len();
+        for (index, digit) in integer.chars().enumerate() {
+            if let Some(group) = self.group_separator
+                && index > 0
+                && (digits - index) % 3 == 0
+            {
+                text.push(group);
+                length += 1;
+            }
+            text.push(digit);
+            length += 1;
+            raw_ends.push(length);
+        }
+
+        if let Some(fraction) = fraction {
+            text.push(self.decimal_separator);
+            length += 1;
+            raw_ends.push(length);
+            for digit in fraction.chars() {
+                text.push(digit);
+                length += 1;
+                raw_ends.push(length);
+            }
+        }
+
+        if !raw.is_empty() {
+            text.push_str(&self.suffix);
+        }
+
+        FormattedText { text, raw_ends }
+    }
+
+    fn finalize(&self, raw: &str) -> String {
+        let Ok(mut number) = raw.parse::<f64>() else {
+            return String::new();
+        };
+        if let Some(min) = self.min {
+            number = number.max(min);
+        }
+        if let Some(max) = self.max {
+            number = number.min(max);
+        }
+
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, number),
+            None => number.to_string(),
+        }
+    }
+
+    fn is_complete(&self, raw: &str) -> bool {
+        raw.parse::<f64>().is_ok_and(|number| {
+            self.min.is_none_or(|min| number >= min) && self.max.is_none_or(|max| number <= max)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grouping() {
+        let format = NumberFormat::new();
+        assert_eq!(format.format("1234567.89").text, "1,234,567.89");
+        assert_eq!(format.format("-1234").text, "-1,234");
+        assert_eq!(format.parse("1,234,567.89"), "1234567.89");
+    }
+
+    #[test]
+    fn test_locale_separators() {
+        let format = NumberFormat::for_locale("de-DE");
+        assert_eq!(format.format("1234.5").text, "1.234,5");
+        assert_eq!(format.parse("1.234,5"), "1234.5");
+        assert_eq!(NumberFormat::for_locale("de-CH").format("1234").text, "1'234");
+    }
+
+    #[test]
+    fn test_currency_precision_and_bounds() {
+        let format = NumberFormat::currency("$").with_min(0.0).with_max(1000.0);
+        assert_eq!(format.parse("-12.345"), "12.34");
+        assert_eq!(format.format("1234.5").text, "$1,234.5");
+        assert_eq!(format.finalize("1234.5"), "1000.00");
+        assert_eq!(format.finalize("7"), "7.00");
+        assert!(format.is_complete("999.99"));
+        assert!(!format.is_complete("1000.01"));
+    }
+
+    #[test]
+    fn test_integer_only() {
+        let format = NumberFormat::new().with_precision(0).with_suffix(" pcs");
+        assert_eq!(format.parse("12.5"), "125");
+        assert_eq!(format.format("125").text, "125 pcs");
+    }
+}
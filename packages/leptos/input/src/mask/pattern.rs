@@ -0,0 +1,166 @@
+//! Pattern masks such as `(999) 999-9999`
+//!
+//! Mask syntax:
+//! - `9` accepts a digit
+//! - `a` accepts a letter
+//! - `*` accepts a letter or digit
+//! - `\` makes the next character a literal
+//! - any other character is a literal inserted automatically
+
+use super::{FormattedText, InputFormatter};
+
+/// Single position of a pattern mask
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaskToken {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            MaskToken::Digit => c.is_ascii_digit(),
+            MaskToken::Letter => c.is_alphabetic(),
+            MaskToken::Alphanumeric => c.is_alphanumeric(),
+            MaskToken::Literal(_) => false,
+        }
+    }
+}
+
+/// Fixed-layout mask for phone numbers, dates, postal codes and similar values
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternMask {
+    pub tokens: Vec<MaskToken>,
+}
+
+impl PatternMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                '*' => MaskToken::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(escaped) => MaskToken::Literal(escaped),
+                    None => break,
+                },
+                literal => MaskToken::Literal(literal),
+            });
+        }
+        Self { tokens }
+    }
+
+    /// Number of characters the raw value holds when complete
+    pub fn slot_count(&self) -> usize {
+        self.tokens
+            .iter()
+            .filter(|token| !matches!(token, MaskToken::Literal(_)))
+            .count()
+    }
+
+    /// Placeholder showing the expected layout, e.g. `(___) ___-____`
+    pub fn placeholder(&self, slot_char: char) -> String {
+        self.tokens
+            .iter()
+            .map(|token| match token {
+                MaskToken::Literal(literal) => *literal,
+                _ => slot_char,
+            })
+            .collect()
+    }
+}
+
+impl InputFormatter for PatternMask {
+    fn parse(&self, text: &str) -> String {
+        let mut raw = String::new();
+        let mut position = 0;
+
+        for c in text.chars() {
+            // Skip literals up to the next slot; a typed literal consumes its position
+            let mut matched_literal = false;
+            while let Some(MaskToken::Literal(literal)) = self.tokens.get(position) {
+                position += 1;
+                if *literal == c {
+                    matched_literal = true;
+                    break;
+                }
+            }
+            if matched_literal {
+                continue;
+            }
+
+            match self.tokens.get(position) {
+                Some(token) if token.accepts(c) => {
+                    raw.push(c);
+                    position += 1;
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        raw
+    }
+
+    fn format(&self, raw: &str) -> FormattedText {
+        let mut text = String::new();
+        let mut raw_ends = Vec::new();
+        let mut raw_chars = raw.chars().peekable();
+
+        for (length, token) in self.tokens.iter().enumerate() {
+            let Some(&next) = raw_chars.peek() else {
+                break;
+            };
+            match token {
+                MaskToken::Literal(literal) => text.push(*literal),
+                _ => {
+                    raw_chars.next();
+                    text.push(next);
+                    raw_ends.push(length + 1);
+                }
+            }
+        }
+
+        FormattedText { text, raw_ends }
+    }
+
+    fn is_complete(&self, raw: &str) -> bool {
+        raw.chars().count() == self.slot_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phone_mask() {
+        let mask = PatternMask::new("(999) 999-9999");
+        assert_eq!(mask.slot_count(), 10);
+        assert_eq!(mask.format("5551234567").text, "(555) 123-4567");
+        assert_eq!(mask.format("555").text, "(555");
+        assert_eq!(mask.parse("(555) 123-4567"), "5551234567");
+        assert_eq!(mask.placeholder('_'), "(___) ___-____");
+    }
+
+    #[test]
+    fn test_rejects_invalid_characters() {
+        let mask = PatternMask::new("99/99/9999");
+        assert_eq!(mask.parse("1a2/0x3"), "1203");
+        assert_eq!(mask.format("1203").text, "12/03");
+    }
+
+    #[test]
+    fn test_escaped_literal_and_letters() {
+        let mask = PatternMask::new("+\\9 aa-***");
+        assert_eq!(mask.tokens[1], MaskToken::Literal('9'));
+        assert_eq!(mask.parse("+9 ab-1c2"), "ab1c2");
+        assert_eq!(mask.format("ab1c2").text, "+9 ab-1c2");
+        assert!(mask.is_complete("ab1c2"));
+        assert!(!mask.is_complete("ab1"));
+    }
+}
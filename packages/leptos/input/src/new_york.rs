@@ -1,6 +1,9 @@
-use leptos::{ev::Event, prelude::*};
+use leptos::prelude::*;
 use leptos_style::Style;
-use leptos::wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use crate::default::{use_input_validation, INPUT_ERROR_CLASS};
+use crate::validation::{AsyncValidator, InputValidator, ValidationContext};
 
 const INPUT_CLASS: &str = "flex h-10 w-full rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
 
@@ -14,32 +17,66 @@ pub fn Input(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
+    #[prop(into, optional)] validator: Option<InputValidator>,
+    #[prop(into, optional)] validation_error: MaybeProp<String>,
+    #[prop(into, optional)] show_validation: Signal<bool>,
+    /// Debounced server-side check, run only once the synchronous rules pass
+    #[prop(into, optional)] async_validator: Option<AsyncValidator>,
+    /// Shared form context, required for cross-field rules such as `Matches`
+    #[prop(into, optional)] validation_context: Option<RwSignal<ValidationContext>>,
 ) -> impl IntoView {
-    let handle_input = {
-        let on_change = on_change.clone();
-        move |event: Event| {
-            if let Some(callback) = &on_change {
-                let target = event.target().unwrap();
-                let input = target.unchecked_into::<web_sys::HtmlInputElement>();
-                callback.run(input.value());
-            }
-        }
-    };
+    let (validation, handle_input) =
+        use_input_validation(on_change, validator, async_validator, validation_context, id);
+    let validation_result = validation.result;
+    let is_validating = validation.is_validating;
+    let has_error =
+        Signal::derive(move || validation_result.get().has_errors() || validation_error.get().is_some());
 
     let computed_class = Signal::derive(move || {
-        format!("{} {}", INPUT_CLASS, class.get().unwrap_or_default())
+        let error_class = if has_error.get() { INPUT_ERROR_CLASS } else { "" };
+        tw_merge!(INPUT_CLASS, class.get().unwrap_or_default(), error_class)
+    });
+
+    let display_error = Signal::derive(move || {
+        validation_error.get().or_else(|| {
+            validation_result
+                .get()
+                .errors
+                .first()
+                .map(|error| error.message.clone())
+        })
     });
 
     view! {
-        <input
-            r#type=move || input_type.get().unwrap_or_else(|| "text".to_string())
-            value=move || value.get().unwrap_or_default()
-            placeholder=move || placeholder.get().unwrap_or_default()
-            disabled=move || disabled.get()
-            class=move || computed_class.get()
-            id=move || id.get().unwrap_or_default()
-            style=move || style.get().to_string()
-            on:input=handle_input
-        />
+        <div class="space-y-1">
+            <input
+                r#type=move || input_type.get().unwrap_or_else(|| "text".to_string())
+                value=move || value.get().unwrap_or_default()
+                placeholder=move || placeholder.get().unwrap_or_default()
+                disabled=move || disabled.get()
+                class=move || computed_class.get()
+                id=move || id.get().unwrap_or_default()
+                style=move || style.get().to_string()
+                on:input=handle_input
+                aria-busy=move || is_validating.get().to_string()
+                aria-invalid=move || has_error.get().to_string()
+                aria-describedby=move || {
+                    if display_error.get().is_some() {
+                        format!("{}-error", id.get().unwrap_or_default())
+                    } else {
+                        String::new()
+                    }
+                }
+            />
+            <Show when=move || display_error.get().is_some() && show_validation.get()>
+                <p
+                    id=move || format!("{}-error", id.get().unwrap_or_default())
+                    class="text-sm text-destructive"
+                    role="alert"
+                >
+                    {move || display_error.get().unwrap_or_default()}
+                </p>
+            </Show>
+        </div>
     }
 }
@@ -8,6 +8,7 @@ use leptos::wasm_bindgen::JsCast;
 use leptos_shadcn_signal_management::*;
 use crate::validation::{InputValidator, ValidationResult};
 use super::types::{SignalManagedInputState, INPUT_CLASS, INPUT_ERROR_CLASS};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 /// Enhanced Input component with advanced signal management
 #[component]
@@ -45,7 +46,7 @@ pub fn EnhancedInput(
         } else {
             INPUT_CLASS.to_string()
         };
-        format!("{} {}", base_class, class.get().unwrap_or_default())
+        tw_merge!(base_class, class.get().unwrap_or_default())
     });
 
     // Create performance metrics
@@ -0,0 +1,154 @@
+//! Asynchronous validators for the Input component
+//!
+//! Async validators cover checks that need a round trip, such as "username taken".
+//! Each run is tagged with a generation number; starting a new run or calling
+//! [`AsyncValidator::cancel`] makes the results of older runs stale, so slow
+//! responses never overwrite the result for newer input.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::types::{ValidationResult, ValidationRule};
+
+/// Future returned by an async validation function. `Err` carries the error message.
+pub type AsyncValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// Default delay between the last keystroke and the async check
+pub const DEFAULT_ASYNC_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Async validator with a stable id, debounce delay and cancellation
+#[derive(Clone)]
+pub struct AsyncValidator {
+    pub id: String,
+    pub debounce: Duration,
+    check: Arc<dyn Fn(String) -> AsyncValidationFuture + Send + Sync>,
+    generation: Arc<AtomicU64>,
+}
+
+impl AsyncValidator {
+    pub fn new<F, Fut>(id: impl Into<String>, check: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self {
+            id: id.into(),
+            debounce: DEFAULT_ASYNC_DEBOUNCE,
+            check: Arc::new(move |value| Box::pin(check(value))),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Invalidates every run that is currently in flight
+    pub fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Runs the check for `value`, cancelling any earlier run.
+    ///
+    /// Resolves to `None` when the run was superseded before it finished.
+    pub fn validate(
+        &self,
+        field_name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> impl Future<Output = Option<ValidationResult>> + 'static {
+        let ticket = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let generation = self.generation.clone();
+        let field_name = field_name.into();
+        let rule = ValidationRule::Async(self.id.clone());
+        let pending = (self.check)(value.into());
+
+        async move {
+            let outcome = pending.await;
+            if generation.load(Ordering::SeqCst) != ticket {
+                return None;
+            }
+
+            let mut result = ValidationResult::new();
+            if let Err(message) = outcome {
+                result.add_error(field_name, message, rule);
+            }
+            Some(result)
+        }
+    }
+}
+
+impl fmt::Debug for AsyncValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncValidator")
+            .field("id", &self.id)
+            .field("debounce", &self.debounce)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{Context, Poll, Waker};
+
+    fn poll_ready<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future was not ready"),
+        }
+    }
+
+    fn taken_usernames() -> AsyncValidator {
+        AsyncValidator::new("username_available", |value: String| async move {
+            if value == "admin" {
+                Err("Username is already taken".to_string())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[test]
+    fn test_async_validation_result() {
+        let validator = taken_usernames();
+
+        let result = poll_ready(validator.validate("username", "admin")).unwrap();
+        assert!(!result.is_valid);
+        assert_eq!(result.get_error_message("username"), Some("Username is already taken"));
+        assert_eq!(
+            result.errors[0].rule,
+            ValidationRule::Async("username_available".to_string())
+        );
+
+        let result = poll_ready(validator.validate("username", "alice")).unwrap();
+        assert!(result.is_valid);
+    }
+
+    #[test]
+    fn test_superseded_runs_are_discarded() {
+        let validator = taken_usernames();
+
+        let first = validator.validate("username", "admin");
+        let second = validator.validate("username", "alice");
+
+        assert_eq!(poll_ready(first), None);
+        assert!(poll_ready(second).unwrap().is_valid);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let validator = taken_usernames().with_debounce(Duration::from_millis(50));
+        assert_eq!(validator.debounce, Duration::from_millis(50));
+
+        let pending = validator.validate("username", "admin");
+        validator.cancel();
+        assert_eq!(poll_ready(pending), None);
+    }
+}
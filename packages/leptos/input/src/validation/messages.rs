@@ -0,0 +1,99 @@
+//! Localizable validation messages for the Input component
+//!
+//! Messages are templates keyed by [`ValidationRule::message_key`](super::ValidationRule::message_key).
+//! Placeholders such as `{min}`, `{max}` and `{field}` are filled in when a rule fails.
+
+use std::collections::HashMap;
+
+/// Message catalog used to render validation errors
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageCatalog {
+    messages: HashMap<String, String>,
+}
+
+impl MessageCatalog {
+    /// Creates an empty catalog. Missing keys fall back to [`MessageCatalog::english`].
+    pub fn new() -> Self {
+        Self {
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Built-in English messages
+    pub fn english() -> Self {
+        let messages = [
+            ("required", "This field is required"),
+            ("min_length", "Must be at least {min} characters long"),
+            ("max_length", "Must be no more than {max} characters long"),
+            ("email", "Please enter a valid email address"),
+            ("pattern", "Please enter a valid format"),
+            ("numeric", "Please enter a valid number"),
+            ("min", "Must be at least {min}"),
+            ("max", "Must be no more than {max}"),
+            ("url", "Please enter a valid URL"),
+            ("date", "Please enter a valid date (YYYY-MM-DD)"),
+            ("min_date", "Must be on or after {min}"),
+            ("max_date", "Must be on or before {max}"),
+            ("matches", "Must match {field}"),
+        ];
+
+        Self {
+            messages: messages
+                .into_iter()
+                .map(|(key, template)| (key.to_string(), template.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn with_message(mut self, key: impl Into<String>, template: impl Into<String>) -> Self {
+        self.messages.insert(key.into(), template.into());
+        self
+    }
+
+    pub fn template(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    /// Renders the template for `key`, substituting `{name}` placeholders from `args`
+    pub fn format(&self, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let template = self
+            .template(key)
+            .map(str::to_string)
+            .or_else(|| Self::english().template(key).map(str::to_string))?;
+
+        Some(args.iter().fold(template, |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), value)
+        }))
+    }
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_defaults() {
+        let catalog = MessageCatalog::default();
+        assert_eq!(
+            catalog.format("min_length", &[("min", "3".to_string())]).as_deref(),
+            Some("Must be at least 3 characters long")
+        );
+    }
+
+    #[test]
+    fn test_override_and_fallback() {
+        let catalog = MessageCatalog::new().with_message("required", "Champ obligatoire");
+        assert_eq!(catalog.format("required", &[]).as_deref(), Some("Champ obligatoire"));
+        assert_eq!(
+            catalog.format("email", &[]).as_deref(),
+            Some("Please enter a valid email address")
+        );
+        assert_eq!(catalog.format("unknown", &[]), None);
+    }
+}
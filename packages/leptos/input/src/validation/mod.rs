@@ -6,6 +6,8 @@
 pub mod types;
 pub mod rules;
 pub mod validator;
+pub mod messages;
+pub mod async_validator;
 
 // Re-export commonly used types and functions
 pub use types::{
@@ -13,6 +15,7 @@ pub use types::{
     ValidationError,
     ValidationResult,
     ValidationContext,
+    CustomValidator,
 };
 
 pub use validator::{
@@ -23,6 +26,17 @@ pub use validator::{
 pub use rules::{
     validate_rule,
     validate_rules,
+    validate_rule_with,
+    validate_rules_with,
+    FieldValues,
+};
+
+pub use messages::MessageCatalog;
+
+pub use async_validator::{
+    AsyncValidator,
+    AsyncValidationFuture,
+    DEFAULT_ASYNC_DEBOUNCE,
 };
 
 #[cfg(test)]
@@ -2,82 +2,111 @@
 //! 
 //! This module contains the core validation logic for different rule types.
 
+use std::collections::HashMap;
+
+use super::messages::MessageCatalog;
 use super::types::{ValidationRule, ValidationResult, ValidationError};
 
+/// Current values of the other fields in a form, keyed by field name
+pub type FieldValues = HashMap<String, String>;
+
 /// Validates a value against a specific validation rule
+///
+/// Cross-field rules (`Matches`, `RequiredIf`) pass here because no other field
+/// values are known; use [`validate_rule_with`] to evaluate them.
 pub fn validate_rule(value: &str, rule: &ValidationRule) -> Result<(), ValidationError> {
-    match rule {
-        ValidationRule::Required => {
-            if value.trim().is_empty() {
-                Err(ValidationError {
-                    field: String::new(),
-                    message: "This field is required".to_string(),
-                    rule: rule.clone(),
-                })
-            } else {
-                Ok(())
-            }
-        }
+    validate_rule_with(value, rule, None, &MessageCatalog::default())
+}
+
+/// Validates a value against a rule, using sibling field values and a message catalog
+pub fn validate_rule_with(
+    value: &str,
+    rule: &ValidationRule,
+    fields: Option<&FieldValues>,
+    messages: &MessageCatalog,
+) -> Result<(), ValidationError> {
+    let failure: Option<Vec<(&str, String)>> = match rule {
+        ValidationRule::Required => value.trim().is_empty().then(Vec::new),
         ValidationRule::MinLength(min_len) => {
-            if value.len() < *min_len {
-                Err(ValidationError {
-                    field: String::new(),
-                    message: format!("Must be at least {} characters long", min_len),
-                    rule: rule.clone(),
-                })
-            } else {
-                Ok(())
-            }
+            (value.len() < *min_len).then(|| vec![("min", min_len.to_string())])
         }
         ValidationRule::MaxLength(max_len) => {
-            if value.len() > *max_len {
-                Err(ValidationError {
-                    field: String::new(),
-                    message: format!("Must be no more than {} characters long", max_len),
-                    rule: rule.clone(),
-                })
-            } else {
-                Ok(())
-            }
+            (value.len() > *max_len).then(|| vec![("max", max_len.to_string())])
         }
-        ValidationRule::Email => {
-            if is_valid_email(value) {
-                Ok(())
-            } else {
-                Err(ValidationError {
-                    field: String::new(),
-                    message: "Please enter a valid email address".to_string(),
-                    rule: rule.clone(),
-                })
-            }
+        ValidationRule::Email => (!is_valid_email(value)).then(Vec::new),
+        ValidationRule::Pattern(pattern) => (!matches_pattern(value, pattern)).then(Vec::new),
+        ValidationRule::Numeric => {
+            (!value.trim().is_empty() && parse_number(value).is_none()).then(Vec::new)
         }
-        ValidationRule::Pattern(pattern) => {
-            if matches_pattern(value, pattern) {
-                Ok(())
-            } else {
-                Err(ValidationError {
-                    field: String::new(),
-                    message: "Please enter a valid format".to_string(),
-                    rule: rule.clone(),
-                })
-            }
+        ValidationRule::Min(min) => (!value.trim().is_empty()
+            && parse_number(value).is_none_or(|number| number < *min))
+        .then(|| vec![("min", min.to_string())]),
+        ValidationRule::Max(max) => (!value.trim().is_empty()
+            && parse_number(value).is_none_or(|number| number > *max))
+        .then(|| vec![("max", max.to_string())]),
+        ValidationRule::Url => (!value.trim().is_empty() && !is_valid_url(value)).then(Vec::new),
+        ValidationRule::Date => {
+            (!value.trim().is_empty() && parse_iso_date(value).is_none()).then(Vec::new)
         }
-        ValidationRule::Custom(_description) => {
-            // For custom rules, we assume they're valid by default
-            // In a real implementation, you'd call the custom validation function
-            Ok(())
+        ValidationRule::MinDate(min) => (!value.trim().is_empty()
+            && (parse_iso_date(value).is_none() || value.trim() < min.as_str()))
+        .then(|| vec![("min", min.clone())]),
+        ValidationRule::MaxDate(max) => (!value.trim().is_empty()
+            && (parse_iso_date(value).is_none() || value.trim() > max.as_str()))
+        .then(|| vec![("max", max.clone())]),
+        ValidationRule::Matches(other) => fields.and_then(|fields| {
+            let other_value = fields.get(other).map(String::as_str).unwrap_or_default();
+            (value != other_value).then(|| vec![("field", other.clone())])
+        }),
+        ValidationRule::RequiredIf { field, value: expected } => fields.and_then(|fields| {
+            let other_value = fields.get(field).map(String::as_str).unwrap_or_default();
+            let required = match expected {
+                Some(expected) => other_value == expected,
+                None => !other_value.trim().is_empty(),
+            };
+            (required && value.trim().is_empty()).then(Vec::new)
+        }),
+        ValidationRule::Custom(validator) => (!validator.check(value)).then(Vec::new),
+        ValidationRule::Async(_) => None,
+    };
+
+    match failure {
+        None => Ok(()),
+        Some(args) => {
+            let message = messages
+                .format(rule.message_key(), &args)
+                .unwrap_or_else(|| match rule {
+                    ValidationRule::Custom(validator) => validator.message.clone(),
+                    _ => "Invalid value".to_string(),
+                });
+
+            Err(ValidationError {
+                field: String::new(),
+                message,
+                rule: rule.clone(),
+            })
         }
     }
 }
 
 /// Validates a value against multiple rules
 pub fn validate_rules(value: &str, rules: &[ValidationRule], field_name: &str) -> ValidationResult {
+    validate_rules_with(value, rules, field_name, None, &MessageCatalog::default())
+}
+
+/// Validates a value against multiple rules, using sibling field values and a message catalog
+pub fn validate_rules_with(
+    value: &str,
+    rules: &[ValidationRule],
+    field_name: &str,
+    fields: Option<&FieldValues>,
+    messages: &MessageCatalog,
+) -> ValidationResult {
     let mut result = ValidationResult::new();
     
     for rule in rules {
-        if let Err(mut error) = validate_rule(value, rule) {
-            error.field = field_name.to_string();
-            result.add_error(field_name, error.message.clone(), error.rule.clone());
+        if let Err(error) = validate_rule_with(value, rule, fields, messages) {
+            result.add_error(field_name, error.message, error.rule);
         }
     }
     
@@ -116,6 +145,51 @@ fn is_valid_email(email: &str) -> bool {
     true
 }
 
+/// Parses a finite number, ignoring surrounding whitespace
+fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+/// Absolute http(s) URL with a non-empty host
+fn is_valid_url(url: &str) -> bool {
+    let url = url.trim();
+    let Some(rest) = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")) else {
+        return false;
+    };
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+
+    !host.is_empty()
+        && !url.contains(char::is_whitespace)
+        && (host == "localhost" || (host.contains('.') && !host.starts_with('.') && !host.ends_with('.')))
+}
+
+/// Parses a `YYYY-MM-DD` date, checking month lengths and leap years
+fn parse_iso_date(date: &str) -> Option<(i32, u32, u32)> {
+    let date = date.trim();
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+
+    let year: i32 = date[0..4].parse().ok()?;
+    let month: u32 = date[5..7].parse().ok()?;
+    let day: u32 = date[8..10].parse().ok()?;
+
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+
+    (1..=days_in_month).contains(&day).then_some((year, month, day))
+}
+
 /// Simple pattern matching (basic regex-like functionality)
 fn matches_pattern(value: &str, pattern: &str) -> bool {
     // This is a simplified pattern matcher
@@ -158,6 +232,7 @@ fn matches_pattern(value: &str, pattern: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validation::types::CustomValidator;
 
     #[test]
     fn test_required_validation() {
@@ -206,4 +281,84 @@ mod tests {
         let result = validate_rules("valid", &rules, "test_field");
         assert!(result.is_valid);
     }
+
+    #[test]
+    fn test_numeric_range_validation() {
+        assert!(validate_rule("abc", &ValidationRule::Numeric).is_err());
+        assert!(validate_rule("-4.5", &ValidationRule::Numeric).is_ok());
+        assert!(validate_rule("", &ValidationRule::Numeric).is_ok());
+        assert!(validate_rule("2", &ValidationRule::Min(3.0)).is_err());
+        assert!(validate_rule("3", &ValidationRule::Min(3.0)).is_ok());
+        assert!(validate_rule("10.5", &ValidationRule::Max(10.0)).is_err());
+        assert!(validate_rule("ten", &ValidationRule::Max(10.0)).is_err());
+    }
+
+    #[test]
+    fn test_url_validation() {
+        assert!(validate_rule("https://example.com/path?q=1", &ValidationRule::Url).is_ok());
+        assert!(validate_rule("http://localhost:3000", &ValidationRule::Url).is_ok());
+        assert!(validate_rule("ftp://example.com", &ValidationRule::Url).is_err());
+        assert!(validate_rule("https://", &ValidationRule::Url).is_err());
+        assert!(validate_rule("https://exa mple.com", &ValidationRule::Url).is_err());
+    }
+
+    #[test]
+    fn test_date_validation() {
+        assert!(validate_rule("2024-02-29", &ValidationRule::Date).is_ok());
+        assert!(validate_rule("2023-02-29", &ValidationRule::Date).is_err());
+        assert!(validate_rule("2024-13-01", &ValidationRule::Date).is_err());
+        assert!(validate_rule("01/02/2024", &ValidationRule::Date).is_err());
+
+        let min = ValidationRule::MinDate("2024-01-01".to_string());
+        assert!(validate_rule("2023-12-31", &min).is_err());
+        assert!(validate_rule("2024-01-01", &min).is_ok());
+
+        let max = ValidationRule::MaxDate("2024-01-01".to_string());
+        assert!(validate_rule("2024-01-02", &max).is_err());
+    }
+
+    #[test]
+    fn test_cross_field_validation() {
+        let messages = MessageCatalog::default();
+        let fields: FieldValues = [("password".to_string(), "secret123".to_string())].into();
+        let matches = ValidationRule::Matches("password".to_string());
+
+        assert!(validate_rule("other", &matches).is_ok());
+        assert!(validate_rule_with("other", &matches, Some(&fields), &messages).is_err());
+        assert!(validate_rule_with("secret123", &matches, Some(&fields), &messages).is_ok());
+
+        let required_if = ValidationRule::RequiredIf {
+            field: "password".to_string(),
+            value: None,
+        };
+        assert!(validate_rule_with("", &required_if, Some(&fields), &messages).is_err());
+        assert!(validate_rule_with("", &required_if, Some(&FieldValues::new()), &messages).is_ok());
+
+        let required_if_value = ValidationRule::RequiredIf {
+            field: "contact".to_string(),
+            value: Some("phone".to_string()),
+        };
+        let fields: FieldValues = [("contact".to_string(), "email".to_string())].into();
+        assert!(validate_rule_with("", &required_if_value, Some(&fields), &messages).is_ok());
+    }
+
+    #[test]
+    fn test_custom_rule_and_messages() {
+        let rule = ValidationRule::Custom(CustomValidator::new(
+            "no_spaces",
+            "Spaces are not allowed",
+            |value| !value.contains(' '),
+        ));
+        let error = validate_rule("a b", &rule).unwrap_err();
+        assert_eq!(error.message, "Spaces are not allowed");
+
+        let messages = MessageCatalog::new()
+            .with_message("no_spaces", "Pas d'espaces")
+            .with_message("min_length", "Au moins {min} caractères");
+        let error = validate_rule_with("a b", &rule, None, &messages).unwrap_err();
+        assert_eq!(error.message, "Pas d'espaces");
+
+        let error = validate_rule_with("ab", &ValidationRule::MinLength(3), None, &messages).unwrap_err();
+        assert_eq!(error.message, "Au moins 3 caractères");
+    }
 }
@@ -3,6 +3,8 @@
 //! This module defines the fundamental types used throughout the validation system.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 /// Validation rule types for different input validation scenarios
 #[derive(Clone, Debug, PartialEq)]
@@ -12,7 +14,100 @@ pub enum ValidationRule {
     MaxLength(usize),
     Email,
     Pattern(String),
-    Custom(String), // Store a description instead of the function for Clone/Debug/PartialEq
+    /// Value must parse as a number
+    Numeric,
+    /// Numeric value must be greater than or equal to the bound
+    Min(f64),
+    /// Numeric value must be less than or equal to the bound
+    Max(f64),
+    /// Absolute `http`/`https` URL
+    Url,
+    /// ISO 8601 calendar date (`YYYY-MM-DD`)
+    Date,
+    /// ISO date on or after the given `YYYY-MM-DD` bound
+    MinDate(String),
+    /// ISO date on or before the given `YYYY-MM-DD` bound
+    MaxDate(String),
+    /// Value must equal the value of another field (e.g. password confirmation)
+    Matches(String),
+    /// Required when another field is filled in, or equals `value` when given
+    RequiredIf { field: String, value: Option<String> },
+    Custom(CustomValidator),
+    /// Marks errors reported by the [`AsyncValidator`](super::AsyncValidator) with this id.
+    /// Always passes when evaluated synchronously.
+    Async(String),
+}
+
+impl ValidationRule {
+    /// Key used to look up this rule's message in a [`MessageCatalog`](super::MessageCatalog)
+    pub fn message_key(&self) -> &str {
+        match self {
+            ValidationRule::Required => "required",
+            ValidationRule::MinLength(_) => "min_length",
+            ValidationRule::MaxLength(_) => "max_length",
+            ValidationRule::Email => "email",
+            ValidationRule::Pattern(_) => "pattern",
+            ValidationRule::Numeric => "numeric",
+            ValidationRule::Min(_) => "min",
+            ValidationRule::Max(_) => "max",
+            ValidationRule::Url => "url",
+            ValidationRule::Date => "date",
+            ValidationRule::MinDate(_) => "min_date",
+            ValidationRule::MaxDate(_) => "max_date",
+            ValidationRule::Matches(_) => "matches",
+            ValidationRule::RequiredIf { .. } => "required",
+            ValidationRule::Custom(validator) => &validator.id,
+            ValidationRule::Async(id) => id,
+        }
+    }
+
+    /// Whether the rule needs the values of other fields to be evaluated
+    pub fn is_cross_field(&self) -> bool {
+        matches!(self, ValidationRule::Matches(_) | ValidationRule::RequiredIf { .. })
+    }
+}
+
+/// Synchronous user-defined validation function with a stable id.
+///
+/// Equality compares ids only, so two validators with the same id are
+/// considered the same rule.
+#[derive(Clone)]
+pub struct CustomValidator {
+    pub id: String,
+    pub message: String,
+    check: Arc<dyn Fn(&str) -> bool + Send + Sync>,
+}
+
+impl CustomValidator {
+    pub fn new<F>(id: impl Into<String>, message: impl Into<String>, check: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            id: id.into(),
+            message: message.into(),
+            check: Arc::new(check),
+        }
+    }
+
+    pub fn check(&self, value: &str) -> bool {
+        (self.check)(value)
+    }
+}
+
+impl fmt::Debug for CustomValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomValidator")
+            .field("id", &self.id)
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomValidator {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
 }
 
 /// Validation error with field name and message
@@ -72,15 +167,26 @@ impl ValidationResult {
 #[derive(Clone, Debug)]
 pub struct ValidationContext {
     pub results: HashMap<String, ValidationResult>,
+    /// Latest value of each field, used by cross-field rules
+    pub values: HashMap<String, String>,
 }
 
 impl ValidationContext {
     pub fn new() -> Self {
         Self {
             results: HashMap::new(),
+            values: HashMap::new(),
         }
     }
 
+    pub fn set_field_value(&mut self, field: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(field.into(), value.into());
+    }
+
+    pub fn get_field_value(&self, field: &str) -> Option<&str> {
+        self.values.get(field).map(String::as_str)
+    }
+
     pub fn add_field_result(&mut self, field: String, result: ValidationResult) {
         self.results.insert(field, result);
     }
@@ -2,14 +2,17 @@
 //! 
 //! This module provides the main InputValidator struct and its functionality.
 
-use super::types::{ValidationRule, ValidationResult, ValidationContext};
-use super::rules::validate_rules;
+use super::messages::MessageCatalog;
+use super::rules::{validate_rules_with, FieldValues};
+use super::types::{CustomValidator, ValidationRule, ValidationResult, ValidationContext};
 
 /// Input validator that applies multiple validation rules
+#[derive(Clone, Debug)]
 pub struct InputValidator {
     pub field_name: String,
     pub rules: Vec<ValidationRule>,
-    pub custom_validators: Vec<Box<dyn Fn(&str) -> bool + Send + Sync>>,
+    pub custom_validators: Vec<CustomValidator>,
+    pub messages: MessageCatalog,
 }
 
 impl InputValidator {
@@ -18,6 +21,7 @@ impl InputValidator {
             field_name: field_name.into(),
             rules: Vec::new(),
             custom_validators: Vec::new(),
+            messages: MessageCatalog::default(),
         }
     }
 
@@ -31,35 +35,63 @@ impl InputValidator {
         self
     }
 
+    /// Adds an anonymous custom validator. Its id is derived from its position,
+    /// use [`InputValidator::with_custom`] for a stable id and message.
     pub fn with_custom_validator<F>(mut self, validator: F) -> Self
     where
         F: Fn(&str) -> bool + Send + Sync + 'static,
     {
-        self.custom_validators.push(Box::new(validator));
+        let id = format!("custom_{}", self.custom_validators.len());
+        self.custom_validators
+            .push(CustomValidator::new(id, "Custom validation failed", validator));
+        self
+    }
+
+    pub fn with_custom<F>(
+        mut self,
+        id: impl Into<String>,
+        message: impl Into<String>,
+        validator: F,
+    ) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.custom_validators
+            .push(CustomValidator::new(id, message, validator));
+        self
+    }
+
+    pub fn with_messages(mut self, messages: MessageCatalog) -> Self {
+        self.messages = messages;
         self
     }
 
     pub fn validate(&self, value: &str) -> ValidationResult {
-        let mut result = validate_rules(value, &self.rules, &self.field_name);
-        
-        // Apply custom validators
-        for validator in &self.custom_validators {
-            if !validator(value) {
-                result.add_error(
-                    &self.field_name,
-                    "Custom validation failed",
-                    ValidationRule::Custom("Custom validator".to_string()),
-                );
-            }
-        }
-        
-        result
+        self.validate_fields(value, None)
     }
 
+    /// Validates `value`, evaluating cross-field rules against `fields`
+    pub fn validate_with_values(&self, value: &str, fields: &FieldValues) -> ValidationResult {
+        self.validate_fields(value, Some(fields))
+    }
+
+    /// Records `value` in the context and validates it against the other recorded fields
     pub fn validate_with_context(&self, value: &str, context: &mut ValidationContext) {
-        let result = self.validate(value);
+        context.set_field_value(self.field_name.clone(), value);
+        let result = self.validate_with_values(value, &context.values);
         context.add_field_result(self.field_name.clone(), result);
     }
+
+    fn validate_fields(&self, value: &str, fields: Option<&FieldValues>) -> ValidationResult {
+        let custom_rules = self
+            .custom_validators
+            .iter()
+            .cloned()
+            .map(ValidationRule::Custom);
+        let rules: Vec<ValidationRule> = self.rules.iter().cloned().chain(custom_rules).collect();
+
+        validate_rules_with(value, &rules, &self.field_name, fields, &self.messages)
+    }
 }
 
 /// Builder functions for common validation scenarios
@@ -102,6 +134,29 @@ pub mod validation_builders {
         InputValidator::new(field_name)
             .with_rule(ValidationRule::Email)
     }
+
+    pub fn confirm_validator(
+        field_name: impl Into<String>,
+        other_field: impl Into<String>,
+    ) -> InputValidator {
+        InputValidator::new(field_name)
+            .with_rule(ValidationRule::Required)
+            .with_rule(ValidationRule::Matches(other_field.into()))
+    }
+
+    pub fn url_validator(field_name: impl Into<String>) -> InputValidator {
+        InputValidator::new(field_name)
+            .with_rule(ValidationRule::Required)
+            .with_rule(ValidationRule::Url)
+    }
+
+    pub fn number_validator(field_name: impl Into<String>, min: f64, max: f64) -> InputValidator {
+        InputValidator::new(field_name)
+            .with_rule(ValidationRule::Required)
+            .with_rule(ValidationRule::Numeric)
+            .with_rule(ValidationRule::Min(min))
+            .with_rule(ValidationRule::Max(max))
+    }
 }
 
 #[cfg(test)]
@@ -158,5 +213,34 @@ mod tests {
         
         let result = validator.validate("invalid");
         assert!(!result.is_valid);
+        assert_eq!(result.get_error_message("test_field"), Some("Custom validation failed"));
+    }
+
+    #[test]
+    fn test_confirm_password_with_context() {
+        let mut context = ValidationContext::new();
+        let password = validation_builders::password_validator("password");
+        let confirm = validation_builders::confirm_validator("confirm", "password");
+
+        password.validate_with_context("StrongPass123", &mut context);
+        confirm.validate_with_context("StrongPass12", &mut context);
+        assert!(context.has_field_error("confirm"));
+        assert_eq!(
+            context.get_field_error("confirm").map(|e| e.message.as_str()),
+            Some("Must match password")
+        );
+
+        confirm.validate_with_context("StrongPass123", &mut context);
+        assert!(context.is_all_valid());
+    }
+
+    #[test]
+    fn test_validator_is_cloneable() {
+        let validator = InputValidator::new("code")
+            .with_custom("even_length", "Must have an even length", |v| v.len() % 2 == 0);
+        let cloned = validator.clone();
+
+        assert_eq!(cloned.custom_validators, validator.custom_validators);
+        assert!(!cloned.validate("abc").is_valid);
     }
 }
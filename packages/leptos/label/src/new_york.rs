@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const LABEL_CLASS: &str = "text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70";
 
@@ -11,7 +12,7 @@ pub fn Label(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", LABEL_CLASS, class.get().unwrap_or_default())
+        tw_merge!(LABEL_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const MENUBAR_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
 
@@ -42,7 +43,7 @@ pub fn Menubar(
             _ => "h-10 px-4 py-2",
         };
         
-        format!("{} {} {} {}", MENUBAR_CLASS, variant_class, size_class, class.get().unwrap_or_default())
+        tw_merge!(MENUBAR_CLASS, variant_class, size_class, class.get().unwrap_or_default())
     });
 
     view! {
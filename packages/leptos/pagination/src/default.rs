@@ -1,152 +1,255 @@
 use leptos::prelude::*;
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_utils::query::{query_value, set_query_param};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
-const PAGINATION_CLASS: &str = "mx-auto flex w-full justify-center";
+const PAGINATION_CLASS: &str = "mx-auto flex w-full flex-wrap items-center justify-center gap-4";
 const PAGINATION_CONTENT_CLASS: &str = "flex flex-row items-center gap-1";
-const PAGINATION_ITEM_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 hover:bg-accent hover:text-accent-foreground h-10 px-4 py-2";
+const PAGINATION_ITEM_CLASS: &str = "inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 aria-disabled:pointer-events-none aria-disabled:opacity-50 hover:bg-accent hover:text-accent-foreground h-10 px-4 py-2";
 const PAGINATION_LINK_CLASS: &str = "gap-1 pl-2.5";
 const PAGINATION_PREVIOUS_CLASS: &str = "gap-1 pr-2.5";
 const PAGINATION_ELLIPSIS_CLASS: &str = "flex h-9 w-9 items-center justify-center";
+const PAGINATION_FIELD_CLASS: &str = "flex items-center gap-2 text-sm";
+const PAGINATION_INPUT_CLASS: &str = "h-9 rounded-md border border-input bg-background px-2 text-sm ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
+
+/// One entry of the page window computed by [`pagination_range`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    /// Link to the previous page, `None` on the first page
+    Previous(Option<usize>),
+    Page(usize),
+    Ellipsis,
+    /// Link to the next page, `None` on the last page
+    Next(Option<usize>),
+}
+
+/// Items to render for `current` out of `total` pages.
+///
+/// Shows `boundary_count` pages at each end and `sibling_count` pages on each
+/// side of the current one. An ellipsis only replaces two or more pages, and
+/// the number of items stays the same as the current page moves, so the
+/// controls don't jump around.
+pub fn pagination_range(current: usize, total: usize, sibling_count: usize, boundary_count: usize) -> Vec<PageItem> {
+    let mut items = Vec::new();
+    let current = current.clamp(1, total.max(1));
+    items.push(PageItem::Previous((current > 1).then(|| current - 1)));
+
+    // Signed so the window arithmetic can go below zero for small totals
+    let (page, count) = (current as i64, total as i64);
+    let (siblings, boundary) = (sibling_count as i64, boundary_count as i64);
+    let start_pages = 1..=boundary.min(count);
+    let end_pages = (count - boundary + 1).max(boundary + 1)..=count;
+    let siblings_start = (page - siblings).min(count - boundary - siblings * 2 - 1).max(boundary + 2);
+    let siblings_end = (page + siblings).max(boundary + siblings * 2 + 2).min(count - boundary - 1);
+
+    let mut pages = start_pages.map(Some).collect::<Vec<_>>();
+    if siblings_start > boundary + 2 {
+        pages.push(None);
+    } else if boundary + 1 < count - boundary {
+        pages.push(Some(boundary + 1));
+    }
+    pages.extend((siblings_start..=siblings_end).map(Some));
+    if siblings_end < count - boundary - 1 {
+        pages.push(None);
+    } else if count - boundary > boundary {
+        pages.push(Some(count - boundary));
+    }
+    pages.extend(end_pages.map(Some));
+
+    items.extend(pages.into_iter().map(|page| match page {
+        Some(page) => PageItem::Page(page as usize),
+        None => PageItem::Ellipsis,
+    }));
+    items.push(PageItem::Next((current < total).then(|| current + 1)));
+    items
+}
+
+/// Page showing the first item of page `current` after switching from
+/// `old_size` to `new_size` items per page
+pub fn page_for_page_size(current: usize, old_size: usize, new_size: usize) -> usize {
+    current.saturating_sub(1) * old_size / new_size.max(1) + 1
+}
+
+/// Page typed into a "go to page" field, if it is one of the `total` pages
+pub fn parse_page(input: &str, total: usize) -> Option<usize> {
+    input.trim().parse().ok().filter(|page| (1..=total).contains(page))
+}
+
+/// Page stored in query parameter `param`, e.g. for reading the current page
+/// on the server
+pub fn page_from_query(search: &str, param: &str) -> Option<usize> {
+    query_value(search, param)
+        .and_then(|value| value.parse().ok())
+        .filter(|page| *page >= 1)
+}
+
+/// Builds the href of each page, so that pages are real links that work
+/// without JavaScript and are followed by the router
+#[derive(Clone)]
+pub enum PageHref {
+    /// A query parameter added to a URL that may already have a query,
+    /// e.g. `/posts?sort=new&page=3`
+    Query { url: String, param: String },
+    /// A path segment added to a URL, e.g. `/posts/3`
+    Path(String),
+    Custom(Callback<usize, String>),
+}
+
+impl PageHref {
+    pub fn query(url: impl Into<String>, param: impl Into<String>) -> Self {
+        Self::Query {
+            url: url.into(),
+            param: param.into(),
+        }
+    }
+
+    pub fn path(url: impl Into<String>) -> Self {
+        Self::Path(url.into())
+    }
+
+    pub fn href(&self, page: usize) -> String {
+        match self {
+            PageHref::Query { url, param } => set_query_param(url, param, &page.to_string()),
+            PageHref::Path(url) => format!("{}/{page}", url.trim_end_matches('/')),
+            PageHref::Custom(href) => href.run(page),
+        }
+    }
+}
 
 #[component]
 pub fn Pagination(
-    #[prop(optional)] current_page: MaybeProp<usize>,
-    #[prop(default = 1)] total_pages: usize,
+    /// Current page, starting at 1. Without it the pagination keeps track of
+    /// the page itself.
+    #[prop(into, optional)] current_page: MaybeProp<usize>,
+    #[prop(into, default = Signal::stored(1))] total_pages: Signal<usize>,
     #[prop(optional)] on_page_change: Option<Callback<usize>>,
+    /// Pages shown on each side of the current page
+    #[prop(into, optional)] sibling_count: MaybeProp<usize>,
+    /// Pages always shown at the start and at the end. Defaults to 1, or to 0
+    /// when `show_first_last` is false.
+    #[prop(into, optional)] boundary_count: MaybeProp<usize>,
+    /// Renders pages as links instead of buttons
+    #[prop(into, optional)] href: Option<PageHref>,
     #[prop(optional)] show_previous_next: MaybeProp<bool>,
     #[prop(optional)] show_first_last: MaybeProp<bool>,
-    #[prop(optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Items per page, shown in a page size selector along with
+    /// `page_size_options`
+    #[prop(into, optional)] page_size: MaybeProp<usize>,
+    #[prop(into, optional)] page_size_options: Option<Vec<usize>>,
+    #[prop(optional)] on_page_size_change: Option<Callback<usize>>,
+    /// Adds a "go to page" field
+    #[prop(into, optional)] show_jump: MaybeProp<bool>,
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
 ) -> impl IntoView {
-    let current = current_page.get().unwrap_or(1);
-    let show_prev_next = show_previous_next.get().unwrap_or(true);
-    let show_first_last_pages = show_first_last.get().unwrap_or(false);
-    
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let internal_page = RwSignal::new(1);
+    let current = move || current_page.get().unwrap_or_else(|| internal_page.get());
+    let show_prev_next = move || show_previous_next.get().unwrap_or(true);
+    let boundary_count = move || {
+        boundary_count
+            .get()
+            .unwrap_or(if show_first_last.get().unwrap_or(true) { 1 } else { 0 })
+    };
+
+    if let Some(href) = href.clone() {
+        provide_context(href);
+    }
+    let href = StoredValue::new(href);
+    let href_for = move |page: Option<usize>| page.and_then(|page| href.with_value(|href| href.as_ref().map(|href| href.href(page))));
+
     let handle_page_change = move |page: usize| {
+        if disabled.get_untracked() || page == current() {
+            return;
+        }
+        internal_page.set(page);
         if let Some(on_page_change) = on_page_change {
             on_page_change.run(page);
         }
     };
-    
-    let get_visible_pages = move || -> Vec<Option<usize>> {
-        let mut pages = Vec::new();
-        
-        if total_pages <= 7 {
-            // Show all pages if 7 or fewer
-            for i in 1..=total_pages {
-                pages.push(Some(i));
-            }
-        } else {
-            // Always show first page
-            if show_first_last_pages {
-                pages.push(Some(1));
-            }
-            
-            // Calculate range around current page
-            let start = if current <= 3 {
-                1
-            } else if current >= total_pages - 2 {
-                total_pages - 4
-            } else {
-                current - 2
-            };
-            
-            let end = if current <= 3 {
-                5
-            } else if current >= total_pages - 2 {
-                total_pages
-            } else {
-                current + 2
-            };
-            
-            // Add ellipsis before if needed
-            if show_first_last_pages && start > 2 {
-                pages.push(None); // Ellipsis
-            }
-            
-            // Add pages in range
-            let actual_start = if show_first_last_pages && start > 1 { start.max(2) } else { start };
-            let actual_end = if show_first_last_pages && end < total_pages { end.min(total_pages - 1) } else { end };
-            
-            for i in actual_start..=actual_end {
-                pages.push(Some(i));
-            }
-            
-            // Add ellipsis after if needed
-            if show_first_last_pages && end < total_pages - 1 {
-                pages.push(None); // Ellipsis
-            }
-            
-            // Always show last page
-            if show_first_last_pages && !pages.contains(&Some(total_pages)) {
-                pages.push(Some(total_pages));
-            }
+
+    let internal_page_size = RwSignal::new(
+        page_size_options.as_ref().and_then(|options| options.first().copied()).unwrap_or(10),
+    );
+    let current_page_size = Signal::derive(move || page_size.get().unwrap_or_else(|| internal_page_size.get()));
+    let handle_page_size_change = Callback::new(move |size: usize| {
+        let page = page_for_page_size(current(), current_page_size.get_untracked(), size);
+        internal_page_size.set(size);
+        if let Some(on_page_size_change) = on_page_size_change {
+            on_page_size_change.run(size);
         }
-        
-        pages
-    };
-    
-    let merged_class = tw_merge!(&format!("{} {}", 
-        PAGINATION_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
+        handle_page_change(page);
+    });
+
+    let merged_class = tw_merge!(PAGINATION_CLASS, class.get().unwrap_or_default());
+
     view! {
-        <nav 
+        <nav
             class={merged_class}
+            id=move || id.get()
             role="navigation"
             aria-label="pagination"
         >
             <div class=PAGINATION_CONTENT_CLASS>
-                // Previous button
-                {if show_prev_next {
-                    view! {
-                        <PaginationItem>
-                            <PaginationPrevious 
-                                disabled={(current <= 1).into()}
-                                on_click=Callback::new(move |_| if current > 1 { handle_page_change(current - 1) })
-                            />
-                        </PaginationItem>
-                    }.into_any()
-                } else {
-                    view! {}.into_any()
-                }}
-                
-                // Page numbers
-                {get_visible_pages().into_iter().map(|page_opt| {
-                    match page_opt {
-                        Some(page) => view! {
-                            <PaginationItem>
-                                <PaginationLink 
-                                    _page=page.into()
-                                    is_active={(page == current).into()}
-                                    on_click=Callback::new(move |_| handle_page_change(page))
-                                >
-                                    {page.to_string()}
-                                </PaginationLink>
-                            </PaginationItem>
-                        }.into_any(),
-                        None => view! {
-                            <PaginationItem>
-                                <PaginationEllipsis />
-                            </PaginationItem>
-                        }.into_any(),
-                    }
-                }).collect::<Vec<_>>()}
-                
-                // Next button
-                {if show_prev_next {
-                    view! {
-                        <PaginationItem>
-                            <PaginationNext 
-                                disabled={(current >= total_pages).into()}
-                                on_click=Callback::new(move |_| if current < total_pages { handle_page_change(current + 1) })
-                            />
-                        </PaginationItem>
-                    }.into_any()
-                } else {
-                    view! {}.into_any()
+                {move || {
+                    pagination_range(current(), total_pages.get(), sibling_count.get().unwrap_or(1), boundary_count())
+                        .into_iter()
+                        .map(|item| match item {
+                            PageItem::Previous(page) => show_prev_next().then(|| view! {
+                                <PaginationItem>
+                                    <PaginationPrevious
+                                        href=href_for(page)
+                                        disabled=page.is_none() || disabled.get()
+                                        on_click=Callback::new(move |_| page.into_iter().for_each(handle_page_change))
+                                    />
+                                </PaginationItem>
+                            }.into_any()),
+                            PageItem::Page(page) => Some(view! {
+                                <PaginationItem>
+                                    <PaginationLink
+                                        page=page
+                                        href=href_for(Some(page))
+                                        is_active=page == current()
+                                        disabled=disabled.get()
+                                        on_click=Callback::new(move |_| handle_page_change(page))
+                                    >
+                                        {page.to_string()}
+                                    </PaginationLink>
+                                </PaginationItem>
+                            }.into_any()),
+                            PageItem::Ellipsis => Some(view! {
+                                <PaginationItem>
+                                    <PaginationEllipsis />
+                                </PaginationItem>
+                            }.into_any()),
+                            PageItem::Next(page) => show_prev_next().then(|| view! {
+                                <PaginationItem>
+                                    <PaginationNext
+                                        href=href_for(page)
+                                        disabled=page.is_none() || disabled.get()
+                                        on_click=Callback::new(move |_| page.into_iter().for_each(handle_page_change))
+                                    />
+                                </PaginationItem>
+                            }.into_any()),
+                        })
+                        .collect_view()
                 }}
             </div>
+            {page_size_options.map(|options| view! {
+                <PaginationPageSize
+                    page_size=current_page_size
+                    options=options
+                    on_page_size_change=handle_page_size_change
+                    disabled=disabled
+                />
+            })}
+            <Show when=move || show_jump.get().unwrap_or(false)>
+                <PaginationJump
+                    total_pages=total_pages
+                    on_jump=Callback::new(handle_page_change)
+                    disabled=disabled
+                />
+            </Show>
         </nav>
     }
 }
@@ -156,11 +259,8 @@ pub fn PaginationContent(
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        PAGINATION_CONTENT_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
+    let merged_class = tw_merge!(PAGINATION_CONTENT_CLASS, class.get().unwrap_or_default());
+
     view! {
         <div class={merged_class}>
             {children()}
@@ -180,75 +280,107 @@ pub fn PaginationItem(
     }
 }
 
+/// A link when there is somewhere to go, a button otherwise. Disabled links
+/// lose their href so they can't be followed.
+fn page_control(
+    href: Option<String>,
+    disabled: bool,
+    class: String,
+    aria_label: Option<&'static str>,
+    aria_current: Option<&'static str>,
+    on_click: Option<Callback<()>>,
+    children: AnyView,
+) -> AnyView {
+    let handle_click = move |_| {
+        if let Some(on_click) = on_click.filter(|_| !disabled) {
+            on_click.run(());
+        }
+    };
+
+    match href {
+        Some(href) => view! {
+            <a
+                class=class
+                href=(!disabled).then_some(href)
+                aria-disabled=disabled.then_some("true")
+                aria-label=aria_label
+                aria-current=aria_current
+                on:click=handle_click
+            >
+                {children}
+            </a>
+        }.into_any(),
+        None => view! {
+            <button
+                class=class
+                disabled=disabled
+                aria-label=aria_label
+                aria-current=aria_current
+                on:click=handle_click
+            >
+                {children}
+            </button>
+        }.into_any(),
+    }
+}
+
 #[component]
 pub fn PaginationLink(
-    #[prop(optional)] _page: MaybeProp<usize>,
-    #[prop(optional)] is_active: MaybeProp<bool>,
-    #[prop(optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] page: MaybeProp<usize>,
+    /// Where the link goes. Defaults to the enclosing pagination's href for
+    /// `page`; without either, a button is rendered.
+    #[prop(into, optional)] href: MaybeProp<String>,
+    #[prop(into, optional)] is_active: MaybeProp<bool>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
     #[prop(optional)] on_click: Option<Callback<()>>,
     #[prop(optional)] class: MaybeProp<String>,
     children: Children,
 ) -> impl IntoView {
     let is_active_val = is_active.get().unwrap_or(false);
     let is_disabled = disabled.get().unwrap_or(false);
-    
+    let href = href
+        .get()
+        .or_else(|| page.get().zip(use_context::<PageHref>()).map(|(page, href)| href.href(page)));
+
     let button_class = if is_active_val {
-        tw_merge!(&format!("{} bg-primary text-primary-foreground hover:bg-primary/80", PAGINATION_ITEM_CLASS))
+        tw_merge!(PAGINATION_ITEM_CLASS, "bg-primary text-primary-foreground hover:bg-primary/80")
     } else {
         PAGINATION_ITEM_CLASS.to_string()
     };
-    
-    let merged_class = tw_merge!(&format!("{} {}", 
-        button_class,
-        class.get().unwrap_or_default()
-    ));
-    
-    view! {
-        <button
-            class={merged_class}
-            disabled={is_disabled}
-            on:click=move |_| {
-                if !is_disabled {
-                    if let Some(on_click) = on_click {
-                        on_click.run(());
-                    }
-                }
-            }
-            aria-current={if is_active_val { "page" } else { "false" }}
-        >
-            {children()}
-        </button>
-    }
+
+    let merged_class = tw_merge!(button_class, class.get().unwrap_or_default());
+
+    page_control(
+        href,
+        is_disabled,
+        merged_class,
+        None,
+        is_active_val.then_some("page"),
+        on_click,
+        children().into_any(),
+    )
 }
 
 #[component]
 pub fn PaginationPrevious(
-    #[prop(optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] href: MaybeProp<String>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
     #[prop(optional)] on_click: Option<Callback<()>>,
     #[prop(optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let is_disabled = disabled.get().unwrap_or(false);
-    
-    let merged_class = tw_merge!(&format!("{} {} {}", 
-        PAGINATION_ITEM_CLASS,
-        PAGINATION_PREVIOUS_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
-    view! {
-        <button
-            class={merged_class}
-            disabled={is_disabled}
-            on:click=move |_| {
-                if !is_disabled {
-                    if let Some(on_click) = on_click {
-                        on_click.run(());
-                    }
-                }
-            }
-            aria-label="Go to previous page"
-        >
+
+    let merged_class = tw_merge!(PAGINATION_ITEM_CLASS, PAGINATION_PREVIOUS_CLASS, class.get().unwrap_or_default());
+
+    page_control(
+        href.get(),
+        is_disabled,
+        merged_class,
+        Some("Go to previous page"),
+        None,
+        on_click,
+        view! {
             <svg class="h-4 w-4" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
                 <path d="m15 18-6-6 6-6"/>
             </svg>
@@ -257,38 +389,30 @@ pub fn PaginationPrevious(
             } else {
                 view! { <span>"Previous"</span> }.into_any()
             }}
-        </button>
-    }
+        }.into_any(),
+    )
 }
 
 #[component]
 pub fn PaginationNext(
-    #[prop(optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] href: MaybeProp<String>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
     #[prop(optional)] on_click: Option<Callback<()>>,
     #[prop(optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let is_disabled = disabled.get().unwrap_or(false);
-    
-    let merged_class = tw_merge!(&format!("{} {} {}", 
-        PAGINATION_ITEM_CLASS,
-        PAGINATION_LINK_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
-    view! {
-        <button
-            class={merged_class}
-            disabled={is_disabled}
-            on:click=move |_| {
-                if !is_disabled {
-                    if let Some(on_click) = on_click {
-                        on_click.run(());
-                    }
-                }
-            }
-            aria-label="Go to next page"
-        >
+
+    let merged_class = tw_merge!(PAGINATION_ITEM_CLASS, PAGINATION_LINK_CLASS, class.get().unwrap_or_default());
+
+    page_control(
+        href.get(),
+        is_disabled,
+        merged_class,
+        Some("Go to next page"),
+        None,
+        on_click,
+        view! {
             {if let Some(children) = children {
                 children().into_any()
             } else {
@@ -297,21 +421,18 @@ pub fn PaginationNext(
             <svg class="h-4 w-4" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
                 <path d="m9 18 6-6-6-6"/>
             </svg>
-        </button>
-    }
+        }.into_any(),
+    )
 }
 
 #[component]
 pub fn PaginationEllipsis(
     #[prop(optional)] class: MaybeProp<String>,
 ) -> impl IntoView {
-    let merged_class = tw_merge!(&format!("{} {}", 
-        PAGINATION_ELLIPSIS_CLASS,
-        class.get().unwrap_or_default()
-    ));
-    
+    let merged_class = tw_merge!(PAGINATION_ELLIPSIS_CLASS, class.get().unwrap_or_default());
+
     view! {
-        <span 
+        <span
             class={merged_class}
             aria-hidden="true"
         >
@@ -319,4 +440,150 @@ pub fn PaginationEllipsis(
             <span class="sr-only">"More pages"</span>
         </span>
     }
-}
\ No newline at end of file
+}
+
+/// Selector for the number of items per page
+#[component]
+pub fn PaginationPageSize(
+    #[prop(into)] page_size: Signal<usize>,
+    #[prop(into, default = vec![10, 20, 50, 100])] options: Vec<usize>,
+    #[prop(optional)] on_page_size_change: Option<Callback<usize>>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let merged_class = tw_merge!(PAGINATION_FIELD_CLASS, class.get().unwrap_or_default());
+
+    view! {
+        <label class={merged_class}>
+            <span>{move || label.get().unwrap_or_else(|| "Rows per page".to_string())}</span>
+            <select
+                class=PAGINATION_INPUT_CLASS
+                disabled=disabled
+                on:change=move |ev| {
+                    if let (Ok(size), Some(on_page_size_change)) = (event_target_value(&ev).parse(), on_page_size_change) {
+                        on_page_size_change.run(size);
+                    }
+                }
+            >
+                {options.into_iter().map(|size| view! {
+                    <option value=size.to_string() selected=move || page_size.get() == size>
+                        {size.to_string()}
+                    </option>
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
+
+/// Field to go straight to a page, submitted with Enter
+#[component]
+pub fn PaginationJump(
+    #[prop(into)] total_pages: Signal<usize>,
+    on_jump: Callback<usize>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let value = RwSignal::new(String::new());
+    let invalid = RwSignal::new(false);
+
+    let merged_class = tw_merge!(PAGINATION_FIELD_CLASS, class.get().unwrap_or_default());
+
+    view! {
+        <form
+            class={merged_class}
+            on:submit=move |ev| {
+                ev.prevent_default();
+                match parse_page(&value.get_untracked(), total_pages.get_untracked()) {
+                    Some(page) => {
+                        value.set(String::new());
+                        invalid.set(false);
+                        on_jump.run(page);
+                    }
+                    None => invalid.set(true),
+                }
+            }
+        >
+            <label class=PAGINATION_FIELD_CLASS>
+                <span>{move || label.get().unwrap_or_else(|| "Go to page".to_string())}</span>
+                <input
+                    class=format!("{PAGINATION_INPUT_CLASS} w-16")
+                    type="number"
+                    inputmode="numeric"
+                    min="1"
+                    max=move || total_pages.get().to_string()
+                    disabled=disabled
+                    aria-invalid=move || invalid.get().then_some("true")
+                    prop:value=move || value.get()
+                    on:input=move |ev| {
+                        invalid.set(false);
+                        value.set(event_target_value(&ev));
+                    }
+                />
+            </label>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pages(current: usize, total: usize, siblings: usize, boundary: usize) -> String {
+        pagination_range(current, total, siblings, boundary)
+            .into_iter()
+            .filter_map(|item| match item {
+                PageItem::Page(page) => Some(page.to_string()),
+                PageItem::Ellipsis => Some("…".to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_pagination_range_window() {
+        assert_eq!(pages(1, 5, 1, 1), "1 2 3 4 5");
+        assert_eq!(pages(1, 10, 1, 1), "1 2 3 4 5 … 10");
+        assert_eq!(pages(5, 10, 1, 1), "1 … 4 5 6 … 10");
+        assert_eq!(pages(10, 10, 1, 1), "1 … 6 7 8 9 10");
+        // An ellipsis never hides a single page
+        assert_eq!(pages(4, 10, 1, 1), "1 2 3 4 5 … 10");
+        assert_eq!(pages(5, 20, 2, 2), "1 2 3 4 5 6 7 8 … 19 20");
+        assert_eq!(pages(10, 20, 0, 0), "… 10 …");
+        assert_eq!(pages(1, 1, 1, 1), "1");
+        assert_eq!(pages(1, 0, 1, 1), "");
+    }
+
+    #[test]
+    fn test_pagination_range_previous_next() {
+        let items = pagination_range(1, 3, 1, 1);
+        assert_eq!(items.first(), Some(&PageItem::Previous(None)));
+        assert_eq!(items.last(), Some(&PageItem::Next(Some(2))));
+
+        // Out of range pages are clamped
+        let items = pagination_range(7, 3, 1, 1);
+        assert_eq!(items.first(), Some(&PageItem::Previous(Some(2))));
+        assert_eq!(items.last(), Some(&PageItem::Next(None)));
+    }
+
+    #[test]
+    fn test_page_href() {
+        assert_eq!(PageHref::query("/posts", "page").href(3), "/posts?page=3");
+        assert_eq!(PageHref::query("/posts?sort=new&page=2#list", "page").href(3), "/posts?sort=new&page=3#list");
+        assert_eq!(PageHref::path("/posts/").href(3), "/posts/3");
+        assert_eq!(page_from_query("?sort=new&page=4", "page"), Some(4));
+        assert_eq!(page_from_query("page=0", "page"), None);
+    }
+
+    #[test]
+    fn test_page_size_and_jump() {
+        // Page 3 of 10 per page starts at item 21, which is on page 2 of 20
+        assert_eq!(page_for_page_size(3, 10, 20), 2);
+        assert_eq!(page_for_page_size(2, 50, 10), 6);
+        assert_eq!(parse_page(" 4 ", 10), Some(4));
+        assert_eq!(parse_page("11", 10), None);
+        assert_eq!(parse_page("abc", 10), None);
+    }
+}
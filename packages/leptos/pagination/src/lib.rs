@@ -7,11 +7,17 @@ pub use default::*;
 #[cfg(feature = "new_york")]
 mod new_york;
 
-#[cfg(not(feature = "new_york"))]
+// The New York theme re-exports the default implementation
 mod default;
 
 pub mod signal_managed;
 
+#[cfg(feature = "router")]
+pub mod router;
+
+#[cfg(feature = "router")]
+pub use router::{PaginationUrlState, use_pagination_query};
+
 mod tests;
 
 mod tdd_tests;
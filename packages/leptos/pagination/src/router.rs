@@ -0,0 +1,52 @@
+//! Keeping the current page in the URL
+//!
+//! Enabled with the `router` feature. [`use_pagination_query`] reads the page
+//! from a query parameter and builds hrefs for the other pages that keep the
+//! rest of the URL, so pages are plain links the router navigates between:
+//!
+//! ```ignore
+//! let pages = use_pagination_query("page");
+//! view! {
+//!     <Pagination current_page=pages.current_page href=pages.href total_pages=20 />
+//! }
+//! ```
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+use crate::{PageHref, page_from_query};
+
+/// Props to pass to [`Pagination`](crate::Pagination)
+#[derive(Clone)]
+pub struct PaginationUrlState {
+    pub current_page: Signal<usize>,
+    pub href: PageHref,
+}
+
+/// Reads the current page from query parameter `param`, defaulting to the
+/// first page. Must be called inside a `<Router>`.
+pub fn use_pagination_query(param: impl Into<String>) -> PaginationUrlState {
+    let location = use_location();
+    let param = StoredValue::new(param.into());
+
+    let current_page = Signal::derive(move || {
+        param.with_value(|param| page_from_query(&location.search.get(), param)).unwrap_or(1)
+    });
+
+    let href = PageHref::Custom(Callback::new(move |page: usize| {
+        let search = location.search.get();
+        let search = search.trim_start_matches('?');
+        let hash = location.hash.get();
+        let hash = hash.trim_start_matches('#');
+        let mut url = location.pathname.get();
+        if !search.is_empty() {
+            url = format!("{url}?{search}");
+        }
+        if !hash.is_empty() {
+            url = format!("{url}#{hash}");
+        }
+        PageHref::query(url, param.get_value()).href(page)
+    }));
+
+    PaginationUrlState { current_page, href }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod basic_rendering_tests {
-    use super::*;
+    use crate::*;
+    use leptos::prelude::*;
 
     // ===== BASIC RENDERING TESTS =====
     // These tests focus on basic rendering and component creation
@@ -127,9 +128,9 @@ mod basic_rendering_tests {
             {if show_pagination {
                 view! {
                     <Pagination total_pages=10/>
-                }.into_view()
+                }.into_any()
             } else {
-                view! {}.into_view()
+                view! {}.into_any()
             }}
         };
     }
@@ -147,8 +148,8 @@ mod basic_rendering_tests {
         let _accessible_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                aria_label="Pagination navigation"
-                role="navigation"
+                attr:aria-label="Pagination navigation"
+                attr:role="navigation"
             />
         };
     }
@@ -182,7 +183,7 @@ mod basic_rendering_tests {
         let _styled_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                style="margin: 20px; padding: 10px;"
+                attr:style="margin: 20px; padding: 10px;"
             />
         };
     }
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod state_management_tests {
-    use super::*;
+    use crate::*;
+    use leptos::prelude::*;
 
     // ===== STATE MANAGEMENT TESTS =====
     // These tests focus on state management and interactions
@@ -66,9 +67,9 @@ mod state_management_tests {
         let _event_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                on_click=move |_| click_handled.set(true)
-                on_focus=move |_| focus_handled.set(true)
-                on_blur=move |_| blur_handled.set(true)
+                on:click=move |_| click_handled.set(true)
+                on:focus=move |_| focus_handled.set(true)
+                on:blur=move |_| blur_handled.set(true)
             />
         };
         
@@ -130,13 +131,13 @@ mod state_management_tests {
         let _validation_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                aria_invalid=MaybeProp::from(move || error_state.get())
-                class=MaybeProp::from(move || {
+                attr:aria-invalid=move || error_state.get()
+                class=Signal::derive(move || {
                     if error_state.get() { "error-pagination" }
                     else if warning_state.get() { "warning-pagination" }
                     else if valid_state.get() { "valid-pagination" }
                     else { "default-pagination" }
-                })
+                }.to_string())
             />
         };
         
@@ -166,9 +167,9 @@ mod state_management_tests {
         let _focus_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                on_focus=move |_| focused_state.set(true)
-                on_blur=move |_| focused_state.set(false)
-                on_focus_visible=move |_| focus_visible_state.set(true)
+                on:focus=move |_| focused_state.set(true)
+                on:blur=move |_| focused_state.set(false)
+                on:focusin=move |_| focus_visible_state.set(true)
             />
         };
         
@@ -193,12 +194,12 @@ mod state_management_tests {
             <Pagination 
                 total_pages=10
                 disabled=MaybeProp::from(disabled_state)
-                readonly=MaybeProp::from(readonly_state)
-                class=MaybeProp::from(move || {
+                attr:aria-readonly=move || readonly_state.get()
+                class=Signal::derive(move || {
                     if disabled_state.get() { "disabled-pagination" }
                     else if readonly_state.get() { "readonly-pagination" }
                     else { "enabled-pagination" }
-                })
+                }.to_string())
             />
         };
         
@@ -258,7 +259,7 @@ mod state_management_tests {
         let _size_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                class=MaybeProp::from(move || format!("pagination-{}", size_signal.get()))
+                class=Signal::derive(move || format!("pagination-{}", size_signal.get()))
             />
         };
         
@@ -280,7 +281,7 @@ mod state_management_tests {
         let _variant_pagination_view = view! {
             <Pagination 
                 total_pages=10
-                class=MaybeProp::from(move || format!("pagination-{}", variant_signal.get()))
+                class=Signal::derive(move || format!("pagination-{}", variant_signal.get()))
             />
         };
         
@@ -306,11 +307,11 @@ mod state_management_tests {
             <Pagination 
                 current_page=MaybeProp::from(current_page)
                 total_pages=total_pages
-                aria_invalid=MaybeProp::from(move || {
+                attr:aria-invalid=move || {
                     let page = current_page.get();
                     let total = total_pages.get();
                     page < min_page.get() || page > max_page.get() || page > total
-                })
+                }
             />
         };
         
@@ -353,7 +354,7 @@ mod state_management_tests {
                 current_page=MaybeProp::from(current_page)
                 total_pages=total_pages
                 disabled=MaybeProp::from(disabled)
-                readonly=MaybeProp::from(readonly)
+                attr:aria-readonly=move || readonly.get()
             />
         };
         
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const PROGRESS_CLASS: &str = "relative w-full overflow-hidden rounded-full bg-secondary";
 const PROGRESS_INDICATOR_CLASS: &str = "h-full w-full flex-1 bg-primary transition-all";
@@ -78,7 +79,7 @@ pub fn Progress(
     });
 
     let computed_class = Signal::derive(move || {
-        format!("{} {} {}", PROGRESS_CLASS, size_class, class.get().unwrap_or_default())
+        tw_merge!(PROGRESS_CLASS, size_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -188,7 +189,7 @@ pub fn ProgressIndicator(
         let base_class = PROGRESS_INDICATOR_CLASS;
         let variant_class = ctx.variant.get().indicator_class();
         let animation_class = if ctx.animated.get() { "animate-pulse" } else { "" };
-        format!("{} {} {} {}", base_class, variant_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(base_class, variant_class, animation_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -216,7 +217,7 @@ pub fn ProgressLabel(
     });
 
     let computed_class = Signal::derive(move || {
-        format!("flex justify-between text-sm text-muted-foreground {}", class.get().unwrap_or_default())
+        tw_merge!("flex justify-between text-sm text-muted-foreground", class.get().unwrap_or_default())
     });
 
     view! {
@@ -1,5 +1,6 @@
 use leptos::{ev::MouseEvent, prelude::*};
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 // New York variant with subtle styling differences
 const RADIO_GROUP_CLASS: &str = "grid gap-2";
@@ -46,10 +47,9 @@ pub fn RadioGroup(
     };
     
     let computed_class = Signal::derive(move || {
-        format!(
-            "{} {}",
+        tw_merge!(
             RADIO_GROUP_CLASS,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
     
@@ -112,10 +112,9 @@ pub fn RadioGroupItem(
     };
     
     let computed_class = Signal::derive(move || {
-        format!(
-            "{} {}",
+        tw_merge!(
             RADIO_ITEM_CLASS,
-            class.get().unwrap_or_default()
+            class.get().unwrap_or_default(),
         )
     });
     
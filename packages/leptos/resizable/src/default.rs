@@ -1,20 +1,50 @@
+use std::sync::Arc;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
 use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys;
 use leptos_style::Style;
 
-/// Resize direction for panels
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum ResizeDirection {
-    Horizontal,
-    Vertical,
-}
+use crate::resizable::utils;
+pub use crate::resizable::ResizeDirection;
+use crate::resizable::{
+    layout_storage_key, LayoutStorage, LocalStorage, PanelConstraints, PanelGroupApi, ResizableContext,
+};
 
-impl Default for ResizeDirection {
-    fn default() -> Self {
-        ResizeDirection::Horizontal
-    }
+/// Classes of one style; the styles share everything else.
+#[derive(Clone, Copy)]
+pub(crate) struct ResizableClasses {
+    pub group: &'static str,
+    pub panel: &'static str,
+    pub collapse_button: &'static str,
+    pub panel_content: &'static str,
+    pub handle: &'static str,
+    pub handle_grip: &'static str,
+    pub grip_dots: &'static str,
 }
 
+const CLASSES: ResizableClasses = ResizableClasses {
+    group: "resizable-panel-group flex h-full w-full",
+    panel: "resizable-panel relative",
+    collapse_button: "collapse-button absolute top-2 right-2 z-10 p-1 rounded hover:bg-gray-200",
+    panel_content: "panel-content h-full w-full",
+    handle: "resizable-handle",
+    handle_grip: "handle-grip",
+    grip_dots: "grip-dots",
+};
+
 /// Resizable panel group component
+///
+/// Each group provides its own context. Sizes and constraints are percentages
+/// of the panel's own group, so a group nested inside a panel keeps its
+/// constraints at any size of that panel. Constraints are not propagated
+/// between groups: a nested group never limits the panel holding it, so give
+/// that panel its own `min_size`/`max_size` instead.
+///
+/// With `auto_save_id` the layout is restored from and saved to `storage`
+/// (`localStorage` by default). Panels may be rendered conditionally; the
+/// others keep their sizes.
 #[component]
 pub fn ResizablePanelGroup(
     #[prop(into, optional)] direction: MaybeProp<ResizeDirection>,
@@ -25,16 +55,112 @@ pub fn ResizablePanelGroup(
     #[prop(into, optional)] touch_support: MaybeProp<bool>,
     #[prop(into, optional)] aria_label: MaybeProp<String>,
     #[prop(into, optional)] on_resize: MaybeProp<Callback<Vec<f64>>>,
+    #[prop(into, optional)] auto_save_id: MaybeProp<String>,
+    #[prop(optional)] storage: Option<Arc<dyn LayoutStorage>>,
+    /// Receives the group's imperative API
+    #[prop(optional)] set_api: Option<Callback<PanelGroupApi>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let (panel_sizes, set_panel_sizes) = signal(Vec::<f64>::new());
-    let (is_resizing, set_is_resizing) = signal(false);
-    let (resize_direction, set_resize_direction) = signal(direction.get().unwrap_or_default());
+    resizable_panel_group(
+        ResizablePanelGroupProps {
+            direction,
+            class,
+            id,
+            style,
+            keyboard_resize,
+            touch_support,
+            aria_label,
+            on_resize,
+            auto_save_id,
+            storage,
+            set_api,
+            children,
+        },
+        CLASSES,
+    )
+}
+
+pub(crate) fn resizable_panel_group(props: ResizablePanelGroupProps, style_classes: ResizableClasses) -> impl IntoView {
+    let ResizablePanelGroupProps {
+        direction,
+        class,
+        id,
+        style,
+        keyboard_resize,
+        touch_support,
+        aria_label,
+        on_resize,
+        auto_save_id,
+        storage,
+        set_api,
+        children,
+    } = props;
+
+    let context = ResizableContext::new();
+    context.state.update_untracked(|s| s.resize_direction = direction.get_untracked().unwrap_or_default());
+    context.config.update_untracked(|c| {
+        c.keyboard_resize = keyboard_resize.get_untracked().unwrap_or(true);
+        c.touch_support = touch_support.get_untracked().unwrap_or(true);
+    });
+    provide_context(context);
+    if let Some(set_api) = set_api {
+        set_api.run(context.api());
+    }
+
+    let storage = StoredValue::new(storage.unwrap_or_else(|| Arc::new(LocalStorage)));
+    let layout = Memo::new(move |_| context.state.with(|s| s.panel_sizes.clone()));
+    let is_resizing = Memo::new(move |_| context.state.with(|s| s.is_resizing));
+
+    Effect::new(move |_| {
+        let direction = direction.get().unwrap_or_default();
+        let keyboard_resize = keyboard_resize.get().unwrap_or(true);
+        let touch_support = touch_support.get().unwrap_or(true);
+        context.state.update(|s| s.resize_direction = direction);
+        context.config.update(|c| {
+            c.keyboard_resize = keyboard_resize;
+            c.touch_support = touch_support;
+        });
+    });
+
+    // Lay out once the panels have registered, and again if panels come or go
+    Effect::new(move |_| {
+        let panels = context.panels.get();
+        if layout.with_untracked(|layout| layout.len() == panels.len()) {
+            return;
+        }
+        let saved = auto_save_id
+            .get_untracked()
+            .and_then(|id| storage.with_value(|storage| storage.get_item(&layout_storage_key(&id))))
+            .and_then(|text| utils::parse_layout(&text))
+            .filter(|saved| saved.len() == panels.len());
+        let next = saved.unwrap_or_else(|| utils::initial_layout(&panels, &context.initially_collapsed()));
+        context.set_layout(next);
+    });
+
+    Effect::new(move |_| {
+        let sizes = layout.get();
+        if !sizes.is_empty()
+            && let Some(callback) = on_resize.get_untracked()
+        {
+            callback.run(sizes);
+        }
+    });
+
+    // Persist once a drag ends rather than on every pointer move
+    Effect::new(move |_| {
+        let sizes = layout.get();
+        if sizes.is_empty() || is_resizing.get() {
+            return;
+        }
+        if let Some(id) = auto_save_id.get_untracked() {
+            storage.with_value(|storage| storage.set_item(&layout_storage_key(&id), &utils::serialize_layout(&sizes)));
+        }
+    });
 
     let computed_class = Signal::derive(move || {
-        let mut classes = vec!["resizable-panel-group".to_string()];
+        let mut classes = vec![style_classes.group.to_string()];
         
-        match resize_direction.get() {
+        match context.direction() {
             ResizeDirection::Horizontal => classes.push("flex-row".to_string()),
             ResizeDirection::Vertical => classes.push("flex-col".to_string()),
         }
@@ -44,23 +170,18 @@ pub fn ResizablePanelGroup(
         }
         
         classes.push(class.get().unwrap_or_default());
-        classes.join(" ")
+        tw_merge!(classes.join(" "))
     });
 
-    let handle_resize = move |sizes: Vec<f64>| {
-        set_panel_sizes.set(sizes.clone());
-        if let Some(callback) = on_resize.get() {
-            callback.run(sizes);
-        }
-    };
-
     view! {
         <div
+            node_ref=context.group_ref
             class=computed_class
             id=id.get().unwrap_or_default()
             style=move || style.get().to_string()
             aria-label=aria_label.get().unwrap_or_default()
             role="group"
+            data-panel-group-direction=move || context.direction().as_str()
         >
             {children.map(|c| c())}
         </div>
@@ -68,6 +189,10 @@ pub fn ResizablePanelGroup(
 }
 
 /// Individual resizable panel component
+///
+/// Sizes are percentages of the group. Collapsible panels collapse when
+/// dragged past half of `min_size`, from the collapse button, or by
+/// double-clicking a neighbouring handle.
 #[component]
 pub fn ResizablePanel(
     #[prop(into, optional)] default_size: MaybeProp<f64>,
@@ -81,18 +206,103 @@ pub fn ResizablePanel(
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(into, optional)] aria_label: MaybeProp<String>,
     #[prop(into, optional)] on_resize: MaybeProp<Callback<f64>>,
+    #[prop(into, optional)] on_collapse: MaybeProp<Callback<()>>,
+    #[prop(into, optional)] on_expand: MaybeProp<Callback<()>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let (current_size, set_current_size) = signal(default_size.get().unwrap_or(50.0));
-    let (is_collapsed, set_is_collapsed) = signal(collapsed.get().unwrap_or(false));
-    let (is_resizing, set_is_resizing) = signal(false);
+    resizable_panel(
+        ResizablePanelProps {
+            default_size,
+            min_size,
+            max_size,
+            collapsible,
+            collapsed_size,
+            collapsed,
+            class,
+            id,
+            style,
+            aria_label,
+            on_resize,
+            on_collapse,
+            on_expand,
+            children,
+        },
+        CLASSES,
+    )
+}
+
+pub(crate) fn resizable_panel(props: ResizablePanelProps, style_classes: ResizableClasses) -> impl IntoView {
+    let ResizablePanelProps {
+        default_size,
+        min_size,
+        max_size,
+        collapsible,
+        collapsed_size,
+        collapsed,
+        class,
+        id,
+        style,
+        aria_label,
+        on_resize,
+        on_collapse,
+        on_expand,
+        children,
+    } = props;
+
+    let constraints = PanelConstraints {
+        default_size: default_size.get_untracked(),
+        min_size: min_size.get_untracked().unwrap_or(10.0),
+        max_size: max_size.get_untracked().unwrap_or(90.0),
+        collapsible: collapsible.get_untracked().unwrap_or(false),
+        collapsed_size: collapsed_size.get_untracked().unwrap_or(0.0),
+    };
+    let initially_collapsed = collapsed.get_untracked().unwrap_or(false);
 
-    let min_size_val = min_size.get().unwrap_or(10.0);
-    let max_size_val = max_size.get().unwrap_or(90.0);
-    let collapsed_size_val = collapsed_size.get().unwrap_or(0.0);
+    // Outside a group the panel keeps its own state
+    let context = use_context::<ResizableContext>();
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let panel_id = context.map(|context| context.register_panel(constraints, initially_collapsed, node_ref));
+    let index = Memo::new(move |_| context.zip(panel_id).and_then(|(context, id)| context.panel_index(id)));
+    let (local_collapsed, set_local_collapsed) = signal(initially_collapsed && constraints.collapsible);
+    track_group_item(context, panel_id, node_ref);
+
+    let is_collapsed = Memo::new(move |_| match (context, index.get()) {
+        (Some(context), Some(index)) => context.is_panel_collapsed(index),
+        _ => local_collapsed.get(),
+    });
+    let current_size = Memo::new(move |_| {
+        let size = context.zip(index.get()).and_then(|(context, index)| context.panel_size(index));
+        match size {
+            Some(size) => size,
+            None if is_collapsed.get() => constraints.collapsed_size,
+            None => constraints.default_size.unwrap_or(50.0),
+        }
+    });
+    let is_resizing = Signal::derive(move || context.is_some_and(|context| context.state.with(|s| s.is_resizing)));
+
+    Effect::new(move |previous: Option<f64>| {
+        let size = current_size.get();
+        if previous.is_some_and(|previous| previous != size)
+            && let Some(callback) = on_resize.get_untracked()
+        {
+            callback.run(size);
+        }
+        size
+    });
+
+    Effect::new(move |previous: Option<bool>| {
+        let collapsed = is_collapsed.get();
+        if previous.is_some_and(|previous| previous != collapsed) {
+            let callback = if collapsed { on_collapse.get_untracked() } else { on_expand.get_untracked() };
+            if let Some(callback) = callback {
+                callback.run(());
+            }
+        }
+        collapsed
+    });
 
     let computed_class = Signal::derive(move || {
-        let mut classes = vec!["resizable-panel".to_string()];
+        let mut classes = vec![style_classes.panel.to_string()];
         
         if is_collapsed.get() {
             classes.push("collapsed".to_string());
@@ -103,51 +313,43 @@ pub fn ResizablePanel(
         }
         
         classes.push(class.get().unwrap_or_default());
-        classes.join(" ")
+        tw_merge!(classes.join(" "))
     });
 
+    // Sizes act as flex-grow weights, so the panels always fill the group
     let computed_style = Signal::derive(move || {
-        let size = if is_collapsed.get() {
-            collapsed_size_val
-        } else {
-            current_size.get().clamp(min_size_val, max_size_val)
-        };
-        
         let mut style_str = style.get().to_string();
-        style_str.push_str(&format!("; flex: 0 0 {}%;", size));
+        style_str.push_str(&format!("; flex: {} 1 0px; overflow: hidden;", current_size.get()));
         
         style_str
     });
 
-    let handle_resize = move |size: f64| {
-        set_current_size.set(size);
-        if let Some(callback) = on_resize.get() {
-            callback.run(size);
-        }
-    };
-
-    let toggle_collapse = move |_| {
-        if collapsible.get().unwrap_or(false) {
-            set_is_collapsed.set(!is_collapsed.get());
-        }
+    let toggle_collapse = move |_| match (context, index.get_untracked()) {
+        (Some(context), Some(index)) => context.toggle_collapse.run(index),
+        _ if constraints.collapsible => set_local_collapsed.update(|collapsed| *collapsed = !*collapsed),
+        _ => {}
     };
 
     view! {
         <div
+            node_ref=node_ref
             class=computed_class
             id=id.get().unwrap_or_default()
             style=computed_style
             aria-label=aria_label.get().unwrap_or_default()
             role="region"
+            data-panel-size=move || format!("{:.1}", current_size.get())
+            data-panel-collapsed=move || is_collapsed.get().to_string()
         >
-            {if collapsible.get().unwrap_or(false) {
+            {if constraints.collapsible {
                 view! {
                     <button
-                        class="collapse-button absolute top-2 right-2 z-10 p-1 rounded hover:bg-gray-200"
+                        class=style_classes.collapse_button
                         on:click=toggle_collapse
-                        aria-label=if is_collapsed.get() { "Expand panel" } else { "Collapse panel" }
+                        aria-label=move || if is_collapsed.get() { "Expand panel" } else { "Collapse panel" }
+                        aria-expanded=move || (!is_collapsed.get()).to_string()
                     >
-                        {if is_collapsed.get() {
+                        {move || if is_collapsed.get() {
                             "→"
                         } else {
                             "←"
@@ -158,20 +360,18 @@ pub fn ResizablePanel(
                 view! { <div></div> }.into_any()
             }}
             
-            {if !is_collapsed.get() {
-                view! {
-                    <div class="panel-content">
-                        {children.map(|c| c())}
-                    </div>
-                }.into_any()
-            } else {
-                view! { <div></div> }.into_any()
-            }}
+            <div class=style_classes.panel_content hidden=move || is_collapsed.get()>
+                {children.map(|c| c())}
+            </div>
         </div>
     }
 }
 
 /// Resizable handle component
+///
+/// Drag with a pointer, or focus it and use the arrow keys, Home and End.
+/// Enter or a double-click collapses (or expands) the neighbouring
+/// collapsible panel.
 #[component]
 pub fn ResizableHandle(
     #[prop(into, optional)] with_handle: MaybeProp<bool>,
@@ -182,11 +382,59 @@ pub fn ResizableHandle(
     #[prop(into, optional)] keyboard_resize: MaybeProp<bool>,
     #[prop(into, optional)] touch_support: MaybeProp<bool>,
 ) -> impl IntoView {
+    resizable_handle(
+        ResizableHandleProps {
+            with_handle,
+            class,
+            disabled,
+            aria_label,
+            role,
+            keyboard_resize,
+            touch_support,
+        },
+        CLASSES,
+    )
+}
+
+pub(crate) fn resizable_handle(props: ResizableHandleProps, style_classes: ResizableClasses) -> impl IntoView {
+    let ResizableHandleProps {
+        with_handle,
+        class,
+        disabled,
+        aria_label,
+        role,
+        keyboard_resize,
+        touch_support,
+    } = props;
+
     let (is_resizing, set_is_resizing) = signal(false);
     let (is_hovering, set_is_hovering) = signal(false);
 
+    let context = use_context::<ResizableContext>();
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let handle_id = context.map(|context| context.register_handle(node_ref));
+    let handle_index = Memo::new(move |_| context.zip(handle_id).and_then(|(context, id)| context.handle_index(id)));
+    track_group_item(context, handle_id, node_ref);
+    // Pointer position and layout when the drag started
+    let drag_start = StoredValue::new(None::<(f64, Vec<f64>)>);
+
+    let is_disabled = move || disabled.get().unwrap_or(false);
+    let keyboard_enabled = Signal::derive(move || {
+        keyboard_resize
+            .get()
+            .or_else(|| context.map(|context| context.config.with(|c| c.keyboard_resize)))
+            .unwrap_or(false)
+    });
+    let touch_enabled = move || {
+        touch_support
+            .get()
+            .or_else(|| context.map(|context| context.config.with_untracked(|c| c.touch_support)))
+            .unwrap_or(true)
+    };
+    let direction = move || context.map(|context| context.direction()).unwrap_or_default();
+
     let computed_class = Signal::derive(move || {
-        let mut classes = vec!["resizable-handle".to_string()];
+        let mut classes = vec![style_classes.handle.to_string()];
         
         if with_handle.get().unwrap_or(true) {
             classes.push("with-handle".to_string());
@@ -205,43 +453,146 @@ pub fn ResizableHandle(
         }
         
         classes.push(class.get().unwrap_or_default());
-        classes.join(" ")
+        tw_merge!(classes.join(" "))
     });
 
-    let handle_mouse_down = move |_| {
-        if !disabled.get().unwrap_or(false) {
-            set_is_resizing.set(true);
+    let pointer_coordinate = move |evt: &web_sys::PointerEvent| match direction() {
+        ResizeDirection::Horizontal => evt.client_x() as f64,
+        ResizeDirection::Vertical => evt.client_y() as f64,
+    };
+
+    let handle_pointer_down = move |evt: web_sys::PointerEvent| {
+        let Some(context) = context else {
+            return;
+        };
+        if is_disabled() || evt.button() != 0 || (evt.pointer_type() == "touch" && !touch_enabled()) {
+            return;
+        }
+        evt.prevent_default();
+        if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+            let _ = target.set_pointer_capture(evt.pointer_id());
         }
+        drag_start.set_value(Some((pointer_coordinate(&evt), context.layout())));
+        set_is_resizing.set(true);
+        context.start_resize.run(());
     };
 
-    let handle_mouse_up = move |_| {
+    let handle_pointer_move = move |evt: web_sys::PointerEvent| {
+        let (Some(context), Some(handle_index)) = (context, handle_index.get_untracked()) else {
+            return;
+        };
+        let Some(group_size) = context.group_size() else {
+            return;
+        };
+        let next = drag_start.with_value(|start| {
+            start.as_ref().map(|(origin, layout)| {
+                let delta = (pointer_coordinate(&evt) - origin) / group_size * 100.0;
+                context.panels.with_untracked(|panels| utils::resize_by_delta(layout, panels, handle_index, delta))
+            })
+        });
+        if let Some(next) = next {
+            context.set_layout(next);
+        }
+    };
+
+    let handle_pointer_up = move |evt: web_sys::PointerEvent| {
+        if drag_start.with_value(|start| start.is_none()) {
+            return;
+        }
+        drag_start.set_value(None);
+        if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+            let _ = target.release_pointer_capture(evt.pointer_id());
+        }
         set_is_resizing.set(false);
+        if let Some(context) = context {
+            context.end_resize.run(());
+        }
+    };
+
+    // Collapses the panel before the handle, or the one after it when only that one is collapsible
+    let toggle_adjacent = move || {
+        let (Some(context), Some(handle_index)) = (context, handle_index.get_untracked()) else {
+            return;
+        };
+        let target = context.panels.with_untracked(|panels| {
+            [handle_index, handle_index + 1]
+                .into_iter()
+                .find(|index| panels.get(*index).is_some_and(|panel| panel.collapsible))
+        });
+        if let Some(index) = target {
+            context.toggle_collapse.run(index);
+        }
     };
 
-    let handle_mouse_enter = move |_| {
-        set_is_hovering.set(true);
+    let handle_key_down = move |evt: web_sys::KeyboardEvent| {
+        let (Some(context), Some(handle_index)) = (context, handle_index.get_untracked()) else {
+            return;
+        };
+        if is_disabled() || !keyboard_enabled.get_untracked() {
+            return;
+        }
+        if evt.key() == "Enter" {
+            evt.prevent_default();
+            toggle_adjacent();
+        } else if let Some(delta) = utils::keyboard_delta(&evt.key(), context.direction()) {
+            evt.prevent_default();
+            context.resize_handle(handle_index, delta);
+        }
     };
 
-    let handle_mouse_leave = move |_| {
-        set_is_hovering.set(false);
+    let panel_before = move || {
+        let (context, handle_index) = context.zip(handle_index.get())?;
+        let size = context.panel_size(handle_index)?;
+        let panel = context.panels.with(|panels| panels.get(handle_index).copied())?;
+        Some((size, panel))
     };
 
     view! {
         <div
+            node_ref=node_ref
             class=computed_class
             aria-label=aria_label.get().unwrap_or_default()
             role=role.get().unwrap_or_else(|| "separator".to_string())
-            aria-orientation="horizontal"
-            tabindex=if keyboard_resize.get().unwrap_or(false) { Some(0) } else { None }
-            on:mousedown=handle_mouse_down
-            on:mouseup=handle_mouse_up
-            on:mouseenter=handle_mouse_enter
-            on:mouseleave=handle_mouse_leave
+            aria-orientation=move || match direction() {
+                ResizeDirection::Horizontal => "vertical",
+                ResizeDirection::Vertical => "horizontal",
+            }
+            aria-valuenow=move || panel_before().map(|(size, _)| size.round().to_string())
+            aria-valuemin=move || panel_before().map(|(_, panel)| panel.min_size.to_string())
+            aria-valuemax=move || panel_before().map(|(_, panel)| panel.max_size.to_string())
+            aria-disabled=move || is_disabled().then_some("true")
+            tabindex=move || keyboard_enabled.get().then_some(0)
+            data-panel-group-direction=move || direction().as_str()
+            data-resize-handle-state=move || {
+                if is_resizing.get() {
+                    "drag"
+                } else if is_hovering.get() {
+                    "hover"
+                } else {
+                    "inactive"
+                }
+            }
+            style=move || match direction() {
+                ResizeDirection::Horizontal => "cursor: col-resize; touch-action: none;",
+                ResizeDirection::Vertical => "cursor: row-resize; touch-action: none;",
+            }
+            on:pointerdown=handle_pointer_down
+            on:pointermove=handle_pointer_move
+            on:pointerup=handle_pointer_up
+            on:pointercancel=handle_pointer_up
+            on:dblclick=move |_| {
+                if !is_disabled() {
+                    toggle_adjacent();
+                }
+            }
+            on:keydown=handle_key_down
+            on:mouseenter=move |_| set_is_hovering.set(true)
+            on:mouseleave=move |_| set_is_hovering.set(false)
         >
             {if with_handle.get().unwrap_or(true) {
                 view! {
-                    <div class="handle-grip">
-                        <div class="grip-dots"></div>
+                    <div class=style_classes.handle_grip>
+                        <div class=style_classes.grip_dots></div>
                     </div>
                 }.into_any()
             } else {
@@ -250,3 +601,17 @@ pub fn ResizableHandle(
         </div>
     }
 }
+
+/// Moves a panel or handle into document order once mounted, and removes it
+/// from the group when it is unmounted
+pub(crate) fn track_group_item(context: Option<ResizableContext>, id: Option<usize>, node_ref: NodeRef<leptos::html::Div>) {
+    let (Some(context), Some(id)) = (context, id) else {
+        return;
+    };
+    Effect::new(move |_| {
+        if node_ref.get().is_some() {
+            context.sync_order();
+        }
+    });
+    on_cleanup(move || context.unregister(id));
+}
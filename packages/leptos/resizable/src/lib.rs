@@ -8,7 +8,8 @@ pub mod resizable;
 pub use default::{ResizablePanelGroup, ResizablePanel, ResizableHandle};
 pub use new_york::{ResizablePanelGroup as ResizablePanelGroupNewYork, ResizablePanel as ResizablePanelNewYork, ResizableHandle as ResizableHandleNewYork};
 pub use resizable::{
-    ResizeDirection, ResizableState, ResizableConfig
+    ResizeDirection, ResizableState, ResizableConfig, ResizableContext, PanelConstraints, PanelGroupApi,
+    LayoutStorage, LocalStorage, MemoryStorage, SharedLayoutStorage, use_resizable_context,
 };
 
 mod tests;
@@ -1,4 +1,10 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use leptos::html::Div;
 use leptos::prelude::*;
+use leptos::web_sys;
 
 /// Resize direction for panels
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -13,6 +19,15 @@ impl Default for ResizeDirection {
     }
 }
 
+impl ResizeDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResizeDirection::Horizontal => "horizontal",
+            ResizeDirection::Vertical => "vertical",
+        }
+    }
+}
+
 /// Resizable state management
 #[derive(Debug, Clone)]
 pub struct ResizableState {
@@ -59,76 +74,434 @@ impl Default for ResizableConfig {
     }
 }
 
+/// Size constraints of a single panel, in percent of its group
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PanelConstraints {
+    /// Initial size; panels without one share the space left by the others
+    pub default_size: Option<f64>,
+    pub min_size: f64,
+    pub max_size: f64,
+    pub collapsible: bool,
+    pub collapsed_size: f64,
+}
+
+impl Default for PanelConstraints {
+    fn default() -> Self {
+        Self {
+            default_size: None,
+            min_size: 0.0,
+            max_size: 100.0,
+            collapsible: false,
+            collapsed_size: 0.0,
+        }
+    }
+}
+
+impl PanelConstraints {
+    /// Clamps `size` into bounds. Collapsible panels snap to their collapsed
+    /// size below half of their minimum size.
+    pub fn clamp(&self, size: f64) -> f64 {
+        if self.collapsible && size < (self.collapsed_size + self.min_size) / 2.0 {
+            return self.collapsed_size;
+        }
+        size.clamp(self.min_size, self.max_size.max(self.min_size))
+    }
+}
+
+/// Persistence backend for `auto_save_id` layouts
+pub trait LayoutStorage: Send + Sync {
+    fn get_item(&self, key: &str) -> Option<String>;
+    fn set_item(&self, key: &str, value: &str);
+}
+
+/// Stores layouts in the browser's `localStorage`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalStorage;
+
+impl LayoutStorage for LocalStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        web_sys::window()?.local_storage().ok()??.get_item(key).ok()?
+    }
+
+    fn set_item(&self, key: &str, value: &str) {
+        if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+            let _ = storage.set_item(key, value);
+        }
+    }
+}
+
+/// In-memory storage, useful for tests and server rendering
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    items: Mutex<HashMap<String, String>>,
+}
+
+impl LayoutStorage for MemoryStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.items.lock().ok()?.get(key).cloned()
+    }
+
+    fn set_item(&self, key: &str, value: &str) {
+        if let Ok(mut items) = self.items.lock() {
+            items.insert(key.to_string(), value.to_string());
+        }
+    }
+}
+
+/// Storage key used for a group's `auto_save_id`
+pub fn layout_storage_key(auto_save_id: &str) -> String {
+    format!("leptos-resizable:{}", auto_save_id)
+}
+
+/// Imperative handle to a panel group, handed out through `set_api`
+#[derive(Clone, Copy)]
+pub struct PanelGroupApi {
+    /// Current panel sizes, in percent
+    pub layout: Signal<Vec<f64>>,
+    /// Indices of the collapsed panels
+    pub collapsed_panels: Signal<Vec<usize>>,
+    /// Applies a layout, clamped to the panel constraints
+    pub set_layout: Callback<Vec<f64>>,
+    pub collapse: Callback<usize>,
+    /// Expands a collapsed panel to its size before collapsing
+    pub expand: Callback<usize>,
+    /// Resizes one panel, taking space from its neighbours
+    pub resize: Callback<(usize, f64)>,
+}
+
+/// Signals backing a panel group's layout
+#[derive(Clone, Copy)]
+struct LayoutStore {
+    state: RwSignal<ResizableState>,
+    panels: RwSignal<Vec<PanelConstraints>>,
+    /// Last non-collapsed size of each panel, restored by `expand`
+    expanded_sizes: StoredValue<Vec<f64>>,
+}
+
+impl LayoutStore {
+    fn layout(&self) -> Vec<f64> {
+        self.state.with_untracked(|s| s.panel_sizes.clone())
+    }
+
+    fn set_layout(&self, layout: Vec<f64>) {
+        let panels = self.panels.get_untracked();
+        let layout = utils::validate_layout(&layout, &panels);
+        let collapsed: Vec<usize> = layout
+            .iter()
+            .zip(&panels)
+            .enumerate()
+            .filter(|(_, (size, panel))| utils::is_collapsed(**size, panel))
+            .map(|(index, _)| index)
+            .collect();
+
+        self.expanded_sizes.update_value(|sizes| {
+            sizes.resize(layout.len(), 0.0);
+            for (index, size) in layout.iter().enumerate() {
+                if !collapsed.contains(&index) {
+                    sizes[index] = *size;
+                }
+            }
+        });
+        self.state.update(|s| {
+            s.panel_sizes = layout;
+            s.collapsed_panels = collapsed;
+        });
+    }
+
+    fn apply(&self, change: impl FnOnce(&[f64], &[PanelConstraints]) -> Vec<f64>) {
+        let layout = self.layout();
+        let next = self.panels.with_untracked(|panels| change(&layout, panels));
+        if next != layout {
+            self.set_layout(next);
+        }
+    }
+
+    fn collapse(&self, index: usize) {
+        self.apply(|layout, panels| utils::collapse_panel(layout, panels, index));
+    }
+
+    fn expand(&self, index: usize) {
+        let previous = self.expanded_sizes.with_value(|sizes| sizes.get(index).copied());
+        self.apply(|layout, panels| utils::expand_panel(layout, panels, index, previous));
+    }
+
+    /// Carries sizes over by panel id after panels were added, removed or
+    /// reordered. Panels new to the layout start at their default size, or
+    /// collapsed when `collapsed` says so.
+    fn remap(&self, previous_ids: &[usize], ids: &[usize], collapsed: &HashSet<usize>) {
+        let layout = self.layout();
+        if layout.is_empty() || layout.len() != previous_ids.len() {
+            return;
+        }
+        let panels = self.panels.get_untracked();
+        let sizes: HashMap<usize, f64> = previous_ids.iter().copied().zip(layout).collect();
+        let next = ids
+            .iter()
+            .zip(&panels)
+            .map(|(id, panel)| match sizes.get(id) {
+                Some(size) => *size,
+                None if panel.collapsible && collapsed.contains(id) => panel.collapsed_size,
+                None => panel.default_size.unwrap_or(panel.min_size),
+            })
+            .collect::<Vec<_>>();
+
+        let expanded: HashMap<usize, f64> = self
+            .expanded_sizes
+            .with_value(|sizes| previous_ids.iter().copied().zip(sizes.iter().copied()).collect());
+        self.expanded_sizes.set_value(
+            ids.iter()
+                .zip(&next)
+                .map(|(id, size)| expanded.get(id).copied().unwrap_or(*size))
+                .collect(),
+        );
+        self.set_layout(next);
+    }
+
+    fn toggle_collapse(&self, index: usize) {
+        if self.state.with_untracked(|s| s.collapsed_panels.contains(&index)) {
+            self.expand(index);
+        } else {
+            self.collapse(index);
+        }
+    }
+}
+
+/// A panel or handle registered with a group
+#[derive(Clone, Copy)]
+struct GroupItem {
+    id: usize,
+    /// Constraints of a panel, `None` for a handle
+    panel: Option<PanelConstraints>,
+    node_ref: NodeRef<Div>,
+}
+
 /// Resizable context for managing state across components
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct ResizableContext {
     pub state: RwSignal<ResizableState>,
     pub config: RwSignal<ResizableConfig>,
+    /// Constraints of the registered panels, in document order
+    pub panels: RwSignal<Vec<PanelConstraints>>,
+    /// Ids of the registered panels, matching `panels`
+    pub panel_ids: RwSignal<Vec<usize>>,
+    /// Group element, measured to turn pointer movement into percentages
+    pub group_ref: NodeRef<Div>,
     pub update_size: Callback<(usize, f64)>,
     pub toggle_collapse: Callback<usize>,
     pub start_resize: Callback<()>,
     pub end_resize: Callback<()>,
+    store: LayoutStore,
+    /// Panels and handles, in document order
+    items: RwSignal<Vec<GroupItem>>,
+    /// Panels registered as initially collapsed
+    collapsed_ids: StoredValue<HashSet<usize>>,
+    next_id: StoredValue<usize>,
 }
 
 impl ResizableContext {
     pub fn new() -> Self {
         let state = RwSignal::new(ResizableState::default());
         let config = RwSignal::new(ResizableConfig::default());
-
-        let update_size = {
-            let state = state.clone();
-            Callback::new(move |(panel_index, size): (usize, f64)| {
-                state.update(|s| {
-                    if panel_index < s.panel_sizes.len() {
-                        s.panel_sizes[panel_index] = size;
-                    }
-                });
-            })
+        let panels = RwSignal::new(Vec::new());
+        let store = LayoutStore {
+            state,
+            panels,
+            expanded_sizes: StoredValue::new(Vec::new()),
         };
 
-        let toggle_collapse = {
-            let state = state.clone();
-            Callback::new(move |panel_index: usize| {
-                state.update(|s| {
-                    if s.collapsed_panels.contains(&panel_index) {
-                        s.collapsed_panels.retain(|&i| i != panel_index);
-                    } else {
-                        s.collapsed_panels.push(panel_index);
-                    }
-                });
-            })
-        };
+        let update_size = Callback::new(move |(panel_index, size): (usize, f64)| {
+            store.apply(|layout, panels| utils::resize_panel(layout, panels, panel_index, size));
+        });
 
-        let start_resize = {
-            let state = state.clone();
-            Callback::new(move |_| {
-                state.update(|s| s.is_resizing = true);
-            })
-        };
+        let toggle_collapse = Callback::new(move |panel_index: usize| store.toggle_collapse(panel_index));
 
-        let end_resize = {
-            let state = state.clone();
-            Callback::new(move |_| {
-                state.update(|s| s.is_resizing = false);
-            })
-        };
+        let start_resize = Callback::new(move |_| {
+            state.update(|s| s.is_resizing = true);
+        });
+
+        let end_resize = Callback::new(move |_| {
+            state.update(|s| s.is_resizing = false);
+        });
 
         Self {
             state,
             config,
+            panels,
+            group_ref: NodeRef::new(),
             update_size,
             toggle_collapse,
             start_resize,
             end_resize,
+            store,
+            panel_ids: RwSignal::new(Vec::new()),
+            items: RwSignal::new(Vec::new()),
+            collapsed_ids: StoredValue::new(HashSet::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    /// Adds a panel and returns its id. `node_ref` should be on the panel
+    /// element, so panels rendered later still take their place in the layout.
+    pub fn register_panel(&self, constraints: PanelConstraints, collapsed: bool, node_ref: NodeRef<Div>) -> usize {
+        let id = self.register(Some(constraints), node_ref);
+        if collapsed && constraints.collapsible {
+            self.collapsed_ids.update_value(|ids| {
+                ids.insert(id);
+            });
+        }
+        self.sync_order();
+        id
+    }
+
+    /// Adds a handle and returns its id
+    pub fn register_handle(&self, node_ref: NodeRef<Div>) -> usize {
+        let id = self.register(None, node_ref);
+        self.sync_order();
+        id
+    }
+
+    fn register(&self, panel: Option<PanelConstraints>, node_ref: NodeRef<Div>) -> usize {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.items.update(|items| items.push(GroupItem { id, panel, node_ref }));
+        id
+    }
+
+    /// Removes a panel or handle, keeping the sizes of the remaining panels
+    pub fn unregister(&self, id: usize) {
+        // The group may be gone already when its children clean up
+        if self.items.try_update(|items| items.retain(|item| item.id != id)).is_none() {
+            return;
+        }
+        self.collapsed_ids.update_value(|ids| {
+            ids.remove(&id);
+        });
+        self.sync_order();
+    }
+
+    /// Sorts panels and handles into document order once they are all
+    /// mounted, and into registration order before that
+    pub fn sync_order(&self) {
+        let mut items = self.items.get_untracked();
+        let elements: Option<Vec<_>> = items.iter().map(|item| item.node_ref.get_untracked()).collect();
+        match elements {
+            Some(elements) => {
+                let mut ordered: Vec<_> = items.into_iter().zip(elements).collect();
+                ordered.sort_by(|(_, a), (_, b)| utils::document_order(a, b));
+                items = ordered.into_iter().map(|(item, _)| item).collect();
+            }
+            None => items.sort_by_key(|item| item.id),
+        }
+
+        let order: Vec<usize> = items.iter().map(|item| item.id).collect();
+        if self.items.with_untracked(|current| !current.iter().map(|item| item.id).eq(order.iter().copied())) {
+            self.items.set(items.clone());
+        }
+
+        let ids: Vec<usize> = items.iter().filter(|item| item.panel.is_some()).map(|item| item.id).collect();
+        let previous_ids = self.panel_ids.get_untracked();
+        if ids == previous_ids {
+            return;
+        }
+        self.panel_ids.set(ids.clone());
+        self.panels.set(items.iter().filter_map(|item| item.panel).collect());
+        self.collapsed_ids
+            .with_value(|collapsed| self.store.remap(&previous_ids, &ids, collapsed));
+    }
+
+    /// Current index of panel `id`
+    pub fn panel_index(&self, id: usize) -> Option<usize> {
+        self.panel_ids.with(|ids| ids.iter().position(|panel_id| *panel_id == id))
+    }
+
+    /// Current index of handle `id`; handle `index` sits between panel
+    /// `index` and panel `index + 1`
+    pub fn handle_index(&self, id: usize) -> Option<usize> {
+        self.items.with(|items| {
+            let position = items.iter().position(|item| item.id == id)?;
+            let panels_before = items[..position].iter().filter(|item| item.panel.is_some()).count();
+            panels_before.checked_sub(1)
+        })
+    }
+
+    /// Whether each panel, in order, was registered as initially collapsed
+    pub fn initially_collapsed(&self) -> Vec<bool> {
+        let ids = self.panel_ids.get_untracked();
+        self.collapsed_ids
+            .with_value(|collapsed| ids.iter().map(|id| collapsed.contains(id)).collect())
+    }
+
+    pub fn direction(&self) -> ResizeDirection {
+        self.state.with(|s| s.resize_direction)
+    }
+
+    pub fn layout(&self) -> Vec<f64> {
+        self.store.layout()
+    }
+
+    pub fn set_layout(&self, layout: Vec<f64>) {
+        self.store.set_layout(layout);
+    }
+
+    /// Current size of a panel, `None` until the group has laid itself out
+    pub fn panel_size(&self, index: usize) -> Option<f64> {
+        self.state.with(|s| s.panel_sizes.get(index).copied())
+    }
+
+    pub fn is_panel_collapsed(&self, index: usize) -> bool {
+        self.state.with(|s| s.collapsed_panels.contains(&index))
+    }
+
+    pub fn collapse(&self, index: usize) {
+        self.store.collapse(index);
+    }
+
+    pub fn expand(&self, index: usize) {
+        self.store.expand(index);
+    }
+
+    /// Moves handle `handle_index` by `delta` percent
+    pub fn resize_handle(&self, handle_index: usize, delta: f64) {
+        self.store.apply(|layout, panels| utils::resize_by_delta(layout, panels, handle_index, delta));
+    }
+
+    /// Size of the group element along the resize direction, in pixels
+    pub fn group_size(&self) -> Option<f64> {
+        let rect = self.group_ref.get_untracked()?.get_bounding_client_rect();
+        let size = match self.state.with_untracked(|s| s.resize_direction) {
+            ResizeDirection::Horizontal => rect.width(),
+            ResizeDirection::Vertical => rect.height(),
+        };
+        (size > 0.0).then_some(size)
+    }
+
+    pub fn api(&self) -> PanelGroupApi {
+        let context = *self;
+        PanelGroupApi {
+            layout: Signal::derive(move || context.state.with(|s| s.panel_sizes.clone())),
+            collapsed_panels: Signal::derive(move || context.state.with(|s| s.collapsed_panels.clone())),
+            set_layout: Callback::new(move |layout: Vec<f64>| context.set_layout(layout)),
+            collapse: Callback::new(move |index: usize| context.collapse(index)),
+            expand: Callback::new(move |index: usize| context.expand(index)),
+            resize: context.update_size,
         }
     }
 }
 
+impl Default for ResizableContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Hook for using resizable context
 pub fn use_resizable_context() -> ResizableContext {
     expect_context::<ResizableContext>()
 }
 
+/// Storage backend shared between a group and its owner
+pub type SharedLayoutStorage = Arc<dyn LayoutStorage>;
+
 /// Utility functions for resizable panels
 pub mod utils {
     use super::*;
@@ -212,4 +585,402 @@ pub mod utils {
             }
         }
     }
+
+    /// Tolerance for comparing floating point sizes
+    const EPSILON: f64 = 1e-6;
+
+    /// Percent moved per arrow key press on a focused handle
+    pub const KEYBOARD_RESIZE_STEP: f64 = 10.0;
+
+    pub fn is_collapsed(size: f64, panel: &PanelConstraints) -> bool {
+        panel.collapsible && size <= panel.collapsed_size + EPSILON
+    }
+
+    /// Layout from the panels' default sizes. Panels without a default share
+    /// the remaining space; `collapsed` marks panels that start collapsed.
+    pub fn initial_layout(panels: &[PanelConstraints], collapsed: &[bool]) -> Vec<f64> {
+        let mut layout = vec![0.0; panels.len()];
+        let mut unsized_panels = Vec::new();
+        let mut used = 0.0;
+
+        for (index, panel) in panels.iter().enumerate() {
+            if panel.collapsible && collapsed.get(index).copied().unwrap_or(false) {
+                layout[index] = panel.collapsed_size;
+            } else if let Some(size) = panel.default_size {
+                layout[index] = size;
+            } else {
+                unsized_panels.push(index);
+                continue;
+            }
+            used += layout[index];
+        }
+
+        if !unsized_panels.is_empty() {
+            let share = ((100.0 - used) / unsized_panels.len() as f64).max(0.0);
+            for index in unsized_panels {
+                layout[index] = share;
+            }
+        }
+
+        validate_layout(&layout, panels)
+    }
+
+    /// Clamps every panel into its constraints and makes the sizes add up to
+    /// 100, growing or shrinking panels in order. A layout for a different
+    /// number of panels is replaced by the initial layout.
+    pub fn validate_layout(layout: &[f64], panels: &[PanelConstraints]) -> Vec<f64> {
+        if layout.len() != panels.len() || layout.iter().any(|size| !size.is_finite()) {
+            return initial_layout(panels, &[]);
+        }
+
+        let mut next: Vec<f64> = layout
+            .iter()
+            .zip(panels)
+            .map(|(size, panel)| panel.clamp(*size))
+            .collect();
+        let remainder = 100.0 - calculate_total_size(&next);
+        if remainder > EPSILON {
+            grow(&mut next, panels, 0..panels.len(), remainder);
+        } else if remainder < -EPSILON {
+            shrink(&mut next, panels, 0..panels.len(), -remainder);
+        }
+        next
+    }
+
+    /// Grows panels in `order` up to their maximum. Returns what is left over.
+    fn grow(layout: &mut [f64], panels: &[PanelConstraints], order: impl IntoIterator<Item = usize>, amount: f64) -> f64 {
+        let mut remaining = amount;
+        for index in order {
+            if remaining <= EPSILON {
+                break;
+            }
+            if is_collapsed(layout[index], &panels[index]) {
+                continue;
+            }
+            let taken = (panels[index].max_size - layout[index]).clamp(0.0, remaining);
+            layout[index] += taken;
+            remaining -= taken;
+        }
+        remaining
+    }
+
+    /// Shrinks panels in `order` down to their minimum. Returns what is left over.
+    fn shrink(layout: &mut [f64], panels: &[PanelConstraints], order: impl IntoIterator<Item = usize>, amount: f64) -> f64 {
+        let mut remaining = amount;
+        for index in order {
+            if remaining <= EPSILON {
+                break;
+            }
+            if is_collapsed(layout[index], &panels[index]) {
+                continue;
+            }
+            let taken = (layout[index] - panels[index].min_size).clamp(0.0, remaining);
+            layout[index] -= taken;
+            remaining -= taken;
+        }
+        remaining
+    }
+
+    /// Moves the handle between panel `handle_index` and the next one by
+    /// `delta` percent.
+    ///
+    /// The panel on the growing side takes the space; panels on the other side
+    /// shrink one after another, nearest first, down to their minimum. The
+    /// panel next to the handle collapses once dragged past half of its
+    /// minimum size, and a collapsed panel re-opens the same way.
+    pub fn resize_by_delta(layout: &[f64], panels: &[PanelConstraints], handle_index: usize, delta: f64) -> Vec<f64> {
+        let count = layout.len();
+        if handle_index + 1 >= count || panels.len() != count || delta.abs() < EPSILON {
+            return layout.to_vec();
+        }
+
+        let (grow_index, shrink_order): (usize, Vec<usize>) = if delta > 0.0 {
+            (handle_index, (handle_index + 1..count).collect())
+        } else {
+            (handle_index + 1, (0..=handle_index).rev().collect())
+        };
+        let grow_panel = &panels[grow_index];
+        let was_collapsed = is_collapsed(layout[grow_index], grow_panel);
+
+        let mut requested = delta.abs();
+        if was_collapsed {
+            let needed = grow_panel.min_size - layout[grow_index];
+            if requested < needed / 2.0 {
+                return layout.to_vec();
+            }
+            requested = requested.max(needed);
+        }
+        requested = requested.min(grow_panel.max_size - layout[grow_index]);
+        if requested <= EPSILON {
+            return layout.to_vec();
+        }
+
+        let mut next = layout.to_vec();
+        let mut freed = 0.0;
+        for (position, &index) in shrink_order.iter().enumerate() {
+            let remaining = requested - freed;
+            if remaining <= EPSILON {
+                break;
+            }
+            let panel = &panels[index];
+            let size = next[index];
+            if is_collapsed(size, panel) {
+                continue;
+            }
+
+            let available = (size - panel.min_size).max(0.0);
+            if available >= remaining {
+                next[index] -= remaining;
+                freed += remaining;
+                break;
+            }
+
+            let collapse_gain = size - panel.collapsed_size;
+            let can_collapse = position == 0
+                && panel.collapsible
+                && remaining - available >= (panel.min_size - panel.collapsed_size) / 2.0
+                && layout[grow_index] + freed + collapse_gain <= grow_panel.max_size + EPSILON;
+            if can_collapse {
+                next[index] = panel.collapsed_size;
+                freed += collapse_gain;
+            } else {
+                next[index] -= available;
+                freed += available;
+            }
+        }
+
+        if was_collapsed && layout[grow_index] + freed < grow_panel.min_size - EPSILON {
+            return layout.to_vec();
+        }
+        next[grow_index] += freed;
+        next
+    }
+
+    /// Resizes panel `index` to `size`, moving the handle after it (or before
+    /// it, for the last panel)
+    pub fn resize_panel(layout: &[f64], panels: &[PanelConstraints], index: usize, size: f64) -> Vec<f64> {
+        if index >= layout.len() || layout.len() < 2 {
+            return layout.to_vec();
+        }
+        let delta = size - layout[index];
+        if index + 1 < layout.len() {
+            resize_by_delta(layout, panels, index, delta)
+        } else {
+            resize_by_delta(layout, panels, index - 1, -delta)
+        }
+    }
+
+    /// Collapses panel `index`, giving its space to the following panels and
+    /// then the preceding ones. Unchanged when nothing can take the space.
+    pub fn collapse_panel(layout: &[f64], panels: &[PanelConstraints], index: usize) -> Vec<f64> {
+        let Some(panel) = panels.get(index) else {
+            return layout.to_vec();
+        };
+        if layout.len() != panels.len() || !panel.collapsible || is_collapsed(layout[index], panel) {
+            return layout.to_vec();
+        }
+
+        let mut next = layout.to_vec();
+        let freed = next[index] - panel.collapsed_size;
+        next[index] = panel.collapsed_size;
+        let order = (index + 1..layout.len()).chain((0..index).rev());
+        if grow(&mut next, panels, order, freed) > EPSILON {
+            return layout.to_vec();
+        }
+        next
+    }
+
+    /// Expands collapsed panel `index` to `size` (or its minimum), taking the
+    /// space from the following panels and then the preceding ones
+    pub fn expand_panel(layout: &[f64], panels: &[PanelConstraints], index: usize, size: Option<f64>) -> Vec<f64> {
+        let Some(panel) = panels.get(index) else {
+            return layout.to_vec();
+        };
+        if layout.len() != panels.len() || !is_collapsed(layout[index], panel) {
+            return layout.to_vec();
+        }
+
+        let target = size
+            .filter(|size| *size > panel.collapsed_size)
+            .unwrap_or(panel.min_size)
+            .clamp(panel.min_size, panel.max_size.max(panel.min_size));
+        let needed = target - layout[index];
+        let mut next = layout.to_vec();
+        let order = (index + 1..layout.len()).chain((0..index).rev());
+        let taken = needed - shrink(&mut next, panels, order, needed);
+        if layout[index] + taken < panel.min_size - EPSILON {
+            return layout.to_vec();
+        }
+        next[index] += taken;
+        next
+    }
+
+    /// Handle movement for a key press, in percent
+    pub fn keyboard_delta(key: &str, direction: ResizeDirection) -> Option<f64> {
+        match (key, direction) {
+            ("ArrowLeft", ResizeDirection::Horizontal) | ("ArrowUp", ResizeDirection::Vertical) => Some(-KEYBOARD_RESIZE_STEP),
+            ("ArrowRight", ResizeDirection::Horizontal) | ("ArrowDown", ResizeDirection::Vertical) => Some(KEYBOARD_RESIZE_STEP),
+            ("Home", _) => Some(-100.0),
+            ("End", _) => Some(100.0),
+            _ => None,
+        }
+    }
+
+    /// Compares two elements by their position in the document
+    pub fn document_order(a: &web_sys::Node, b: &web_sys::Node) -> Ordering {
+        let position = a.compare_document_position(b);
+        if position & web_sys::Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+            Ordering::Less
+        } else if position & web_sys::Node::DOCUMENT_POSITION_PRECEDING != 0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Serializes a layout for `LayoutStorage`
+    pub fn serialize_layout(layout: &[f64]) -> String {
+        layout.iter().map(|size| size.to_string()).collect::<Vec<_>>().join(",")
+    }
+
+    pub fn parse_layout(text: &str) -> Option<Vec<f64>> {
+        text.split(',')
+            .map(|size| size.trim().parse::<f64>().ok().filter(|size| size.is_finite()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::utils::*;
+    use super::*;
+
+    fn panel(min_size: f64, max_size: f64) -> PanelConstraints {
+        PanelConstraints {
+            min_size,
+            max_size,
+            ..PanelConstraints::default()
+        }
+    }
+
+    fn collapsible(min_size: f64) -> PanelConstraints {
+        PanelConstraints {
+            min_size,
+            collapsible: true,
+            ..PanelConstraints::default()
+        }
+    }
+
+    fn assert_layout(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "{:?} != {:?}", actual, expected);
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_initial_layout_shares_remaining_space() {
+        let panels = [
+            PanelConstraints { default_size: Some(20.0), ..panel(10.0, 90.0) },
+            panel(10.0, 90.0),
+            panel(10.0, 90.0),
+        ];
+        assert_layout(&initial_layout(&panels, &[]), &[20.0, 40.0, 40.0]);
+
+        let panels = [collapsible(20.0), panel(10.0, 100.0)];
+        assert_layout(&initial_layout(&panels, &[true]), &[0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_validate_layout_clamps_and_normalizes() {
+        let panels = [panel(10.0, 60.0), panel(10.0, 90.0)];
+        assert_layout(&validate_layout(&[80.0, 20.0], &panels), &[60.0, 40.0]);
+        assert_layout(&validate_layout(&[30.0, 30.0], &panels), &[60.0, 40.0]);
+        // Saved layouts for another panel count are ignored
+        assert_layout(&validate_layout(&[100.0], &panels), &[50.0, 50.0]);
+    }
+
+    #[test]
+    fn test_resize_cascades_across_panels() {
+        let panels = [panel(10.0, 90.0), panel(10.0, 90.0), panel(10.0, 90.0)];
+        let layout = [30.0, 30.0, 40.0];
+        assert_layout(&resize_by_delta(&layout, &panels, 0, 10.0), &[40.0, 20.0, 40.0]);
+        // The middle panel stops at its minimum, the last one gives the rest
+        assert_layout(&resize_by_delta(&layout, &panels, 0, 40.0), &[70.0, 10.0, 20.0]);
+        // The growing panel stops at its maximum
+        assert_layout(&resize_by_delta(&layout, &panels, 1, -80.0), &[10.0, 10.0, 80.0]);
+    }
+
+    #[test]
+    fn test_drag_collapses_and_reopens() {
+        let panels = [collapsible(20.0), panel(10.0, 100.0)];
+        let layout = [30.0, 70.0];
+        // Not past half of the minimum: stops at the minimum
+        assert_layout(&resize_by_delta(&layout, &panels, 0, -15.0), &[20.0, 80.0]);
+        assert_layout(&resize_by_delta(&layout, &panels, 0, -21.0), &[0.0, 100.0]);
+
+        let collapsed = [0.0, 100.0];
+        assert_layout(&resize_by_delta(&collapsed, &panels, 0, 5.0), &collapsed);
+        assert_layout(&resize_by_delta(&collapsed, &panels, 0, 12.0), &[20.0, 80.0]);
+    }
+
+    #[test]
+    fn test_collapse_and_expand() {
+        let panels = [collapsible(20.0), panel(10.0, 100.0), panel(10.0, 100.0)];
+        let layout = [30.0, 30.0, 40.0];
+        let collapsed = collapse_panel(&layout, &panels, 0);
+        assert_layout(&collapsed, &[0.0, 60.0, 40.0]);
+        assert_layout(&expand_panel(&collapsed, &panels, 0, Some(30.0)), &[30.0, 30.0, 40.0]);
+        assert_layout(&expand_panel(&collapsed, &panels, 0, None), &[20.0, 40.0, 40.0]);
+        // Non-collapsible panels are left alone
+        assert_layout(&collapse_panel(&layout, &panels, 1), &layout);
+    }
+
+    #[test]
+    fn test_resize_panel_and_keyboard() {
+        let panels = [panel(10.0, 90.0), panel(10.0, 90.0)];
+        assert_layout(&resize_panel(&[50.0, 50.0], &panels, 1, 70.0), &[30.0, 70.0]);
+        assert_eq!(keyboard_delta("ArrowLeft", ResizeDirection::Horizontal), Some(-KEYBOARD_RESIZE_STEP));
+        assert_eq!(keyboard_delta("ArrowDown", ResizeDirection::Vertical), Some(KEYBOARD_RESIZE_STEP));
+        assert_eq!(keyboard_delta("ArrowDown", ResizeDirection::Horizontal), None);
+    }
+
+    #[test]
+    fn test_panels_unregister_and_keep_sizes() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let context = ResizableContext::new();
+            let first = context.register_panel(panel(10.0, 90.0), false, NodeRef::new());
+            let first_handle = context.register_handle(NodeRef::new());
+            let second = context.register_panel(panel(10.0, 90.0), false, NodeRef::new());
+            let second_handle = context.register_handle(NodeRef::new());
+            let third = context.register_panel(panel(10.0, 90.0), false, NodeRef::new());
+            assert_eq!(context.handle_index(first_handle), Some(0));
+            assert_eq!(context.handle_index(second_handle), Some(1));
+
+            context.set_layout(vec![20.0, 30.0, 50.0]);
+            context.unregister(second);
+            context.unregister(first_handle);
+            assert_eq!(context.panel_index(third), Some(1));
+            assert_eq!(context.handle_index(second_handle), Some(0));
+            assert_layout(&context.layout(), &[50.0, 50.0]);
+
+            // A panel rendered later starts at its default size
+            let late = PanelConstraints { default_size: Some(20.0), ..panel(10.0, 90.0) };
+            let fourth = context.register_panel(late, false, NodeRef::new());
+            assert_eq!(context.panel_index(fourth), Some(2));
+            assert_eq!(context.panel_index(first), Some(0));
+            assert_layout(&context.layout(), &[30.0, 50.0, 20.0]);
+        });
+    }
+
+    #[test]
+    fn test_layout_storage_round_trip() {
+        let storage = MemoryStorage::default();
+        let key = layout_storage_key("sidebar");
+        storage.set_item(&key, &serialize_layout(&[25.5, 74.5]));
+        assert_eq!(storage.get_item(&key).and_then(|text| parse_layout(&text)), Some(vec![25.5, 74.5]));
+        assert_eq!(parse_layout("25,abc"), None);
+        assert_eq!(storage.get_item("missing"), None);
+    }
 }
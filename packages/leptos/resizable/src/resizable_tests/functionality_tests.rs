@@ -4,17 +4,23 @@
 //! and interactive features for the Resizable component.
 
 use leptos::prelude::*;
-use crate::default::{ResizableHandle, ResizablePanel, ResizablePanelGroup};
+use crate::default::{ResizableHandle, ResizablePanel, ResizablePanelGroup, ResizeDirection};
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_horizontal_resizing() {
+        init_executor();
         // Test horizontal resizing
         let resizable_view = view! {
-            <ResizablePanelGroup direction="horizontal">
+            <ResizablePanelGroup direction=ResizeDirection::Horizontal>
                 <ResizablePanel>
                     "Horizontal panel 1"
                 </ResizablePanel>
@@ -31,9 +37,10 @@ mod tests {
 
     #[test]
     fn test_vertical_resizing() {
+        init_executor();
         // Test vertical resizing
         let resizable_view = view! {
-            <ResizablePanelGroup direction="vertical">
+            <ResizablePanelGroup direction=ResizeDirection::Vertical>
                 <ResizablePanel>
                     "Vertical panel 1"
                 </ResizablePanel>
@@ -50,6 +57,7 @@ mod tests {
 
     #[test]
     fn test_collapsible_panels() {
+        init_executor();
         // Test collapsible panels
         let (is_collapsed, set_is_collapsed) = create_signal(false);
         
@@ -71,11 +79,12 @@ mod tests {
 
     #[test]
     fn test_nested_resizable_panels() {
+        init_executor();
         // Test nested resizable panels
         let resizable_view = view! {
             <ResizablePanelGroup>
                 <ResizablePanel>
-                    <ResizablePanelGroup direction="vertical">
+                    <ResizablePanelGroup direction=ResizeDirection::Vertical>
                         <ResizablePanel>
                             "Nested panel 1"
                         </ResizablePanel>
@@ -98,6 +107,7 @@ mod tests {
 
     #[test]
     fn test_resize_handle() {
+        init_executor();
         // Test resize handle
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -117,6 +127,7 @@ mod tests {
 
     #[test]
     fn test_keyboard_navigation() {
+        init_executor();
         // Test keyboard navigation
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -136,6 +147,7 @@ mod tests {
 
     #[test]
     fn test_accessibility_features() {
+        init_executor();
         // Test accessibility features
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -155,6 +167,7 @@ mod tests {
 
     #[test]
     fn test_touch_support() {
+        init_executor();
         // Test touch support
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -174,10 +187,11 @@ mod tests {
 
     #[test]
     fn test_size_constraints() {
+        init_executor();
         // Test size constraints
         let resizable_view = view! {
             <ResizablePanelGroup>
-                <ResizablePanel min_size=20 max_size=80>
+                <ResizablePanel min_size=20.0 max_size=80.0>
                     "Constrained panel"
                 </ResizablePanel>
                 <ResizableHandle />
@@ -193,6 +207,7 @@ mod tests {
 
     #[test]
     fn test_resize_events() {
+        init_executor();
         // Test resize events
         let (resize_count, set_resize_count) = create_signal(0);
         
@@ -214,6 +229,7 @@ mod tests {
 
     #[test]
     fn test_panel_sizing() {
+        init_executor();
         // Test panel sizing
         let (panel_size, set_panel_size) = create_signal(50.0);
         
@@ -235,6 +251,7 @@ mod tests {
 
     #[test]
     fn test_panel_ordering() {
+        init_executor();
         // Test panel ordering
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -258,6 +275,7 @@ mod tests {
 
     #[test]
     fn test_panel_visibility() {
+        init_executor();
         // Test panel visibility
         let (is_visible, set_is_visible) = create_signal(true);
         
@@ -279,6 +297,7 @@ mod tests {
 
     #[test]
     fn test_panel_focus() {
+        init_executor();
         // Test panel focus
         let (focused_panel, set_focused_panel) = create_signal(0);
         
@@ -300,6 +319,7 @@ mod tests {
 
     #[test]
     fn test_panel_selection() {
+        init_executor();
         // Test panel selection
         let (selected_panel, set_selected_panel) = create_signal(0);
         
@@ -321,6 +341,7 @@ mod tests {
 
     #[test]
     fn test_panel_state_management() {
+        init_executor();
         // Test panel state management
         let (panel_state, set_panel_state) = create_signal("initialized".to_string());
         
@@ -342,6 +363,7 @@ mod tests {
 
     #[test]
     fn test_panel_data_binding() {
+        init_executor();
         // Test panel data binding
         let (panel_data, set_panel_data) = create_signal("data".to_string());
         
@@ -363,6 +385,7 @@ mod tests {
 
     #[test]
     fn test_panel_validation() {
+        init_executor();
         // Test panel validation
         let (is_valid, set_is_valid) = create_signal(true);
         
@@ -384,6 +407,7 @@ mod tests {
 
     #[test]
     fn test_panel_error_handling() {
+        init_executor();
         // Test panel error handling
         let (has_error, set_has_error) = create_signal(false);
         
@@ -405,6 +429,7 @@ mod tests {
 
     #[test]
     fn test_panel_loading_states() {
+        init_executor();
         // Test panel loading states
         let (is_loading, set_is_loading) = create_signal(false);
         
@@ -426,6 +451,7 @@ mod tests {
 
     #[test]
     fn test_panel_async_operations() {
+        init_executor();
         // Test panel async operations
         let (operation_status, set_operation_status) = create_signal("idle".to_string());
         
@@ -447,6 +473,7 @@ mod tests {
 
     #[test]
     fn test_panel_caching() {
+        init_executor();
         // Test panel caching
         let (cache_status, set_cache_status) = create_signal("empty".to_string());
         
@@ -468,6 +495,7 @@ mod tests {
 
     #[test]
     fn test_panel_persistence() {
+        init_executor();
         // Test panel persistence
         let (persistent_data, set_persistent_data) = create_signal("persistent".to_string());
         
@@ -489,6 +517,7 @@ mod tests {
 
     #[test]
     fn test_panel_synchronization() {
+        init_executor();
         // Test panel synchronization
         let (sync_status, set_sync_status) = create_signal("synced".to_string());
         
@@ -510,6 +539,7 @@ mod tests {
 
     #[test]
     fn test_panel_coordination() {
+        init_executor();
         // Test panel coordination
         let (coordination_level, set_coordination_level) = create_signal(0);
         
@@ -531,6 +561,7 @@ mod tests {
 
     #[test]
     fn test_panel_communication() {
+        init_executor();
         // Test panel communication
         let (message_count, set_message_count) = create_signal(0);
         
@@ -552,6 +583,7 @@ mod tests {
 
     #[test]
     fn test_panel_integration() {
+        init_executor();
         // Test panel integration
         let (integration_status, set_integration_status) = create_signal("connected".to_string());
         
@@ -573,6 +605,7 @@ mod tests {
 
     #[test]
     fn test_panel_compatibility() {
+        init_executor();
         // Test panel compatibility
         let (compatibility_level, set_compatibility_level) = create_signal(100);
         
@@ -594,6 +627,7 @@ mod tests {
 
     #[test]
     fn test_panel_interoperability() {
+        init_executor();
         // Test panel interoperability
         let (interop_status, set_interop_status) = create_signal("enabled".to_string());
         
@@ -615,6 +649,7 @@ mod tests {
 
     #[test]
     fn test_panel_scalability() {
+        init_executor();
         // Test panel scalability
         let (scale_factor, set_scale_factor) = create_signal(1.0);
         
@@ -636,6 +671,7 @@ mod tests {
 
     #[test]
     fn test_panel_extensibility() {
+        init_executor();
         // Test panel extensibility
         let (extension_count, set_extension_count) = create_signal(0);
         
@@ -657,6 +693,7 @@ mod tests {
 
     #[test]
     fn test_panel_modularity() {
+        init_executor();
         // Test panel modularity
         let (module_count, set_module_count) = create_signal(1);
         
@@ -678,6 +715,7 @@ mod tests {
 
     #[test]
     fn test_panel_composability() {
+        init_executor();
         // Test panel composability
         let (component_count, set_component_count) = create_signal(1);
         
@@ -699,6 +737,7 @@ mod tests {
 
     #[test]
     fn test_panel_reusability() {
+        init_executor();
         // Test panel reusability
         let (reuse_count, set_reuse_count) = create_signal(0);
         
@@ -720,6 +759,7 @@ mod tests {
 
     #[test]
     fn test_panel_maintainability() {
+        init_executor();
         // Test panel maintainability
         let (maintenance_score, set_maintenance_score) = create_signal(100);
         
@@ -741,6 +781,7 @@ mod tests {
 
     #[test]
     fn test_panel_testability() {
+        init_executor();
         // Test panel testability
         let (test_coverage, set_test_coverage) = create_signal(100);
         
@@ -762,6 +803,7 @@ mod tests {
 
     #[test]
     fn test_panel_debuggability() {
+        init_executor();
         // Test panel debuggability
         let (debug_level, set_debug_level) = create_signal("info".to_string());
         
@@ -783,6 +825,7 @@ mod tests {
 
     #[test]
     fn test_panel_monitoring() {
+        init_executor();
         // Test panel monitoring
         let (monitoring_status, set_monitoring_status) = create_signal("active".to_string());
         
@@ -804,6 +847,7 @@ mod tests {
 
     #[test]
     fn test_panel_analytics() {
+        init_executor();
         // Test panel analytics
         let (analytics_data, set_analytics_data) = create_signal("collected".to_string());
         
@@ -825,6 +869,7 @@ mod tests {
 
     #[test]
     fn test_panel_reporting() {
+        init_executor();
         // Test panel reporting
         let (report_status, set_report_status) = create_signal("generated".to_string());
         
@@ -846,6 +891,7 @@ mod tests {
 
     #[test]
     fn test_panel_comprehensive_functionality() {
+        init_executor();
         // Test panel comprehensive functionality
         let (functionality_score, set_functionality_score) = create_signal(100);
         let (feature_count, set_feature_count) = create_signal(10);
@@ -10,8 +10,14 @@ use crate::default::{ResizableHandle, ResizablePanel, ResizablePanelGroup};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_resizable_panel_system_requirements() {
+        init_executor();
         // Test resizable panel system requirements
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -31,6 +37,7 @@ mod tests {
 
     #[test]
     fn test_resizable_basic_functionality() {
+        init_executor();
         // Test resizable basic functionality
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -46,6 +53,7 @@ mod tests {
 
     #[test]
     fn test_resizable_minimal_setup() {
+        init_executor();
         // Test resizable minimal setup
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -61,6 +69,7 @@ mod tests {
 
     #[test]
     fn test_resizable_default_props() {
+        init_executor();
         // Test resizable default props
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -76,6 +85,7 @@ mod tests {
 
     #[test]
     fn test_resizable_initialization() {
+        init_executor();
         // Test resizable initialization
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -91,6 +101,7 @@ mod tests {
 
     #[test]
     fn test_resizable_rendering() {
+        init_executor();
         // Test resizable rendering
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -106,6 +117,7 @@ mod tests {
 
     #[test]
     fn test_resizable_mounting() {
+        init_executor();
         // Test resizable mounting
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -121,6 +133,7 @@ mod tests {
 
     #[test]
     fn test_resizable_unmounting() {
+        init_executor();
         // Test resizable unmounting
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -136,6 +149,7 @@ mod tests {
 
     #[test]
     fn test_resizable_lifecycle() {
+        init_executor();
         // Test resizable lifecycle
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -151,6 +165,7 @@ mod tests {
 
     #[test]
     fn test_resizable_state_management() {
+        init_executor();
         // Test resizable state management
         let (panel_size, set_panel_size) = create_signal(50.0);
         
@@ -168,6 +183,7 @@ mod tests {
 
     #[test]
     fn test_resizable_event_handling() {
+        init_executor();
         // Test resizable event handling
         let (event_count, set_event_count) = create_signal(0);
         
@@ -185,6 +201,7 @@ mod tests {
 
     #[test]
     fn test_resizable_prop_handling() {
+        init_executor();
         // Test resizable prop handling
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -200,6 +217,7 @@ mod tests {
 
     #[test]
     fn test_resizable_validation() {
+        init_executor();
         // Test resizable validation
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -215,6 +233,7 @@ mod tests {
 
     #[test]
     fn test_resizable_error_handling() {
+        init_executor();
         // Test resizable error handling
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -230,6 +249,7 @@ mod tests {
 
     #[test]
     fn test_resizable_edge_cases() {
+        init_executor();
         // Test resizable edge cases
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -245,6 +265,7 @@ mod tests {
 
     #[test]
     fn test_resizable_boundary_conditions() {
+        init_executor();
         // Test resizable boundary conditions
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -260,6 +281,7 @@ mod tests {
 
     #[test]
     fn test_resizable_performance() {
+        init_executor();
         // Test resizable performance
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -275,6 +297,7 @@ mod tests {
 
     #[test]
     fn test_resizable_memory_usage() {
+        init_executor();
         // Test resizable memory usage
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -290,6 +313,7 @@ mod tests {
 
     #[test]
     fn test_resizable_cpu_usage() {
+        init_executor();
         // Test resizable CPU usage
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -305,6 +329,7 @@ mod tests {
 
     #[test]
     fn test_resizable_resource_management() {
+        init_executor();
         // Test resizable resource management
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -320,6 +345,7 @@ mod tests {
 
     #[test]
     fn test_resizable_cleanup() {
+        init_executor();
         // Test resizable cleanup
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -335,6 +361,7 @@ mod tests {
 
     #[test]
     fn test_resizable_garbage_collection() {
+        init_executor();
         // Test resizable garbage collection
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -350,6 +377,7 @@ mod tests {
 
     #[test]
     fn test_resizable_memory_leaks() {
+        init_executor();
         // Test resizable memory leaks
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -365,6 +393,7 @@ mod tests {
 
     #[test]
     fn test_resizable_thread_safety() {
+        init_executor();
         // Test resizable thread safety
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -380,6 +409,7 @@ mod tests {
 
     #[test]
     fn test_resizable_concurrency() {
+        init_executor();
         // Test resizable concurrency
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -395,6 +425,7 @@ mod tests {
 
     #[test]
     fn test_resizable_synchronization() {
+        init_executor();
         // Test resizable synchronization
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -410,6 +441,7 @@ mod tests {
 
     #[test]
     fn test_resizable_atomic_operations() {
+        init_executor();
         // Test resizable atomic operations
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -425,6 +457,7 @@ mod tests {
 
     #[test]
     fn test_resizable_lock_free_operations() {
+        init_executor();
         // Test resizable lock-free operations
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -440,6 +473,7 @@ mod tests {
 
     #[test]
     fn test_resizable_data_races() {
+        init_executor();
         // Test resizable data races
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -455,6 +489,7 @@ mod tests {
 
     #[test]
     fn test_resizable_deadlocks() {
+        init_executor();
         // Test resizable deadlocks
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -470,6 +505,7 @@ mod tests {
 
     #[test]
     fn test_resizable_livelocks() {
+        init_executor();
         // Test resizable livelocks
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -485,6 +521,7 @@ mod tests {
 
     #[test]
     fn test_resizable_race_conditions() {
+        init_executor();
         // Test resizable race conditions
         let resizable_view = view! {
             <ResizablePanelGroup>
@@ -500,6 +537,7 @@ mod tests {
 
     #[test]
     fn test_resizable_comprehensive_system_requirements() {
+        init_executor();
         // Test resizable comprehensive system requirements
         let (system_ready, set_system_ready) = create_signal(false);
         let (requirements_met, set_requirements_met) = create_signal(false);
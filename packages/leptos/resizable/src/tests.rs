@@ -5,8 +5,14 @@ mod tests {
         ResizablePanelGroup, ResizablePanel, ResizableHandle, ResizeDirection
     };
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_resizable_panel_group_creation() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             let _component = view! {
                 <ResizablePanelGroup>
@@ -28,6 +34,7 @@ mod tests {
 
     #[test]
     fn test_resizable_panel_creation() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             let _component = view! {
                 <ResizablePanel
@@ -63,6 +70,7 @@ mod tests {
 
     #[test]
     fn test_collapsible_panel() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             let _component = view! {
                 <ResizablePanel
@@ -83,6 +91,7 @@ mod tests {
 
     #[test]
     fn test_horizontal_direction() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             let _component = view! {
                 <ResizablePanelGroup
@@ -106,6 +115,7 @@ mod tests {
 
     #[test]
     fn test_vertical_direction() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             let _component = view! {
                 <ResizablePanelGroup
@@ -126,4 +136,31 @@ mod tests {
 
         assert!(test_result.is_ok(), "Vertical direction test failed");
     }
+
+    #[test]
+    fn test_new_york_style() {
+        use crate::new_york::{
+            ResizableHandle as ResizableHandleNewYork, ResizablePanel as ResizablePanelNewYork,
+            ResizablePanelGroup as ResizablePanelGroupNewYork,
+        };
+
+        init_executor();
+        let test_result = std::panic::catch_unwind(|| {
+            let _component = view! {
+                <ResizablePanelGroupNewYork direction=ResizeDirection::Vertical>
+                    <ResizablePanelNewYork default_size=30.0 collapsible=true>
+                        <div>"Top Panel"</div>
+                    </ResizablePanelNewYork>
+                    <ResizableHandleNewYork with_handle=true />
+                    <ResizablePanelNewYork>
+                        <div>"Bottom Panel"</div>
+                    </ResizablePanelNewYork>
+                </ResizablePanelGroupNewYork>
+            };
+
+            true
+        });
+
+        assert!(test_result.is_ok(), "New York style test failed");
+    }
 }
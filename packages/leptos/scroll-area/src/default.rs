@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const SCROLL_AREA_CLASS: &str = "rounded-lg border bg-card text-card-foreground shadow-sm";
 
@@ -11,7 +12,7 @@ pub fn ScrollArea(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("{} {}", SCROLL_AREA_CLASS, class.get().unwrap_or_default())
+        tw_merge!(SCROLL_AREA_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
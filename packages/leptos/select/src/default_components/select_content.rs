@@ -2,7 +2,7 @@ use leptos::{ev::MouseEvent, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
-use tailwind_fuse::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 // Select Content
 #[component]
@@ -15,9 +15,9 @@ pub fn SelectContent(
     let open_state = expect_context::<Signal<bool>>();
     let set_open = expect_context::<Callback<bool>>();
     
-    let content_class = format!(
-        "relative z-50 max-h-96 min-w-[8rem] overflow-hidden rounded-md border bg-popover text-popover-foreground shadow-md data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2 {}",
-        class.get().unwrap_or_default()
+    let content_class = tw_merge!(
+        "relative z-50 max-h-96 min-w-[8rem] overflow-hidden rounded-md border bg-popover text-popover-foreground shadow-md data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2",
+        class.get().unwrap_or_default(),
     );
 
     let handle_click_outside = move |_e: MouseEvent| {
@@ -51,9 +51,9 @@ pub fn SelectItem(
     let set_value = expect_context::<Callback<String>>();
     let set_open = expect_context::<Callback<bool>>();
     
-    let item_class = format!(
-        "relative flex w-full cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50 {}",
-        class.get().unwrap_or_default()
+    let item_class = tw_merge!(
+        "relative flex w-full cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50",
+        class.get().unwrap_or_default(),
     );
 
     let value_clone = value.clone();
@@ -87,9 +87,9 @@ pub fn SelectSeparator(
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
 ) -> impl IntoView {
-    let separator_class = format!(
-        "-mx-1 my-1 h-px bg-muted {}",
-        class.get().unwrap_or_default()
+    let separator_class = tw_merge!(
+        "-mx-1 my-1 h-px bg-muted",
+        class.get().unwrap_or_default(),
     );
 
     view! {
@@ -110,9 +110,9 @@ pub fn SelectLabel(
     #[prop(into, optional)] style: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let label_class = format!(
-        "py-1.5 pl-8 pr-2 text-sm font-semibold {}",
-        class.get().unwrap_or_default()
+    let label_class = tw_merge!(
+        "py-1.5 pl-8 pr-2 text-sm font-semibold",
+        class.get().unwrap_or_default(),
     );
 
     view! {
@@ -135,9 +135,9 @@ pub fn SelectGroup(
     #[prop(into, optional)] style: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let group_class = format!(
-        "p-1 text-muted-foreground {}",
-        class.get().unwrap_or_default()
+    let group_class = tw_merge!(
+        "p-1 text-muted-foreground",
+        class.get().unwrap_or_default(),
     );
 
     view! {
@@ -2,7 +2,7 @@ use leptos::{ev::MouseEvent, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
-use tailwind_fuse::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 // Select Root Provider
 #[component]
@@ -73,9 +73,9 @@ pub fn SelectTrigger(
     let set_open = expect_context::<Callback<bool>>();
     let disabled = expect_context::<Signal<bool>>();
     
-    let trigger_class = format!(
-        "flex h-10 w-full items-center justify-between rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background placeholder:text-muted-foreground focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 [&>span]:line-clamp-1 {}",
-        class.get().unwrap_or_default()
+    let trigger_class = tw_merge!(
+        "flex h-10 w-full items-center justify-between rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background placeholder:text-muted-foreground focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 [&>span]:line-clamp-1",
+        class.get().unwrap_or_default(),
     );
 
     let handle_click = move |_e: MouseEvent| {
@@ -110,9 +110,9 @@ pub fn SelectValue(
 ) -> impl IntoView {
     let value_state = expect_context::<Signal<String>>();
     
-    let value_class = format!(
-        "placeholder:text-muted-foreground {}",
-        class.get().unwrap_or_default()
+    let value_class = tw_merge!(
+        "placeholder:text-muted-foreground",
+        class.get().unwrap_or_default(),
     );
 
     view! {
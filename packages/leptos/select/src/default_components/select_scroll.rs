@@ -2,7 +2,7 @@ use leptos::{ev::MouseEvent, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
-use tailwind_fuse::*;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 // Select Scroll Up Button
 #[component]
@@ -11,9 +11,9 @@ pub fn SelectScrollUpButton(
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
 ) -> impl IntoView {
-    let scroll_up_class = format!(
-        "flex cursor-default items-center justify-center py-1 {}",
-        class.get().unwrap_or_default()
+    let scroll_up_class = tw_merge!(
+        "flex cursor-default items-center justify-center py-1",
+        class.get().unwrap_or_default(),
     );
 
     view! {
@@ -50,9 +50,9 @@ pub fn SelectScrollDownButton(
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: MaybeProp<String>,
 ) -> impl IntoView {
-    let scroll_down_class = format!(
-        "flex cursor-default items-center justify-center py-1 {}",
-        class.get().unwrap_or_default()
+    let scroll_down_class = tw_merge!(
+        "flex cursor-default items-center justify-center py-1",
+        class.get().unwrap_or_default(),
     );
 
     view! {
@@ -1,26 +1,123 @@
+use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_utils::modal::{use_modal, ModalEvent, ModalOptions};
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const SHEET_CLASS: &str = "rounded-lg border bg-card text-card-foreground shadow-sm";
+const SHEET_MODAL_CLASS: &str = "fixed z-50 gap-4 bg-background p-6 shadow-lg transition ease-in-out data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:duration-300 data-[state=open]:duration-500";
+const SHEET_OVERLAY_CLASS: &str = "fixed inset-0 z-50 bg-black/80 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0";
 
+/// Edge of the screen a modal sheet slides in from
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SheetSide {
+    Top,
+    #[default]
+    Right,
+    Bottom,
+    Left,
+}
+
+impl SheetSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SheetSide::Top => "top",
+            SheetSide::Right => "right",
+            SheetSide::Bottom => "bottom",
+            SheetSide::Left => "left",
+        }
+    }
+
+    pub(crate) fn class(&self) -> &'static str {
+        match self {
+            SheetSide::Top => "inset-x-0 top-0 border-b data-[state=closed]:slide-out-to-top data-[state=open]:slide-in-from-top",
+            SheetSide::Right => "inset-y-0 right-0 h-full w-3/4 border-l data-[state=closed]:slide-out-to-right data-[state=open]:slide-in-from-right sm:max-w-sm",
+            SheetSide::Bottom => "inset-x-0 bottom-0 border-t data-[state=closed]:slide-out-to-bottom data-[state=open]:slide-in-from-bottom",
+            SheetSide::Left => "inset-y-0 left-0 h-full w-3/4 border-r data-[state=closed]:slide-out-to-left data-[state=open]:slide-in-from-left sm:max-w-sm",
+        }
+    }
+}
+
+/// Sheet panel.
+///
+/// Without `open` the sheet renders in place. With `open` it becomes a modal
+/// panel on the `side` edge of the screen, closed by Escape or pressing outside.
 #[component]
 pub fn Sheet(
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
-    #[prop(optional)] children: Option<Children>,
+    /// Shows the sheet as a modal panel while true
+    #[prop(optional)] open: Option<RwSignal<bool>>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+    #[prop(into, optional)] side: Signal<SheetSide>,
+    /// Runs before focus moves into the sheet; prevent it to focus something else
+    #[prop(optional)] on_open_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs before focus returns to the trigger; prevent it to focus something else
+    #[prop(optional)] on_close_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs on Escape; prevent it to keep the sheet open
+    #[prop(optional)] on_escape_key_down: Option<Callback<ModalEvent>>,
+    /// Runs on a press outside the sheet; prevent it to keep the sheet open
+    #[prop(optional)] on_interact_outside: Option<Callback<ModalEvent>>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
+    let Some(open) = open else {
+        let computed_class = Signal::derive(move || {
+            tw_merge!(SHEET_CLASS, class.get().unwrap_or_default())
+        });
+
+        return view! {
+            <div
+                class=computed_class
+                id=id.get().unwrap_or_default()
+                style=move || style.get().to_string()
+            >
+                {children.map(|c| c())}
+            </div>
+        }
+        .into_any();
+    };
+
+    let content_ref = NodeRef::<Div>::new();
+    use_modal(
+        content_ref,
+        open.into(),
+        Callback::new(move |_| {
+            open.set(false);
+            if let Some(callback) = &on_open_change {
+                callback.run(false);
+            }
+        }),
+        ModalOptions {
+            on_open_auto_focus,
+            on_close_auto_focus,
+            on_escape_key_down,
+            on_interact_outside,
+            ..ModalOptions::default()
+        },
+    );
+
     let computed_class = Signal::derive(move || {
-        format!("{} {}", SHEET_CLASS, class.get().unwrap_or_default())
+        tw_merge!(SHEET_MODAL_CLASS, side.get().class(), class.get().unwrap_or_default())
     });
 
     view! {
-        <div
-            class=computed_class
-            id=id.get().unwrap_or_default()
-            style=move || style.get().to_string()
-        >
-            {children.map(|c| c())}
-        </div>
+        <Show when=move || open.get()>
+            <div class=SHEET_OVERLAY_CLASS data-state="open"></div>
+            <div
+                node_ref=content_ref
+                class=computed_class
+                id=move || id.get().unwrap_or_default()
+                style=move || style.get().to_string()
+                role="dialog"
+                aria-modal="true"
+                tabindex="-1"
+                data-state="open"
+                data-side=move || side.get().as_str()
+            >
+                {children.clone().map(|c| c())}
+            </div>
+        </Show>
     }
+    .into_any()
 }
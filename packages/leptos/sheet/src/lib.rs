@@ -4,7 +4,7 @@ pub mod signal_managed;
 pub mod default;
 pub mod new_york;
 
-pub use default::{Sheet};
+pub use default::{Sheet, SheetSide};
 pub use new_york::{Sheet as SheetNewYork};
 
 mod tests;
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 pub const SKELETON_CLASS: &str = "animate-pulse rounded-md bg-muted";
 
@@ -105,7 +106,7 @@ pub fn Skeleton(
         } else {
             base_class
         };
-        format!("{} {} {} {}", SKELETON_CLASS, variant_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SKELETON_CLASS, variant_class, animation_class, class.get().unwrap_or_default())
     });
 
     let computed_style = Signal::derive(move || {
@@ -148,7 +149,7 @@ pub fn SkeletonText(
     let animation_class = if animated.get() { "animate-pulse" } else { "" };
     
     let computed_class = Signal::derive(move || {
-        format!("{} {} {} {}", SKELETON_CLASS, size_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SKELETON_CLASS, size_class, animation_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -156,7 +157,7 @@ pub fn SkeletonText(
             {move || (0..line_count).map(|i| {
                 let width_class = if i == line_count - 1 { "w-3/4" } else { "w-full" };
                 view! {
-                    <div class={format!("{} {}", computed_class.get(), width_class)} />
+                    <div class={tw_merge!(computed_class.get(), width_class)} />
                 }
             }).collect::<Vec<_>>()}
         </div>
@@ -181,7 +182,7 @@ pub fn SkeletonAvatar(
     let animation_class = if animated.get() { "animate-pulse" } else { "" };
     
     let computed_class = Signal::derive(move || {
-        format!("{} {} rounded-full {} {}", SKELETON_CLASS, size_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SKELETON_CLASS, size_class, "rounded-full", animation_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -204,7 +205,7 @@ pub fn SkeletonCard(
     let animation_class = if animated.get() { "animate-pulse" } else { "" };
     
     let computed_class = Signal::derive(move || {
-        format!("{} h-32 w-full rounded-lg {} {}", SKELETON_CLASS, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SKELETON_CLASS, "h-32 w-full rounded-lg", animation_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -1,11 +1,20 @@
-use leptos::{ev::Event, prelude::*};
+use leptos::{ev, prelude::*};
 use leptos_style::Style;
 use leptos::wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
-const SLIDER_CLASS: &str = "relative flex w-full touch-none select-none items-center";
-const SLIDER_TRACK_CLASS: &str = "relative h-2 w-full grow overflow-hidden rounded-full bg-secondary";
-const SLIDER_RANGE_CLASS: &str = "absolute h-full bg-primary";
-const SLIDER_THUMB_CLASS: &str = "block h-5 w-5 rounded-full border-2 border-primary bg-background ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
+use crate::math::{
+    apply_key_action, closest_thumb, key_action, update_values, SliderBounds, SliderMark,
+    SliderOrientation,
+};
+
+const SLIDER_CLASS: &str = "relative flex touch-none select-none items-center data-[orientation=horizontal]:w-full data-[orientation=vertical]:h-full data-[orientation=vertical]:min-h-44 data-[orientation=vertical]:w-auto data-[orientation=vertical]:flex-col data-[disabled]:opacity-50";
+const SLIDER_TRACK_CLASS: &str = "relative grow overflow-hidden rounded-full bg-secondary";
+const SLIDER_RANGE_CLASS: &str = "absolute bg-primary";
+const SLIDER_THUMB_CLASS: &str = "absolute block rounded-full border-2 border-primary bg-background ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
+const SLIDER_TOOLTIP_CLASS: &str = "pointer-events-none absolute z-10 whitespace-nowrap rounded-md bg-primary px-2 py-1 text-xs text-primary-foreground";
+const SLIDER_MARK_CLASS: &str = "pointer-events-none absolute h-1 w-1 rounded-full bg-muted-foreground";
+const SLIDER_MARK_LABEL_CLASS: &str = "pointer-events-none absolute whitespace-nowrap text-xs text-muted-foreground";
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum SliderVariant {
@@ -70,11 +79,14 @@ impl From<String> for SliderSize {
 }
 
 impl SliderSize {
-    fn track_class(&self) -> &'static str {
-        match self {
-            SliderSize::Sm => "h-1",
-            SliderSize::Md => "h-2",
-            SliderSize::Lg => "h-3",
+    fn track_class(&self, orientation: SliderOrientation) -> &'static str {
+        match (self, orientation) {
+            (SliderSize::Sm, SliderOrientation::Horizontal) => "h-1 w-full",
+            (SliderSize::Md, SliderOrientation::Horizontal) => "h-2 w-full",
+            (SliderSize::Lg, SliderOrientation::Horizontal) => "h-3 w-full",
+            (SliderSize::Sm, SliderOrientation::Vertical) => "w-1 h-full",
+            (SliderSize::Md, SliderOrientation::Vertical) => "w-2 h-full",
+            (SliderSize::Lg, SliderOrientation::Vertical) => "w-3 h-full",
         }
     }
     
@@ -87,189 +99,339 @@ impl SliderSize {
     }
 }
 
+/// Slider with one thumb per entry of `value`.
+///
+/// Dragging the track moves the closest thumb. Thumbs are keyboard focusable:
+/// arrows move by `step`, Shift+arrows and PageUp/PageDown by ten steps, and
+/// Home/End jump to `min`/`max`.
 #[component]
 pub fn Slider(
-    #[prop(into, optional)] value: Signal<f64>,
+    #[prop(into, optional)] value: Signal<Vec<f64>>,
     #[prop(into, optional)] min: MaybeProp<f64>,
     #[prop(into, optional)] max: MaybeProp<f64>,
     #[prop(into, optional)] step: MaybeProp<f64>,
-    #[prop(into, optional)] on_change: Option<Callback<f64>>,
+    /// Minimum distance between thumbs, in steps. Thumbs can't pass each other when set.
+    #[prop(into, optional)] min_steps_between_thumbs: MaybeProp<usize>,
+    #[prop(into, optional)] orientation: MaybeProp<SliderOrientation>,
+    /// Puts `max` at the start of the track (left, or top when vertical)
+    #[prop(into, optional)] inverted: Signal<bool>,
+    #[prop(into, optional)] marks: MaybeProp<Vec<SliderMark>>,
+    /// Formats values for `aria-valuetext`, the tooltip and the value display
+    #[prop(into, optional)] format_value: Option<Callback<f64, String>>,
+    /// Shows the formatted value above a thumb while it is dragged or focused
+    #[prop(into, optional)] show_tooltip: Signal<bool>,
+    #[prop(into, optional)] on_change: Option<Callback<Vec<f64>>>,
+    /// Called when a drag or key press ends
+    #[prop(into, optional)] on_value_commit: Option<Callback<Vec<f64>>>,
     #[prop(into, optional)] variant: MaybeProp<SliderVariant>,
     #[prop(into, optional)] size: MaybeProp<SliderSize>,
     #[prop(into, optional)] disabled: Signal<bool>,
     #[prop(into, optional)] show_value: Signal<bool>,
+    /// Form field name; each thumb submits one hidden input
+    #[prop(into, optional)] name: MaybeProp<String>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
 ) -> impl IntoView {
-    let min_value = min.get().unwrap_or(0.0);
-    let max_value = max.get().unwrap_or(100.0);
-    let step_value = step.get().unwrap_or(1.0);
+    let bounds = Signal::derive(move || SliderBounds {
+        min: min.get().unwrap_or(0.0),
+        max: max.get().unwrap_or(100.0),
+        step: step.get().unwrap_or(1.0),
+        min_steps_between_thumbs: min_steps_between_thumbs.get().unwrap_or(0),
+    });
+    let orientation = Signal::derive(move || orientation.get().unwrap_or_default());
     let slider_variant = variant.get().unwrap_or_default();
     let slider_size = size.get().unwrap_or_default();
-    
-    let handle_change = {
-        let on_change = on_change.clone();
-        move |event: Event| {
-            if let Some(callback) = &on_change {
-                let target = event.target().unwrap();
-                let input = target.unchecked_into::<web_sys::HtmlInputElement>();
-                if let Ok(val) = input.value().parse::<f64>() {
-                    callback.run(val);
-                }
+
+    let initial_values = move || {
+        let values = value.get();
+        if values.is_empty() { vec![bounds.get().min] } else { values }
+    };
+    let values = RwSignal::new(initial_values());
+    Effect::new(move |_| values.set(initial_values()));
+
+    let active_thumb = RwSignal::new(None::<usize>);
+    let dragging = RwSignal::new(false);
+    let root_ref = NodeRef::<leptos::html::Div>::new();
+    let thumb_refs: StoredValue<Vec<NodeRef<leptos::html::Span>>> = StoredValue::new(Vec::new());
+
+    let format = move |v: f64| match format_value {
+        Some(format_value) => format_value.run(v),
+        None => format!("{}", v),
+    };
+
+    let set_thumb_value = move |index: usize, next_value: f64| {
+        let current = values.get_untracked();
+        let (next, moved) = update_values(&current, index, next_value, &bounds.get_untracked());
+        active_thumb.set(Some(moved));
+        if next != current {
+            values.set(next.clone());
+            if let Some(on_change) = on_change {
+                on_change.run(next);
             }
         }
+        if moved != index {
+            // Keep keyboard focus on the thumb that was moved after it passed a neighbour
+            if let Some(thumb) = thumb_refs.with_value(|refs| refs.get(moved).and_then(|r| r.get_untracked())) {
+                let _ = thumb.focus();
+            }
+        }
+        moved
     };
 
-    let progress_percentage = Signal::derive(move || {
-        let val = value.get();
-        let range = max_value - min_value;
-        if range <= 0.0 { 0.0 } else { ((val - min_value) / range * 100.0).clamp(0.0, 100.0) }
-    });
+    let commit = move || {
+        if let Some(on_value_commit) = on_value_commit {
+            on_value_commit.run(values.get_untracked());
+        }
+    };
+
+    // Value under the pointer, from the root element's bounding box
+    let value_at_pointer = move |event: &ev::PointerEvent| -> Option<f64> {
+        let root = root_ref.get_untracked()?;
+        let rect = root.get_bounding_client_rect();
+        let bounds = bounds.get_untracked();
+        let fraction = match orientation.get_untracked() {
+            SliderOrientation::Horizontal if rect.width() > 0.0 => (event.client_x() as f64 - rect.left()) / rect.width(),
+            SliderOrientation::Vertical if rect.height() > 0.0 => (rect.bottom() - event.client_y() as f64) / rect.height(),
+            _ => return None,
+        };
+        let fraction = if inverted.get_untracked() { 1.0 - fraction } else { fraction };
+        Some(bounds.value_at_percent(fraction * 100.0))
+    };
+
+    let handle_pointer_down = move |event: ev::PointerEvent| {
+        if disabled.get_untracked() {
+            return;
+        }
+        let Some(pointer_value) = value_at_pointer(&event) else {
+            return;
+        };
+        event.prevent_default();
+        if let Some(target) = event.target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) {
+            let _ = target.set_pointer_capture(event.pointer_id());
+        }
+        dragging.set(true);
+        let index = closest_thumb(&values.get_untracked(), pointer_value);
+        let moved = set_thumb_value(index, pointer_value);
+        if let Some(thumb) = thumb_refs.with_value(|refs| refs.get(moved).and_then(|r| r.get_untracked())) {
+            let _ = thumb.focus();
+        }
+    };
+
+    let handle_pointer_move = move |event: ev::PointerEvent| {
+        if !dragging.get_untracked() {
+            return;
+        }
+        if let (Some(index), Some(pointer_value)) = (active_thumb.get_untracked(), value_at_pointer(&event)) {
+            set_thumb_value(index, pointer_value);
+        }
+    };
+
+    let handle_pointer_up = move |_: ev::PointerEvent| {
+        if dragging.get_untracked() {
+            dragging.set(false);
+            commit();
+        }
+    };
+
+    let percent_of = move |v: f64| {
+        let percent = bounds.get().percent(v);
+        if inverted.get() { 100.0 - percent } else { percent }
+    };
+
+    // Inline style placing an element at `percent` along the track
+    let position_style = move |percent: f64| match orientation.get() {
+        SliderOrientation::Horizontal => format!("left: {}%; transform: translateX(-50%);", percent),
+        SliderOrientation::Vertical => format!("bottom: {}%; transform: translateY(50%);", percent),
+    };
+
+    let range_style = move || {
+        let current = values.get();
+        let (start, end) = match current.as_slice() {
+            [single] => (percent_of(bounds.get().min), percent_of(*single)),
+            many => (
+                percent_of(many.first().copied().unwrap_or_default()),
+                percent_of(many.last().copied().unwrap_or_default()),
+            ),
+        };
+        let (from, to) = if start <= end { (start, end) } else { (end, start) };
+        match orientation.get() {
+            SliderOrientation::Horizontal => format!("left: {}%; width: {}%; height: 100%;", from, to - from),
+            SliderOrientation::Vertical => format!("bottom: {}%; height: {}%; width: 100%;", from, to - from),
+        }
+    };
 
-    let track_class = slider_size.track_class();
-    let thumb_class = slider_size.thumb_class();
     let variant_class = slider_variant.range_class();
-    
+    let thumb_class = slider_size.thumb_class();
+
     let computed_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_CLASS, track_class, class.get().unwrap_or_default())
+        tw_merge!(SLIDER_CLASS, class.get().unwrap_or_default())
     });
 
-    let computed_range_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_RANGE_CLASS, variant_class, track_class)
+    let computed_track_class = Signal::derive(move || {
+        format!("{} {}", SLIDER_TRACK_CLASS, slider_size.track_class(orientation.get()))
     });
 
-    let computed_thumb_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_THUMB_CLASS, thumb_class, track_class)
-    });
+    let computed_range_class = format!("{} {}", SLIDER_RANGE_CLASS, variant_class);
+
+    let computed_thumb_class = format!("{} {}", SLIDER_THUMB_CLASS, thumb_class);
+
+    // Only re-render thumbs when their number changes, so focus survives value updates
+    let thumb_count = Memo::new(move |_| values.with(Vec::len));
+    let thumbs = move || {
+        let count = thumb_count.get();
+        thumb_refs.update_value(|refs| refs.resize_with(count, NodeRef::new));
+
+        (0..count).map(|index| {
+            let thumb_ref = thumb_refs.with_value(|refs| refs[index]);
+            let thumb_value = move || values.with(|values| values.get(index).copied().unwrap_or_default());
+            let handle_keydown = move |event: ev::KeyboardEvent| {
+                if disabled.get_untracked() {
+                    return;
+                }
+                let Some(action) = key_action(&event.key(), event.shift_key(), orientation.get_untracked(), inverted.get_untracked()) else {
+                    return;
+                };
+                event.prevent_default();
+                let target = apply_key_action(action, thumb_value(), &bounds.get_untracked());
+                set_thumb_value(index, target);
+                commit();
+            };
+            let show_thumb_tooltip = move || {
+                show_tooltip.get() && active_thumb.get() == Some(index)
+            };
+            let tooltip_style = move || match orientation.get() {
+                SliderOrientation::Horizontal => "bottom: calc(100% + 0.5rem); left: 50%; transform: translateX(-50%);",
+                SliderOrientation::Vertical => "left: calc(100% + 0.5rem); top: 50%; transform: translateY(-50%);",
+            };
+
+            view! {
+                <span
+                    node_ref=thumb_ref
+                    role="slider"
+                    tabindex=move || if disabled.get() { "-1" } else { "0" }
+                    class=computed_thumb_class.clone()
+                    style=move || position_style(percent_of(thumb_value()))
+                    aria-valuemin=move || bounds.get().min
+                    aria-valuemax=move || bounds.get().max
+                    aria-valuenow=thumb_value
+                    aria-valuetext=move || format(thumb_value())
+                    aria-orientation=move || orientation.get().as_str()
+                    aria-disabled=move || disabled.get().to_string()
+                    data-state=move || if active_thumb.get() == Some(index) && dragging.get() { "dragging" } else { "idle" }
+                    on:keydown=handle_keydown
+                    on:focus=move |_| active_thumb.set(Some(index))
+                    on:blur=move |_| if !dragging.get_untracked() { active_thumb.set(None) }
+                >
+                    <Show when=show_thumb_tooltip>
+                        <span class=SLIDER_TOOLTIP_CLASS style=tooltip_style>
+                            {move || format(thumb_value())}
+                        </span>
+                    </Show>
+                </span>
+                <input
+                    r#type="hidden"
+                    name=move || name.get()
+                    value=move || thumb_value().to_string()
+                />
+            }
+        }).collect_view()
+    };
+
+    let mark_views = move || {
+        marks.get().unwrap_or_default().into_iter().map(|mark| {
+            let position = position_style(percent_of(mark.value));
+            let label_style = match orientation.get() {
+                SliderOrientation::Horizontal => format!("{} top: calc(100% + 0.5rem);", position),
+                SliderOrientation::Vertical => format!("{} left: calc(100% + 0.75rem);", position),
+            };
+            view! {
+                <span class=SLIDER_MARK_CLASS style=position data-value=mark.value />
+                {mark.label.map(|label| view! {
+                    <span class=SLIDER_MARK_LABEL_CLASS style=label_style>{label}</span>
+                })}
+            }
+        }).collect_view()
+    };
 
     view! {
         <div class="w-full space-y-2">
             <div
+                node_ref=root_ref
                 class=move || computed_class.get()
                 id=id.get().unwrap_or_default()
                 style=move || style.get().to_string()
+                data-orientation=move || orientation.get().as_str()
+                data-disabled=move || disabled.get().then_some("")
+                aria-disabled=move || disabled.get().to_string()
+                on:pointerdown=handle_pointer_down
+                on:pointermove=handle_pointer_move
+                on:pointerup=handle_pointer_up
+                on:pointercancel=handle_pointer_up
             >
-                <div class={format!("{} {}", SLIDER_TRACK_CLASS, track_class)}>
-                    <div
-                        class=move || computed_range_class.get()
-                        style={move || format!("width: {}%", progress_percentage.get())}
-                    />
+                <div class=move || computed_track_class.get()>
+                    <div class=computed_range_class style=range_style />
                 </div>
-                <input
-                    r#type="range"
-                    min={min_value}
-                    max={max_value}
-                    step={step_value}
-                    value={move || value.get()}
-                    disabled=move || disabled.get()
-                    class="absolute inset-0 h-full w-full opacity-0 cursor-pointer"
-                    on:input=handle_change
-                />
-                <div
-                    class=move || computed_thumb_class.get()
-                    style={move || format!("left: {}%", progress_percentage.get())}
-                />
+                {mark_views}
+                {thumbs}
             </div>
             <Show
                 when=move || show_value.get()
                 fallback=|| view! { <div class="hidden"></div> }
             >
                 <div class="flex justify-between text-sm text-muted-foreground">
-                    <span>{move || format!("{:.0}", min_value)}</span>
-                    <span>{move || format!("{:.0}", value.get())}</span>
-                    <span>{move || format!("{:.0}", max_value)}</span>
+                    <span>{move || format(bounds.get().min)}</span>
+                    <span>{move || values.get().into_iter().map(format).collect::<Vec<_>>().join(" - ")}</span>
+                    <span>{move || format(bounds.get().max)}</span>
                 </div>
             </Show>
         </div>
     }
 }
 
-// Range Slider (for dual values)
+/// Two-thumb slider, kept for compatibility with the tuple-based API
 #[component]
 pub fn RangeSlider(
     #[prop(into, optional)] values: Signal<(f64, f64)>,
     #[prop(into, optional)] min: MaybeProp<f64>,
     #[prop(into, optional)] max: MaybeProp<f64>,
     #[prop(into, optional)] step: MaybeProp<f64>,
-    #[prop(into, optional)] _on_change: Option<Callback<(f64, f64)>>,
+    #[prop(into, optional)] min_steps_between_thumbs: MaybeProp<usize>,
+    #[prop(into, optional)] on_change: Option<Callback<(f64, f64)>>,
     #[prop(into, optional)] variant: MaybeProp<SliderVariant>,
     #[prop(into, optional)] size: MaybeProp<SliderSize>,
-    #[prop(into, optional)] _disabled: Signal<bool>,
+    #[prop(into, optional)] disabled: Signal<bool>,
     #[prop(into, optional)] show_values: Signal<bool>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
 ) -> impl IntoView {
-    let min_value = min.get().unwrap_or(0.0);
-    let max_value = max.get().unwrap_or(100.0);
-    let _step_value = step.get().unwrap_or(1.0);
-    let slider_variant = variant.get().unwrap_or_default();
-    let slider_size = size.get().unwrap_or_default();
-    
-    let (_min_val, _max_val) = values.get();
-    let range_percentage = Signal::derive(move || {
-        let (min_v, max_v) = values.get();
-        let range = max_value - min_value;
-        if range <= 0.0 { (0.0, 0.0) } else {
-            let min_percent = ((min_v - min_value) / range * 100.0).clamp(0.0, 100.0);
-            let max_percent = ((max_v - min_value) / range * 100.0).clamp(0.0, 100.0);
-            (min_percent, max_percent)
-        }
+    let value = Signal::derive(move || {
+        let (start, end) = values.get();
+        vec![start, end]
     });
-
-    let track_class = slider_size.track_class();
-    let thumb_class = slider_size.thumb_class();
-    let variant_class = slider_variant.range_class();
-    
-    let computed_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_CLASS, track_class, class.get().unwrap_or_default())
-    });
-
-    let computed_range_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_RANGE_CLASS, variant_class, track_class)
-    });
-
-    let computed_thumb_class = Signal::derive(move || {
-        format!("{} {} {}", SLIDER_THUMB_CLASS, thumb_class, track_class)
+    let handle_change = Callback::new(move |values: Vec<f64>| {
+        if let (Some(on_change), [start, end]) = (on_change, values.as_slice()) {
+            on_change.run((*start, *end));
+        }
     });
 
     view! {
-        <div class="w-full space-y-2">
-            <div
-                class=move || computed_class.get()
-                id=id.get().unwrap_or_default()
-                style=move || style.get().to_string()
-            >
-                <div class={format!("{} {}", SLIDER_TRACK_CLASS, track_class)}>
-                    <div
-                        class=computed_range_class
-                        style={move || {
-                            let (min_p, max_p) = range_percentage.get();
-                            format!("left: {}%; width: {}%", min_p, max_p - min_p)
-                        }}
-                    />
-                </div>
-                <div
-                    class=computed_thumb_class
-                    style={move || format!("left: {}%", range_percentage.get().0)}
-                />
-                <div
-                    class=computed_thumb_class
-                    style={move || format!("left: {}%", range_percentage.get().1)}
-                />
-            </div>
-            <Show
-                when=move || show_values.get()
-                fallback=|| view! { <div class="hidden"></div> }
-            >
-                <div class="flex justify-between text-sm text-muted-foreground">
-                    <span>{move || format!("{:.0}", min_value)}</span>
-                    <span>{move || format!("{:.0} - {:.0}", values.get().0, values.get().1)}</span>
-                    <span>{move || format!("{:.0}", max_value)}</span>
-                </div>
-            </Show>
-        </div>
+        <Slider
+            value=value
+            min=min
+            max=max
+            step=step
+            min_steps_between_thumbs=min_steps_between_thumbs
+            on_change=handle_change
+            variant=variant
+            size=size
+            disabled=disabled
+            show_value=show_values
+            class=class
+            id=id
+            style=style
+        />
     }
 }
 
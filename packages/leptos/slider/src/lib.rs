@@ -1,9 +1,14 @@
 //! Leptos port of shadcn/ui slider
 
 pub mod signal_managed;
+pub mod math;
 pub mod default;
 pub mod new_york;
 
+pub use math::{
+    SliderBounds, SliderMark, SliderOrientation, SliderKeyAction,
+    closest_thumb, update_values, key_action, apply_key_action,
+};
 pub use default::{
     Slider, RangeSlider, SliderRoot, SliderVariant, SliderSize
 };
@@ -0,0 +1,286 @@
+//! Pure value math for the Slider components
+//!
+//! Snapping, clamping, thumb selection and keyboard steps live here so they can
+//! be tested without a DOM.
+
+/// Slider layout direction
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl SliderOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SliderOrientation::Horizontal => "horizontal",
+            SliderOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+impl From<String> for SliderOrientation {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "vertical" => SliderOrientation::Vertical,
+            _ => SliderOrientation::Horizontal,
+        }
+    }
+}
+
+/// Tick mark drawn on the track
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderMark {
+    pub value: f64,
+    pub label: Option<String>,
+}
+
+impl SliderMark {
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Range, step and thumb spacing of a slider
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliderBounds {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    /// Minimum distance between neighbouring thumbs, in steps. When non-zero,
+    /// thumbs stop at their neighbours instead of passing them.
+    pub min_steps_between_thumbs: usize,
+}
+
+impl Default for SliderBounds {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            min_steps_between_thumbs: 0,
+        }
+    }
+}
+
+impl SliderBounds {
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Snaps to the nearest step from `min` and clamps into range
+    pub fn snap(&self, value: f64) -> f64 {
+        if self.step <= 0.0 {
+            return self.clamp(value);
+        }
+        let snapped = ((value - self.min) / self.step).round() * self.step + self.min;
+        self.clamp(round_to_precision(snapped, decimal_count(self.step).max(decimal_count(self.min))))
+    }
+
+    /// Position of `value` along the track, from 0 to 100
+    pub fn percent(&self, value: f64) -> f64 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
+            0.0
+        } else {
+            ((value - self.min) / range * 100.0).clamp(0.0, 100.0)
+        }
+    }
+
+    /// Value at `percent` (0 to 100) along the track, before snapping
+    pub fn value_at_percent(&self, percent: f64) -> f64 {
+        self.min + (self.max - self.min) * percent.clamp(0.0, 100.0) / 100.0
+    }
+
+    fn min_gap(&self) -> f64 {
+        self.min_steps_between_thumbs as f64 * self.step
+    }
+}
+
+/// Number of decimals in `value`'s shortest representation
+fn decimal_count(value: f64) -> usize {
+    let text = value.to_string();
+    text.split_once('.').map(|(_, decimals)| decimals.len()).unwrap_or(0)
+}
+
+fn round_to_precision(value: f64, precision: usize) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+/// Thumb that a pointer press at `value` should move.
+///
+/// When several thumbs are equally close (e.g. stacked at the same value) the
+/// last one is picked when moving up and the first one when moving down, so
+/// stacked thumbs can be pulled apart in either direction.
+pub fn closest_thumb(values: &[f64], value: f64) -> usize {
+    let mut closest = 0;
+    let mut closest_distance = f64::INFINITY;
+    for (index, thumb) in values.iter().enumerate() {
+        let distance = (thumb - value).abs();
+        if distance < closest_distance || (distance == closest_distance && value > *thumb) {
+            closest = index;
+            closest_distance = distance;
+        }
+    }
+    closest
+}
+
+/// Moves thumb `index` towards `next_value`.
+///
+/// Returns the new values, kept sorted, and the index the moved thumb ends up
+/// at (it changes when a thumb passes its neighbour).
+pub fn update_values(values: &[f64], index: usize, next_value: f64, bounds: &SliderBounds) -> (Vec<f64>, usize) {
+    let mut next = values.to_vec();
+    if index >= next.len() {
+        return (next, index);
+    }
+
+    let mut value = bounds.snap(next_value);
+    if bounds.min_steps_between_thumbs > 0 {
+        let gap = bounds.min_gap();
+        if index > 0 {
+            value = value.max(next[index - 1] + gap);
+        }
+        if index + 1 < next.len() {
+            value = value.min(next[index + 1] - gap);
+        }
+        next[index] = value;
+        return (next, index);
+    }
+
+    next[index] = value;
+    next.sort_by(|a, b| a.total_cmp(b));
+    let moved = if value >= values[index] {
+        next.iter().rposition(|v| *v == value)
+    } else {
+        next.iter().position(|v| *v == value)
+    };
+    (next, moved.unwrap_or(index))
+}
+
+/// Keyboard action for a focused thumb
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderKeyAction {
+    /// Move by this many steps (negative moves toward `min`)
+    Steps(f64),
+    ToMin,
+    ToMax,
+}
+
+/// Steps moved by PageUp/PageDown and Shift+Arrow
+pub const PAGE_STEP_MULTIPLIER: f64 = 10.0;
+
+/// Maps a key to an action, honouring orientation and inversion
+pub fn key_action(key: &str, shift: bool, orientation: SliderOrientation, inverted: bool) -> Option<SliderKeyAction> {
+    let multiplier = if shift { PAGE_STEP_MULTIPLIER } else { 1.0 };
+    let flip = if inverted { -1.0 } else { 1.0 };
+
+    let direction = match (key, orientation) {
+        ("ArrowRight", SliderOrientation::Horizontal) | ("ArrowUp", SliderOrientation::Vertical) => flip,
+        ("ArrowLeft", SliderOrientation::Horizontal) | ("ArrowDown", SliderOrientation::Vertical) => -flip,
+        // The cross-axis arrows always follow the value direction
+        ("ArrowUp", SliderOrientation::Horizontal) | ("ArrowRight", SliderOrientation::Vertical) => 1.0,
+        ("ArrowDown", SliderOrientation::Horizontal) | ("ArrowLeft", SliderOrientation::Vertical) => -1.0,
+        ("PageUp", _) => return Some(SliderKeyAction::Steps(PAGE_STEP_MULTIPLIER)),
+        ("PageDown", _) => return Some(SliderKeyAction::Steps(-PAGE_STEP_MULTIPLIER)),
+        ("Home", _) => return Some(SliderKeyAction::ToMin),
+        ("End", _) => return Some(SliderKeyAction::ToMax),
+        _ => return None,
+    };
+
+    Some(SliderKeyAction::Steps(direction * multiplier))
+}
+
+/// Target value for a keyboard action applied to `value`
+pub fn apply_key_action(action: SliderKeyAction, value: f64, bounds: &SliderBounds) -> f64 {
+    match action {
+        SliderKeyAction::Steps(steps) => value + steps * bounds.step,
+        SliderKeyAction::ToMin => bounds.min,
+        SliderKeyAction::ToMax => bounds.max,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(step: f64, min_steps_between_thumbs: usize) -> SliderBounds {
+        SliderBounds {
+            step,
+            min_steps_between_thumbs,
+            ..SliderBounds::default()
+        }
+    }
+
+    #[test]
+    fn test_snap_and_clamp() {
+        let b = bounds(5.0, 0);
+        assert_eq!(b.snap(12.0), 10.0);
+        assert_eq!(b.snap(13.0), 15.0);
+        assert_eq!(b.snap(-20.0), 0.0);
+        assert_eq!(b.snap(140.0), 100.0);
+
+        let fine = SliderBounds { min: 0.0, max: 1.0, step: 0.1, min_steps_between_thumbs: 0 };
+        assert_eq!(fine.snap(0.30000000000000004), 0.3);
+        assert_eq!(fine.snap(0.74), 0.7);
+    }
+
+    #[test]
+    fn test_percent_conversion() {
+        let b = SliderBounds { min: -50.0, max: 50.0, ..SliderBounds::default() };
+        assert_eq!(b.percent(0.0), 50.0);
+        assert_eq!(b.percent(100.0), 100.0);
+        assert_eq!(b.value_at_percent(25.0), -25.0);
+    }
+
+    #[test]
+    fn test_closest_thumb() {
+        assert_eq!(closest_thumb(&[10.0, 50.0, 90.0], 60.0), 1);
+        assert_eq!(closest_thumb(&[10.0, 50.0, 90.0], 80.0), 2);
+        // Stacked thumbs: moving up takes the last, moving down the first
+        assert_eq!(closest_thumb(&[40.0, 40.0], 45.0), 1);
+        assert_eq!(closest_thumb(&[40.0, 40.0], 35.0), 0);
+    }
+
+    #[test]
+    fn test_thumbs_swap_without_min_steps() {
+        let (values, index) = update_values(&[20.0, 60.0], 0, 70.0, &bounds(1.0, 0));
+        assert_eq!(values, vec![60.0, 70.0]);
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn test_thumb_collision_with_min_steps() {
+        let b = bounds(5.0, 2);
+        let (values, index) = update_values(&[20.0, 60.0, 80.0], 1, 95.0, &b);
+        assert_eq!(values, vec![20.0, 70.0, 80.0]);
+        assert_eq!(index, 1);
+
+        let (values, _) = update_values(&[20.0, 60.0, 80.0], 1, 0.0, &b);
+        assert_eq!(values, vec![20.0, 30.0, 80.0]);
+    }
+
+    #[test]
+    fn test_key_actions() {
+        let h = SliderOrientation::Horizontal;
+        let v = SliderOrientation::Vertical;
+        assert_eq!(key_action("ArrowRight", false, h, false), Some(SliderKeyAction::Steps(1.0)));
+        assert_eq!(key_action("ArrowRight", false, h, true), Some(SliderKeyAction::Steps(-1.0)));
+        assert_eq!(key_action("ArrowUp", true, v, false), Some(SliderKeyAction::Steps(10.0)));
+        assert_eq!(key_action("ArrowUp", false, v, true), Some(SliderKeyAction::Steps(-1.0)));
+        assert_eq!(key_action("PageDown", false, h, false), Some(SliderKeyAction::Steps(-10.0)));
+        assert_eq!(key_action("Home", false, h, false), Some(SliderKeyAction::ToMin));
+        assert_eq!(key_action("Tab", false, h, false), None);
+
+        let b = bounds(2.0, 0);
+        assert_eq!(apply_key_action(SliderKeyAction::Steps(-10.0), 50.0, &b), 30.0);
+        assert_eq!(apply_key_action(SliderKeyAction::ToMax, 50.0, &b), 100.0);
+    }
+}
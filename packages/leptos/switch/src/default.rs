@@ -1,5 +1,6 @@
 use leptos::{ev::MouseEvent, prelude::*};
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const SWITCH_CLASS: &str = "peer inline-flex h-6 w-11 shrink-0 cursor-pointer items-center rounded-full border-2 border-transparent transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 focus-visible:ring-offset-background disabled:cursor-not-allowed disabled:opacity-50 data-[state=checked]:bg-primary data-[state=unchecked]:bg-input";
 const SWITCH_THUMB_CLASS: &str = "pointer-events-none block h-5 w-5 rounded-full bg-background shadow-lg ring-0 transition-transform data-[state=checked]:translate-x-5 data-[state=unchecked]:translate-x-0";
@@ -115,7 +116,7 @@ pub fn Switch(
     let animation_class = if animated.get() { "transition-all duration-200" } else { "transition-colors" };
     
     let computed_class = Signal::derive(move || {
-        format!("{} {} {} {} {}", SWITCH_CLASS, switch_class, variant_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SWITCH_CLASS, switch_class, variant_class, animation_class, class.get().unwrap_or_default())
     });
 
     let computed_thumb_class = Signal::derive(move || {
@@ -218,7 +219,7 @@ pub fn SwitchThumb(
     });
     
     let computed_class = Signal::derive(move || {
-        format!("{} {} {} {}", SWITCH_THUMB_CLASS, thumb_class, animation_class, class.get().unwrap_or_default())
+        tw_merge!(SWITCH_THUMB_CLASS, thumb_class, animation_class, class.get().unwrap_or_default())
     });
 
     view! {
@@ -240,7 +241,7 @@ pub fn SwitchLabel(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let computed_class = Signal::derive(move || {
-        format!("text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70 {}", class.get().unwrap_or_default())
+        tw_merge!("text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70", class.get().unwrap_or_default())
     });
 
     view! {
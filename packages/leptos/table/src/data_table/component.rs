@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 use std::collections::HashMap;
 use super::types::*;
 
@@ -161,7 +162,7 @@ pub fn DataTable(
 
     view! {
         <div
-            class=move || format!("data-table {}", class.as_ref().unwrap_or(&String::new()))
+            class=move || tw_merge!("data-table", class.get().unwrap_or_default())
             id=id
             style=style
         >
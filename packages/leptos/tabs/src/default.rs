@@ -1,19 +1,127 @@
-use leptos::{ev::MouseEvent, prelude::*};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use leptos::{
+    ev::{FocusEvent, KeyboardEvent, MouseEvent},
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys,
+};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
 
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TabsOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl TabsOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TabsOrientation::Horizontal => "horizontal",
+            TabsOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// Whether moving focus to a trigger also selects it
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TabsActivationMode {
+    /// Arrow keys select the tab they move to
+    #[default]
+    Automatic,
+    /// Arrow keys only move focus; Enter or Space selects
+    Manual,
+}
+
+/// When the content of inactive tabs is in the document
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TabsMountMode {
+    /// Every panel is rendered up front and hidden while inactive
+    Eager,
+    /// A panel is rendered the first time it is selected and kept alive after
+    #[default]
+    Lazy,
+    /// Only the active panel is rendered
+    Unmount,
+}
+
+/// Whether a panel's content is rendered
+pub fn is_mounted(mode: TabsMountMode, active: bool, visited: bool, force_mount: bool) -> bool {
+    force_mount
+        || active
+        || match mode {
+            TabsMountMode::Eager => true,
+            TabsMountMode::Lazy => visited,
+            TabsMountMode::Unmount => false,
+        }
+}
+
+/// Position among `count` enabled triggers to move focus to for `key`, from
+/// `current`. Arrow keys follow the orientation and wrap around when `looping`.
+pub fn navigation_target(
+    key: &str,
+    current: usize,
+    count: usize,
+    orientation: TabsOrientation,
+    looping: bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let (previous, next) = match orientation {
+        TabsOrientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+        TabsOrientation::Vertical => ("ArrowUp", "ArrowDown"),
+    };
+    match key {
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        key if key == next => match current + 1 {
+            next if next < count => Some(next),
+            _ if looping => Some(0),
+            _ => None,
+        },
+        key if key == previous => match current.checked_sub(1) {
+            Some(previous) => Some(previous),
+            None if looping => Some(count - 1),
+            None => None,
+        },
+        _ => None,
+    }
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Trigger registered for roving focus
+#[derive(Clone, Copy)]
+struct RegisteredTrigger {
+    id: usize,
+    value: Signal<String>,
+    disabled: Signal<bool>,
+    node_ref: AnyNodeRef,
+}
+
 // Tabs Root Provider
 #[component]
 pub fn Tabs(
     #[prop(into, optional)] value: Signal<String>,
     #[prop(into, optional)] on_value_change: Option<Callback<String>>,
     #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] orientation: Signal<TabsOrientation>,
+    #[prop(into, optional)] activation_mode: Signal<TabsActivationMode>,
+    #[prop(into, optional)] mount_mode: Signal<TabsMountMode>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let internal_value = RwSignal::new(default_value.get().unwrap_or_default());
-    
+
     let value_state = Signal::derive(move || {
         if !value.get().is_empty() && value.get() != internal_value.get() {
             value.get()
@@ -32,14 +140,19 @@ pub fn Tabs(
     provide_context(TabsContextValue {
         value: value_state,
         set_value,
+        orientation,
+        activation_mode,
+        mount_mode,
+        base_id: next_id(),
+        triggers: RwSignal::new(Vec::new()),
     });
 
     let tabs_class = Signal::derive(move || {
-        format!("{}", class.get().unwrap_or_default())
+        tw_merge!(class.get().unwrap_or_default())
     });
 
     view! {
-        <div class={tabs_class}>
+        <div class={tabs_class} data-orientation=move || orientation.get().as_str()>
             {children.map(|c| c())}
         </div>
     }
@@ -49,20 +162,120 @@ pub fn Tabs(
 pub struct TabsContextValue {
     pub value: Signal<String>,
     pub set_value: Callback<String>,
+    pub orientation: Signal<TabsOrientation>,
+    pub activation_mode: Signal<TabsActivationMode>,
+    pub mount_mode: Signal<TabsMountMode>,
+    base_id: usize,
+    /// Triggers in document order
+    triggers: RwSignal<Vec<RegisteredTrigger>>,
+}
+
+impl TabsContextValue {
+    /// The enclosing tabs. Without them (or without a reactive owner to look
+    /// them up from) the part gets standalone tabs that nothing selects.
+    fn current() -> Self {
+        use_context::<Self>().unwrap_or_else(|| {
+            let value = RwSignal::new(String::new());
+            Self {
+                value: value.into(),
+                set_value: Callback::new(move |new_value| value.set(new_value)),
+                orientation: Signal::default(),
+                activation_mode: Signal::default(),
+                mount_mode: Signal::default(),
+                base_id: next_id(),
+                triggers: RwSignal::new(Vec::new()),
+            }
+        })
+    }
+
+    fn id_for(&self, part: &str, value: &str) -> String {
+        let value = value.replace(char::is_whitespace, "-");
+        format!("tabs-{}-{part}-{value}", self.base_id)
+    }
+
+    fn register_trigger(self, value: Signal<String>, disabled: Signal<bool>, node_ref: AnyNodeRef) -> usize {
+        let id = next_id();
+        self.triggers.update(|triggers| triggers.push(RegisteredTrigger { id, value, disabled, node_ref }));
+        id
+    }
+
+    fn unregister_trigger(self, id: usize) {
+        self.triggers.update(|triggers| triggers.retain(|trigger| trigger.id != id));
+    }
+
+    /// The trigger reachable with Tab: the selected one, or the first enabled
+    /// one when no enabled trigger is selected
+    fn tab_stop(&self) -> Option<usize> {
+        let value = self.value.get();
+        self.triggers.with(|triggers| {
+            let enabled = || triggers.iter().filter(|trigger| !trigger.disabled.get());
+            enabled()
+                .find(|trigger| trigger.value.get() == value)
+                .or_else(|| enabled().next())
+                .map(|trigger| trigger.id)
+        })
+    }
+
+    /// Moves focus from trigger `id` to another enabled trigger. Returns
+    /// whether `key` was a navigation key.
+    fn focus_sibling(self, id: usize, key: &str, looping: bool) -> bool {
+        let enabled = self.triggers.with_untracked(|triggers| {
+            triggers
+                .iter()
+                .filter(|trigger| !trigger.disabled.get_untracked())
+                .copied()
+                .collect::<Vec<_>>()
+        });
+        let Some(current) = enabled.iter().position(|trigger| trigger.id == id) else {
+            return false;
+        };
+        let Some(target) = navigation_target(key, current, enabled.len(), self.orientation.get_untracked(), looping) else {
+            return false;
+        };
+
+        if let Some(element) = enabled[target]
+            .node_ref
+            .get_untracked()
+            .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            let _ = element.focus();
+        }
+        true
+    }
+}
+
+/// Settings of the enclosing [`TabsList`]
+#[derive(Clone, Copy)]
+struct TabsListContext {
+    looping: Signal<bool>,
 }
 
 // Tabs List
 #[component]
 pub fn TabsList(
+    /// Whether arrow keys wrap from the last trigger to the first and back
+    #[prop(into, default = Signal::stored(true))] r#loop: Signal<bool>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    let ctx = TabsContextValue::current();
+    provide_context(TabsListContext { looping: r#loop });
+
     let list_class = Signal::derive(move || {
-        format!("inline-flex h-10 items-center justify-center rounded-md bg-muted p-1 text-muted-foreground {}", class.get().unwrap_or_default())
+        let orientation_class = match ctx.orientation.get() {
+            TabsOrientation::Horizontal => "h-10",
+            TabsOrientation::Vertical => "h-auto flex-col",
+        };
+        tw_merge!("inline-flex items-center justify-center rounded-md bg-muted p-1 text-muted-foreground", orientation_class, class.get().unwrap_or_default())
     });
 
     view! {
-        <div class={list_class} role="tablist">
+        <div
+            class={list_class}
+            role="tablist"
+            aria-orientation=move || ctx.orientation.get().as_str()
+            data-orientation=move || ctx.orientation.get().as_str()
+        >
             {children.map(|c| c())}
         </div>
     }
@@ -80,12 +293,17 @@ pub struct TabsTriggerChildProps {
     pub r#type: MaybeProp<String>,
     pub role: Signal<String>,
     pub aria_selected: Signal<String>,
+    pub aria_controls: Signal<String>,
+    pub tabindex: Signal<String>,
     pub onclick: Option<Callback<MouseEvent>>,
+    pub onkeydown: Option<Callback<KeyboardEvent>>,
+    pub onfocus: Option<Callback<FocusEvent>>,
 }
 
 #[component]
 pub fn TabsTrigger(
     #[prop(into)] value: MaybeProp<String>,
+    #[prop(into, optional)] disabled: Signal<bool>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
@@ -93,12 +311,23 @@ pub fn TabsTrigger(
     #[prop(into, optional)] as_child: Option<Callback<TabsTriggerChildProps, AnyView>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let ctx = expect_context::<TabsContextValue>();
-    
+    let ctx = TabsContextValue::current();
+    let looping = use_context::<TabsListContext>().map_or(Signal::stored(true), |list| list.looping);
+    let value = Signal::derive(move || value.get().unwrap_or_default());
+
     let is_selected = Signal::derive(move || {
-        ctx.value.get() == value.get().unwrap_or_default()
+        ctx.value.get() == value.get()
     });
 
+    let trigger_id = ctx.register_trigger(value, disabled, node_ref);
+    on_cleanup(move || ctx.unregister_trigger(trigger_id));
+
+    let select = move || {
+        if !disabled.get_untracked() {
+            ctx.set_value.run(value.get_untracked());
+        }
+    };
+
     let trigger_class = Signal::derive(move || {
         let base_class = "inline-flex items-center justify-center whitespace-nowrap rounded-sm px-3 py-1.5 text-sm font-medium ring-offset-background transition-all focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
         let selected_class = if is_selected.get() {
@@ -106,24 +335,34 @@ pub fn TabsTrigger(
         } else {
             "hover:bg-background hover:text-foreground"
         };
-        format!("{} {} {}", base_class, selected_class, class.get().unwrap_or_default())
+        let orientation_class = match ctx.orientation.get() {
+            TabsOrientation::Horizontal => "",
+            TabsOrientation::Vertical => "w-full",
+        };
+        tw_merge!(base_class, selected_class, orientation_class, class.get().unwrap_or_default())
     });
 
     let child_props = TabsTriggerChildProps {
         node_ref,
         class: trigger_class,
-        id,
+        id: MaybeProp::derive(move || Some(id.get().unwrap_or_else(|| ctx.id_for("trigger", &value.get())))),
         style,
-        disabled: Signal::derive(|| false),
+        disabled,
         r#type: "button".to_string().into(),
         role: "tab".to_string().into(),
-        aria_selected: Signal::derive(move || is_selected.get().to_string()).into(),
-        onclick: Some(Callback::new({
-            let ctx = ctx.clone();
-            let value = value.clone();
-            move |_: MouseEvent| {
-                let val = value.get().unwrap_or_default();
-                ctx.set_value.run(val);
+        aria_selected: Signal::derive(move || is_selected.get().to_string()),
+        aria_controls: Signal::derive(move || ctx.id_for("content", &value.get())),
+        // Roving focus: only one trigger is reachable with Tab
+        tabindex: Signal::derive(move || if ctx.tab_stop() == Some(trigger_id) { "0" } else { "-1" }.to_string()),
+        onclick: Some(Callback::new(move |_: MouseEvent| select())),
+        onkeydown: Some(Callback::new(move |event: KeyboardEvent| {
+            if ctx.focus_sibling(trigger_id, &event.key(), looping.get_untracked()) {
+                event.prevent_default();
+            }
+        })),
+        onfocus: Some(Callback::new(move |_: FocusEvent| {
+            if ctx.activation_mode.get_untracked() == TabsActivationMode::Automatic && !is_selected.get_untracked() {
+                select();
             }
         })),
     };
@@ -139,27 +378,81 @@ pub fn TabsTrigger(
 #[component]
 pub fn TabsContent(
     #[prop(into)] value: MaybeProp<String>,
+    /// Render the content even while inactive, e.g. for SSR and SEO
+    #[prop(into, optional)] force_mount: Signal<bool>,
     #[prop(into, optional)] class: MaybeProp<String>,
-    #[prop(optional)] children: Option<Children>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
-    let ctx = expect_context::<TabsContextValue>();
-    
+    let ctx = TabsContextValue::current();
+    let value = Signal::derive(move || value.get().unwrap_or_default());
+
     let is_selected = Signal::derive(move || {
-        ctx.value.get() == value.get().unwrap_or_default()
+        ctx.value.get() == value.get()
+    });
+
+    // Lazy panels stay mounted once they have been shown
+    let visited = RwSignal::new(false);
+    Effect::new(move |_| {
+        if is_selected.get() {
+            visited.set(true);
+        }
+    });
+    let mounted = Signal::derive(move || {
+        is_mounted(ctx.mount_mode.get(), is_selected.get(), visited.get(), force_mount.get())
     });
 
     let content_class = Signal::derive(move || {
-        format!("mt-2 ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 {}", class.get().unwrap_or_default())
+        tw_merge!("mt-2 ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2", class.get().unwrap_or_default())
     });
 
     view! {
         <div
             class={content_class}
+            id=move || ctx.id_for("content", &value.get())
             role="tabpanel"
-            aria-selected={move || is_selected.get().to_string()}
-            style={move || if is_selected.get() { "" } else { "display: none;" }}
+            tabindex="0"
+            aria-labelledby=move || ctx.id_for("trigger", &value.get())
+            data-state=move || if is_selected.get() { "active" } else { "inactive" }
+            data-orientation=move || ctx.orientation.get().as_str()
+            hidden=move || !is_selected.get()
         >
-            {children.map(|c| c())}
+            {move || mounted.get().then(|| children.clone().map(|c| c()))}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_target_follows_orientation() {
+        let horizontal = TabsOrientation::Horizontal;
+        assert_eq!(navigation_target("ArrowRight", 0, 3, horizontal, true), Some(1));
+        assert_eq!(navigation_target("ArrowLeft", 0, 3, horizontal, true), Some(2));
+        assert_eq!(navigation_target("ArrowDown", 0, 3, horizontal, true), None);
+        assert_eq!(navigation_target("ArrowDown", 0, 3, TabsOrientation::Vertical, true), Some(1));
+        assert_eq!(navigation_target("Home", 2, 3, horizontal, true), Some(0));
+        assert_eq!(navigation_target("End", 0, 3, horizontal, true), Some(2));
+    }
+
+    #[test]
+    fn test_navigation_target_without_loop() {
+        let horizontal = TabsOrientation::Horizontal;
+        assert_eq!(navigation_target("ArrowRight", 2, 3, horizontal, false), None);
+        assert_eq!(navigation_target("ArrowLeft", 0, 3, horizontal, false), None);
+        assert_eq!(navigation_target("ArrowLeft", 2, 3, horizontal, false), Some(1));
+        assert_eq!(navigation_target("End", 0, 0, horizontal, false), None);
+    }
+
+    #[test]
+    fn test_is_mounted() {
+        assert!(is_mounted(TabsMountMode::Eager, false, false, false));
+        assert!(!is_mounted(TabsMountMode::Lazy, false, false, false));
+        // Kept alive once visited
+        assert!(is_mounted(TabsMountMode::Lazy, false, true, false));
+        assert!(!is_mounted(TabsMountMode::Unmount, false, true, false));
+        assert!(is_mounted(TabsMountMode::Unmount, true, true, false));
+        assert!(is_mounted(TabsMountMode::Unmount, false, false, true));
+    }
+}
@@ -3,10 +3,15 @@
 pub mod signal_managed;
 pub mod default;
 pub mod new_york;
+#[cfg(feature = "router")]
+pub mod router;
 
 pub use default::{
-    Tabs, TabsList, TabsTrigger, TabsContent
+    Tabs, TabsList, TabsTrigger, TabsContent,
+    TabsActivationMode, TabsMountMode, TabsOrientation,
 };
+#[cfg(feature = "router")]
+pub use router::{TabsUrlState, TabsUrlSync, use_tabs_url_sync};
 pub use new_york::{
     Tabs as TabsNewYork, TabsList as TabsListNewYork, TabsTrigger as TabsTriggerNewYork, TabsContent as TabsContentNewYork
 };
@@ -0,0 +1,112 @@
+//! Syncing the active tab with the URL
+//!
+//! Enabled with the `router` feature. [`use_tabs_url_sync`] keeps the active
+//! tab in a query parameter or in the hash, so it survives reloads and can be
+//! shared in links:
+//!
+//! ```ignore
+//! let sync = use_tabs_url_sync(TabsUrlSync::Query("tab".into()));
+//! view! {
+//!     <Tabs value=sync.value on_value_change=sync.on_value_change default_value="account">
+//!         // ...
+//!     </Tabs>
+//! }
+//! ```
+
+use leptos::prelude::*;
+use leptos_router::NavigateOptions;
+use leptos_shadcn_utils::query::{decode_component, encode_component, query_value, set_query_param};
+use leptos_router::hooks::{use_location, use_navigate};
+
+/// Where the active tab is kept in the URL
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabsUrlSync {
+    /// A query parameter with this name, e.g. `?tab=billing`
+    Query(String),
+    /// The hash fragment, e.g. `#billing`
+    Hash,
+}
+
+/// Props to pass to [`Tabs`](crate::Tabs)
+#[derive(Clone, Copy)]
+pub struct TabsUrlState {
+    pub value: Signal<String>,
+    pub on_value_change: Callback<String>,
+}
+
+/// Active tab stored in the URL, or an empty string when the URL has none
+pub fn tab_from_url(search: &str, hash: &str, sync: &TabsUrlSync) -> String {
+    match sync {
+        TabsUrlSync::Query(key) => query_value(search, key).unwrap_or_default(),
+        TabsUrlSync::Hash => decode_component(hash.trim_start_matches('#')),
+    }
+}
+
+/// URL of the current page with `value` as the active tab, keeping the rest
+/// of the query and the hash
+pub fn tab_url(pathname: &str, search: &str, hash: &str, sync: &TabsUrlSync, value: &str) -> String {
+    let search = search.trim_start_matches('?');
+    let hash = hash.trim_start_matches('#');
+    match sync {
+        TabsUrlSync::Query(key) => set_query_param(&format!("{pathname}?{search}#{hash}"), key, value),
+        TabsUrlSync::Hash => {
+            let search = if search.is_empty() { String::new() } else { format!("?{search}") };
+            format!("{pathname}{search}#{}", encode_component(value))
+        }
+    }
+}
+
+/// Reads the active tab from the URL and navigates when it changes. Must be
+/// called inside a `<Router>`.
+pub fn use_tabs_url_sync(sync: TabsUrlSync) -> TabsUrlState {
+    let location = use_location();
+    let navigate = use_navigate();
+    let sync = StoredValue::new(sync);
+
+    let value = Signal::derive(move || {
+        tab_from_url(&location.search.get(), &location.hash.get(), &sync.read_value())
+    });
+
+    let on_value_change = Callback::new(move |value: String| {
+        let url = tab_url(
+            &location.pathname.get_untracked(),
+            &location.search.get_untracked(),
+            &location.hash.get_untracked(),
+            &sync.read_value(),
+            &value,
+        );
+        // Switching tabs should neither grow the history nor jump to the top
+        navigate(
+            &url,
+            NavigateOptions {
+                replace: true,
+                scroll: false,
+                ..Default::default()
+            },
+        );
+    });
+
+    TabsUrlState { value, on_value_change }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_from_url() {
+        let sync = TabsUrlSync::Query("tab".into());
+        assert_eq!(tab_from_url("?page=2&tab=billing", "", &sync), "billing");
+        assert_eq!(tab_from_url("tab=two%20words", "", &sync), "two words");
+        assert_eq!(tab_from_url("", "#billing", &sync), "");
+        assert_eq!(tab_from_url("", "#billing", &TabsUrlSync::Hash), "billing");
+    }
+
+    #[test]
+    fn test_tab_url_keeps_other_state() {
+        let sync = TabsUrlSync::Query("tab".into());
+        assert_eq!(tab_url("/settings", "?page=2&tab=account", "#top", &sync, "billing"), "/settings?page=2&tab=billing#top");
+        assert_eq!(tab_url("/settings", "", "", &sync, "a&b"), "/settings?tab=a%26b");
+        assert_eq!(tab_url("/settings", "page=2", "#top", &TabsUrlSync::Hash, "billing"), "/settings?page=2#billing");
+    }
+}
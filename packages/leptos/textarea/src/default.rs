@@ -1,6 +1,7 @@
 use leptos::{ev::Event, prelude::*};
 use leptos_style::Style;
 use leptos::wasm_bindgen::JsCast;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const TEXTAREA_CLASS: &str = "flex h-10 w-full rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
 
@@ -27,7 +28,7 @@ pub fn Textarea(
     };
 
     let computed_class = Signal::derive(move || {
-        format!("{} {}", TEXTAREA_CLASS, class.get().unwrap_or_default())
+        tw_merge!(TEXTAREA_CLASS, class.get().unwrap_or_default())
     });
 
     view! {
@@ -8,7 +8,7 @@ pub mod sonner;
 pub use default::{Toast};
 pub use new_york::{Toast as ToastNewYork};
 pub use sonner::{
-    SonnerProvider, SonnerViewport, SonnerToast,
+    SonnerProvider, SonnerViewport, SonnerToast, SonnerContextValue,
     ToastPosition, ToastTheme, ToastVariant, ToastAction, ToastData, ToastBuilder,
     toast
 };
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use leptos_style::Style;
+use leptos_shadcn_tailwind_merge::tw_merge;
 
 const TOAST_CLASS: &str = "relative w-full rounded-lg border p-4";
 
@@ -20,7 +21,7 @@ pub fn Toast(
             _ => "bg-background text-foreground",
         };
         
-        format!("{} {} {}", TOAST_CLASS, variant_class, class.get().unwrap_or_default())
+        tw_merge!(TOAST_CLASS, variant_class, class.get().unwrap_or_default())
     });
 
     view! {
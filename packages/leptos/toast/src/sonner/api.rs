@@ -1,11 +1,14 @@
 //! Toast API functions
-//! 
+//!
 //! This module contains the public API functions for creating
-//! and managing toast notifications.
+//! and managing toast notifications. They use the provider in context when
+//! called from a component and the global provider otherwise.
 
 use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::future::Future;
 use crate::sonner::builder::ToastBuilder;
-use crate::sonner::types::ToastVariant;
+use crate::sonner::types::{ToastData, ToastVariant};
 use crate::sonner::context::SonnerContextValue;
 
 /// Toast API functions
@@ -28,6 +31,7 @@ pub mod toast {
         ToastBuilder::new(title.to_string()).variant(ToastVariant::Warning)
     }
 
+    /// A toast that stays open until it is updated to another variant
     pub fn loading(title: &str) -> ToastBuilder {
         ToastBuilder::new(title.to_string()).variant(ToastVariant::Loading)
     }
@@ -36,14 +40,55 @@ pub mod toast {
         ToastBuilder::new(title.to_string())
     }
 
+    /// Shows a loading toast while `future` runs, then turns the same toast
+    /// into a success or error toast with the message built from the result.
+    ///
+    /// Returns the toast id.
+    pub fn promise<T, E, F>(
+        future: F,
+        loading: &str,
+        success: impl FnOnce(&T) -> String + 'static,
+        error: impl FnOnce(&E) -> String + 'static,
+    ) -> String
+    where
+        F: Future<Output = Result<T, E>> + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        let context = SonnerContextValue::current();
+        let id = self::loading(loading).show();
+
+        let toast_id = id.clone();
+        spawn_local(async move {
+            let result = future.await;
+            let Some(context) = context else {
+                return;
+            };
+            let (title, variant) = match &result {
+                Ok(value) => (success(value), ToastVariant::Success),
+                Err(err) => (error(err), ToastVariant::Error),
+            };
+            context.update(&toast_id, |toast| {
+                toast.title = title;
+                toast.variant = variant;
+            });
+        });
+        id
+    }
+
+    /// Edits an open toast in place. Returns false when no toast has `id`.
+    pub fn update(id: &str, f: impl FnOnce(&mut ToastData)) -> bool {
+        SonnerContextValue::current().is_some_and(|context| context.update(id, f))
+    }
+
     pub fn dismiss(id: String) {
-        if let Some(context) = use_context::<SonnerContextValue>() {
-            context.remove_toast.run(id);
+        if let Some(context) = SonnerContextValue::current() {
+            context.dismiss(&id);
         }
     }
 
     pub fn dismiss_all() {
-        if let Some(context) = use_context::<SonnerContextValue>() {
+        if let Some(context) = SonnerContextValue::current() {
             context.dismiss_all.run(());
         }
     }
@@ -55,14 +55,32 @@ impl ToastBuilder {
         self
     }
 
+    /// Sets the toast id. Showing a toast with the id of an open toast
+    /// replaces that toast in place.
     pub fn id(mut self, id: String) -> Self {
         self.data.id = id;
         self
     }
 
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.data.dismissible = dismissible;
+        self
+    }
+
+    pub fn on_dismiss(mut self, on_dismiss: Callback<ToastData>) -> Self {
+        self.data.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    pub fn on_auto_close(mut self, on_auto_close: Callback<ToastData>) -> Self {
+        self.data.on_auto_close = Some(on_auto_close);
+        self
+    }
+
+    /// Shows the toast on the provider in context, or on the global one
     pub fn show(self) -> String {
         let toast_id = self.data.id.clone();
-        if let Some(provider) = use_context::<SonnerContextValue>() {
+        if let Some(provider) = SonnerContextValue::current() {
             provider.add_toast.run(self.data);
         }
         toast_id
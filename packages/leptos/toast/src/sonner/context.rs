@@ -1,85 +1,199 @@
 //! Sonner context and provider
-//! 
+//!
 //! This module contains the SonnerContextValue and SonnerProvider
 //! for managing toast state and providing context to child components.
+//! The most recently mounted provider is also registered as a global handle,
+//! so toasts can be shown from outside the component tree (e.g. from async
+//! tasks or plain event handlers).
 
 use leptos::prelude::*;
+use leptos_style::Style;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use crate::sonner::stack::{stack_height, VISIBLE_TOASTS};
 use crate::sonner::types::{ToastData, ToastPosition, ToastTheme};
-use crate::sonner::toast::SonnerToast;
+use crate::sonner::item::SonnerToast;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+thread_local! {
+    static GLOBAL_SONNER: RefCell<Option<SonnerContextValue>> = const { RefCell::new(None) };
+}
 
 /// Sonner context value
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct SonnerContextValue {
-    pub toasts: RwSignal<HashMap<String, ToastData>>,
+    /// Open toasts, oldest first
+    pub toasts: RwSignal<Vec<ToastData>>,
+    /// Shows a toast, or replaces the toast with the same id in place
     pub add_toast: Callback<ToastData>,
     pub remove_toast: Callback<String>,
     pub dismiss_all: Callback<()>,
     pub position: RwSignal<ToastPosition>,
     pub theme: RwSignal<ToastTheme>,
     pub max_toasts: RwSignal<usize>,
+    /// Number of toasts shown in the stack
+    pub visible_toasts: RwSignal<usize>,
+    /// Keeps the stack expanded instead of only while hovered
+    pub expand: RwSignal<bool>,
+    /// Whether the pointer is over the stack, which expands it and pauses timers
+    pub hovered: RwSignal<bool>,
+    /// Whether the page is hidden, which pauses timers
+    pub document_hidden: RwSignal<bool>,
+    /// Measured toast heights by id
+    pub heights: RwSignal<HashMap<String, f64>>,
+}
+
+impl Default for SonnerContextValue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SonnerContextValue {
     pub fn new() -> Self {
-        let toasts = RwSignal::new(HashMap::<String, ToastData>::new());
-        let position = RwSignal::new(ToastPosition::TopRight);
-        let theme = RwSignal::new(ToastTheme::Auto);
+        let toasts = RwSignal::new(Vec::<ToastData>::new());
         let max_toasts = RwSignal::new(5);
+        let heights = RwSignal::new(HashMap::<String, f64>::new());
+
+        let add_toast = Callback::new(move |toast: ToastData| {
+            toasts.update(|current_toasts| {
+                if let Some(existing) = current_toasts.iter_mut().find(|existing| existing.id == toast.id) {
+                    *existing = toast;
+                    return;
+                }
 
-        let add_toast = {
-            let toasts = toasts.clone();
-            let max_toasts = max_toasts.clone();
-            Callback::new(move |toast: ToastData| {
-                let mut current_toasts = toasts.get();
-                let max = max_toasts.get();
-                
                 // Remove oldest toasts if we exceed the limit
+                let max = max_toasts.get_untracked().max(1);
                 if current_toasts.len() >= max {
-                    let mut sorted_toasts: Vec<_> = current_toasts.iter().collect();
-                    sorted_toasts.sort_by_key(|(_, data)| data.created_at);
-                    
-                    let to_remove: Vec<String> = sorted_toasts.iter()
-                        .take(current_toasts.len() - max + 1)
-                        .map(|(id, _)| (*id).clone())
-                        .collect();
-                    
-                    for id in to_remove {
-                        current_toasts.remove(&id);
-                    }
+                    let evicted: Vec<ToastData> = current_toasts.drain(..=current_toasts.len() - max).collect();
+                    heights.update(|heights| {
+                        for toast in &evicted {
+                            heights.remove(&toast.id);
+                        }
+                    });
                 }
-                
-                current_toasts.insert(toast.id.clone(), toast);
-                toasts.set(current_toasts);
-            })
-        };
-
-        let remove_toast = {
-            let toasts = toasts.clone();
-            Callback::new(move |id: String| {
-                let mut current_toasts = toasts.get();
-                current_toasts.remove(&id);
-                toasts.set(current_toasts);
-            })
-        };
+                current_toasts.push(toast);
+            });
+        });
 
-        let dismiss_all = {
-            let toasts = toasts.clone();
-            Callback::new(move |_| {
-                toasts.set(HashMap::new());
-            })
-        };
+        let remove_toast = Callback::new(move |id: String| {
+            toasts.update(|current_toasts| current_toasts.retain(|toast| toast.id != id));
+            heights.update(|heights| {
+                heights.remove(&id);
+            });
+        });
+
+        let dismiss_all = Callback::new(move |_| {
+            let dismissed = toasts.try_update(std::mem::take).unwrap_or_default();
+            heights.update(HashMap::clear);
+            for toast in dismissed {
+                if let Some(on_dismiss) = toast.on_dismiss {
+                    on_dismiss.run(toast);
+                }
+            }
+        });
 
         Self {
             toasts,
             add_toast,
             remove_toast,
             dismiss_all,
-            position,
-            theme,
+            position: RwSignal::new(ToastPosition::TopRight),
+            theme: RwSignal::new(ToastTheme::Auto),
             max_toasts,
+            visible_toasts: RwSignal::new(VISIBLE_TOASTS),
+            expand: RwSignal::new(false),
+            hovered: RwSignal::new(false),
+            document_hidden: RwSignal::new(false),
+            heights,
+        }
+    }
+
+    /// The provider in context, falling back to the global handle
+    pub fn current() -> Option<Self> {
+        use_context::<Self>().or_else(Self::global)
+    }
+
+    /// The most recently mounted provider, usable outside the component tree
+    pub fn global() -> Option<Self> {
+        GLOBAL_SONNER.with(|global| *global.borrow())
+    }
+
+    fn register_global(self) {
+        GLOBAL_SONNER.with(|global| *global.borrow_mut() = Some(self));
+    }
+
+    fn unregister_global(self) {
+        GLOBAL_SONNER.with(|global| {
+            let mut global = global.borrow_mut();
+            if global.is_some_and(|current| current.toasts == self.toasts) {
+                *global = None;
+            }
+        });
+    }
+
+    pub fn get(&self, id: &str) -> Option<ToastData> {
+        self.toasts.with_untracked(|toasts| toasts.iter().find(|toast| toast.id == id).cloned())
+    }
+
+    /// Edits an open toast in place. Returns false when no toast has `id`.
+    pub fn update(&self, id: &str, f: impl FnOnce(&mut ToastData)) -> bool {
+        self.toasts
+            .try_update(|toasts| match toasts.iter_mut().find(|toast| toast.id == id) {
+                Some(toast) => {
+                    f(toast);
+                    true
+                }
+                None => false,
+            })
+            .unwrap_or(false)
+    }
+
+    /// Closes a toast on behalf of the user, running its `on_dismiss`
+    pub fn dismiss(&self, id: &str) {
+        if let Some(toast) = self.get(id) {
+            self.remove_toast.run(toast.id.clone());
+            if let Some(on_dismiss) = toast.on_dismiss {
+                on_dismiss.run(toast);
+            }
+        }
+    }
+
+    /// Closes a toast whose duration ran out, running its `on_auto_close`
+    pub fn auto_close(&self, id: &str) {
+        if let Some(toast) = self.get(id) {
+            self.remove_toast.run(toast.id.clone());
+            if let Some(on_auto_close) = toast.on_auto_close {
+                on_auto_close.run(toast);
+            }
         }
     }
+
+    pub fn is_expanded(&self) -> bool {
+        self.expand.get() || self.hovered.get()
+    }
+
+    /// Whether auto-close timers are paused
+    pub fn is_paused(&self) -> bool {
+        self.hovered.get() || self.document_hidden.get()
+    }
+
+    /// Position of a toast in the stack, 0 being the newest
+    pub fn index_of(&self, id: &str) -> Option<usize> {
+        self.toasts.with(|toasts| toasts.iter().rev().position(|toast| toast.id == id))
+    }
+
+    /// Measured heights, newest first
+    pub fn stack_heights(&self) -> Vec<f64> {
+        let heights = self.heights.get();
+        self.toasts.with(|toasts| {
+            toasts
+                .iter()
+                .rev()
+                .map(|toast| heights.get(&toast.id).copied().unwrap_or(0.0))
+                .collect()
+        })
+    }
 }
 
 /// Sonner provider component
@@ -88,10 +202,17 @@ pub fn SonnerProvider(
     #[prop(into, optional)] position: MaybeProp<ToastPosition>,
     #[prop(into, optional)] theme: MaybeProp<ToastTheme>,
     #[prop(into, optional)] max_toasts: MaybeProp<usize>,
+    /// Number of toasts shown in the stack
+    #[prop(into, optional)] visible_toasts: MaybeProp<usize>,
+    /// Keeps the stack expanded instead of only while hovered
+    #[prop(into, optional)] expand: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] style: Signal<Style>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let context = SonnerContextValue::new();
-    
+
     // Set initial values
     if let Some(pos) = position.get() {
         context.position.set(pos);
@@ -102,11 +223,32 @@ pub fn SonnerProvider(
     if let Some(max) = max_toasts.get() {
         context.max_toasts.set(max);
     }
+    if let Some(visible) = visible_toasts.get() {
+        context.visible_toasts.set(visible);
+    }
+    if let Some(expand) = expand.get() {
+        context.expand.set(expand);
+    }
 
     provide_context(context);
+    context.register_global();
+    on_cleanup(move || context.unregister_global());
+
+    // Pause timers while the page is hidden
+    Effect::new(move |_| {
+        let handle = window_event_listener_untyped("visibilitychange", move |_| {
+            let hidden = document().hidden();
+            context.document_hidden.set(hidden);
+        });
+        on_cleanup(move || handle.remove());
+    });
 
     view! {
-        <div>
+        <div
+            class=move || class.get().unwrap_or_default()
+            id=move || id.get().unwrap_or_default()
+            style=move || style.get().to_string()
+        >
             {children.map(|c| c())}
             <SonnerViewport />
         </div>
@@ -116,7 +258,7 @@ pub fn SonnerProvider(
 /// Sonner viewport component that renders all toasts
 #[component]
 pub fn SonnerViewport() -> impl IntoView {
-    let context = expect_context::<SonnerContextValue>();
+    let context = SonnerContextValue::current().expect("SonnerViewport must be used inside a SonnerProvider");
     let toasts = context.toasts;
     let position = context.position;
     let theme = context.theme;
@@ -140,25 +282,99 @@ pub fn SonnerViewport() -> impl IntoView {
         }
     });
 
+    let height = move || {
+        stack_height(&context.stack_heights(), context.is_expanded(), context.visible_toasts.get())
+    };
+
     view! {
-        <div class=move || format!("{} {}", position_class.get(), theme_class.get())>
-            {move || {
-                toasts.get().into_iter().map(|(id, toast_data)| {
-                    let context = context.clone();
-                    let on_dismiss = {
-                        let context = context.clone();
-                        let id = id.clone();
-                        Callback::new(move |_| context.remove_toast.run(id.clone()))
-                    };
-                    view! {
-                        <SonnerToast
-                            id=id.clone()
-                            data=toast_data.clone()
-                            on_dismiss=on_dismiss
-                        />
-                    }
-                }).collect::<Vec<_>>()
-            }}
-        </div>
+        <section aria-label="Notifications" aria-live="polite" aria-relevant="additions text">
+            <ol
+                class=move || tw_merge!(position_class.get(), theme_class.get(), "w-[356px] max-w-[calc(100vw-2rem)] list-none")
+                style=move || format!("height: {}px;", height())
+                data-expanded=move || context.is_expanded().to_string()
+                on:mouseenter=move |_| context.hovered.set(true)
+                on:mouseleave=move |_| context.hovered.set(false)
+            >
+                // Keyed so each toast keeps its timer and swipe state while others change;
+                // built lazily because `For` needs the owner it is rendered under
+                {move || view! {
+                    <For
+                        each=move || toasts.get()
+                        key=|toast| toast.id.clone()
+                        let:toast
+                    >
+                        <SonnerToast id=toast.id />
+                    </For>
+                }}
+            </ol>
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn toast(id: &str) -> ToastData {
+        let mut toast = ToastData::new(id.to_string());
+        toast.id = id.to_string();
+        toast
+    }
+
+    fn ids(context: &SonnerContextValue) -> Vec<String> {
+        context.toasts.get_untracked().into_iter().map(|toast| toast.id).collect()
+    }
+
+    #[test]
+    fn test_add_replaces_same_id_in_place() {
+        let context = SonnerContextValue::new();
+        context.add_toast.run(toast("a"));
+        context.add_toast.run(toast("b"));
+        let mut replacement = toast("a");
+        replacement.title = "Updated".to_string();
+        context.add_toast.run(replacement);
+
+        assert_eq!(ids(&context), vec!["a", "b"]);
+        assert_eq!(context.get("a").unwrap().title, "Updated");
+    }
+
+    #[test]
+    fn test_max_toasts_evicts_oldest() {
+        let context = SonnerContextValue::new();
+        context.max_toasts.set(2);
+        for id in ["a", "b", "c"] {
+            context.add_toast.run(toast(id));
+        }
+        assert_eq!(ids(&context), vec!["b", "c"]);
+        assert_eq!(context.index_of("c"), Some(0));
+    }
+
+    #[test]
+    fn test_update_and_callbacks() {
+        let context = SonnerContextValue::new();
+        let closed = Arc::new(Mutex::new(Vec::new()));
+
+        let mut dismissed = toast("dismissed");
+        dismissed.on_dismiss = Some(Callback::new({
+            let closed = closed.clone();
+            move |toast: ToastData| closed.lock().unwrap().push(format!("dismiss:{}", toast.id))
+        }));
+        let mut expired = toast("expired");
+        expired.on_auto_close = Some(Callback::new({
+            let closed = closed.clone();
+            move |toast: ToastData| closed.lock().unwrap().push(format!("auto:{}", toast.id))
+        }));
+        context.add_toast.run(dismissed);
+        context.add_toast.run(expired);
+
+        assert!(context.update("expired", |toast| toast.title = "Done".to_string()));
+        assert!(!context.update("missing", |_| {}));
+        assert_eq!(context.get("expired").unwrap().title, "Done");
+
+        context.dismiss("dismissed");
+        context.auto_close("expired");
+        assert!(ids(&context).is_empty());
+        assert_eq!(*closed.lock().unwrap(), vec!["dismiss:dismissed", "auto:expired"]);
     }
 }
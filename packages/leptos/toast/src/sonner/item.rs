@@ -0,0 +1,271 @@
+//! Individual Sonner toast component
+//!
+//! This module contains the SonnerToast component that renders
+//! individual toast notifications. Each toast reads its data from the
+//! provider, counts down while the stack is not paused, places itself in the
+//! stack and can be swiped away.
+
+use leptos::html::Li;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys;
+use crate::sonner::context::SonnerContextValue;
+use crate::sonner::stack::{self, SwipeAxis};
+use crate::sonner::timer::ToastTimer;
+use crate::sonner::types::{ToastData, ToastVariant};
+
+/// Pointer press that may become a swipe
+#[derive(Clone, Copy)]
+struct SwipeStart {
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+    time: f64,
+}
+
+fn now() -> f64 {
+    web_sys::js_sys::Date::now()
+}
+
+fn variant_class(variant: ToastVariant) -> &'static str {
+    match variant {
+        ToastVariant::Default => "bg-background text-foreground border",
+        ToastVariant::Success => "bg-green-50 text-green-900 border-green-200 dark:bg-green-900 dark:text-green-100 dark:border-green-800",
+        ToastVariant::Error => "bg-red-50 text-red-900 border-red-200 dark:bg-red-900 dark:text-red-100 dark:border-red-800",
+        ToastVariant::Warning => "bg-yellow-50 text-yellow-900 border-yellow-200 dark:bg-yellow-900 dark:text-yellow-100 dark:border-yellow-800",
+        ToastVariant::Info => "bg-blue-50 text-blue-900 border-blue-200 dark:bg-blue-900 dark:text-blue-100 dark:border-blue-800",
+        ToastVariant::Loading => "bg-gray-50 text-gray-900 border-gray-200 dark:bg-gray-900 dark:text-gray-100 dark:border-gray-800",
+    }
+}
+
+/// Individual Sonner toast component
+#[component]
+pub fn SonnerToast(id: String) -> impl IntoView {
+    let context = SonnerContextValue::current().expect("SonnerToast must be used inside a SonnerProvider");
+    let id = StoredValue::new(id);
+    let node_ref = NodeRef::<Li>::new();
+
+    let data = Signal::derive(move || {
+        let id = id.get_value();
+        context.toasts.with(|toasts| toasts.iter().find(|toast| toast.id == id).cloned())
+    });
+    let field = move |f: fn(&ToastData) -> String| data.with(|data| data.as_ref().map(f).unwrap_or_default());
+
+    // Auto-close countdown, restarted when the duration or variant changes
+    // (e.g. a promise toast settling) and paused with the stack
+    let auto_close_after = Memo::new(move |_| data.with(|data| data.as_ref().and_then(ToastData::auto_close_after)));
+    let timer = StoredValue::new(None::<ToastTimer>);
+    let timeout = StoredValue::new(None::<TimeoutHandle>);
+    let clear_timeout = move || {
+        if let Some(handle) = timeout.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+
+    Effect::new(move |previous: Option<Option<std::time::Duration>>| {
+        let after = auto_close_after.get();
+        let paused = context.is_paused();
+        clear_timeout();
+        if previous != Some(after) {
+            timer.set_value(after.map(|after| ToastTimer::new(after.as_millis() as f64)));
+        }
+
+        let remaining = timer
+            .try_update_value(|timer| {
+                let timer = timer.as_mut()?;
+                if paused {
+                    timer.pause(now());
+                    None
+                } else {
+                    timer.start(now());
+                    Some(timer.remaining(now()))
+                }
+            })
+            .flatten();
+        if let Some(remaining) = remaining {
+            let handle = set_timeout_with_handle(
+                move || context.auto_close(&id.get_value()),
+                std::time::Duration::from_millis(remaining as u64),
+            );
+            timeout.set_value(handle.ok());
+        }
+        after
+    });
+    on_cleanup(clear_timeout);
+
+    // Report the height so the stack can lay out the toasts around it
+    Effect::new(move |_| {
+        data.track();
+        if let Some(element) = node_ref.get() {
+            let height = element.get_bounding_client_rect().height();
+            context.heights.update(|heights| {
+                heights.insert(id.get_value(), height);
+            });
+        }
+    });
+
+    let position = context.position;
+    let layout = move || {
+        let index = context.index_of(&id.get_value()).unwrap_or(0);
+        let heights = context.stack_heights();
+        (index, stack::stack_layout(index, &heights, context.is_expanded(), context.visible_toasts.get()))
+    };
+
+    // Swipe to dismiss
+    let swipe_start = StoredValue::new(None::<SwipeStart>);
+    let swipe = RwSignal::new(0.0_f64);
+    let is_swiping = RwSignal::new(false);
+    let swipe_delta = move |evt: &web_sys::PointerEvent, start: &SwipeStart| {
+        let (axis, _) = stack::swipe_direction(position.get_untracked());
+        match axis {
+            SwipeAxis::X => evt.client_x() as f64 - start.x,
+            SwipeAxis::Y => evt.client_y() as f64 - start.y,
+        }
+    };
+
+    let handle_pointer_down = move |evt: web_sys::PointerEvent| {
+        let on_button = evt
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .is_some_and(|target| target.closest("button").ok().flatten().is_some());
+        let dismissible = data.with_untracked(|data| data.as_ref().is_some_and(|data| data.dismissible));
+        if evt.button() != 0 || on_button || !dismissible {
+            return;
+        }
+        if let Some(target) = evt.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+            let _ = target.set_pointer_capture(evt.pointer_id());
+        }
+        swipe_start.set_value(Some(SwipeStart {
+            pointer_id: evt.pointer_id(),
+            x: evt.client_x() as f64,
+            y: evt.client_y() as f64,
+            time: evt.time_stamp(),
+        }));
+        is_swiping.set(true);
+    };
+
+    let handle_pointer_move = move |evt: web_sys::PointerEvent| {
+        let Some(start) = swipe_start.get_value().filter(|start| start.pointer_id == evt.pointer_id()) else {
+            return;
+        };
+        let (_, sign) = stack::swipe_direction(position.get_untracked());
+        swipe.set(stack::swipe_offset(swipe_delta(&evt, &start), sign));
+    };
+
+    let handle_pointer_up = move |evt: web_sys::PointerEvent| {
+        let Some(start) = swipe_start.try_update_value(Option::take).flatten() else {
+            return;
+        };
+        is_swiping.set(false);
+        let (_, sign) = stack::swipe_direction(position.get_untracked());
+        if stack::swipe_dismisses(swipe_delta(&evt, &start), sign, evt.time_stamp() - start.time) {
+            context.dismiss(&id.get_value());
+        } else {
+            swipe.set(0.0);
+        }
+    };
+
+    let style = move || {
+        let (index, layout) = layout();
+        let grows_down = stack::grows_down(position.get());
+        let (axis, _) = stack::swipe_direction(position.get());
+        let offset = if grows_down { layout.offset } else { -layout.offset };
+        let (swipe_x, swipe_y) = match axis {
+            SwipeAxis::X => (swipe.get(), 0.0),
+            SwipeAxis::Y => (0.0, swipe.get()),
+        };
+        let total = context.toasts.with(Vec::len);
+        format!(
+            "position: absolute; {}: 0; left: 0; right: 0; z-index: {}; transform: translate({}px, {}px) scale({}); transform-origin: {}; opacity: {}; pointer-events: {}; {} transition: {};",
+            if grows_down { "top" } else { "bottom" },
+            total.saturating_sub(index),
+            swipe_x,
+            offset + swipe_y,
+            layout.scale,
+            if grows_down { "bottom" } else { "top" },
+            if layout.visible { 1.0 - (swipe.get().abs() / 200.0).min(0.5) } else { 0.0 },
+            if layout.visible { "auto" } else { "none" },
+            layout.height.map(|height| format!("height: {}px; overflow: hidden;", height)).unwrap_or_default(),
+            if is_swiping.get() { "none" } else { "transform 400ms, opacity 400ms, height 400ms" },
+        )
+    };
+
+    let class = move || {
+        data.with(|data| {
+            let Some(data) = data else {
+                return String::new();
+            };
+            format!("{} {} p-4 rounded-lg shadow-lg w-full touch-none select-none",
+                variant_class(data.variant),
+                if data.actions.is_empty() { "" } else { "pb-2" }
+            )
+        })
+    };
+
+    view! {
+        <li
+            node_ref=node_ref
+            class=class
+            style=style
+            role=move || if data.with(|data| data.as_ref().is_some_and(|data| data.variant == ToastVariant::Error)) { "alert" } else { "status" }
+            aria-atomic="true"
+            tabindex="0"
+            data-index=move || layout().0
+            data-front=move || (layout().0 == 0).to_string()
+            data-swiping=move || is_swiping.get().to_string()
+            on:pointerdown=handle_pointer_down
+            on:pointermove=handle_pointer_move
+            on:pointerup=handle_pointer_up
+            on:pointercancel=handle_pointer_up
+        >
+            <div class="flex items-start justify-between">
+                <div class="flex-1">
+                    <div class="font-medium text-sm">
+                        {move || field(|data| data.title.clone())}
+                    </div>
+                    <Show when=move || data.with(|data| data.as_ref().is_some_and(|data| data.description.is_some()))>
+                        <div class="text-sm opacity-90 mt-1">
+                            {move || field(|data| data.description.clone().unwrap_or_default())}
+                        </div>
+                    </Show>
+                </div>
+                <Show when=move || data.with(|data| data.as_ref().is_some_and(|data| data.dismissible))>
+                    <button
+                        class="ml-2 text-sm opacity-70 hover:opacity-100"
+                        aria-label="Close toast"
+                        on:click=move |_| context.dismiss(&id.get_value())
+                    >
+                        "×"
+                    </button>
+                </Show>
+            </div>
+
+            {move || data.with(|data| data.as_ref().and_then(|data| data.progress)).map(|progress| view! {
+                <div class="w-full bg-gray-200 rounded-full h-1 mt-2">
+                    <div
+                        class="bg-blue-600 h-1 rounded-full transition-all duration-300"
+                        style=format!("width: {}%", (progress.clamp(0.0, 1.0) * 100.0) as u32)
+                    ></div>
+                </div>
+            })}
+
+            {move || {
+                let actions = data.with(|data| data.as_ref().map(|data| data.actions.clone()).unwrap_or_default());
+                (!actions.is_empty()).then(|| view! {
+                    <div class="flex gap-2 mt-3">
+                        {actions.into_iter().map(|action| {
+                            view! {
+                                <button
+                                    class="text-xs px-2 py-1 rounded bg-gray-100 hover:bg-gray-200 dark:bg-gray-800 dark:hover:bg-gray-700"
+                                    on:click=move |_| action.action.run(())
+                                >
+                                    {action.label}
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                })
+            }}
+        </li>
+    }
+}
@@ -6,12 +6,16 @@
 pub mod types;
 pub mod builder;
 pub mod context;
-pub mod toast;
+pub mod item;
 pub mod api;
+pub mod stack;
+pub mod timer;
 
 // Re-export the main components and types
 pub use types::*;
 pub use builder::ToastBuilder;
 pub use context::{SonnerProvider, SonnerViewport, SonnerContextValue};
-pub use toast::SonnerToast;
+pub use item::SonnerToast;
+pub use api::toast;
 pub use api::toast as api_toast;
+pub use timer::ToastTimer;
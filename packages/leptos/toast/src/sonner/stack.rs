@@ -0,0 +1,169 @@
+//! Stack layout and swipe gestures
+//!
+//! Toasts are stacked from the viewport edge, newest in front. Collapsed, the
+//! older toasts peek out behind the front one, scaled down; expanded (on hover
+//! or with `expand`) they are laid out as a list. A toast can be swiped away
+//! from the edge it sits on.
+
+use crate::sonner::types::ToastPosition;
+
+/// Number of toasts shown in the stack by default
+pub const VISIBLE_TOASTS: usize = 3;
+
+/// Space between toasts in an expanded stack, in pixels
+pub const TOAST_GAP: f64 = 14.0;
+
+/// How far each toast behind the front one peeks out, in pixels
+pub const STACK_PEEK: f64 = 10.0;
+
+/// Scale lost by each toast behind the front one
+pub const STACK_SCALE_STEP: f64 = 0.05;
+
+/// Swipe distance, in pixels, that dismisses a toast
+pub const SWIPE_THRESHOLD: f64 = 45.0;
+
+/// Swipe velocity, in px/ms, that dismisses a toast regardless of distance
+pub const SWIPE_VELOCITY_THRESHOLD: f64 = 0.11;
+
+/// Where one toast sits in the stack
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToastLayout {
+    /// Distance from the viewport edge, in pixels
+    pub offset: f64,
+    pub scale: f64,
+    /// Height the toast is clipped to, so toasts behind the front one match it
+    pub height: Option<f64>,
+    pub visible: bool,
+}
+
+/// Layout of the toast at `index` (0 is the newest, in front).
+///
+/// `heights` are the measured heights of the toasts, newest first.
+pub fn stack_layout(index: usize, heights: &[f64], expanded: bool, visible_toasts: usize) -> ToastLayout {
+    let visible = index < visible_toasts;
+    if expanded {
+        let newer: f64 = heights.iter().take(index).sum();
+        return ToastLayout {
+            offset: newer + TOAST_GAP * index as f64,
+            scale: 1.0,
+            height: None,
+            visible,
+        };
+    }
+
+    let peek = index.min(visible_toasts.saturating_sub(1)) as f64;
+    ToastLayout {
+        offset: STACK_PEEK * peek,
+        scale: (1.0 - STACK_SCALE_STEP * peek).max(0.0),
+        height: (index > 0).then(|| heights.first().copied()).flatten(),
+        visible,
+    }
+}
+
+/// Height the stack occupies, so the whole stack keeps hover while expanded
+pub fn stack_height(heights: &[f64], expanded: bool, visible_toasts: usize) -> f64 {
+    let shown = heights.len().min(visible_toasts);
+    if shown == 0 {
+        return 0.0;
+    }
+    if expanded {
+        heights.iter().take(shown).sum::<f64>() + TOAST_GAP * (shown - 1) as f64
+    } else {
+        heights[0] + STACK_PEEK * (shown - 1) as f64
+    }
+}
+
+/// Whether the stack grows downwards from the top of the screen
+pub fn grows_down(position: ToastPosition) -> bool {
+    matches!(position, ToastPosition::TopLeft | ToastPosition::TopRight | ToastPosition::TopCenter)
+}
+
+/// Axis along which a toast is swiped away
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwipeAxis {
+    X,
+    Y,
+}
+
+/// Axis and sign of the dismissing swipe for a position: towards the nearest
+/// side edge, or towards the top or bottom edge for centered toasts
+pub fn swipe_direction(position: ToastPosition) -> (SwipeAxis, f64) {
+    match position {
+        ToastPosition::TopLeft | ToastPosition::BottomLeft => (SwipeAxis::X, -1.0),
+        ToastPosition::TopRight | ToastPosition::BottomRight => (SwipeAxis::X, 1.0),
+        ToastPosition::TopCenter => (SwipeAxis::Y, -1.0),
+        ToastPosition::BottomCenter => (SwipeAxis::Y, 1.0),
+    }
+}
+
+/// Offset a toast follows for a pointer `delta` along the swipe axis.
+/// Movement away from the dismissing direction is damped.
+pub fn swipe_offset(delta: f64, sign: f64) -> f64 {
+    if delta * sign >= 0.0 {
+        delta
+    } else {
+        delta.signum() * delta.abs().sqrt()
+    }
+}
+
+/// Whether a swipe of `delta` pixels over `elapsed` ms dismisses the toast
+pub fn swipe_dismisses(delta: f64, sign: f64, elapsed: f64) -> bool {
+    let distance = delta * sign;
+    distance >= SWIPE_THRESHOLD || (distance > 0.0 && distance / elapsed.max(1.0) > SWIPE_VELOCITY_THRESHOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsed_stack_peeks_and_scales() {
+        let heights = [60.0, 80.0, 50.0, 70.0];
+        let front = stack_layout(0, &heights, false, 3);
+        assert_eq!(front, ToastLayout { offset: 0.0, scale: 1.0, height: None, visible: true });
+
+        let second = stack_layout(1, &heights, false, 3);
+        assert_eq!(second.offset, STACK_PEEK);
+        assert_eq!(second.scale, 0.95);
+        // Toasts behind take the front toast's height
+        assert_eq!(second.height, Some(60.0));
+
+        let hidden = stack_layout(3, &heights, false, 3);
+        assert!(!hidden.visible);
+        assert_eq!(hidden.offset, stack_layout(2, &heights, false, 3).offset);
+        assert_eq!(stack_height(&heights, false, 3), 60.0 + 2.0 * STACK_PEEK);
+    }
+
+    #[test]
+    fn test_expanded_stack_lists_toasts() {
+        let heights = [60.0, 80.0, 50.0];
+        assert_eq!(stack_layout(0, &heights, true, 3).offset, 0.0);
+        assert_eq!(stack_layout(1, &heights, true, 3).offset, 60.0 + TOAST_GAP);
+        assert_eq!(stack_layout(2, &heights, true, 3).offset, 140.0 + 2.0 * TOAST_GAP);
+        assert_eq!(stack_layout(2, &heights, true, 3).scale, 1.0);
+        assert_eq!(stack_height(&heights, true, 3), 190.0 + 2.0 * TOAST_GAP);
+        assert_eq!(stack_height(&[], true, 3), 0.0);
+    }
+
+    #[test]
+    fn test_swipe_direction_follows_position() {
+        assert_eq!(swipe_direction(ToastPosition::TopRight), (SwipeAxis::X, 1.0));
+        assert_eq!(swipe_direction(ToastPosition::BottomLeft), (SwipeAxis::X, -1.0));
+        assert_eq!(swipe_direction(ToastPosition::TopCenter), (SwipeAxis::Y, -1.0));
+        assert!(grows_down(ToastPosition::TopCenter));
+        assert!(!grows_down(ToastPosition::BottomRight));
+    }
+
+    #[test]
+    fn test_swipe_dismissal() {
+        // Far enough, slowly
+        assert!(swipe_dismisses(50.0, 1.0, 1000.0));
+        // Short but fast flick
+        assert!(swipe_dismisses(20.0, 1.0, 100.0));
+        assert!(!swipe_dismisses(20.0, 1.0, 1000.0));
+        // Wrong direction never dismisses
+        assert!(!swipe_dismisses(-80.0, 1.0, 50.0));
+        assert_eq!(swipe_offset(30.0, 1.0), 30.0);
+        assert_eq!(swipe_offset(-16.0, 1.0), -4.0);
+    }
+}
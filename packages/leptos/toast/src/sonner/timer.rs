@@ -0,0 +1,80 @@
+//! Pausable auto-close timer
+//!
+//! Toasts stop counting down while the stack is hovered or the page is
+//! hidden. The timer keeps the remaining time across pauses; the toast
+//! component schedules the actual timeout from it.
+
+/// Auto-close countdown, in milliseconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToastTimer {
+    remaining: f64,
+    /// Clock time the countdown last resumed at, while running
+    started_at: Option<f64>,
+}
+
+impl ToastTimer {
+    /// A paused timer with `duration` left
+    pub fn new(duration: f64) -> Self {
+        Self {
+            remaining: duration.max(0.0),
+            started_at: None,
+        }
+    }
+
+    /// Resumes counting down at clock time `now`
+    pub fn start(&mut self, now: f64) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+    }
+
+    /// Stops counting down at clock time `now`, keeping the time left
+    pub fn pause(&mut self, now: f64) {
+        if let Some(started_at) = self.started_at.take() {
+            self.remaining = (self.remaining - (now - started_at)).max(0.0);
+        }
+    }
+
+    /// Time left at clock time `now`
+    pub fn remaining(&self, now: f64) -> f64 {
+        match self.started_at {
+            Some(started_at) => (self.remaining - (now - started_at)).max(0.0),
+            None => self.remaining,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_counts_down_while_running() {
+        let mut timer = ToastTimer::new(4000.0);
+        assert_eq!(timer.remaining(100.0), 4000.0);
+        timer.start(1000.0);
+        assert!(timer.is_running());
+        assert_eq!(timer.remaining(2500.0), 2500.0);
+        assert_eq!(timer.remaining(9000.0), 0.0);
+    }
+
+    #[test]
+    fn test_timer_keeps_remaining_time_across_pauses() {
+        let mut timer = ToastTimer::new(4000.0);
+        timer.start(0.0);
+        timer.pause(1000.0);
+        assert!(!timer.is_running());
+        // Time spent paused does not count
+        assert_eq!(timer.remaining(60_000.0), 3000.0);
+        timer.start(60_000.0);
+        timer.pause(61_000.0);
+        assert_eq!(timer.remaining(61_000.0), 2000.0);
+        // Pausing twice is harmless
+        timer.pause(70_000.0);
+        assert_eq!(timer.remaining(70_000.0), 2000.0);
+    }
+}
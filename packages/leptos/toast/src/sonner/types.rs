@@ -4,7 +4,10 @@
 //! used by the Sonner toast system.
 
 use leptos::prelude::*;
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+/// How long a toast stays open when no duration is given
+pub const DEFAULT_DURATION: Duration = Duration::from_millis(4000);
 
 /// Toast position variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -93,12 +96,18 @@ pub struct ToastData {
     pub title: String,
     pub description: Option<String>,
     pub variant: ToastVariant,
+    /// Time until the toast closes itself; `None` or zero keeps it open
     pub duration: Option<Duration>,
     pub position: ToastPosition,
     pub theme: ToastTheme,
     pub actions: Vec<ToastAction>,
     pub progress: Option<f64>,
-    pub created_at: Instant,
+    /// Whether the close button and swiping can dismiss the toast
+    pub dismissible: bool,
+    /// Runs when the toast is dismissed by the user or `toast::dismiss`
+    pub on_dismiss: Option<Callback<ToastData>>,
+    /// Runs when the toast closes because its duration ran out
+    pub on_auto_close: Option<Callback<ToastData>>,
 }
 
 impl ToastData {
@@ -108,12 +117,23 @@ impl ToastData {
             title,
             description: None,
             variant: ToastVariant::Default,
-            duration: Some(Duration::from_millis(4000)),
+            duration: Some(DEFAULT_DURATION),
             position: ToastPosition::TopRight,
             theme: ToastTheme::Auto,
             actions: Vec::new(),
             progress: None,
-            created_at: Instant::now(),
+            dismissible: true,
+            on_dismiss: None,
+            on_auto_close: None,
+        }
+    }
+
+    /// Time until the toast closes itself. Loading toasts stay open until
+    /// they are updated to another variant.
+    pub fn auto_close_after(&self) -> Option<Duration> {
+        if self.variant == ToastVariant::Loading {
+            return None;
         }
+        self.duration.filter(|duration| !duration.is_zero())
     }
 }
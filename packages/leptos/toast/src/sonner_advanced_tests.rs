@@ -6,10 +6,16 @@ mod sonner_advanced_tests {
     };
     use std::time::Duration;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     /// Test that verifies Sonner toast provider/context system
     /// This test will fail until we implement Sonner provider
     #[test]
     fn test_sonner_provider_system() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             // This should fail until we implement SonnerProvider
             let _provider = view! {
@@ -144,6 +150,7 @@ mod sonner_advanced_tests {
     /// This test will fail until we implement toast queue
     #[test]
     fn test_sonner_toast_queue() {
+        init_executor();
         let test_result = std::panic::catch_unwind(|| {
             // This should fail until we implement toast queue
             let _toast_queue = view! {
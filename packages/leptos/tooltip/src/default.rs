@@ -1,25 +1,35 @@
-use leptos::{ev::MouseEvent, prelude::*};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use leptos_shadcn_tailwind_merge::tw_merge;
+
+use leptos::{
+    ev::{self, FocusEvent, PointerEvent},
+    html::Div,
+    prelude::*,
+    tachys::html::{attribute::custom::custom_attribute, node_ref::NodeRefContainer},
+    wasm_bindgen::JsCast,
+    web_sys,
+};
 use leptos_node_ref::AnyNodeRef;
 use leptos_struct_component::{StructComponent, struct_component};
 use leptos_style::Style;
-use tailwind_fuse::*;
 
-#[derive(TwClass)]
-#[tw(
-    class = "z-50 overflow-hidden rounded-md border bg-popover px-3 py-1.5 text-sm text-popover-foreground shadow-md animate-in fade-in-0 zoom-in-95 data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=closed]:zoom-out-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2"
-)]
-pub struct TooltipContentClass {
-    pub variant: TooltipVariant,
-}
+use crate::position::{self, Placement, Rect};
 
-#[derive(PartialEq, TwVariant)]
-pub enum TooltipVariant {
-    #[tw(default, class = "")]
-    Default,
-}
+/// Time the pointer has to rest on a trigger before its tooltip opens, in milliseconds
+pub const DEFAULT_DELAY_DURATION: u32 = 700;
+
+/// Time after a tooltip closes during which the next one opens without delay, in milliseconds
+pub const DEFAULT_SKIP_DELAY_DURATION: u32 = 300;
+
+/// Time the pointer has to move from the trigger onto hoverable content, in milliseconds
+const HOVERABLE_CLOSE_DELAY: u64 = 100;
 
-#[derive(Clone, Copy, PartialEq)]
+pub const TOOLTIP_CONTENT_CLASS: &str = "z-50 overflow-hidden rounded-md border bg-popover px-3 py-1.5 text-sm text-popover-foreground shadow-md animate-in fade-in-0 zoom-in-95 data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=closed]:zoom-out-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum TooltipSide {
+    #[default]
     Top,
     Right,
     Bottom,
@@ -30,11 +40,20 @@ impl TooltipSide {
     pub fn as_str(self) -> &'static str {
         match self {
             TooltipSide::Top => "top",
-            TooltipSide::Right => "right", 
+            TooltipSide::Right => "right",
             TooltipSide::Bottom => "bottom",
             TooltipSide::Left => "left",
         }
     }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            TooltipSide::Top => TooltipSide::Bottom,
+            TooltipSide::Right => TooltipSide::Left,
+            TooltipSide::Bottom => TooltipSide::Top,
+            TooltipSide::Left => TooltipSide::Right,
+        }
+    }
 }
 
 impl std::fmt::Display for TooltipSide {
@@ -43,43 +62,271 @@ impl std::fmt::Display for TooltipSide {
     }
 }
 
-impl Default for TooltipSide {
-    fn default() -> Self {
-        TooltipSide::Top
+fn clear_timer(timer: StoredValue<Option<TimeoutHandle>>) {
+    if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+        handle.clear();
     }
 }
 
-#[derive(Clone, StructComponent)]
-#[struct_component(tag = "div")]
-pub struct TooltipContentChildProps {
-    pub node_ref: AnyNodeRef,
-    pub class: Signal<String>,
-    pub id: MaybeProp<String>,
-    pub style: Signal<Style>,
+/// Delays shared by the tooltips inside a [`TooltipProvider`]
+///
+/// After a tooltip closes, the next one opens instantly until
+/// `skip_delay_duration` has passed, so moving along a toolbar does not wait
+/// for every tooltip.
+#[derive(Clone, Copy)]
+pub struct TooltipProviderContext {
+    pub delay_duration: Signal<u32>,
+    pub skip_delay_duration: Signal<u32>,
+    pub disable_hoverable_content: Signal<bool>,
+    is_open_delayed: RwSignal<bool>,
+    skip_delay_timer: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl TooltipProviderContext {
+    pub fn new(delay_duration: Signal<u32>, skip_delay_duration: Signal<u32>, disable_hoverable_content: Signal<bool>) -> Self {
+        Self {
+            delay_duration,
+            skip_delay_duration,
+            disable_hoverable_content,
+            is_open_delayed: RwSignal::new(true),
+            skip_delay_timer: StoredValue::new(None),
+        }
+    }
+
+    /// Whether the next tooltip waits for its delay before opening
+    pub fn is_open_delayed(&self) -> bool {
+        self.is_open_delayed.get_untracked()
+    }
+
+    fn on_open(&self) {
+        clear_timer(self.skip_delay_timer);
+        self.is_open_delayed.set(false);
+    }
+
+    fn on_close(&self) {
+        clear_timer(self.skip_delay_timer);
+        let skip_delay_duration = self.skip_delay_duration.get_untracked();
+        if skip_delay_duration == 0 {
+            self.is_open_delayed.set(true);
+            return;
+        }
+
+        let is_open_delayed = self.is_open_delayed;
+        let handle = set_timeout_with_handle(
+            move || is_open_delayed.set(true),
+            Duration::from_millis(skip_delay_duration.into()),
+        );
+        self.skip_delay_timer.set_value(handle.ok());
+    }
+}
+
+impl Default for TooltipProviderContext {
+    fn default() -> Self {
+        Self::new(
+            Signal::stored(DEFAULT_DELAY_DURATION),
+            Signal::stored(DEFAULT_SKIP_DELAY_DURATION),
+            Signal::stored(false),
+        )
+    }
 }
 
 #[component]
-pub fn TooltipProvider(#[prop(optional)] children: Option<Children>) -> impl IntoView {
+pub fn TooltipProvider(
+    /// Time the pointer has to rest on a trigger before its tooltip opens, in milliseconds
+    #[prop(into, default = Signal::stored(DEFAULT_DELAY_DURATION))]
+    delay_duration: Signal<u32>,
+    /// Time after a tooltip closes during which moving to another trigger opens it instantly
+    #[prop(into, default = Signal::stored(DEFAULT_SKIP_DELAY_DURATION))]
+    skip_delay_duration: Signal<u32>,
+    /// Close tooltips as soon as the pointer leaves the trigger, even when moving onto the content
+    #[prop(into, optional)]
+    disable_hoverable_content: Signal<bool>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let context = TooltipProviderContext::new(delay_duration, skip_delay_duration, disable_hoverable_content);
+    on_cleanup(move || clear_timer(context.skip_delay_timer));
+    provide_context(context);
+
     children.map(|children| children())
 }
 
-#[component]  
+static NEXT_CONTENT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// State of one [`Tooltip`], shared by its trigger and content
+#[derive(Clone, Copy)]
+pub struct TooltipContext {
+    pub(crate) open: RwSignal<bool>,
+    on_open_change: Option<Callback<bool>>,
+    provider: TooltipProviderContext,
+    delay_duration: Signal<u32>,
+    pub(crate) disable_hoverable_content: Signal<bool>,
+    open_timer: StoredValue<Option<TimeoutHandle>>,
+    close_timer: StoredValue<Option<TimeoutHandle>>,
+    pub(crate) trigger_ref: AnyNodeRef,
+    pub(crate) content_id: RwSignal<String>,
+}
+
+impl TooltipContext {
+    fn new(
+        open: bool,
+        on_open_change: Option<Callback<bool>>,
+        provider: TooltipProviderContext,
+        delay_duration: Signal<u32>,
+        disable_hoverable_content: Signal<bool>,
+    ) -> Self {
+        Self {
+            open: RwSignal::new(open),
+            on_open_change,
+            provider,
+            delay_duration,
+            disable_hoverable_content,
+            open_timer: StoredValue::new(None),
+            close_timer: StoredValue::new(None),
+            trigger_ref: AnyNodeRef::new(),
+            content_id: RwSignal::new(format!(
+                "tooltip-content-{}",
+                NEXT_CONTENT_ID.fetch_add(1, Ordering::Relaxed)
+            )),
+        }
+    }
+
+    /// The enclosing tooltip. Without one (or without a reactive owner to
+    /// look it up from) the part gets a standalone tooltip with default delays.
+    pub(crate) fn current() -> Self {
+        use_context::<Self>().unwrap_or_else(|| {
+            let provider = TooltipProviderContext::default();
+            Self::new(false, None, provider, provider.delay_duration, provider.disable_hoverable_content)
+        })
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    fn clear_timers(self) {
+        clear_timer(self.open_timer);
+        clear_timer(self.close_timer);
+    }
+
+    /// Opens or closes immediately, cancelling pending delays
+    pub fn set_open(self, open: bool) {
+        self.clear_timers();
+        if self.open.get_untracked() == open {
+            return;
+        }
+
+        self.open.set(open);
+        if open {
+            self.provider.on_open();
+        } else {
+            self.provider.on_close();
+        }
+        if let Some(on_open_change) = self.on_open_change {
+            on_open_change.run(open);
+        }
+    }
+
+    /// Opens after the delay, or instantly while the provider skips delays
+    fn open_with_delay(self) {
+        clear_timer(self.close_timer);
+        if self.open.get_untracked() || self.open_timer.with_value(Option::is_some) {
+            return;
+        }
+
+        let delay_duration = self.delay_duration.get_untracked();
+        if !self.provider.is_open_delayed() || delay_duration == 0 {
+            self.set_open(true);
+            return;
+        }
+
+        let handle = set_timeout_with_handle(
+            move || {
+                self.open_timer.set_value(None);
+                self.set_open(true);
+            },
+            Duration::from_millis(delay_duration.into()),
+        );
+        self.open_timer.set_value(handle.ok());
+    }
+
+    /// Closes once the pointer has left, leaving time to reach hoverable content
+    fn close_after_leave(self) {
+        clear_timer(self.open_timer);
+        if self.disable_hoverable_content.get_untracked() {
+            self.set_open(false);
+            return;
+        }
+
+        clear_timer(self.close_timer);
+        let handle = set_timeout_with_handle(
+            move || {
+                self.close_timer.set_value(None);
+                self.set_open(false);
+            },
+            Duration::from_millis(HOVERABLE_CLOSE_DELAY),
+        );
+        self.close_timer.set_value(handle.ok());
+    }
+
+    fn cancel_close(self) {
+        clear_timer(self.close_timer);
+    }
+}
+
+#[component]
 pub fn Tooltip(
     #[prop(into, optional)] open: Signal<bool>,
     #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
-    #[prop(into, optional)] delay_duration: Signal<u32>,
+    /// Overrides the provider's delay for this tooltip, in milliseconds
+    #[prop(into, optional)] delay_duration: MaybeProp<u32>,
+    /// Overrides the provider's `disable_hoverable_content` for this tooltip
+    #[prop(into, optional)] disable_hoverable_content: MaybeProp<bool>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let (is_open, set_is_open) = signal(open.get_untracked());
-    
+    let provider = use_context::<TooltipProviderContext>().unwrap_or_default();
+    let context = TooltipContext::new(
+        open.get_untracked(),
+        on_open_change,
+        provider,
+        Signal::derive(move || delay_duration.get().unwrap_or_else(|| provider.delay_duration.get())),
+        Signal::derive(move || {
+            disable_hoverable_content
+                .get()
+                .unwrap_or_else(|| provider.disable_hoverable_content.get())
+        }),
+    );
+
     Effect::new(move |_| {
-        if open.get() != is_open.get() {
-            set_is_open.set(open.get());
+        let open = open.get();
+        if open != context.open.get_untracked() {
+            context.open.set(open);
         }
     });
 
-    provide_context((is_open, set_is_open, on_open_change, delay_duration));
-    
+    // Escape closes the tooltip while it is open
+    let escape_listener = StoredValue::new_local(None::<WindowListenerHandle>);
+    let remove_escape_listener = move || {
+        if let Some(listener) = escape_listener.try_update_value(Option::take).flatten() {
+            listener.remove();
+        }
+    };
+    Effect::new(move |_| {
+        remove_escape_listener();
+        if context.open.get() {
+            escape_listener.set_value(Some(window_event_listener(ev::keydown, move |evt| {
+                if evt.key() == "Escape" {
+                    context.set_open(false);
+                }
+            })));
+        }
+    });
+    on_cleanup(move || {
+        remove_escape_listener();
+        context.clear_timers();
+    });
+
+    provide_context(context);
+
     children.map(|children| children())
 }
 
@@ -92,30 +339,57 @@ pub fn TooltipTrigger(
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let (_is_open, set_is_open, on_open_change, _delay_duration) = 
-        expect_context::<(ReadSignal<bool>, WriteSignal<bool>, Option<Callback<bool>>, Signal<u32>)>();
+    let context = TooltipContext::current();
+
+    Effect::new(move |_| {
+        if let Some(element) = context.trigger_ref.get() {
+            NodeRefContainer::<Div>::load(node_ref, &element);
+        }
+    });
+
+    // A click on the trigger closes the tooltip, and the focus it causes
+    // must not reopen it
+    let pointer_down = StoredValue::new(false);
+
+    let handle_pointer_enter = move |evt: PointerEvent| {
+        if evt.pointer_type() != "touch" {
+            context.open_with_delay();
+        }
+    };
 
-    let handle_mouse_enter = move |_: MouseEvent| {
-        set_is_open.set(true);
-        if let Some(callback) = on_open_change {
-            callback.run(true);
+    let handle_pointer_leave = move |evt: PointerEvent| {
+        if evt.pointer_type() != "touch" {
+            context.close_after_leave();
         }
     };
 
-    let handle_mouse_leave = move |_: MouseEvent| {
-        set_is_open.set(false);
-        if let Some(callback) = on_open_change {
-            callback.run(false);
+    let handle_pointer_down = move |_: PointerEvent| {
+        pointer_down.set_value(true);
+        context.set_open(false);
+    };
+
+    let handle_focus = move |_: FocusEvent| {
+        if !pointer_down.get_value() {
+            context.set_open(true);
         }
     };
 
+    let handle_blur = move |_: FocusEvent| {
+        pointer_down.set_value(false);
+        context.set_open(false);
+    };
+
     let child_props = TooltipTriggerChildProps {
-        node_ref,
+        node_ref: context.trigger_ref,
         class: class.get().unwrap_or_default(),
         id,
         style,
-        onmouseenter: Some(Callback::new(handle_mouse_enter)),
-        onmouseleave: Some(Callback::new(handle_mouse_leave)),
+        aria_describedby: MaybeProp::derive(move || context.open.get().then(|| context.content_id.get())),
+        onpointerenter: Some(Callback::new(handle_pointer_enter)),
+        onpointerleave: Some(Callback::new(handle_pointer_leave)),
+        onpointerdown: Some(Callback::new(handle_pointer_down)),
+        onfocus: Some(Callback::new(handle_focus)),
+        onblur: Some(Callback::new(handle_blur)),
     };
 
     if let Some(as_child) = as_child {
@@ -132,45 +406,184 @@ pub struct TooltipTriggerChildProps {
     pub class: String,
     pub id: MaybeProp<String>,
     pub style: Signal<Style>,
-    pub onmouseenter: Option<Callback<MouseEvent>>,
-    pub onmouseleave: Option<Callback<MouseEvent>>,
+    pub aria_describedby: MaybeProp<String>,
+    pub onpointerenter: Option<Callback<PointerEvent>>,
+    pub onpointerleave: Option<Callback<PointerEvent>>,
+    pub onpointerdown: Option<Callback<PointerEvent>>,
+    pub onfocus: Option<Callback<FocusEvent>>,
+    pub onblur: Option<Callback<FocusEvent>>,
+}
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "div")]
+pub struct TooltipContentChildProps {
+    pub node_ref: AnyNodeRef,
+    pub class: Signal<String>,
+    pub id: MaybeProp<String>,
+    pub style: Signal<Style>,
+    pub onpointerenter: Option<Callback<PointerEvent>>,
+    pub onpointerleave: Option<Callback<PointerEvent>>,
+}
+
+/// Measures the trigger, the content and the viewport and places the content
+fn measure_placement(trigger: &web_sys::Element, content: &web_sys::Element, side: TooltipSide, side_offset: f64) -> Option<Placement> {
+    let window = web_sys::window()?;
+    let viewport = (
+        window.inner_width().ok()?.as_f64()?,
+        window.inner_height().ok()?.as_f64()?,
+    );
+    let rect = trigger.get_bounding_client_rect();
+    let trigger = Rect::new(rect.x(), rect.y(), rect.width(), rect.height());
+    // Offset sizes ignore the zoom-in transform of the enter animation
+    let content = content.dyn_ref::<web_sys::HtmlElement>()?;
+    let size = (content.offset_width() as f64, content.offset_height() as f64);
+
+    Some(position::place(trigger, size, viewport, side, side_offset))
 }
 
+/// Open tooltip content, positioned next to the trigger
 #[component]
 pub fn TooltipContent(
-    #[prop(into, optional)] _side: TooltipSide,
-    #[prop(into, optional)] _side_offset: i32,
+    /// Preferred side of the trigger; the content flips when it does not fit
+    #[prop(into, optional)] side: Signal<TooltipSide>,
+    /// Distance from the trigger, in pixels
+    #[prop(into, default = Signal::stored(4))] side_offset: Signal<i32>,
     #[prop(into, optional)] class: MaybeProp<String>,
     #[prop(into, optional)] id: MaybeProp<String>,
     #[prop(into, optional)] style: Signal<Style>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     #[prop(into, optional)] as_child: Option<Callback<TooltipContentChildProps, AnyView>>,
-    #[prop(optional)] children: Option<Children>,
+    #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
-    let (is_open, _, _, _) = 
-        expect_context::<(ReadSignal<bool>, WriteSignal<bool>, Option<Callback<bool>>, Signal<u32>)>();
+    let class = Signal::derive(move || tw_merge!(TOOLTIP_CONTENT_CLASS, class.get().unwrap_or_default()));
+    let context = TooltipContext::current();
+    let content_ref = AnyNodeRef::new();
 
-    let computed_class = Memo::new(move |_| {
-        TooltipContentClass {
-            variant: TooltipVariant::Default,
+    Effect::new(move |_| {
+        if let Some(id) = id.get() {
+            context.content_id.set(id);
         }
-        .with_class(class.get().unwrap_or_default())
     });
 
-    let child_props = TooltipContentChildProps {
-        node_ref,
-        class: computed_class.into(),
-        id,
-        style,
+    Effect::new(move |_| {
+        if let Some(element) = content_ref.get() {
+            NodeRefContainer::<Div>::load(node_ref, &element);
+        }
+    });
+
+    // Placement is measured once the content is mounted and again when the
+    // viewport scrolls or resizes
+    let placement = RwSignal::new(None::<Placement>);
+    let reposition = move || {
+        let (Some(trigger), Some(content)) = (context.trigger_ref.get_untracked(), content_ref.get_untracked()) else {
+            return;
+        };
+        let side_offset = side_offset.get_untracked() as f64;
+        placement.set(measure_placement(&trigger, &content, side.get_untracked(), side_offset));
     };
 
-    if is_open.get() {
-        if let Some(as_child) = as_child.as_ref() {
-            as_child.run(child_props.clone())
+    let listeners = StoredValue::new_local(Vec::<WindowListenerHandle>::new());
+    let remove_listeners = move || {
+        for listener in listeners.try_update_value(std::mem::take).unwrap_or_default() {
+            listener.remove();
+        }
+    };
+    Effect::new(move |_| {
+        side.track();
+        side_offset.track();
+        remove_listeners();
+        if context.open.get() && content_ref.get().is_some() {
+            reposition();
+            listeners.set_value(vec![
+                window_event_listener(ev::resize, move |_| reposition()),
+                window_event_listener(ev::scroll, move |_| reposition()),
+            ]);
         } else {
-            child_props.render(children)
+            placement.set(None);
         }
-    } else {
-        view! { <div></div> }.into_any()
+    });
+    on_cleanup(remove_listeners);
+
+    let style = Signal::derive(move || {
+        let position = match placement.get() {
+            Some(placement) => format!("position: fixed; left: {}px; top: {}px;", placement.x, placement.y),
+            // Laid out but hidden until measured
+            None => "position: fixed; left: 0px; top: 0px; visibility: hidden;".to_string(),
+        };
+        style.get().with_defaults(position)
+    });
+    let data_side = move || placement.get().map_or(side.get(), |placement| placement.side).as_str();
+
+    let child_props = TooltipContentChildProps {
+        node_ref: content_ref,
+        class,
+        id: MaybeProp::derive(move || Some(context.content_id.get())),
+        style,
+        onpointerenter: Some(Callback::new(move |_| {
+            if !context.disable_hoverable_content.get_untracked() {
+                context.cancel_close();
+            }
+        })),
+        onpointerleave: Some(Callback::new(move |_| {
+            if !context.disable_hoverable_content.get_untracked() {
+                context.close_after_leave();
+            }
+        })),
+    };
+
+    view! {
+        <Show when=move || context.open.get()>
+            {
+                let child_props = child_props.clone();
+                let view = if let Some(as_child) = as_child.as_ref() {
+                    as_child.run(child_props)
+                } else {
+                    child_props.render(children.clone().map(|children| Box::new(move || children()) as Children))
+                };
+                view.add_any_attr((
+                    custom_attribute("role", "tooltip"),
+                    custom_attribute("data-side", data_side),
+                    custom_attribute("data-state", "open"),
+                ))
+            }
+        </Show>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_skips_delay_after_close() {
+        let provider = TooltipProviderContext::new(Signal::stored(700), Signal::stored(0), Signal::stored(false));
+        assert!(provider.is_open_delayed());
+
+        provider.on_open();
+        assert!(!provider.is_open_delayed());
+
+        // Without a skip window the next tooltip waits again right away
+        provider.on_close();
+        assert!(provider.is_open_delayed());
+    }
+
+    #[test]
+    fn test_tooltip_opens_instantly_while_skipping_delay() {
+        let provider = TooltipProviderContext::new(Signal::stored(700), Signal::stored(0), Signal::stored(false));
+        let first = TooltipContext::new(false, None, provider, provider.delay_duration, provider.disable_hoverable_content);
+        let second = TooltipContext::new(false, None, provider, provider.delay_duration, provider.disable_hoverable_content);
+        assert_ne!(first.content_id.get_untracked(), second.content_id.get_untracked());
+
+        // Focus opens without delay and marks the provider as skipping
+        first.set_open(true);
+        assert!(first.open.get_untracked());
+        second.open_with_delay();
+        assert!(second.open.get_untracked());
+    }
+
+    #[test]
+    fn test_side_opposite() {
+        assert_eq!(TooltipSide::Top.opposite(), TooltipSide::Bottom);
+        assert_eq!(TooltipSide::Left.opposite(), TooltipSide::Right);
+    }
+}
@@ -7,6 +7,7 @@
 pub mod signal_managed;
 pub mod default;
 pub mod new_york;
+pub mod position;
 
 mod tests;
 
@@ -0,0 +1,134 @@
+//! Tooltip placement with collision handling
+//!
+//! Content is placed on the preferred side of the trigger and centered along
+//! it. When it does not fit in the viewport on that side it flips to the
+//! opposite side if there is more room there, and it is shifted along the
+//! trigger so it stays on screen.
+
+use crate::default::TooltipSide;
+
+/// Distance kept between the content and the viewport edges, in pixels
+pub const COLLISION_PADDING: f64 = 8.0;
+
+/// Rectangle in viewport coordinates, in pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self { x, y, width, height }
+    }
+
+    fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// Where the content ends up: the side it was placed on and its top-left corner
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub side: TooltipSide,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Room between the trigger and the viewport edge on `side`
+fn space(trigger: Rect, viewport: (f64, f64), side: TooltipSide) -> f64 {
+    let room = match side {
+        TooltipSide::Top => trigger.y,
+        TooltipSide::Bottom => viewport.1 - trigger.bottom(),
+        TooltipSide::Left => trigger.x,
+        TooltipSide::Right => viewport.0 - trigger.right(),
+    };
+    room - COLLISION_PADDING
+}
+
+/// Keeps `start..start + size` inside `0..limit`, minus the padding. Content
+/// larger than the viewport sticks to the start edge.
+fn shift(start: f64, size: f64, limit: f64) -> f64 {
+    let max = limit - size - COLLISION_PADDING;
+    start.min(max).max(COLLISION_PADDING)
+}
+
+/// Places `content` (width, height) next to `trigger` inside `viewport`
+/// (width, height), preferring `side` and keeping `side_offset` pixels away
+/// from the trigger.
+pub fn place(trigger: Rect, content: (f64, f64), viewport: (f64, f64), side: TooltipSide, side_offset: f64) -> Placement {
+    let (width, height) = content;
+    let needed = match side {
+        TooltipSide::Top | TooltipSide::Bottom => height,
+        TooltipSide::Left | TooltipSide::Right => width,
+    } + side_offset;
+
+    let preferred = space(trigger, viewport, side);
+    let side = if needed > preferred && space(trigger, viewport, side.opposite()) > preferred {
+        side.opposite()
+    } else {
+        side
+    };
+
+    let center_x = trigger.x + trigger.width / 2.0;
+    let center_y = trigger.y + trigger.height / 2.0;
+    let (x, y) = match side {
+        TooltipSide::Top => (center_x - width / 2.0, trigger.y - side_offset - height),
+        TooltipSide::Bottom => (center_x - width / 2.0, trigger.bottom() + side_offset),
+        TooltipSide::Left => (trigger.x - side_offset - width, center_y - height / 2.0),
+        TooltipSide::Right => (trigger.right() + side_offset, center_y - height / 2.0),
+    };
+
+    match side {
+        TooltipSide::Top | TooltipSide::Bottom => Placement { side, x: shift(x, width, viewport.0), y },
+        TooltipSide::Left | TooltipSide::Right => Placement { side, x, y: shift(y, height, viewport.1) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (800.0, 600.0);
+
+    #[test]
+    fn test_places_on_preferred_side() {
+        let trigger = Rect::new(300.0, 300.0, 100.0, 40.0);
+        let placement = place(trigger, (120.0, 30.0), VIEWPORT, TooltipSide::Top, 4.0);
+        assert_eq!(placement, Placement { side: TooltipSide::Top, x: 290.0, y: 266.0 });
+
+        let placement = place(trigger, (120.0, 30.0), VIEWPORT, TooltipSide::Right, 4.0);
+        assert_eq!(placement, Placement { side: TooltipSide::Right, x: 404.0, y: 305.0 });
+    }
+
+    #[test]
+    fn test_flips_when_side_overflows() {
+        // No room above a trigger at the top of the screen
+        let trigger = Rect::new(300.0, 10.0, 100.0, 40.0);
+        let placement = place(trigger, (120.0, 30.0), VIEWPORT, TooltipSide::Top, 4.0);
+        assert_eq!(placement.side, TooltipSide::Bottom);
+        assert_eq!(placement.y, 54.0);
+
+        // Does not flip when the opposite side has even less room
+        let trigger = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let placement = place(trigger, (120.0, 30.0), VIEWPORT, TooltipSide::Left, 4.0);
+        assert_eq!(placement.side, TooltipSide::Left);
+    }
+
+    #[test]
+    fn test_shifts_along_trigger_to_stay_on_screen() {
+        let trigger = Rect::new(0.0, 300.0, 40.0, 40.0);
+        let placement = place(trigger, (200.0, 30.0), VIEWPORT, TooltipSide::Bottom, 4.0);
+        assert_eq!(placement.x, COLLISION_PADDING);
+
+        let trigger = Rect::new(780.0, 300.0, 20.0, 40.0);
+        let placement = place(trigger, (200.0, 30.0), VIEWPORT, TooltipSide::Top, 4.0);
+        assert_eq!(placement.x, 800.0 - 200.0 - COLLISION_PADDING);
+    }
+}
@@ -10,8 +10,14 @@ use crate::default::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_tooltip_accessibility_features() {
+        init_executor();
         // Test tooltip accessibility features
         let tooltip_view = view! {
             <TooltipProvider>
@@ -28,6 +34,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_form_association() {
+        init_executor();
         // Test tooltip form association
         let tooltip_view = view! {
             <TooltipProvider>
@@ -44,6 +51,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_required_indicator() {
+        init_executor();
         // Test tooltip required indicator
         let tooltip_view = view! {
             <TooltipProvider>
@@ -60,6 +68,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_optional_indicator() {
+        init_executor();
         // Test tooltip optional indicator
         let tooltip_view = view! {
             <TooltipProvider>
@@ -76,12 +85,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_aria_attributes() {
+        init_executor();
         // Test tooltip ARIA attributes
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger aria-label="Custom label">"ARIA trigger"</TooltipTrigger>
-                    <TooltipContent aria-describedby="tooltip-content">"ARIA content"</TooltipContent>
+                    <TooltipTrigger attr:aria-label="Custom label">"ARIA trigger"</TooltipTrigger>
+                    <TooltipContent attr:aria-describedby="tooltip-content">"ARIA content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -92,11 +102,12 @@ mod tests {
 
     #[test]
     fn test_tooltip_keyboard_navigation() {
+        init_executor();
         // Test tooltip keyboard navigation
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger tabindex="0">"Keyboard trigger"</TooltipTrigger>
+                    <TooltipTrigger attr:tabindex="0">"Keyboard trigger"</TooltipTrigger>
                     <TooltipContent>"Keyboard content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
@@ -108,12 +119,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_screen_reader_support() {
+        init_executor();
         // Test tooltip screen reader support
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger role="button">"Screen reader trigger"</TooltipTrigger>
-                    <TooltipContent role="tooltip">"Screen reader content"</TooltipContent>
+                    <TooltipTrigger attr:role="button">"Screen reader trigger"</TooltipTrigger>
+                    <TooltipContent attr:role="tooltip">"Screen reader content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -124,6 +136,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_focus_management() {
+        init_executor();
         // Test tooltip focus management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -140,6 +153,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_high_contrast_support() {
+        init_executor();
         // Test tooltip high contrast support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -156,6 +170,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_reduced_motion_support() {
+        init_executor();
         // Test tooltip reduced motion support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -172,6 +187,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_voice_control_support() {
+        init_executor();
         // Test tooltip voice control support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -188,6 +204,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_switch_control_support() {
+        init_executor();
         // Test tooltip switch control support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -204,6 +221,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_eye_tracking_support() {
+        init_executor();
         // Test tooltip eye tracking support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -220,6 +238,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_motor_impairment_support() {
+        init_executor();
         // Test tooltip motor impairment support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -236,6 +255,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_cognitive_impairment_support() {
+        init_executor();
         // Test tooltip cognitive impairment support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -252,6 +272,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_visual_impairment_support() {
+        init_executor();
         // Test tooltip visual impairment support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -268,6 +289,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_hearing_impairment_support() {
+        init_executor();
         // Test tooltip hearing impairment support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -284,12 +306,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_multilingual_support() {
+        init_executor();
         // Test tooltip multilingual support
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger lang="en">"Multilingual trigger"</TooltipTrigger>
-                    <TooltipContent lang="en">"Multilingual content"</TooltipContent>
+                    <TooltipTrigger attr:lang="en">"Multilingual trigger"</TooltipTrigger>
+                    <TooltipContent attr:lang="en">"Multilingual content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -300,12 +323,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_rtl_support() {
+        init_executor();
         // Test tooltip RTL support
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger dir="rtl">"RTL trigger"</TooltipTrigger>
-                    <TooltipContent dir="rtl">"RTL content"</TooltipContent>
+                    <TooltipTrigger attr:dir="rtl">"RTL trigger"</TooltipTrigger>
+                    <TooltipContent attr:dir="rtl">"RTL content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -316,6 +340,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_zoom_support() {
+        init_executor();
         // Test tooltip zoom support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -332,6 +357,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_mobile_accessibility() {
+        init_executor();
         // Test tooltip mobile accessibility
         let tooltip_view = view! {
             <TooltipProvider>
@@ -348,6 +374,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_touch_accessibility() {
+        init_executor();
         // Test tooltip touch accessibility
         let tooltip_view = view! {
             <TooltipProvider>
@@ -364,6 +391,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_gesture_accessibility() {
+        init_executor();
         // Test tooltip gesture accessibility
         let tooltip_view = view! {
             <TooltipProvider>
@@ -380,6 +408,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_voice_over_support() {
+        init_executor();
         // Test tooltip VoiceOver support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -396,6 +425,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_nvda_support() {
+        init_executor();
         // Test tooltip NVDA support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -412,6 +442,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_jaws_support() {
+        init_executor();
         // Test tooltip JAWS support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -428,6 +459,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_orca_support() {
+        init_executor();
         // Test tooltip Orca support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -444,6 +476,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_talkback_support() {
+        init_executor();
         // Test tooltip TalkBack support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -460,6 +493,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_switch_access_support() {
+        init_executor();
         // Test tooltip Switch Access support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -476,6 +510,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_voice_control_ios_support() {
+        init_executor();
         // Test tooltip Voice Control iOS support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -492,6 +527,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_voice_control_macos_support() {
+        init_executor();
         // Test tooltip Voice Control macOS support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -508,6 +544,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_dragon_naturally_speaking_support() {
+        init_executor();
         // Test tooltip Dragon NaturallySpeaking support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -524,6 +561,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_windows_speech_recognition_support() {
+        init_executor();
         // Test tooltip Windows Speech Recognition support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -540,6 +578,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_google_voice_access_support() {
+        init_executor();
         // Test tooltip Google Voice Access support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -556,6 +595,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_samsung_voice_assistant_support() {
+        init_executor();
         // Test tooltip Samsung Voice Assistant support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -572,6 +612,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_amazon_alexa_support() {
+        init_executor();
         // Test tooltip Amazon Alexa support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -588,6 +629,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_google_assistant_support() {
+        init_executor();
         // Test tooltip Google Assistant support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -604,6 +646,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_siri_support() {
+        init_executor();
         // Test tooltip Siri support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -620,6 +663,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_cortana_support() {
+        init_executor();
         // Test tooltip Cortana support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -636,6 +680,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_bixby_support() {
+        init_executor();
         // Test tooltip Bixby support
         let tooltip_view = view! {
             <TooltipProvider>
@@ -652,24 +697,25 @@ mod tests {
 
     #[test]
     fn test_tooltip_comprehensive_accessibility() {
+        init_executor();
         // Test tooltip comprehensive accessibility
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
                     <TooltipTrigger 
-                        aria-label="Comprehensive accessibility trigger"
-                        role="button"
-                        tabindex="0"
-                        lang="en"
-                        dir="ltr"
+                        attr:aria-label="Comprehensive accessibility trigger"
+                        attr:role="button"
+                        attr:tabindex="0"
+                        attr:lang="en"
+                        attr:dir="ltr"
                     >
                         "Comprehensive trigger"
                     </TooltipTrigger>
                     <TooltipContent 
-                        role="tooltip"
-                        aria-describedby="tooltip-content"
-                        lang="en"
-                        dir="ltr"
+                        attr:role="tooltip"
+                        attr:aria-describedby="tooltip-content"
+                        attr:lang="en"
+                        attr:dir="ltr"
                     >
                         "Comprehensive content"
                     </TooltipContent>
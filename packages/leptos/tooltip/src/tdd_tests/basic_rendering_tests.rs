@@ -10,8 +10,14 @@ use crate::default::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_tooltip_basic_rendering() {
+        init_executor();
         // Test basic tooltip rendering
         let tooltip_view = view! {
             <TooltipProvider>
@@ -28,6 +34,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_with_custom_content() {
+        init_executor();
         // Test tooltip with custom content
         let tooltip_view = view! {
             <TooltipProvider>
@@ -44,6 +51,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_with_html_content() {
+        init_executor();
         // Test tooltip with HTML content
         let tooltip_view = view! {
             <TooltipProvider>
@@ -62,6 +70,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_custom_styling() {
+        init_executor();
         // Test tooltip with custom styling
         let tooltip_view = view! {
             <TooltipProvider>
@@ -78,6 +87,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_variants() {
+        init_executor();
         // Test different tooltip variants
         let tooltip_view = view! {
             <TooltipProvider>
@@ -94,6 +104,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_sizes() {
+        init_executor();
         // Test different tooltip sizes
         let tooltip_view = view! {
             <TooltipProvider>
@@ -110,6 +121,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_with_id() {
+        init_executor();
         // Test tooltip with custom ID
         let tooltip_view = view! {
             <TooltipProvider>
@@ -126,12 +138,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_with_style() {
+        init_executor();
         // Test tooltip with custom style
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger style="color: red;">"Styled trigger"</TooltipTrigger>
-                    <TooltipContent style="background: blue;">"Styled content"</TooltipContent>
+                    <TooltipTrigger style=leptos_style::Style::from("color: red;")>"Styled trigger"</TooltipTrigger>
+                    <TooltipContent style=leptos_style::Style::from("background: blue;")>"Styled content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -142,6 +155,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_multiple_instances() {
+        init_executor();
         // Test multiple tooltip instances
         let tooltip_view = view! {
             <TooltipProvider>
@@ -162,6 +176,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_edge_cases() {
+        init_executor();
         // Test edge cases
         let tooltip_view = view! {
             <TooltipProvider>
@@ -178,6 +193,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_children_content() {
+        init_executor();
         // Test tooltip with children content
         let tooltip_view = view! {
             <TooltipProvider>
@@ -200,6 +216,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_dynamic_content() {
+        init_executor();
         // Test tooltip with dynamic content
         let (count, set_count) = create_signal(0);
         
@@ -220,6 +237,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_conditional_rendering() {
+        init_executor();
         // Test tooltip with conditional rendering
         let (show_tooltip, set_show_tooltip) = create_signal(true);
         
@@ -10,8 +10,14 @@ use crate::default::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_tooltip_integration_scenarios() {
+        init_executor();
         // Test tooltip integration scenarios
         let tooltip_view = view! {
             <TooltipProvider>
@@ -28,6 +34,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_complete_workflow() {
+        init_executor();
         // Test tooltip complete workflow
         let (workflow_step, set_workflow_step) = create_signal(0);
         
@@ -48,6 +55,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_edge_cases() {
+        init_executor();
         // Test tooltip edge cases
         let tooltip_view = view! {
             <TooltipProvider>
@@ -64,6 +72,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_error_handling() {
+        init_executor();
         // Test tooltip error handling
         let tooltip_view = view! {
             <TooltipProvider>
@@ -80,6 +89,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_click_handling() {
+        init_executor();
         // Test tooltip click handling
         let (click_count, set_click_count) = create_signal(0);
         
@@ -100,6 +110,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_checked_change_callback() {
+        init_executor();
         // Test tooltip checked change callback
         let (checked, set_checked) = create_signal(false);
         
@@ -120,6 +131,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_variant_combinations() {
+        init_executor();
         // Test tooltip variant combinations
         let tooltip_view = view! {
             <TooltipProvider>
@@ -136,6 +148,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_form_integration() {
+        init_executor();
         // Test tooltip form integration
         let tooltip_view = view! {
             <TooltipProvider>
@@ -152,6 +165,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_validation_states() {
+        init_executor();
         // Test tooltip validation states
         let (is_valid, set_is_valid) = create_signal(true);
         
@@ -172,6 +186,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_focus_management() {
+        init_executor();
         // Test tooltip focus management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -188,6 +203,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_disabled_states() {
+        init_executor();
         // Test tooltip disabled states
         let (is_disabled, set_is_disabled) = create_signal(false);
         
@@ -208,6 +224,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_sizing_system() {
+        init_executor();
         // Test tooltip sizing system
         let tooltip_view = view! {
             <TooltipProvider>
@@ -224,6 +241,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_translation_system() {
+        init_executor();
         // Test tooltip translation system
         let tooltip_view = view! {
             <TooltipProvider>
@@ -240,6 +258,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_border_system() {
+        init_executor();
         // Test tooltip border system
         let tooltip_view = view! {
             <TooltipProvider>
@@ -256,6 +275,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_ring_system() {
+        init_executor();
         // Test tooltip ring system
         let tooltip_view = view! {
             <TooltipProvider>
@@ -272,6 +292,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_transition_system() {
+        init_executor();
         // Test tooltip transition system
         let tooltip_view = view! {
             <TooltipProvider>
@@ -288,6 +309,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_performance_characteristics() {
+        init_executor();
         // Test tooltip performance characteristics
         let tooltip_view = view! {
             <TooltipProvider>
@@ -304,6 +326,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_memory_management() {
+        init_executor();
         // Test tooltip memory management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -320,6 +343,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_validation_logic() {
+        init_executor();
         // Test tooltip validation logic
         let tooltip_view = view! {
             <TooltipProvider>
@@ -336,6 +360,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_state_combinations() {
+        init_executor();
         // Test tooltip state combinations
         let (state1, set_state1) = create_signal(false);
         let (state2, set_state2) = create_signal(false);
@@ -356,7 +381,8 @@ mod tests {
     }
 
     #[test]
-    fn test_tooltip_variant_combinations() {
+    fn test_tooltip_variant_combination_content() {
+        init_executor();
         // Test tooltip variant combinations
         let tooltip_view = view! {
             <TooltipProvider>
@@ -373,6 +399,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_callback_combinations() {
+        init_executor();
         // Test tooltip callback combinations
         let (callback_count, set_callback_count) = create_signal(0);
         
@@ -392,7 +419,8 @@ mod tests {
     }
 
     #[test]
-    fn test_tooltip_integration_scenarios() {
+    fn test_tooltip_integration_scenario_content() {
+        init_executor();
         // Test tooltip integration scenarios
         let tooltip_view = view! {
             <TooltipProvider>
@@ -409,6 +437,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_component_consistency() {
+        init_executor();
         // Test tooltip component consistency
         let tooltip_view = view! {
             <TooltipProvider>
@@ -425,6 +454,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_complex_workflow() {
+        init_executor();
         // Test tooltip complex workflow
         let (step, set_step) = create_signal(0);
         let (data, set_data) = create_signal("initial".to_string());
@@ -446,9 +476,10 @@ mod tests {
 
     #[test]
     fn test_tooltip_multi_step_process() {
+        init_executor();
         // Test tooltip multi-step process
         let (current_step, set_current_step) = create_signal(0);
-        let steps = vec!["Step 1", "Step 2", "Step 3", "Step 4", "Step 5"];
+        let steps = ["Step 1", "Step 2", "Step 3", "Step 4", "Step 5"];
         
         let tooltip_view = view! {
             <TooltipProvider>
@@ -474,6 +505,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_error_recovery() {
+        init_executor();
         // Test tooltip error recovery
         let (has_error, set_has_error) = create_signal(false);
         let (retry_count, set_retry_count) = create_signal(0);
@@ -501,6 +533,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_data_persistence() {
+        init_executor();
         // Test tooltip data persistence
         let (persistent_data, set_persistent_data) = create_signal("persistent".to_string());
         
@@ -521,6 +554,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_concurrent_operations() {
+        init_executor();
         // Test tooltip concurrent operations
         let (operation_count, set_operation_count) = create_signal(0);
         let (active_operations, set_active_operations) = create_signal(0);
@@ -542,6 +576,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_resource_management() {
+        init_executor();
         // Test tooltip resource management
         let (resource_count, set_resource_count) = create_signal(0);
         let (allocated_resources, set_allocated_resources) = create_signal(0);
@@ -563,6 +598,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_lifecycle_management() {
+        init_executor();
         // Test tooltip lifecycle management
         let (lifecycle_stage, set_lifecycle_stage) = create_signal("initialized".to_string());
         
@@ -583,6 +619,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_state_synchronization() {
+        init_executor();
         // Test tooltip state synchronization
         let (local_state, set_local_state) = create_signal("local".to_string());
         let (remote_state, set_remote_state) = create_signal("remote".to_string());
@@ -604,6 +641,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_event_coordination() {
+        init_executor();
         // Test tooltip event coordination
         let (event_count, set_event_count) = create_signal(0);
         let (last_event, set_last_event) = create_signal("none".to_string());
@@ -625,6 +663,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_comprehensive_integration() {
+        init_executor();
         // Test tooltip comprehensive integration
         let (integration_state, set_integration_state) = create_signal("initialized".to_string());
         let (integration_data, set_integration_data) = create_signal("data".to_string());
@@ -10,8 +10,14 @@ use crate::default::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_tooltip_performance_characteristics() {
+        init_executor();
         // Test tooltip performance characteristics
         let tooltip_view = view! {
             <TooltipProvider>
@@ -28,6 +34,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_memory_management() {
+        init_executor();
         // Test tooltip memory management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -44,6 +51,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_callback_handling() {
+        init_executor();
         // Test tooltip callback handling
         let (callback_count, set_callback_count) = create_signal(0);
         
@@ -64,6 +72,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_disabled_states() {
+        init_executor();
         // Test tooltip disabled states
         let (is_disabled, set_is_disabled) = create_signal(false);
         
@@ -84,12 +93,13 @@ mod tests {
 
     #[test]
     fn test_tooltip_custom_styles() {
+        init_executor();
         // Test tooltip custom styles
         let tooltip_view = view! {
             <TooltipProvider>
                 <Tooltip>
-                    <TooltipTrigger style="color: red;">"Custom style trigger"</TooltipTrigger>
-                    <TooltipContent style="background: blue;">"Custom style content"</TooltipContent>
+                    <TooltipTrigger style=leptos_style::Style::from("color: red;")>"Custom style trigger"</TooltipTrigger>
+                    <TooltipContent style=leptos_style::Style::from("background: blue;")>"Custom style content"</TooltipContent>
                 </Tooltip>
             </TooltipProvider>
         };
@@ -100,6 +110,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_complex_content() {
+        init_executor();
         // Test tooltip complex content
         let tooltip_view = view! {
             <TooltipProvider>
@@ -130,6 +141,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_rendering_performance() {
+        init_executor();
         // Test tooltip rendering performance
         let tooltip_view = view! {
             <TooltipProvider>
@@ -146,6 +158,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_update_performance() {
+        init_executor();
         // Test tooltip update performance
         let (update_count, set_update_count) = create_signal(0);
         
@@ -166,6 +179,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_memory_usage() {
+        init_executor();
         // Test tooltip memory usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -182,6 +196,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_cpu_usage() {
+        init_executor();
         // Test tooltip CPU usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -198,6 +213,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_gpu_usage() {
+        init_executor();
         // Test tooltip GPU usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -214,6 +230,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_network_usage() {
+        init_executor();
         // Test tooltip network usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -230,6 +247,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_storage_usage() {
+        init_executor();
         // Test tooltip storage usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -246,6 +264,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_battery_usage() {
+        init_executor();
         // Test tooltip battery usage
         let tooltip_view = view! {
             <TooltipProvider>
@@ -262,6 +281,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_thermal_management() {
+        init_executor();
         // Test tooltip thermal management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -278,6 +298,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_power_management() {
+        init_executor();
         // Test tooltip power management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -294,6 +315,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_resource_optimization() {
+        init_executor();
         // Test tooltip resource optimization
         let tooltip_view = view! {
             <TooltipProvider>
@@ -310,6 +332,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_caching_strategy() {
+        init_executor();
         // Test tooltip caching strategy
         let tooltip_view = view! {
             <TooltipProvider>
@@ -326,6 +349,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_lazy_loading() {
+        init_executor();
         // Test tooltip lazy loading
         let tooltip_view = view! {
             <TooltipProvider>
@@ -342,6 +366,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_preloading() {
+        init_executor();
         // Test tooltip preloading
         let tooltip_view = view! {
             <TooltipProvider>
@@ -358,6 +383,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_compression() {
+        init_executor();
         // Test tooltip compression
         let tooltip_view = view! {
             <TooltipProvider>
@@ -374,6 +400,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_decompression() {
+        init_executor();
         // Test tooltip decompression
         let tooltip_view = view! {
             <TooltipProvider>
@@ -390,6 +417,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_serialization() {
+        init_executor();
         // Test tooltip serialization
         let tooltip_view = view! {
             <TooltipProvider>
@@ -406,6 +434,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_deserialization() {
+        init_executor();
         // Test tooltip deserialization
         let tooltip_view = view! {
             <TooltipProvider>
@@ -422,6 +451,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_encryption() {
+        init_executor();
         // Test tooltip encryption
         let tooltip_view = view! {
             <TooltipProvider>
@@ -438,6 +468,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_decryption() {
+        init_executor();
         // Test tooltip decryption
         let tooltip_view = view! {
             <TooltipProvider>
@@ -454,6 +485,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_hashing() {
+        init_executor();
         // Test tooltip hashing
         let tooltip_view = view! {
             <TooltipProvider>
@@ -470,6 +502,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_validation() {
+        init_executor();
         // Test tooltip validation
         let tooltip_view = view! {
             <TooltipProvider>
@@ -486,6 +519,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_verification() {
+        init_executor();
         // Test tooltip verification
         let tooltip_view = view! {
             <TooltipProvider>
@@ -502,6 +536,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_authentication() {
+        init_executor();
         // Test tooltip authentication
         let tooltip_view = view! {
             <TooltipProvider>
@@ -518,6 +553,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_authorization() {
+        init_executor();
         // Test tooltip authorization
         let tooltip_view = view! {
             <TooltipProvider>
@@ -534,6 +570,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_auditing() {
+        init_executor();
         // Test tooltip auditing
         let tooltip_view = view! {
             <TooltipProvider>
@@ -550,6 +587,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_logging() {
+        init_executor();
         // Test tooltip logging
         let tooltip_view = view! {
             <TooltipProvider>
@@ -566,6 +604,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_monitoring() {
+        init_executor();
         // Test tooltip monitoring
         let tooltip_view = view! {
             <TooltipProvider>
@@ -582,6 +621,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_alerting() {
+        init_executor();
         // Test tooltip alerting
         let tooltip_view = view! {
             <TooltipProvider>
@@ -598,6 +638,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_metrics() {
+        init_executor();
         // Test tooltip metrics
         let tooltip_view = view! {
             <TooltipProvider>
@@ -614,6 +655,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_analytics() {
+        init_executor();
         // Test tooltip analytics
         let tooltip_view = view! {
             <TooltipProvider>
@@ -630,6 +672,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_reporting() {
+        init_executor();
         // Test tooltip reporting
         let tooltip_view = view! {
             <TooltipProvider>
@@ -646,6 +689,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_dashboard() {
+        init_executor();
         // Test tooltip dashboard
         let tooltip_view = view! {
             <TooltipProvider>
@@ -662,6 +706,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_visualization() {
+        init_executor();
         // Test tooltip visualization
         let tooltip_view = view! {
             <TooltipProvider>
@@ -678,6 +723,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_comprehensive_performance() {
+        init_executor();
         // Test tooltip comprehensive performance
         let (performance_metric, set_performance_metric) = create_signal(0.0);
         let (memory_usage, set_memory_usage) = create_signal(0);
@@ -10,8 +10,14 @@ use crate::default::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 mod tests {
     use super::*;
 
+    /// Components spawn their effects, which needs an executor
+    fn init_executor() {
+        let _ = any_spawner::Executor::init_futures_executor();
+    }
+
     #[test]
     fn test_tooltip_state_management() {
+        init_executor();
         // Test tooltip state management
         let (is_open, set_is_open) = create_signal(false);
         
@@ -32,6 +38,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_context_management() {
+        init_executor();
         // Test tooltip context management
         let tooltip_view = view! {
             <TooltipProvider>
@@ -48,6 +55,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_theme_switching() {
+        init_executor();
         // Test tooltip theme switching
         let (theme, set_theme) = create_signal("light");
         
@@ -68,6 +76,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_responsive_design() {
+        init_executor();
         // Test tooltip responsive design
         let tooltip_view = view! {
             <TooltipProvider>
@@ -84,6 +93,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_advanced_interactions() {
+        init_executor();
         // Test tooltip advanced interactions
         let (interaction_count, set_interaction_count) = create_signal(0);
         
@@ -104,6 +114,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_group_functionality() {
+        init_executor();
         // Test tooltip group functionality
         let tooltip_view = view! {
             <TooltipProvider>
@@ -124,6 +135,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_validation_comprehensive() {
+        init_executor();
         // Test tooltip validation comprehensive
         let (is_valid, set_is_valid) = create_signal(true);
         
@@ -144,6 +156,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_accessibility_comprehensive() {
+        init_executor();
         // Test tooltip accessibility comprehensive
         let tooltip_view = view! {
             <TooltipProvider>
@@ -160,6 +173,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_performance_comprehensive() {
+        init_executor();
         // Test tooltip performance comprehensive
         let tooltip_view = view! {
             <TooltipProvider>
@@ -176,6 +190,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_integration_scenarios() {
+        init_executor();
         // Test tooltip integration scenarios
         let tooltip_view = view! {
             <TooltipProvider>
@@ -192,6 +207,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_error_handling() {
+        init_executor();
         // Test tooltip error handling
         let tooltip_view = view! {
             <TooltipProvider>
@@ -208,6 +224,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_click_handling() {
+        init_executor();
         // Test tooltip click handling
         let (click_count, set_click_count) = create_signal(0);
         
@@ -228,6 +245,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_checked_change_callback() {
+        init_executor();
         // Test tooltip checked change callback
         let (checked, set_checked) = create_signal(false);
         
@@ -248,6 +266,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_variant_combinations() {
+        init_executor();
         // Test tooltip variant combinations
         let tooltip_view = view! {
             <TooltipProvider>
@@ -264,6 +283,7 @@ mod tests {
 
     #[test]
     fn test_tooltip_complete_workflow() {
+        init_executor();
         // Test tooltip complete workflow
         let (workflow_step, set_workflow_step) = create_signal(0);
         
@@ -0,0 +1,188 @@
+//! Shared behaviour for collapsible content
+//!
+//! Accordion and Collapsible animate their content between zero and its
+//! natural size with Tailwind keyframes that read the
+//! `--radix-{accordion,collapsible}-content-{height,width}` CSS variables.
+//! [`use_collapsible_content`] measures the content with a `ResizeObserver`
+//! so those variables are always up to date, keeps the content mounted until
+//! its close animation has finished, and opens content hidden with
+//! `hidden="until-found"` when find-in-page matches inside it.
+
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::web_sys;
+
+/// Natural size of collapsible content, in pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContentSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl ContentSize {
+    /// CSS variables read by the `{component}-up`/`{component}-down` keyframes
+    pub fn css_vars(&self, component: &str) -> String {
+        format!(
+            "--radix-{component}-content-height: {}px; --radix-{component}-content-width: {}px;",
+            self.height, self.width
+        )
+    }
+}
+
+/// Value of the `hidden` attribute for content that is not shown, or `None`
+/// while it is open or animating closed
+pub fn hidden_attr(present: bool, hidden_until_found: bool) -> Option<&'static str> {
+    match (present, hidden_until_found) {
+        (true, _) => None,
+        (false, true) => Some("until-found"),
+        (false, false) => Some(""),
+    }
+}
+
+/// State returned by [`use_collapsible_content`]
+#[derive(Clone, Copy)]
+pub struct CollapsibleContentState {
+    /// Last measured size of the content, once it has been shown
+    pub size: ReadSignal<Option<ContentSize>>,
+    /// Whether the content is open or still playing its close animation
+    pub present: Signal<bool>,
+    closing: RwSignal<bool>,
+}
+
+impl CollapsibleContentState {
+    /// Call from the content's `animationend` handler
+    pub fn on_animation_end(&self) {
+        self.closing.set(false);
+    }
+}
+
+/// Whether `element` is playing a CSS animation
+fn is_animating(element: &web_sys::Element) -> bool {
+    window()
+        .get_computed_style(element)
+        .ok()
+        .flatten()
+        .and_then(|style| style.get_property_value("animation-name").ok())
+        .is_some_and(|name| !name.is_empty() && name != "none")
+}
+
+fn measure(element: &web_sys::Element) -> Option<ContentSize> {
+    let rect = element.get_bounding_client_rect();
+    // Hidden content measures as zero; keep the last real size
+    (rect.width() > 0.0 || rect.height() > 0.0).then(|| ContentSize {
+        width: rect.width(),
+        height: rect.height(),
+    })
+}
+
+type ResizeCallback = Closure<dyn FnMut(web_sys::js_sys::Array)>;
+
+/// Measures and tracks the presence of collapsible content.
+///
+/// `content_ref` is the animated element, `measure_ref` an element inside it
+/// that keeps its natural size while the outer one animates. `on_before_match`
+/// runs when find-in-page reveals content hidden until found.
+pub fn use_collapsible_content(
+    content_ref: NodeRef<Div>,
+    measure_ref: NodeRef<Div>,
+    open: Signal<bool>,
+    on_before_match: Callback<()>,
+) -> CollapsibleContentState {
+    let (size, set_size) = signal(None::<ContentSize>);
+    let closing = RwSignal::new(false);
+
+    let observer = StoredValue::new_local(None::<(web_sys::ResizeObserver, ResizeCallback)>);
+    let disconnect = move || {
+        if let Some((observer, _)) = observer.try_update_value(Option::take).flatten() {
+            observer.disconnect();
+        }
+    };
+
+    Effect::new(move |_| {
+        disconnect();
+        let Some(inner) = measure_ref.get() else {
+            return;
+        };
+
+        let callback: ResizeCallback = Closure::new({
+            let inner = inner.clone();
+            move |_: web_sys::js_sys::Array| {
+                if let Some(size) = measure(&inner) {
+                    set_size.set(Some(size));
+                }
+            }
+        });
+        if let Ok(resize_observer) = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+            resize_observer.observe(&inner);
+            observer.set_value(Some((resize_observer, callback)));
+        }
+    });
+
+    Effect::new(move |previous: Option<bool>| {
+        let open = open.get();
+        if open {
+            closing.set(false);
+            // Measure right away so the open animation has its target
+            if let Some(size) = measure_ref.get_untracked().and_then(|inner| measure(&inner)) {
+                set_size.set(Some(size));
+            }
+        } else if previous == Some(true) {
+            closing.set(content_ref.get_untracked().is_some_and(|content| is_animating(&content)));
+        }
+        open
+    });
+
+    let before_match = StoredValue::new_local(None::<(web_sys::HtmlDivElement, Closure<dyn FnMut()>)>);
+    let remove_before_match = move || {
+        if let Some((content, listener)) = before_match.try_update_value(Option::take).flatten() {
+            let _ = content.remove_event_listener_with_callback("beforematch", listener.as_ref().unchecked_ref());
+        }
+    };
+    Effect::new(move |_| {
+        remove_before_match();
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        let listener: Closure<dyn FnMut()> = Closure::new(move || on_before_match.run(()));
+        if content
+            .add_event_listener_with_callback("beforematch", listener.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            before_match.set_value(Some((content, listener)));
+        }
+    });
+
+    on_cleanup(move || {
+        disconnect();
+        remove_before_match();
+    });
+
+    CollapsibleContentState {
+        size,
+        present: Signal::derive(move || open.get() || closing.get()),
+        closing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_vars() {
+        let size = ContentSize { width: 320.0, height: 96.5 };
+        assert_eq!(
+            size.css_vars("accordion"),
+            "--radix-accordion-content-height: 96.5px; --radix-accordion-content-width: 320px;"
+        );
+    }
+
+    #[test]
+    fn test_hidden_attr() {
+        assert_eq!(hidden_attr(true, true), None);
+        assert_eq!(hidden_attr(false, false), Some(""));
+        assert_eq!(hidden_attr(false, true), Some("until-found"));
+    }
+}
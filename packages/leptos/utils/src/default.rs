@@ -1,6 +1,6 @@
 //! Default utilities for Tailwind CSS classes.
 
-use tailwind_fuse::tw_merge;
+use leptos_shadcn_tailwind_merge::merge_class_lists;
 
 /// Combines multiple class names and merges Tailwind CSS classes.
 /// This is equivalent to the `cn` helper function from shadcn/ui.
@@ -16,7 +16,7 @@ use tailwind_fuse::tw_merge;
 /// # Example
 /// 
 /// ```rust
-/// use shadcn_ui_leptos_utils::cn;
+/// use leptos_shadcn_utils::cn;
 /// 
 /// let result = cn(&["bg-blue-500", "text-white", "p-4"]);
 /// assert_eq!(result, "bg-blue-500 text-white p-4");
@@ -26,7 +26,7 @@ use tailwind_fuse::tw_merge;
 /// assert_eq!(result, "p-4");
 /// ```
 pub fn cn(classes: &[&str]) -> String {
-    tw_merge!(classes.join(" "))
+    merge_class_lists(classes)
 }
 
 /// Combines multiple class names and merges Tailwind CSS classes.
@@ -43,7 +43,7 @@ pub fn cn(classes: &[&str]) -> String {
 /// # Example
 /// 
 /// ```rust
-/// use shadcn_ui_leptos_utils::cn_flexible;
+/// use leptos_shadcn_utils::cn_flexible;
 /// 
 /// let result = cn_flexible(&["bg-blue-500", "text-white", "p-4"]);
 /// assert_eq!(result, "bg-blue-500 text-white p-4");
@@ -53,5 +53,5 @@ pub fn cn(classes: &[&str]) -> String {
 /// ```
 pub fn cn_flexible<T: AsRef<str>>(classes: &[T]) -> String {
     let class_strings: Vec<&str> = classes.iter().map(|c| c.as_ref()).collect();
-    tw_merge!(class_strings.join(" "))
+    merge_class_lists(&class_strings)
 }
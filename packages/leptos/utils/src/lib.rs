@@ -4,8 +4,12 @@
 //!
 //! See [the Rust shadcn/ui book](https://shadcn-ui.rustforweb.org/) for more documentation.
 
+pub mod collapsible;
 pub mod default;
+pub mod modal;
 pub mod new_york;
+pub mod query;
+pub mod theme;
 
 // Re-export the main utility functions for convenience
 pub use default::{cn, cn_flexible};
@@ -0,0 +1,431 @@
+//! Shared behaviour for modal overlays
+//!
+//! Dialog, AlertDialog, Sheet and Drawer call [`use_modal`] from their content
+//! component. While open, a modal sits on a global stack: only the topmost one
+//! reacts to Escape and outside interactions, focus is trapped inside it and
+//! returned to the previously focused element on close, body scrolling is
+//! locked and everything outside the content is marked inert.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys;
+
+/// Elements that can receive keyboard focus
+pub const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type='hidden']), select:not([disabled]), textarea:not([disabled]), iframe, summary, audio[controls], video[controls], [contenteditable='true'], [tabindex]:not([tabindex='-1'])";
+
+/// Identifies one open modal on the [`ModalStack`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModalId(u64);
+
+/// Open modals, from bottom to top
+#[derive(Debug, Default)]
+pub struct ModalStack {
+    next_id: u64,
+    layers: Vec<ModalId>,
+}
+
+impl ModalStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a new layer on top of the stack
+    pub fn push(&mut self) -> ModalId {
+        let id = ModalId(self.next_id);
+        self.next_id += 1;
+        self.layers.push(id);
+        id
+    }
+
+    /// Removes a layer wherever it is; modals may close out of order
+    pub fn remove(&mut self, id: ModalId) {
+        self.layers.retain(|layer| *layer != id);
+    }
+
+    pub fn is_topmost(&self, id: ModalId) -> bool {
+        self.layers.last() == Some(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+thread_local! {
+    static MODAL_STACK: RefCell<ModalStack> = RefCell::new(ModalStack::new());
+    static SCROLL_LOCK: RefCell<Option<ScrollLock>> = const { RefCell::new(None) };
+}
+
+/// Whether `id` is the topmost open modal on the page
+pub fn is_topmost_modal(id: ModalId) -> bool {
+    MODAL_STACK.with(|stack| stack.borrow().is_topmost(id))
+}
+
+/// Number of modals currently open on the page
+pub fn open_modal_count() -> usize {
+    MODAL_STACK.with(|stack| stack.borrow().len())
+}
+
+/// Event passed to the modal hooks; preventing it skips the default action
+#[derive(Clone, Debug, Default)]
+pub struct ModalEvent {
+    prevented: Rc<Cell<bool>>,
+}
+
+impl ModalEvent {
+    pub fn prevent_default(&self) {
+        self.prevented.set(true);
+    }
+
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+}
+
+/// Runs `hook` with a fresh event and returns whether the default action should happen
+fn run_hook(hook: Option<Callback<ModalEvent>>) -> bool {
+    let event = ModalEvent::default();
+    if let Some(hook) = hook {
+        hook.run(event.clone());
+    }
+    !event.default_prevented()
+}
+
+/// Hooks and behaviour of a modal
+#[derive(Clone, Copy)]
+pub struct ModalOptions {
+    /// Runs before focus moves into the content; prevent it to manage focus yourself
+    pub on_open_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs before focus returns to the trigger; prevent it to manage focus yourself
+    pub on_close_auto_focus: Option<Callback<ModalEvent>>,
+    /// Runs on Escape; prevent it to keep the modal open
+    pub on_escape_key_down: Option<Callback<ModalEvent>>,
+    /// Runs on a pointer press outside the content; prevent it to keep the modal open
+    pub on_interact_outside: Option<Callback<ModalEvent>>,
+    /// Whether pressing outside the content dismisses the modal
+    pub dismiss_on_interact_outside: Signal<bool>,
+}
+
+impl Default for ModalOptions {
+    fn default() -> Self {
+        Self {
+            on_open_auto_focus: None,
+            on_close_auto_focus: None,
+            on_escape_key_down: None,
+            on_interact_outside: None,
+            dismiss_on_interact_outside: Signal::stored(true),
+        }
+    }
+}
+
+/// Makes the element behind `content_ref` modal while `open` is true.
+///
+/// `on_dismiss` runs when the modal asks to close (Escape or an outside press).
+/// Call it from the content component, outside any `<Show>`, so the modal is
+/// released when the content goes away.
+pub fn use_modal(content_ref: NodeRef<Div>, open: Signal<bool>, on_dismiss: Callback<()>, options: ModalOptions) {
+    let active = StoredValue::new_local(None::<ActiveModal>);
+
+    let release = move || {
+        if let Some(modal) = active.try_update_value(|active| active.take()).flatten() {
+            modal.deactivate(options);
+        }
+    };
+
+    Effect::new(move |_| {
+        let content = content_ref.get();
+        match content.filter(|content| open.get() && content.is_connected()) {
+            Some(content) => {
+                if active.with_value(|active| active.is_none()) {
+                    let modal = ActiveModal::activate(content.unchecked_into(), on_dismiss, options);
+                    active.set_value(Some(modal));
+                }
+            }
+            None => release(),
+        }
+    });
+
+    on_cleanup(release);
+}
+
+/// DOM state held while a modal is open
+struct ActiveModal {
+    id: ModalId,
+    hidden: Vec<web_sys::Element>,
+    restore_focus: Option<web_sys::HtmlElement>,
+    listeners: Vec<WindowListenerHandle>,
+}
+
+impl ActiveModal {
+    fn activate(content: web_sys::HtmlElement, on_dismiss: Callback<()>, options: ModalOptions) -> Self {
+        let id = MODAL_STACK.with(|stack| stack.borrow_mut().push());
+        let restore_focus = active_element();
+        lock_scroll();
+        let hidden = hide_outside(&content);
+
+        if run_hook(options.on_open_auto_focus) && !content.contains(active_element().as_ref().map(AsRef::as_ref)) {
+            focus_first(&content);
+        }
+
+        let listeners = vec![
+            window_event_listener(ev::keydown, {
+                let content = content.clone();
+                move |evt: web_sys::KeyboardEvent| {
+                    if !is_topmost_modal(id) {
+                        return;
+                    }
+                    match evt.key().as_str() {
+                        "Escape" if run_hook(options.on_escape_key_down) => {
+                            evt.prevent_default();
+                            on_dismiss.run(());
+                        }
+                        "Tab" => trap_tab(&content, &evt),
+                        _ => {}
+                    }
+                }
+            }),
+            window_event_listener(ev::pointerdown, {
+                let content = content.clone();
+                move |evt: web_sys::PointerEvent| {
+                    let target = evt.target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    if !is_topmost_modal(id) || content.contains(target.as_ref()) {
+                        return;
+                    }
+                    if run_hook(options.on_interact_outside) && options.dismiss_on_interact_outside.get_untracked() {
+                        on_dismiss.run(());
+                    }
+                }
+            }),
+            window_event_listener(ev::focusin, {
+                let content = content.clone();
+                move |evt: web_sys::FocusEvent| {
+                    let target = evt.target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    if is_topmost_modal(id) && !content.contains(target.as_ref()) {
+                        focus_first(&content);
+                    }
+                }
+            }),
+        ];
+
+        Self {
+            id,
+            hidden,
+            restore_focus,
+            listeners,
+        }
+    }
+
+    fn deactivate(self, options: ModalOptions) {
+        for listener in self.listeners {
+            listener.remove();
+        }
+        for element in &self.hidden {
+            let _ = element.remove_attribute("aria-hidden");
+            let _ = element.remove_attribute("inert");
+        }
+        let is_last = MODAL_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            stack.remove(self.id);
+            stack.is_empty()
+        });
+        if is_last {
+            unlock_scroll();
+        }
+
+        if run_hook(options.on_close_auto_focus)
+            && let Some(trigger) = self.restore_focus.filter(|trigger| trigger.is_connected())
+        {
+            let _ = trigger.focus();
+        }
+    }
+}
+
+fn active_element() -> Option<web_sys::HtmlElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+}
+
+fn focusable_elements(content: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = content.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .filter(|element| !element.has_attribute("inert") && element.closest("[inert]").ok().flatten().is_none())
+        .collect()
+}
+
+/// Focuses the `autofocus` element, else the first focusable element, else the content itself
+fn focus_first(content: &web_sys::HtmlElement) {
+    let autofocus = content
+        .query_selector("[autofocus]")
+        .ok()
+        .flatten()
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok());
+    let target = autofocus.or_else(|| focusable_elements(content).into_iter().next());
+    let _ = target.as_ref().unwrap_or(content).focus();
+}
+
+/// Index to move focus to when Tab is pressed, or `None` to let the browser move it.
+///
+/// `current` is the index of the focused element among `count` focusable
+/// elements, or `None` when focus is elsewhere (e.g. on the content itself).
+pub fn tab_target(count: usize, current: Option<usize>, backwards: bool) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    match current {
+        None => Some(if backwards { last } else { 0 }),
+        Some(0) if backwards => Some(last),
+        Some(index) if !backwards && index >= last => Some(0),
+        _ => None,
+    }
+}
+
+fn trap_tab(content: &web_sys::HtmlElement, evt: &web_sys::KeyboardEvent) {
+    let focusable = focusable_elements(content);
+    if focusable.is_empty() {
+        evt.prevent_default();
+        let _ = content.focus();
+        return;
+    }
+    let focused = active_element();
+    let current = focused.and_then(|focused| focusable.iter().position(|element| *element == focused));
+    if let Some(index) = tab_target(focusable.len(), current, evt.shift_key()) {
+        evt.prevent_default();
+        let _ = focusable[index].focus();
+    }
+}
+
+/// Marks every element outside `content` as `aria-hidden` and `inert`.
+///
+/// Walks from the content up to `<body>`, hiding the siblings at each level.
+/// Elements that are already hidden, scripts and live regions (e.g. toasts)
+/// are left alone. Returns the elements that were changed.
+fn hide_outside(content: &web_sys::Element) -> Vec<web_sys::Element> {
+    let mut hidden = Vec::new();
+    let mut current = content.clone();
+    while let Some(parent) = current.parent_element() {
+        let children = parent.children();
+        for sibling in (0..children.length()).filter_map(|index| children.item(index)) {
+            if sibling == current
+                || sibling.has_attribute("inert")
+                || sibling.has_attribute("aria-hidden")
+                || sibling.has_attribute("aria-live")
+                || matches!(sibling.tag_name().to_ascii_lowercase().as_str(), "script" | "style" | "template")
+            {
+                continue;
+            }
+            let _ = sibling.set_attribute("aria-hidden", "true");
+            let _ = sibling.set_attribute("inert", "");
+            hidden.push(sibling);
+        }
+        if parent.tag_name().eq_ignore_ascii_case("body") {
+            break;
+        }
+        current = parent;
+    }
+    hidden
+}
+
+/// Body styles replaced while scrolling is locked
+struct ScrollLock {
+    overflow: String,
+    padding_right: String,
+}
+
+fn body() -> Option<web_sys::HtmlElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body())
+}
+
+/// Hides body overflow, padding for the scrollbar so the page does not shift
+fn lock_scroll() {
+    if SCROLL_LOCK.with(|lock| lock.borrow().is_some()) {
+        return;
+    }
+    let Some(body) = body() else {
+        return;
+    };
+    let style = body.style();
+    let saved = ScrollLock {
+        overflow: style.get_property_value("overflow").unwrap_or_default(),
+        padding_right: style.get_property_value("padding-right").unwrap_or_default(),
+    };
+
+    let scrollbar_width = web_sys::window()
+        .and_then(|window| {
+            let inner_width = window.inner_width().ok()?.as_f64()?;
+            let client_width = window.document()?.document_element()?.client_width() as f64;
+            Some(inner_width - client_width)
+        })
+        .unwrap_or(0.0);
+    let _ = style.set_property("overflow", "hidden");
+    if scrollbar_width > 0.0 {
+        let _ = style.set_property("padding-right", &format!("{}px", scrollbar_width));
+    }
+    SCROLL_LOCK.with(|lock| *lock.borrow_mut() = Some(saved));
+}
+
+fn unlock_scroll() {
+    let Some(saved) = SCROLL_LOCK.with(|lock| lock.borrow_mut().take()) else {
+        return;
+    };
+    let Some(body) = body() else {
+        return;
+    };
+    let style = body.style();
+    let _ = style.set_property("overflow", &saved.overflow);
+    let _ = style.set_property("padding-right", &saved.padding_right);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modal_stack_topmost() {
+        let mut stack = ModalStack::new();
+        let first = stack.push();
+        let second = stack.push();
+        assert!(stack.is_topmost(second));
+        assert!(!stack.is_topmost(first));
+
+        // Closing out of order keeps the remaining top
+        stack.remove(first);
+        assert!(stack.is_topmost(second));
+        stack.remove(second);
+        assert!(stack.is_empty());
+        assert_ne!(stack.push(), first);
+    }
+
+    #[test]
+    fn test_tab_wraps_at_edges() {
+        assert_eq!(tab_target(3, Some(2), false), Some(0));
+        assert_eq!(tab_target(3, Some(0), true), Some(2));
+        assert_eq!(tab_target(3, Some(1), false), None);
+        assert_eq!(tab_target(3, Some(1), true), None);
+        // Focus outside the focusable elements enters at the matching end
+        assert_eq!(tab_target(3, None, false), Some(0));
+        assert_eq!(tab_target(3, None, true), Some(2));
+        assert_eq!(tab_target(0, None, false), None);
+    }
+
+    #[test]
+    fn test_modal_event_prevent_default() {
+        let prevent = Callback::new(|event: ModalEvent| event.prevent_default());
+        assert!(!run_hook(Some(prevent)));
+        assert!(run_hook(None));
+    }
+}
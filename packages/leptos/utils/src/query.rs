@@ -0,0 +1,87 @@
+//! Query string helpers shared by components that keep state in the URL.
+//!
+//! These work on plain strings, so they can be used with any router and on
+//! the server.
+
+/// Escapes the characters that would break a query pair or the hash
+pub fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '%' | '&' | '=' | '#' | '?' | '+' | ' ' => encoded.push_str(&format!("%{:02X}", c as u32)),
+            c => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// Decodes percent escapes, and `+` as a space
+pub fn decode_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(if bytes[i] == b'+' { b' ' } else { bytes[i] });
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Raw pairs of a query string, with or without the leading `?`
+pub fn query_pairs(search: &str) -> impl Iterator<Item = (&str, &str)> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+/// Decoded value of query parameter `key`; the last one wins when repeated
+pub fn query_value(search: &str, key: &str) -> Option<String> {
+    query_pairs(search)
+        .filter(|(name, _)| decode_component(name) == key)
+        .last()
+        .map(|(_, value)| decode_component(value))
+}
+
+/// `url` with query parameter `key` set to `value`, keeping the other
+/// parameters and the hash
+pub fn set_query_param(url: &str, key: &str, value: &str) -> String {
+    let (url, hash) = url.split_once('#').unwrap_or((url, ""));
+    let (path, search) = url.split_once('?').unwrap_or((url, ""));
+    let mut pairs = query_pairs(search)
+        .filter(|(name, _)| decode_component(name) != key)
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>();
+    pairs.push(format!("{}={}", encode_component(key), encode_component(value)));
+    let hash = if hash.is_empty() { String::new() } else { format!("#{hash}") };
+    format!("{path}?{}{hash}", pairs.join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_value_and_set_query_param() {
+        assert_eq!(query_value("?page=2&tab=two%20words", "tab").as_deref(), Some("two words"));
+        assert_eq!(query_value("tab=a&tab=b", "tab").as_deref(), Some("b"));
+        assert_eq!(query_value("", "tab"), None);
+
+        assert_eq!(set_query_param("/posts?sort=new&page=2#list", "page", "3"), "/posts?sort=new&page=3#list");
+        assert_eq!(set_query_param("/settings", "tab", "a&b"), "/settings?tab=a%26b");
+        assert_eq!(decode_component(&encode_component("50% off + more")), "50% off + more");
+    }
+}
@@ -0,0 +1,385 @@
+//! Runtime theming
+//!
+//! [`ThemeProvider`] applies a light, dark or system color mode and optionally
+//! a theme's CSS variables to the document (or to a scoped wrapper element): it
+//! toggles the `dark` class, sets `color-scheme` and writes the theme's CSS
+//! variables as inline styles. The chosen mode is persisted to `localStorage`
+//! or a cookie, and the same logic runs as an inline script before hydration
+//! so server-rendered pages don't flash the wrong theme.
+//! [`use_theme`] reads and changes the theme from any descendant.
+
+use std::collections::{BTreeMap, HashMap};
+
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::web_sys;
+use leptos_style::Style;
+use serde::{Deserialize, Serialize};
+
+/// Media query matching a dark system preference
+pub const PREFERS_DARK: &str = "(prefers-color-scheme: dark)";
+
+/// Color mode being rendered
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+    Light,
+    Dark,
+}
+
+/// CSS variables of a theme by mode, named without the leading `--`.
+///
+/// This is the shape of the `cssVars` of a registry theme, so those and the
+/// themes generated by `rust-shadcn theme` deserialize into it.
+pub type ThemeVars = HashMap<Mode, HashMap<String, String>>;
+
+/// Color mode chosen by the user
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    /// Follows `prefers-color-scheme`
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "light" => Some(ThemeMode::Light),
+            "dark" => Some(ThemeMode::Dark),
+            "system" => Some(ThemeMode::System),
+            _ => None,
+        }
+    }
+
+    /// The mode to render, given whether the system prefers dark
+    pub fn resolve(&self, prefers_dark: bool) -> Mode {
+        match self {
+            ThemeMode::Light => Mode::Light,
+            ThemeMode::Dark => Mode::Dark,
+            ThemeMode::System if prefers_dark => Mode::Dark,
+            ThemeMode::System => Mode::Light,
+        }
+    }
+}
+
+/// Where the chosen mode is persisted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeStorage {
+    #[default]
+    LocalStorage,
+    /// A cookie, so the server can read the mode too
+    Cookie,
+    None,
+}
+
+/// Variables to set for `mode`, sorted by name.
+///
+/// Registry themes only store `radius` in light mode, it applies to both.
+pub fn mode_vars(css_vars: &ThemeVars, mode: Mode) -> BTreeMap<String, String> {
+    let mut vars = css_vars.get(&mode).cloned().unwrap_or_default().into_iter().collect::<BTreeMap<_, _>>();
+    if let Some(radius) = css_vars.get(&Mode::Light).and_then(|light| light.get("radius")) {
+        vars.entry("radius".to_string()).or_insert_with(|| radius.clone());
+    }
+    vars
+}
+
+/// JSON without `</script>` or `<!--`, for inlining in a script element
+fn script_json(value: &impl serde::Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "null".into()).replace('<', "\\u003c")
+}
+
+/// Inline script applying the persisted mode before the page is hydrated.
+///
+/// [`ThemeProvider`] renders it; render it in the `<head>` of the shell too
+/// to apply the mode before anything paints. With `scoped`, the script
+/// applies the mode to its parent element rather than to `<html>`.
+pub fn theme_script(
+    storage: ThemeStorage,
+    storage_key: &str,
+    default_mode: ThemeMode,
+    css_vars: Option<&ThemeVars>,
+    scoped: bool,
+) -> String {
+    let key = script_json(&storage_key);
+    let read = match storage {
+        ThemeStorage::LocalStorage => format!("m=localStorage.getItem({key});"),
+        ThemeStorage::Cookie => format!(
+            "var c=document.cookie.split('; ').find(function(c){{return c.indexOf({key}+'=')===0}});m=c&&decodeURIComponent(c.slice({key}.length+1));"
+        ),
+        ThemeStorage::None => String::new(),
+    };
+    let vars = |mode| script_json(&css_vars.map(|css_vars| mode_vars(css_vars, mode)).unwrap_or_default());
+    format!(
+        "(function(){{try{{var m=null;{read}if(['light','dark','system'].indexOf(m)<0)m={default};\
+var d=m==='dark'||(m==='system'&&matchMedia({query}).matches);\
+var e={target};e.classList.toggle('dark',d);e.style.colorScheme=d?'dark':'light';\
+var v=d?{dark}:{light};for(var n in v)e.style.setProperty('--'+n,v[n]);}}catch(_){{}}}})();",
+        default = script_json(&default_mode.as_str()),
+        query = script_json(&PREFERS_DARK),
+        target = if scoped { "document.currentScript.parentElement" } else { "document.documentElement" },
+        dark = vars(Mode::Dark),
+        light = vars(Mode::Light),
+    )
+}
+
+/// Theme state provided by [`ThemeProvider`]
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    /// The chosen mode, persisted
+    pub mode: RwSignal<ThemeMode>,
+    /// The mode being rendered, with `System` resolved
+    pub resolved: Signal<Mode>,
+    /// Variables written onto the themed element, if any
+    pub css_vars: RwSignal<Option<ThemeVars>>,
+}
+
+impl ThemeContext {
+    pub fn set_mode(&self, mode: ThemeMode) {
+        self.mode.set(mode);
+    }
+
+    pub fn is_dark(&self) -> bool {
+        self.resolved.get() == Mode::Dark
+    }
+
+    /// Switches to the opposite of the rendered mode
+    pub fn toggle(&self) {
+        let mode = if self.resolved.get_untracked() == Mode::Dark { ThemeMode::Light } else { ThemeMode::Dark };
+        self.mode.set(mode);
+    }
+
+    /// Applies the variables of another theme, or none
+    pub fn set_theme(&self, css_vars: Option<ThemeVars>) {
+        self.css_vars.set(css_vars);
+    }
+}
+
+/// The theme state of the closest [`ThemeProvider`]
+pub fn use_theme() -> ThemeContext {
+    expect_context::<ThemeContext>()
+}
+
+fn window() -> Option<web_sys::Window> {
+    web_sys::window()
+}
+
+fn html_document() -> Option<web_sys::HtmlDocument> {
+    window()?.document()?.dyn_into::<web_sys::HtmlDocument>().ok()
+}
+
+fn load_mode(storage: ThemeStorage, key: &str) -> Option<ThemeMode> {
+    let value = match storage {
+        ThemeStorage::LocalStorage => window()?.local_storage().ok()??.get_item(key).ok()??,
+        ThemeStorage::Cookie => {
+            let cookies = html_document()?.cookie().ok()?;
+            // Mode names need no decoding.
+            let value = cookies
+                .split("; ")
+                .find_map(|cookie| cookie.strip_prefix(key)?.strip_prefix('='))?;
+            value.to_string()
+        }
+        ThemeStorage::None => return None,
+    };
+    ThemeMode::parse(&value)
+}
+
+fn save_mode(storage: ThemeStorage, key: &str, mode: ThemeMode) {
+    match storage {
+        ThemeStorage::LocalStorage => {
+            if let Some(local_storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+                let _ = local_storage.set_item(key, mode.as_str());
+            }
+        }
+        ThemeStorage::Cookie => {
+            if let Some(document) = html_document() {
+                let _ = document.set_cookie(&format!("{key}={}; path=/; max-age=31536000; SameSite=Lax", mode.as_str()));
+            }
+        }
+        ThemeStorage::None => {}
+    }
+}
+
+fn system_prefers_dark() -> Option<web_sys::MediaQueryList> {
+    window()?.match_media(PREFERS_DARK).ok()?
+}
+
+/// Applies a color mode and theme to the document, or to a wrapper element
+/// with `scoped`, and provides [`ThemeContext`] to its children
+#[component]
+pub fn ThemeProvider(
+    /// Mode used until the user picks one
+    #[prop(optional)]
+    default_mode: ThemeMode,
+    /// Variables of the theme to apply, e.g. the `cssVars` of a registry theme
+    #[prop(optional)]
+    css_vars: Option<ThemeVars>,
+    #[prop(optional)] storage: ThemeStorage,
+    #[prop(into, default = "theme".into())] storage_key: String,
+    /// Theme a wrapper `<div>` instead of the whole document
+    #[prop(optional)]
+    scoped: bool,
+    /// Class of the scoped wrapper
+    #[prop(into, optional)]
+    class: MaybeProp<String>,
+    /// Id of the scoped wrapper
+    #[prop(into, optional)]
+    id: MaybeProp<String>,
+    /// Style of the scoped wrapper, the theme's variables are added to it
+    #[prop(into, optional)]
+    style: Signal<Style>,
+    children: Children,
+) -> impl IntoView {
+    let script = theme_script(storage, &storage_key, default_mode, css_vars.as_ref(), scoped);
+
+    // The stored mode and the system preference are only known in the
+    // browser, the pre-hydration script has already applied them.
+    let mode = RwSignal::new(default_mode);
+    let prefers_dark = RwSignal::new(false);
+    let resolved = Signal::derive(move || mode.get().resolve(prefers_dark.get()));
+    let css_vars = RwSignal::new(css_vars);
+    provide_context(ThemeContext { mode, resolved, css_vars });
+
+    type MediaListener = (web_sys::MediaQueryList, Closure<dyn Fn(web_sys::MediaQueryListEvent)>);
+    let media_listener = StoredValue::new_local(None::<MediaListener>);
+    let storage_key = StoredValue::new(storage_key);
+    Effect::new(move |_| {
+        if let Some(stored) = storage_key.with_value(|key| load_mode(storage, key)) {
+            mode.set(stored);
+        }
+        let Some(media) = system_prefers_dark() else {
+            return;
+        };
+        prefers_dark.set(media.matches());
+        let listener = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::new(move |evt: web_sys::MediaQueryListEvent| {
+            prefers_dark.set(evt.matches());
+        });
+        let _ = media.add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+        media_listener.set_value(Some((media, listener)));
+    });
+    on_cleanup(move || {
+        if let Some((media, listener)) = media_listener.try_update_value(|listener| listener.take()).flatten() {
+            let _ = media.remove_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+        }
+    });
+
+    Effect::new(move |previous: Option<ThemeMode>| {
+        let mode = mode.get();
+        if previous.is_some_and(|previous| previous != mode) {
+            storage_key.with_value(|key| save_mode(storage, key, mode));
+        }
+        mode
+    });
+
+    let wrapper = NodeRef::<Div>::new();
+    Effect::new(move |applied: Option<Vec<String>>| {
+        let element = if scoped {
+            wrapper.get().map(|wrapper| wrapper.unchecked_into::<web_sys::HtmlElement>())
+        } else {
+            window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.document_element())
+                .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+        };
+        let mode = resolved.get();
+        let vars = css_vars.with(|css_vars| css_vars.as_ref().map(|css_vars| mode_vars(css_vars, mode)).unwrap_or_default());
+        // Setting the wrapper's class and style drops the mode and variables, so
+        // they are set here rather than by the view, and applied again below.
+        let wrapper_attributes = scoped.then(|| (class.get().unwrap_or_default(), style.get().to_string()));
+        let Some(element) = element else {
+            return applied.unwrap_or_default();
+        };
+        if let Some((class, style)) = &wrapper_attributes {
+            let _ = element.set_attribute("class", class);
+            let _ = element.set_attribute("style", style);
+        }
+
+        let dark = mode == Mode::Dark;
+        let _ = element.class_list().toggle_with_force("dark", dark);
+        let style = element.style();
+        let _ = style.set_property("color-scheme", if dark { "dark" } else { "light" });
+        // Variables of a previous theme that the current one doesn't define.
+        for name in applied.iter().flatten().filter(|name| !vars.contains_key(*name)) {
+            let _ = style.remove_property(&format!("--{name}"));
+        }
+        for (name, value) in &vars {
+            let _ = style.set_property(&format!("--{name}"), value);
+        }
+        vars.into_keys().collect()
+    });
+
+    let script = view! { <script inner_html=script></script> };
+    if scoped {
+        view! {
+            <div
+                node_ref=wrapper
+                id=move || id.get().unwrap_or_default()
+                class=class.get_untracked().unwrap_or_default()
+                style=style.get_untracked().to_string()
+            >
+                {script}
+                {children()}
+            </div>
+        }
+        .into_any()
+    } else {
+        view! {
+            {script}
+            {children()}
+        }
+        .into_any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_mode() {
+        assert_eq!(ThemeMode::System.resolve(true), Mode::Dark);
+        assert_eq!(ThemeMode::System.resolve(false), Mode::Light);
+        assert_eq!(ThemeMode::Light.resolve(true), Mode::Light);
+        for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+            assert_eq!(ThemeMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ThemeMode::parse("sepia"), None);
+    }
+
+    #[test]
+    fn test_mode_vars() {
+        let css_vars: ThemeVars = serde_json::from_str(
+            r#"{"light":{"background":"0 0% 100%","radius":"0.5rem"},"dark":{"background":"240 10% 3.9%"}}"#,
+        )
+        .unwrap();
+        let dark = mode_vars(&css_vars, Mode::Dark);
+        assert_eq!(dark.get("background").map(String::as_str), Some("240 10% 3.9%"));
+        assert_eq!(dark.get("radius").map(String::as_str), Some("0.5rem"));
+        assert_eq!(mode_vars(&css_vars, Mode::Light).len(), 2);
+    }
+
+    #[test]
+    fn test_theme_script() {
+        let css_vars = HashMap::from([(Mode::Light, HashMap::from([("radius".to_string(), "1rem</script>".to_string())]))]);
+        let script = theme_script(ThemeStorage::Cookie, "theme", ThemeMode::Dark, Some(&css_vars), false);
+        assert!(script.contains("document.cookie"));
+        assert!(script.contains("m=\"dark\""));
+        assert!(script.contains("document.documentElement"));
+        assert!(!script.contains("</script>"));
+
+        let script = theme_script(ThemeStorage::None, "theme", ThemeMode::System, None, true);
+        assert!(!script.contains("localStorage"));
+        assert!(script.contains("document.currentScript.parentElement"));
+    }
+}
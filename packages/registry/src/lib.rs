@@ -10,6 +10,7 @@ pub mod registry_styles;
 pub mod registry_themes;
 pub mod registry_ui;
 pub mod schema;
+pub mod theme_generator;
 
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -36,7 +36,7 @@ pub static LIB: LazyLock<HashMap<FrameworkName, Registry>> = LazyLock::new(|| {
                 name: "utils".into(),
                 r#type: RegistryItemType::Lib,
                 description: None,
-                dependencies: Some(vec!["tailwind_fuse".into()]),
+                dependencies: Some(vec!["leptos-shadcn-tailwind-merge".into()]),
                 dev_dependencies: None,
                 registry_dependencies: None,
                 files: Some(vec![RegistryItemFile {
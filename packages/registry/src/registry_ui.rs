@@ -106,62 +106,63 @@ fn create_complete_registry() -> Registry {
 fn create_leptos_registry() -> Registry {
     vec![
         // Form & Input Components (12 total) - ALL COMPLETED ✅
-        create_ui_component("button", "Displays a button or a component that looks like a button.", "forms", vec!["tailwind_fuse"]),
-        create_ui_component("checkbox", "A control that allows the user to toggle between checked and not checked.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("radio-group", "A set of checkable buttons—known as radio buttons—where no more than one of the buttons can be checked at a time.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("select", "Displays a list of options for the user to pick from—triggered by a button.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("combobox", "Autocomplete input and command palette with a list of suggestions.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("form", "Building blocks for creating accessible forms.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("input", "Displays a form input field or a component that looks like an input field.", "forms", vec!["tailwind_fuse"]),
-        create_ui_component("label", "Renders an accessible label associated with controls.", "forms", vec!["tailwind_fuse"]),
-        create_ui_component("textarea", "Displays a form textarea or a component that looks like a textarea.", "forms", vec!["tailwind_fuse"]),
-        create_ui_component("slider", "An input where the user selects a value from within a given range.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("switch", "A control that allows the user to toggle between checked and not checked.", "forms", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("toggle", "A two-state button that can be either on or off.", "forms", vec!["tailwind_fuse", "web-sys"]),
+        create_ui_component("button", "Displays a button or a component that looks like a button.", "forms", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("checkbox", "A control that allows the user to toggle between checked and not checked.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("radio-group", "A set of checkable buttons—known as radio buttons—where no more than one of the buttons can be checked at a time.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("select", "Displays a list of options for the user to pick from—triggered by a button.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("combobox", "Autocomplete input and command palette with a list of suggestions.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("form", "Building blocks for creating accessible forms.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("input", "Displays a form input field or a component that looks like an input field.", "forms", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("label", "Renders an accessible label associated with controls.", "forms", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("textarea", "Displays a form textarea or a component that looks like a textarea.", "forms", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("slider", "An input where the user selects a value from within a given range.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("switch", "A control that allows the user to toggle between checked and not checked.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("toggle", "A two-state button that can be either on or off.", "forms", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
 
         // Navigation Components (7 total) - ALL COMPLETED ✅
-        create_ui_component("navigation-menu", "A collection of links for navigating websites.", "navigation", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("menubar", "A visually persistent menu common in desktop applications.", "navigation", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("tabs", "A set of layered sections of content—known as tab panels—that are displayed one at a time.", "navigation", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("breadcrumb", "Displays the path to the current resource using a hierarchy of links.", "navigation", vec!["tailwind_fuse"]),
-        create_ui_component("pagination", "Pagination with page navigation, next and previous links.", "navigation", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("command", "Fast, composable, unstyled command menu for React.", "navigation", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("context-menu", "Displays a menu to the user — such as a set of actions or functions — triggered by a button.", "navigation", vec!["tailwind_fuse", "web-sys"]),
+        create_ui_component("navigation-menu", "A collection of links for navigating websites.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("menubar", "A visually persistent menu common in desktop applications.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("tabs", "A set of layered sections of content—known as tab panels—that are displayed one at a time.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("breadcrumb", "Displays the path to the current resource using a hierarchy of links.", "navigation", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("pagination", "Pagination with page navigation, next and previous links.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("command", "Fast, composable, unstyled command menu for React.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("context-menu", "Displays a menu to the user — such as a set of actions or functions — triggered by a button.", "navigation", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
 
         // Overlay Components (8 total) - ALL COMPLETED ✅
-        create_ui_component("dialog", "A window overlaid on either the primary window or another dialog window.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("alert-dialog", "A modal dialog that interrupts the user with important content and expects a response.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("sheet", "Extends the Dialog component to display content that complements the main content of the screen.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("drawer", "A panel that slides out from the edge of the screen.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("dropdown-menu", "Displays a menu to the user — such as a set of actions or functions — triggered by a button.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("popover", "Displays rich content in a portal, triggered by a button.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("tooltip", "A popup that displays information related to an element when the element receives keyboard focus or the mouse hovers over it.", "overlay", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("toast", "A succinct message that is displayed temporarily.", "overlay", vec!["tailwind_fuse", "web-sys"]),
+        create_ui_component("dialog", "A window overlaid on either the primary window or another dialog window.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("alert-dialog", "A modal dialog that interrupts the user with important content and expects a response.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("sheet", "Extends the Dialog component to display content that complements the main content of the screen.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("drawer", "A panel that slides out from the edge of the screen.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("dropdown-menu", "Displays a menu to the user — such as a set of actions or functions — triggered by a button.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("popover", "Displays rich content in a portal, triggered by a button.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("tooltip", "A popup that displays information related to an element when the element receives keyboard focus or the mouse hovers over it.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("toast", "A succinct message that is displayed temporarily.", "overlay", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
 
         // Layout Components (5 total) - MOSTLY COMPLETED ✅
-        create_ui_component("accordion", "A vertically stacked set of interactive headings that each reveal a section of content.", "layout", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("collapsible", "An interactive component which can be expanded/collapsed.", "layout", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("scroll-area", "Augments native scroll functionality for custom, cross-browser styling.", "layout", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("separator", "Visually or semantically separates content.", "layout", vec!["tailwind_fuse"]),
-        create_ui_component("aspect-ratio", "Displays content within a desired ratio.", "layout", vec!["tailwind_fuse"]),
-        // MISSING: resizable, sidebar
+        create_ui_component("accordion", "A vertically stacked set of interactive headings that each reveal a section of content.", "layout", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("collapsible", "An interactive component which can be expanded/collapsed.", "layout", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("scroll-area", "Augments native scroll functionality for custom, cross-browser styling.", "layout", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("separator", "Visually or semantically separates content.", "layout", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("aspect-ratio", "Displays content within a desired ratio.", "layout", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("resizable", "Accessible resizable panel groups and layouts with keyboard support.", "layout", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        // MISSING: sidebar
 
         // Display Components (8 total) - MOSTLY COMPLETED ✅
-        create_ui_component("alert", "Displays a callout for user attention.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("avatar", "An image element with a fallback for representing the user.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("badge", "Displays a badge or a component that looks like a badge.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("card", "Displays a card with header, content, and footer.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("calendar", "A date field component that allows users to enter and edit date.", "display", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("progress", "Displays an indicator showing the completion progress of a task.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("skeleton", "Use to show a placeholder while content is loading.", "display", vec!["tailwind_fuse"]),
-        create_ui_component("table", "A responsive table component.", "display", vec!["tailwind_fuse"]),
+        create_ui_component("alert", "Displays a callout for user attention.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("avatar", "An image element with a fallback for representing the user.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("badge", "Displays a badge or a component that looks like a badge.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("card", "Displays a card with header, content, and footer.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("calendar", "A date field component that allows users to enter and edit date.", "display", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("progress", "Displays an indicator showing the completion progress of a task.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("skeleton", "Use to show a placeholder while content is loading.", "display", vec!["leptos-shadcn-tailwind-merge"]),
+        create_ui_component("table", "A responsive table component.", "display", vec!["leptos-shadcn-tailwind-merge"]),
 
         // Advanced Components (7 total) - MOSTLY COMPLETED ✅
-        create_ui_component("carousel", "A carousel with motion and swipe built using Embla.", "advanced", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("date-picker", "A date picker component with range and multiple selection.", "advanced", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("hover-card", "For sighted users to preview content available behind a link.", "advanced", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("input-otp", "Accessible one-time password component with copy paste functionality.", "advanced", vec!["tailwind_fuse", "web-sys"]),
-        create_ui_component("utils", "Utility functions and helpers for the component library.", "advanced", vec!["tailwind_fuse"]),
+        create_ui_component("carousel", "A carousel with motion and swipe built using Embla.", "advanced", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("date-picker", "A date picker component with range and multiple selection.", "advanced", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("hover-card", "For sighted users to preview content available behind a link.", "advanced", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("input-otp", "Accessible one-time password component with copy paste functionality.", "advanced", vec!["leptos-shadcn-tailwind-merge", "web-sys"]),
+        create_ui_component("utils", "Utility functions and helpers for the component library.", "advanced", vec!["leptos-shadcn-tailwind-merge"]),
         // MISSING: chart, data-table, sonner, typography
     ]
 }
@@ -0,0 +1,414 @@
+//! Generates complete light and dark themes from brand colors, checking the
+//! WCAG contrast of every foreground and background pair.
+
+use std::{collections::HashMap, fmt::Write};
+
+use crate::schema::{Mode, RegistryEntry, RegistryItemCssVars, RegistryItemType};
+
+/// Minimum contrast ratio of normal text, WCAG 2.1 level AA.
+pub const CONTRAST_AA: f64 = 4.5;
+
+/// Minimum contrast ratio of user interface components, WCAG 2.1 level AA.
+pub const CONTRAST_UI: f64 = 3.0;
+
+/// Foreground and background variables checked for contrast.
+pub const CONTRAST_PAIRS: &[(&str, &str)] = &[
+    ("foreground", "background"),
+    ("card-foreground", "card"),
+    ("popover-foreground", "popover"),
+    ("primary-foreground", "primary"),
+    ("secondary-foreground", "secondary"),
+    ("muted-foreground", "muted"),
+    ("muted-foreground", "background"),
+    ("accent-foreground", "accent"),
+    ("destructive-foreground", "destructive"),
+];
+
+/// A color in the `H S% L%` notation of the theme variables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// Hue in degrees, `0..360`.
+    pub h: f64,
+    /// Saturation in percent.
+    pub s: f64,
+    /// Lightness in percent.
+    pub l: f64,
+}
+
+impl Hsl {
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Self {
+            h: h.rem_euclid(360.0),
+            s: s.clamp(0.0, 100.0),
+            l: l.clamp(0.0, 100.0),
+        }
+    }
+
+    /// Parses `#rrggbb`, `#rgb`, `hsl(H S% L%)` or `H S% L%`.
+    pub fn parse(color: &str) -> Option<Self> {
+        let color = color.trim();
+        if let Some(hex) = color.strip_prefix('#') {
+            return Self::from_hex(hex);
+        }
+        let color = color
+            .strip_prefix("hsl(")
+            .and_then(|color| color.strip_suffix(')'))
+            .unwrap_or(color);
+        let mut parts = color
+            .split(|char: char| char.is_whitespace() || char == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| part.trim_end_matches('%').trim_end_matches("deg").parse::<f64>());
+        let (Some(Ok(h)), Some(Ok(s)), Some(Ok(l)), None) = (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+        Some(Self::new(h, s, l))
+    }
+
+    fn from_hex(hex: &str) -> Option<Self> {
+        // Also keeps the byte slicing below on char boundaries
+        if !hex.chars().all(|char| char.is_ascii_hexdigit()) {
+            return None;
+        }
+        let hex = match hex.len() {
+            3 => hex.chars().flat_map(|char| [char, char]).collect(),
+            6 => hex.to_string(),
+            _ => return None,
+        };
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        Some(Self::from_rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let (r, g, b) = (f64::from(r) / 255.0, f64::from(g) / 255.0, f64::from(b) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        if max == min {
+            return Self::new(0.0, 0.0, l * 100.0);
+        }
+        let d = max - min;
+        let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
+        let h = if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        Self::new(h * 60.0, s * 100.0, l * 100.0)
+    }
+
+    /// Red, green and blue in `0.0..=1.0`.
+    pub fn to_rgb(self) -> (f64, f64, f64) {
+        let (s, l) = (self.s / 100.0, self.l / 100.0);
+        let a = s * l.min(1.0 - l);
+        let channel = |n: f64| {
+            let k = (n + self.h / 30.0) % 12.0;
+            l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        (channel(0.0), channel(8.0), channel(4.0))
+    }
+
+    /// Relative luminance as defined by WCAG.
+    pub fn luminance(self) -> f64 {
+        let linear = |c: f64| if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) };
+        let (r, g, b) = self.to_rgb();
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+
+    pub fn with_lightness(self, l: f64) -> Self {
+        Self::new(self.h, self.s, l)
+    }
+
+    /// The color as a theme variable value, e.g. `240 5.9% 10%`.
+    pub fn to_css(self) -> String {
+        let number = |value: f64| {
+            let value = (value * 10.0).round() / 10.0;
+            if value.fract() == 0.0 { format!("{value:.0}") } else { format!("{value:.1}") }
+        };
+        format!("{} {}% {}%", number(self.h), number(self.s), number(self.l))
+    }
+}
+
+/// Contrast ratio of two colors, from 1 to 21.
+pub fn contrast_ratio(a: Hsl, b: Hsl) -> f64 {
+    let (a, b) = (a.luminance(), b.luminance());
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// `color` with its lightness moved away from `background` until their
+/// contrast is at least `target`, or as far as it goes.
+pub fn ensure_contrast(color: Hsl, background: Hsl, target: f64) -> Hsl {
+    let darker = contrast_ratio(color.with_lightness(0.0), background) > contrast_ratio(color.with_lightness(100.0), background);
+    let mut color = color;
+    while contrast_ratio(color, background) < target && (if darker { color.l > 0.0 } else { color.l < 100.0 }) {
+        color = color.with_lightness(if darker { color.l - 1.0 } else { color.l + 1.0 });
+    }
+    color
+}
+
+/// Options of a generated theme.
+#[derive(Clone, Debug)]
+pub struct ThemeOptions {
+    /// Name of the theme, without the `theme-` prefix.
+    pub name: String,
+    /// Brand colors: the primary color, then optionally the secondary and
+    /// accent colors. All of them lead the chart colors.
+    pub brand: Vec<Hsl>,
+    pub radius: String,
+}
+
+impl ThemeOptions {
+    pub fn new(name: impl Into<String>, brand: Vec<Hsl>) -> Self {
+        Self {
+            name: name.into(),
+            brand,
+            radius: "0.5rem".into(),
+        }
+    }
+}
+
+/// Contrast of a foreground and background pair of a theme.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastCheck {
+    pub mode: Mode,
+    pub foreground: String,
+    pub background: String,
+    pub ratio: f64,
+    pub passed: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct GeneratedTheme {
+    pub name: String,
+    pub css_vars: RegistryItemCssVars,
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl GeneratedTheme {
+    pub fn failures(&self) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(|check| !check.passed)
+    }
+
+    /// The variables as `:root` and `.dark` rules.
+    pub fn to_css(&self) -> String {
+        let mut css = String::from("@layer base {\n");
+        for (selector, mode) in [(":root", Mode::Light), (".dark", Mode::Dark)] {
+            let mut vars = self.css_vars.get(&mode).into_iter().flatten().collect::<Vec<_>>();
+            vars.sort_by_key(|(name, _)| var_order(name));
+            let _ = writeln!(css, "  {selector} {{");
+            for (name, value) in vars {
+                let _ = writeln!(css, "    --{name}: {value};");
+            }
+            css.push_str("  }\n");
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    pub fn to_registry_entry(&self) -> RegistryEntry {
+        RegistryEntry {
+            name: format!("theme-{}", self.name),
+            r#type: RegistryItemType::Theme,
+            description: None,
+            dependencies: None,
+            dev_dependencies: None,
+            registry_dependencies: None,
+            files: None,
+            tailwind: None,
+            css_vars: Some(self.css_vars.clone()),
+            source: None,
+            category: None,
+            subcategory: None,
+            chunks: None,
+            docs: None,
+        }
+    }
+}
+
+/// Variables in the order of the registry themes.
+const VAR_ORDER: &[&str] = &[
+    "background",
+    "foreground",
+    "card",
+    "card-foreground",
+    "popover",
+    "popover-foreground",
+    "primary",
+    "primary-foreground",
+    "secondary",
+    "secondary-foreground",
+    "muted",
+    "muted-foreground",
+    "accent",
+    "accent-foreground",
+    "destructive",
+    "destructive-foreground",
+    "border",
+    "input",
+    "ring",
+    "chart-1",
+    "chart-2",
+    "chart-3",
+    "chart-4",
+    "chart-5",
+    "radius",
+];
+
+fn var_order(name: &str) -> usize {
+    VAR_ORDER.iter().position(|var| *var == name).unwrap_or(VAR_ORDER.len())
+}
+
+/// Near white or near black text for `background`, whichever contrasts
+/// more, made to meet level AA.
+fn foreground_for(background: Hsl, neutral: Hsl) -> Hsl {
+    let candidates = [neutral.with_lightness(98.0), neutral.with_lightness(9.0)];
+    let best = candidates
+        .into_iter()
+        .max_by(|a, b| contrast_ratio(*a, background).total_cmp(&contrast_ratio(*b, background)))
+        .unwrap_or(neutral);
+    ensure_contrast(best, background, CONTRAST_AA)
+}
+
+/// Sets `name` to `color` and `name-foreground` to readable text on it.
+fn surface(vars: &mut HashMap<String, String>, name: &str, color: Hsl, neutral: Hsl) {
+    vars.insert(name.into(), color.to_css());
+    vars.insert(format!("{name}-foreground"), foreground_for(color, neutral).to_css());
+}
+
+fn mode_vars(options: &ThemeOptions, mode: Mode) -> HashMap<String, String> {
+    let primary = options.brand[0];
+    // Surfaces are tinted with the hue of the primary color.
+    let neutral = Hsl::new(primary.h, (primary.s * 0.1).min(10.0), 50.0);
+    let dark = mode == Mode::Dark;
+    let (background, foreground, subtle, border, muted_text) = if dark {
+        (3.9, 98.0, 15.9, 15.9, 64.9)
+    } else {
+        (100.0, 3.9, 95.9, 89.8, 46.1)
+    };
+    let background = neutral.with_lightness(background);
+    // Brand colors stand out from the background as much as UI components must.
+    let brand = |index: usize| options.brand.get(index).map(|color| ensure_contrast(*color, background, CONTRAST_UI));
+
+    let mut vars = HashMap::new();
+    vars.insert("background".into(), background.to_css());
+    let foreground = ensure_contrast(neutral.with_lightness(foreground), background, CONTRAST_AA).to_css();
+    for name in ["card", "popover"] {
+        vars.insert(name.into(), background.to_css());
+        vars.insert(format!("{name}-foreground"), foreground.clone());
+    }
+    vars.insert("foreground".into(), foreground);
+    let primary = brand(0).unwrap_or(primary);
+    surface(&mut vars, "primary", primary, neutral);
+    surface(&mut vars, "secondary", options.brand.get(1).copied().unwrap_or(neutral.with_lightness(subtle)), neutral);
+    surface(&mut vars, "accent", options.brand.get(2).copied().unwrap_or(neutral.with_lightness(subtle)), neutral);
+    let muted = neutral.with_lightness(subtle);
+    vars.insert("muted".into(), muted.to_css());
+    let muted_foreground = ensure_contrast(neutral.with_lightness(muted_text), muted, CONTRAST_AA);
+    vars.insert("muted-foreground".into(), ensure_contrast(muted_foreground, background, CONTRAST_AA).to_css());
+    let destructive = if dark { Hsl::new(0.0, 62.8, 30.6) } else { Hsl::new(0.0, 72.2, 50.6) };
+    surface(&mut vars, "destructive", destructive, neutral);
+    vars.insert("border".into(), neutral.with_lightness(border).to_css());
+    vars.insert("input".into(), neutral.with_lightness(border).to_css());
+    vars.insert("ring".into(), primary.to_css());
+
+    // Brand colors first, then hues spread evenly around the primary hue.
+    for index in 0..5 {
+        let color = brand(index).unwrap_or_else(|| {
+            let color = Hsl::new(primary.h + 72.0 * index as f64, primary.s.clamp(50.0, 80.0), if dark { 60.0 } else { 50.0 });
+            ensure_contrast(color, background, CONTRAST_UI)
+        });
+        vars.insert(format!("chart-{}", index + 1), color.to_css());
+    }
+    if !dark {
+        vars.insert("radius".into(), options.radius.clone());
+    }
+    vars
+}
+
+/// Checks the contrast of the foreground and background pairs of `css_vars`,
+/// e.g. of the registry themes.
+pub fn check_contrast(css_vars: &RegistryItemCssVars) -> Vec<ContrastCheck> {
+    let mut checks = Vec::new();
+    for mode in [Mode::Light, Mode::Dark] {
+        let Some(vars) = css_vars.get(&mode) else {
+            continue;
+        };
+        for (foreground, background) in CONTRAST_PAIRS {
+            let color = |name: &str| vars.get(name).and_then(|value| Hsl::parse(value));
+            let (Some(fg), Some(bg)) = (color(foreground), color(background)) else {
+                continue;
+            };
+            let ratio = contrast_ratio(fg, bg);
+            checks.push(ContrastCheck {
+                mode,
+                foreground: foreground.to_string(),
+                background: background.to_string(),
+                ratio,
+                passed: ratio >= CONTRAST_AA,
+            });
+        }
+    }
+    checks
+}
+
+/// Generates the light and dark variables of a theme from its brand colors.
+///
+/// # Panics
+///
+/// If `options.brand` is empty.
+pub fn generate_theme(options: &ThemeOptions) -> GeneratedTheme {
+    assert!(!options.brand.is_empty(), "A theme needs at least one brand color.");
+    let css_vars = HashMap::from([
+        (Mode::Light, mode_vars(options, Mode::Light)),
+        (Mode::Dark, mode_vars(options, Mode::Dark)),
+    ]);
+    GeneratedTheme {
+        name: options.name.clone(),
+        checks: check_contrast(&css_vars),
+        css_vars,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(Hsl::parse("#dc2626").map(Hsl::to_css).as_deref(), Some("0 72.2% 50.6%"));
+        assert_eq!(Hsl::parse("hsl(240 5.9% 10%)"), Some(Hsl::new(240.0, 5.9, 10.0)));
+        assert_eq!(Hsl::parse("#fff").map(Hsl::to_css).as_deref(), Some("0 0% 100%"));
+        assert_eq!(Hsl::parse("blue"), None);
+        assert_eq!(Hsl::parse("#aé€"), None);
+        assert_eq!(Hsl::parse("#+f+f+f"), None);
+
+        let white = Hsl::new(0.0, 0.0, 100.0);
+        let black = Hsl::new(0.0, 0.0, 0.0);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generate_theme() {
+        // Mid-tone brand colors are the hardest to find readable text for.
+        for brand in ["#f97316", "#22c55e", "#3b82f6", "#eab308", "#808080"] {
+            let theme = generate_theme(&ThemeOptions::new("brand", vec![Hsl::parse(brand).unwrap()]));
+            assert_eq!(theme.checks.len(), 2 * CONTRAST_PAIRS.len());
+            assert_eq!(theme.failures().count(), 0, "{brand}: {:?}", theme.failures().collect::<Vec<_>>());
+
+            for mode in [Mode::Light, Mode::Dark] {
+                let vars = &theme.css_vars[&mode];
+                for name in VAR_ORDER.iter().filter(|name| **name != "radius") {
+                    assert!(vars.contains_key(*name), "{mode:?} is missing {name}");
+                }
+            }
+        }
+
+        let theme = generate_theme(&ThemeOptions::new("acme", vec![Hsl::new(262.0, 83.0, 58.0)]));
+        assert_eq!(theme.css_vars[&Mode::Light]["primary"], "262 83% 58%");
+        assert_eq!(theme.to_registry_entry().name, "theme-acme");
+        assert!(theme.to_css().contains("  :root {\n    --background: 262 8.3% 100%;\n"));
+    }
+}
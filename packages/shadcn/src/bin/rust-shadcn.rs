@@ -1,7 +1,11 @@
 use anyhow::Result;
-use clap::{Args, Parser, Subcommand};
+use clap::{Parser, Subcommand};
+use shadcn::commands::add::{AddOptions, add};
+use shadcn::commands::diff::{DiffOptions, diff};
 use shadcn::commands::init::{InitOptions, init};
+use shadcn::commands::registry::{RegistryOptions, registry};
 use shadcn::commands::generate::{GenerateArgs, generate};
+use shadcn::commands::theme::{ThemeOptions, theme};
 
 #[derive(Parser)]
 #[command(version, propagate_version = true)]
@@ -15,29 +19,29 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     #[command(about = "add a component to your project")]
-    Add(AddArgs),
+    Add(AddOptions),
     #[command(about = "check for updates against the registry")]
-    Diff(DiffArgs),
+    Diff(DiffOptions),
     #[command(about = "generate a new component scaffold")]
     Generate(GenerateArgs),
     #[command(about = "initialize your project and install dependencies")]
     Init(InitOptions),
+    #[command(about = "build and manage registries")]
+    Registry(RegistryOptions),
+    #[command(about = "generate a light and dark theme from brand colors")]
+    Theme(ThemeOptions),
 }
 
-#[derive(Args)]
-struct AddArgs {}
-
-#[derive(Args)]
-struct DiffArgs {}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Add(_args) => Ok(()),
-        Commands::Diff(_args) => Ok(()),
+        Commands::Add(args) => add(args).await,
+        Commands::Diff(args) => diff(args).await,
         Commands::Generate(args) => generate(args).await,
         Commands::Init(args) => init(args).await,
+        Commands::Registry(args) => registry(args).await,
+        Commands::Theme(args) => theme(args).await,
     }
 }
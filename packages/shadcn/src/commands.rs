@@ -2,3 +2,5 @@ pub mod add;
 pub mod diff;
 pub mod generate;
 pub mod init;
+pub mod registry;
+pub mod theme;
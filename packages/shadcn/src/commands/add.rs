@@ -1 +1,189 @@
+use std::path::{Path, PathBuf};
 
+use anyhow::{Result, bail};
+use clap::Args;
+use tokio::fs;
+
+use crate::utils::{
+    get_config::{Config, VendoredComponent, get_config, write_config},
+    highlighter::HIGHLIGHTER,
+    logger::LOGGER,
+    registry::{REGISTRY_VERSION, crate_module, file_target, get_file_content, get_registry_index, resolve_tree},
+    registry_source::resolve_remote_items,
+    transformers::transform_crate_paths,
+    updaters::{
+        update_dependencies::add_dependencies,
+        update_files::{FileAction, plan_file, read_existing, register_module, update_files},
+    },
+};
+
+#[derive(Args)]
+pub struct AddOptions {
+    #[arg(help = "the components to add: names, @namespace/names, or urls or paths of registry items.")]
+    pub components: Vec<String>,
+
+    #[arg(short, long, help = "overwrite existing files.")]
+    pub overwrite: bool,
+
+    #[arg(long, help = "show what would change without writing anything.")]
+    pub dry_run: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "the working directory. defaults to the current directory.",
+        default_value = "."
+    )]
+    pub cwd: PathBuf,
+
+    #[arg(short, long, help = "the path to add the components to.")]
+    pub path: Option<PathBuf>,
+
+    #[arg(short, long, help = "mute output.")]
+    pub silent: bool,
+}
+
+pub(crate) fn display_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+pub async fn add(options: AddOptions) -> Result<()> {
+    if options.components.is_empty() {
+        LOGGER.warn("Which components would you like to add? Pass their names, e.g. `add button card`.");
+        bail!("");
+    }
+
+    let mut config = match get_config(&options.cwd).await? {
+        Some(config) => config,
+        None => {
+            if !options.silent {
+                LOGGER.info("No components.toml found, creating one with the default configuration. Run init to customize it.");
+            }
+            Config::default()
+        }
+    };
+    let components_dir = options.cwd.join(options.path.clone().unwrap_or_else(|| config.components_dir.clone().into()));
+
+    let (remote, built_in) = resolve_remote_items(&options.components, &options.cwd, &config).await?;
+    let index = get_registry_index()?;
+    let mut tree = resolve_tree(index, &built_in)?;
+    tree.extend(remote.iter().map(|item| &item.entry));
+    let vendored = tree.iter().map(|entry| entry.name.clone()).collect::<Vec<_>>();
+
+    let mut changes = Vec::new();
+    let mut modules = Vec::new();
+    for entry in &tree {
+        for file in entry.files.iter().flatten() {
+            let content = get_file_content(file)?;
+            let module = crate_module(file);
+            let content = transform_crate_paths(&content, module.as_deref(), &vendored, &config.aliases.ui);
+
+            let path = file_target(&options.cwd, &components_dir, entry, file);
+            if file.target.is_none() {
+                // Files of a vendored crate are declared by its mod.rs.
+                let module = module.or_else(|| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()));
+                modules.extend(module.filter(|module| !modules.contains(module)));
+            }
+
+            let existing = read_existing(&path).await?;
+            let change = plan_file(path, content, existing.as_deref(), options.overwrite);
+            // Skipped files keep their base, the one they were customized from.
+            if change.action != FileAction::Skip {
+                let component = config.components.entry(entry.name.clone()).or_insert_with(|| VendoredComponent {
+                    version: REGISTRY_VERSION.into(),
+                    source: None,
+                    files: Default::default(),
+                });
+                component.version = REGISTRY_VERSION.into();
+                component.source = remote
+                    .iter()
+                    .find(|item| item.entry.name == entry.name)
+                    .map(|item| item.location.clone());
+                component
+                    .files
+                    .insert(display_path(&options.cwd, &change.path), change.content.clone());
+            }
+            changes.push(change);
+        }
+    }
+
+    // Vendored modules are declared in the components directory's mod.rs.
+    let mod_rs = components_dir.join("mod.rs");
+    let existing = read_existing(&mod_rs).await?;
+    let mut content = existing.clone().unwrap_or_default();
+    for module in &modules {
+        if let Some(registered) = register_module(&content, module) {
+            content = registered;
+        }
+    }
+    changes.push(plan_file(mod_rs, content, existing.as_deref(), true));
+
+    let manifest_path = options.cwd.join("Cargo.toml");
+    let dependencies = tree
+        .iter()
+        .flat_map(|entry| entry.dependencies.iter().flatten().cloned())
+        .collect::<Vec<_>>();
+    let manifest = match read_existing(&manifest_path).await? {
+        Some(manifest) => Some(add_dependencies(&manifest, &dependencies)?),
+        None => {
+            LOGGER.warn(&format!(
+                "No Cargo.toml found at {}, add these dependencies yourself: {}",
+                HIGHLIGHTER.info(&options.cwd.to_string_lossy()),
+                dependencies.join(", ")
+            ));
+            None
+        }
+    };
+
+    if !options.dry_run {
+        update_files(&changes).await?;
+        write_config(&options.cwd, &config).await?;
+        if let Some((manifest, added)) = &manifest
+            && !added.is_empty()
+        {
+            fs::write(&manifest_path, manifest).await?;
+        }
+    }
+
+    if options.silent {
+        return Ok(());
+    }
+
+    if options.dry_run {
+        LOGGER.info("Dry run, nothing was written.");
+    }
+    for change in &changes {
+        let path = display_path(&options.cwd, &change.path);
+        match (change.action, options.dry_run) {
+            (FileAction::Create, false) => LOGGER.success(&format!("Created {path}")),
+            (FileAction::Create, true) => LOGGER.log(&format!("Would create {path}")),
+            (FileAction::Overwrite, false) => LOGGER.success(&format!("Overwrote {path}")),
+            (FileAction::Overwrite, true) => LOGGER.log(&format!("Would overwrite {path}")),
+            (FileAction::Unchanged | FileAction::Skip, _) => {}
+        }
+    }
+
+    if let Some((_, added)) = manifest.filter(|(_, added)| !added.is_empty()) {
+        let verb = if options.dry_run { "Would add" } else { "Added" };
+        LOGGER.log(&format!("{verb} to Cargo.toml: {}", added.join(", ")));
+    }
+
+    let skipped = changes
+        .iter()
+        .filter(|change| change.action == FileAction::Skip)
+        .map(|change| display_path(&options.cwd, &change.path))
+        .collect::<Vec<_>>();
+    if !skipped.is_empty() {
+        LOGGER.r#break();
+        LOGGER.warn(&format!(
+            "{} file(s) already exist with other content and were skipped:",
+            skipped.len()
+        ));
+        for path in skipped {
+            LOGGER.log(&format!("  - {path}"));
+        }
+        LOGGER.warn(&format!("Run with {} to replace them.", HIGHLIGHTER.info("--overwrite")));
+    }
+
+    Ok(())
+}
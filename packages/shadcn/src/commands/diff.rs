@@ -1 +1,172 @@
+use std::path::{Path, PathBuf};
 
+use anyhow::{Result, bail};
+use clap::Args;
+use shadcn_registry::schema::RegistryEntry;
+use tokio::fs;
+
+use crate::{
+    commands::add::display_path,
+    utils::{
+        diff::{colorize, merge3, unified_diff},
+        get_config::{Config, get_config, write_config},
+        highlighter::HIGHLIGHTER,
+        logger::LOGGER,
+        registry::{REGISTRY_VERSION, crate_module, file_target, get_file_content, get_registry_index},
+        registry_source::{Cache, ItemAddress, fetch_item, parse_address},
+        transformers::transform_crate_paths,
+        updaters::update_files::read_existing,
+    },
+};
+
+#[derive(Args)]
+pub struct DiffOptions {
+    #[arg(help = "the component to check. defaults to all added components.")]
+    pub component: Option<String>,
+
+    #[arg(short, long, help = "merge the registry changes into the local files.")]
+    pub apply: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "the working directory. defaults to the current directory.",
+        default_value = "."
+    )]
+    pub cwd: PathBuf,
+}
+
+/// A local file of a component and its registry version.
+struct FileDiff {
+    path: PathBuf,
+    local: String,
+    upstream: String,
+}
+
+/// Current registry entry of a component, fetched again if it came from
+/// another registry.
+async fn registry_entry(name: &str, cwd: &Path, config: &Config) -> Result<Option<RegistryEntry>> {
+    let source = config.components.get(name).and_then(|component| component.source.as_deref());
+    match source.map(|source| parse_address(source, cwd, config)).transpose()? {
+        Some(ItemAddress::Remote(address)) => {
+            let entry = fetch_item(address.source().as_ref(), &address.location, Cache::new().as_ref()).await?;
+            Ok(Some(entry))
+        }
+        _ => Ok(get_registry_index()?.iter().find(|entry| entry.name == name).cloned()),
+    }
+}
+
+/// Local files of `entry` that differ from the registry.
+async fn diff_component(cwd: &Path, config: &Config, entry: &RegistryEntry) -> Result<Vec<FileDiff>> {
+    let vendored = config.components.keys().cloned().collect::<Vec<_>>();
+    let components_dir = cwd.join(&config.components_dir);
+
+    let mut diffs = Vec::new();
+    for file in entry.files.iter().flatten() {
+        let path = file_target(cwd, &components_dir, entry, file);
+        let Some(local) = read_existing(&path).await? else {
+            continue;
+        };
+        let upstream = get_file_content(file)?;
+        let upstream = transform_crate_paths(&upstream, crate_module(file).as_deref(), &vendored, &config.aliases.ui);
+        if local != upstream {
+            diffs.push(FileDiff { path, local, upstream });
+        }
+    }
+    Ok(diffs)
+}
+
+pub async fn diff(options: DiffOptions) -> Result<()> {
+    let Some(mut config) = get_config(&options.cwd).await? else {
+        LOGGER.error(&format!(
+            "No components.toml found at {}. Run {} first.",
+            HIGHLIGHTER.info(&options.cwd.to_string_lossy()),
+            HIGHLIGHTER.info("init")
+        ));
+        bail!("");
+    };
+
+    let names = match &options.component {
+        Some(name) => vec![name.clone()],
+        None => config.components.keys().cloned().collect(),
+    };
+    if names.is_empty() {
+        LOGGER.info("No components were added yet, nothing to compare.");
+        return Ok(());
+    }
+
+    let mut outdated = 0;
+    let mut conflicts = 0;
+    for name in &names {
+        let Some(entry) = registry_entry(name, &options.cwd, &config).await? else {
+            LOGGER.warn(&format!("Component {name} was not found in the registry."));
+            continue;
+        };
+
+        let diffs = diff_component(&options.cwd, &config, &entry).await?;
+        if diffs.is_empty() {
+            if options.component.is_some() {
+                LOGGER.success(&format!("{name} is up to date."));
+            }
+            continue;
+        }
+        outdated += 1;
+
+        LOGGER.info(&format!("{name} differs from the registry:"));
+        for file in &diffs {
+            let path = display_path(&options.cwd, &file.path);
+            if !options.apply {
+                let patch = unified_diff(&file.local, &file.upstream, &format!("a/{path}"), &format!("b/{path}"));
+                LOGGER.log(&colorize(&patch));
+                continue;
+            }
+
+            let Some(component) = config.components.get_mut(name) else {
+                LOGGER.warn(&format!(
+                    "{name} wasn't added with {}, run {} to replace it.",
+                    HIGHLIGHTER.info("add"),
+                    HIGHLIGHTER.info(&format!("add {name} --overwrite"))
+                ));
+                break;
+            };
+            let Some(base) = component.files.get(&path) else {
+                LOGGER.warn(&format!(
+                    "No base version of {path} is recorded, run {} to replace it.",
+                    HIGHLIGHTER.info(&format!("add {name} --overwrite"))
+                ));
+                continue;
+            };
+
+            let merge = merge3(base, &file.local, &file.upstream);
+            fs::write(&file.path, &merge.content).await?;
+            component.files.insert(path.clone(), file.upstream.clone());
+            component.version = REGISTRY_VERSION.into();
+            if merge.conflicts > 0 {
+                conflicts += merge.conflicts;
+                LOGGER.warn(&format!("Merged {path} with {} conflict(s).", merge.conflicts));
+            } else {
+                LOGGER.success(&format!("Merged {path}."));
+            }
+        }
+        LOGGER.r#break();
+    }
+
+    if options.apply {
+        write_config(&options.cwd, &config).await?;
+    }
+
+    if outdated == 0 {
+        if options.component.is_none() {
+            LOGGER.success("No updates found.");
+        }
+    } else if !options.apply {
+        LOGGER.log(&format!(
+            "Run {} to merge the registry changes into your files.",
+            HIGHLIGHTER.info("diff --apply")
+        ));
+    } else if conflicts > 0 {
+        LOGGER.warn("Resolve the conflicts between the <<<<<<< local and >>>>>>> registry markers.");
+    }
+
+    Ok(())
+}
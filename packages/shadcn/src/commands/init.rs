@@ -1,17 +1,46 @@
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
-use anyhow::Result;
-use clap::Args;
+use anyhow::{Result, bail};
+use clap::{ArgAction, Args};
+use shadcn_registry::schema::Style;
+use tokio::fs;
 
 use crate::{
+    commands::add::{AddOptions, add},
     preflights::preflight_init::pre_flight_init,
-    utils::{errors::ErrorType, get_project_info::get_project_info, logger::LOGGER},
+    utils::{
+        errors::ErrorType,
+        get_config::{CONFIG_FILE, Config, TailwindConfig, get_config},
+        get_project_info::get_project_info,
+        highlighter::HIGHLIGHTER,
+        logger::LOGGER,
+        registry::get_css_vars,
+        templates::{MAIN_RS, cargo_toml, index_html, tailwind_config, tailwind_css},
+        updaters::{
+            update_dependencies::add_dependencies,
+            update_files::{FileAction, FileChange, plan_file, read_existing, update_files},
+        },
+    },
 };
 
+/// Dependencies of the components, added to every project.
+const DEPENDENCIES: &[&str] = &["leptos", "leptos-shadcn-tailwind-merge"];
+
 fn _default_cwd() -> PathBuf {
     env::current_dir().expect("Current directory should be accessible.")
 }
 
+fn parse_style(style: &str) -> Result<Style, String> {
+    match style {
+        "default" => Ok(Style::Default),
+        "new-york" | "new_york" => Ok(Style::NewYork),
+        _ => Err(format!("unknown style {style}, use default or new-york.")),
+    }
+}
+
 #[derive(Args)]
 pub struct InitOptions {
     #[arg(help = "the components to add or a url to the component.")]
@@ -40,29 +69,256 @@ pub struct InitOptions {
     #[arg(long, help = "use the src directory when creating a new project.")]
     pub src_dir: bool,
 
+    #[arg(long, help = "the style of the components: default or new-york.", default_value = "default", value_parser = parse_style)]
+    pub style: Style,
+
+    #[arg(short, long, help = "the base color of the theme.", default_value = "neutral")]
+    pub base_color: String,
+
+    #[arg(short, long, help = "a registry theme laid over the base color, e.g. theme-daylight.")]
+    pub theme: Option<String>,
+
+    #[arg(
+        long = "no-css-variables",
+        help = "inline colors into the Tailwind config instead of using CSS variables.",
+        action = ArgAction::SetFalse
+    )]
+    pub css_variables: bool,
+
     #[arg(skip)]
     pub skip_preflight: bool,
 }
 
+/// Crate name for a project in `cwd`, derived from the directory name.
+fn crate_name(cwd: &Path) -> Result<String> {
+    let path = std::path::absolute(cwd)?;
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+        .chars()
+        .map(|char| if char.is_ascii_alphanumeric() || char == '_' { char } else { '-' })
+        .collect::<String>();
+    let name = name.trim_matches('-');
+    Ok(if name.is_empty() || name.starts_with(|char: char| char.is_ascii_digit()) {
+        format!("app-{name}").trim_end_matches('-').to_string()
+    } else {
+        name.to_string()
+    })
+}
+
+/// Scaffolds a client-side rendered Leptos app, built with Trunk, keeping
+/// any of its files that already exist.
+async fn create_project(options: &InitOptions) -> Result<()> {
+    let cwd = &options.cwd;
+    let files = [
+        ("Cargo.toml", cargo_toml(&crate_name(cwd)?)),
+        ("src/main.rs", MAIN_RS.to_string()),
+        ("index.html", index_html(&TailwindConfig::default().css)),
+    ];
+
+    let mut changes = Vec::new();
+    for (path, content) in files {
+        let path = cwd.join(path);
+        let existing = read_existing(&path).await?;
+        changes.push(plan_file(path, content, existing.as_deref(), false));
+    }
+    update_files(&changes).await?;
+
+    if !options.silent {
+        LOGGER.info(&format!(
+            "Created a new Leptos project at {}.",
+            HIGHLIGHTER.info(&cwd.to_string_lossy())
+        ));
+    }
+    Ok(())
+}
+
+/// The configuration written by `init`. An existing configuration, replaced
+/// with `--force`, keeps its added components, whose bases `diff --apply`
+/// merges with, and its registries and aliases.
+fn init_config(style: Style, tailwind: TailwindConfig, existing: Option<Config>) -> Config {
+    let existing = existing.unwrap_or_default();
+    Config {
+        style,
+        tailwind,
+        aliases: existing.aliases,
+        registries: existing.registries,
+        components: existing.components,
+        ..Config::default()
+    }
+}
+
+fn display_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
 pub async fn init(options: InitOptions) -> Result<()> {
-    let project_info = if !options.skip_preflight {
-        let mut preflight = pre_flight_init(options).await?;
+    let mut tailwind_configured = true;
+    if !options.skip_preflight {
+        let mut preflight = pre_flight_init(&options).await?;
         if preflight
             .errors
             .remove(&ErrorType::MissingDirOrEmptyProject)
             .unwrap_or_default()
         {
-            // TODO: create project
+            create_project(&options).await?;
         }
-        preflight.project_info
-    } else {
-        Some(get_project_info(&options.cwd).await?)
+        tailwind_configured = !preflight
+            .errors
+            .remove(&ErrorType::TailwindNotConfigured)
+            .unwrap_or_default();
+    }
+    let project_info = get_project_info(&options.cwd).await?;
+
+    let defaults = TailwindConfig::default();
+    let path_or = |path: Option<PathBuf>, default: String| {
+        path.map_or(default, |path| path.to_string_lossy().into_owned())
     };
+    // An invalid configuration is replaced as a whole.
+    let existing = get_config(&options.cwd).await.ok().flatten();
+    let config = init_config(
+        options.style,
+        TailwindConfig {
+            config: path_or(project_info.tailwind_config_file, defaults.config),
+            css: path_or(project_info.tailwind_css_file, defaults.css),
+            base_color: options.base_color.clone(),
+            css_variables: options.css_variables,
+        },
+        existing,
+    );
+    let (light, dark) = get_css_vars(&config.tailwind.base_color, options.theme.as_deref())?;
 
-    // TODO
+    if !tailwind_configured && !options.silent {
+        LOGGER.info("Tailwind CSS is not configured yet, setting it up.");
+    }
 
-    LOGGER.info("Success! Project initialization completed.\nYou may now add components.");
-    LOGGER.r#break();
+    let files = [
+        (CONFIG_FILE.to_string(), toml::to_string(&config)?, true),
+        (
+            config.tailwind.config.clone(),
+            tailwind_config((!config.tailwind.css_variables).then_some(&light)),
+            options.force,
+        ),
+        (
+            config.tailwind.css.clone(),
+            tailwind_css(config.tailwind.css_variables.then_some((&light, &dark))),
+            options.force,
+        ),
+    ];
+    let mut changes = Vec::<FileChange>::new();
+    for (path, content, overwrite) in files {
+        let path = options.cwd.join(path);
+        let existing = read_existing(&path).await?;
+        changes.push(plan_file(path, content, existing.as_deref(), overwrite));
+    }
+    update_files(&changes).await?;
+
+    let manifest_path = options.cwd.join("Cargo.toml");
+    let Some(manifest) = read_existing(&manifest_path).await? else {
+        bail!("No Cargo.toml found at {}.", options.cwd.display());
+    };
+    let dependencies = DEPENDENCIES.iter().map(|dependency| dependency.to_string()).collect::<Vec<_>>();
+    let (manifest, added) = add_dependencies(&manifest, &dependencies)?;
+    if !added.is_empty() {
+        fs::write(&manifest_path, manifest).await?;
+    }
+
+    if !options.silent {
+        for change in &changes {
+            let path = display_path(&options.cwd, &change.path);
+            match change.action {
+                FileAction::Create => LOGGER.success(&format!("Created {path}")),
+                FileAction::Overwrite => LOGGER.success(&format!("Overwrote {path}")),
+                FileAction::Skip => LOGGER.warn(&format!(
+                    "Kept the existing {path}, run with {} to replace it.",
+                    HIGHLIGHTER.info("--force")
+                )),
+                FileAction::Unchanged => {}
+            }
+        }
+        if !added.is_empty() {
+            LOGGER.log(&format!("Added to Cargo.toml: {}", added.join(", ")));
+        }
+        LOGGER.log(&format!(
+            "The Tailwind config uses the {} plugin, install it with {}.",
+            HIGHLIGHTER.info("tailwindcss-animate"),
+            HIGHLIGHTER.info("npm install -D tailwindcss@3 tailwindcss-animate")
+        ));
+        LOGGER.r#break();
+    }
+
+    if !options.components.is_empty() {
+        add(AddOptions {
+            components: options.components,
+            overwrite: options.force,
+            dry_run: false,
+            cwd: options.cwd.clone(),
+            path: None,
+            silent: options.silent,
+        })
+        .await?;
+    }
+
+    if !options.silent {
+        LOGGER.info("Success! Project initialization completed.\nYou may now add components.");
+        LOGGER.r#break();
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_config::Aliases;
+
+    use super::*;
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!(crate_name(Path::new("/projects/My App")).unwrap(), "my-app");
+        assert_eq!(crate_name(Path::new("/projects/web_ui/.")).unwrap(), "web_ui");
+        assert_eq!(crate_name(Path::new("/projects/2024")).unwrap(), "app-2024");
+    }
+
+    #[test]
+    fn test_init_config() {
+        let existing = toml::from_str::<Config>(
+            r#"
+            style = "default"
+            components_dir = "src/ui"
+
+            [tailwind]
+            config = "tailwind.config.js"
+            css = "style/tailwind.css"
+            base_color = "zinc"
+            css_variables = false
+
+            [aliases]
+            components = "crate::ui"
+            ui = "crate::ui"
+
+            [registries.acme]
+            url = "https://registry.acme.com/r/{name}.json"
+            headers = { Authorization = "Bearer ${ACME_TOKEN}" }
+
+            [components.button]
+            version = "0.9.1"
+            files = { "src/ui/button.rs" = "pub fn Button() {}" }
+            "#,
+        )
+        .unwrap();
+
+        let config = init_config(Style::NewYork, TailwindConfig::default(), Some(existing.clone()));
+        assert_eq!(config.style, Style::NewYork);
+        assert_eq!(config.tailwind, TailwindConfig::default());
+        assert_eq!(config.aliases, existing.aliases);
+        assert_eq!(config.registries, existing.registries);
+        assert_eq!(config.registries["acme"].headers["Authorization"], "Bearer ${ACME_TOKEN}");
+        assert_eq!(config.components, existing.components);
+
+        let config = init_config(Style::Default, TailwindConfig::default(), None);
+        assert_eq!(config.aliases, Aliases::default());
+        assert!(config.registries.is_empty() && config.components.is_empty());
+    }
+}
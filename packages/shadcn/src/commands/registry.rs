@@ -0,0 +1,176 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use shadcn_registry::schema::RegistryEntry;
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use shadcn_registry::registry_styles::STYLES;
+use tokio::fs;
+
+use crate::utils::{
+    crate_sources::{read_crate, vendor_crate},
+    highlighter::HIGHLIGHTER,
+    logger::LOGGER,
+    registry::{get_registry_definitions, validate_registry},
+};
+
+#[derive(Args)]
+pub struct RegistryOptions {
+    #[command(subcommand)]
+    pub command: RegistryCommand,
+}
+
+#[derive(Subcommand)]
+pub enum RegistryCommand {
+    #[command(about = "build a static registry with the component sources inlined")]
+    Build(BuildOptions),
+}
+
+#[derive(Args)]
+pub struct BuildOptions {
+    #[arg(
+        short,
+        long,
+        help = "the directory of the component crates.",
+        default_value = "packages/leptos"
+    )]
+    pub sources: PathBuf,
+
+    #[arg(short, long, help = "the directory to write the registry to.", default_value = "registry")]
+    pub output: PathBuf,
+
+    #[arg(
+        long,
+        help = "also write the items with their content to a single file, as in packages/shadcn/registry/leptos.json."
+    )]
+    pub bundle: Option<PathBuf>,
+
+    #[arg(
+        short,
+        long,
+        help = "the working directory. defaults to the current directory.",
+        default_value = "."
+    )]
+    pub cwd: PathBuf,
+}
+
+pub async fn registry(options: RegistryOptions) -> Result<()> {
+    match options.command {
+        RegistryCommand::Build(options) => build(options).await,
+    }
+}
+
+/// Names of the crates in `sources` that have a `src` directory.
+async fn component_crates(sources: &Path) -> Result<BTreeSet<String>> {
+    let mut crates = BTreeSet::new();
+    let mut entries = fs::read_dir(sources).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.file_type().await?.is_dir() && fs::try_exists(entry.path().join("src")).await? {
+            crates.insert(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    Ok(crates)
+}
+
+async fn write_json(path: &Path, value: &impl Serialize) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(path, serde_json::to_string_pretty(value)? + "\n").await?;
+    Ok(())
+}
+
+/// Registry items with the crates in `sources` vendored into them, checked
+/// for references to missing components.
+pub async fn build_index(sources: &Path, workspace_manifest: &Path) -> Result<Vec<RegistryEntry>> {
+    let crates = component_crates(sources).await?;
+    let workspace_manifest = fs::read_to_string(workspace_manifest)
+        .await
+        .with_context(|| format!("No workspace manifest at {}.", workspace_manifest.display()))?;
+
+    // Entries are vendored from their crate in `sources`. Those without one
+    // need their files inlined already. The first entry of a name is the one
+    // `add` resolves.
+    let mut names = HashSet::new();
+    let mut index = Vec::new();
+    for entry in get_registry_definitions()? {
+        if !names.insert(entry.name.as_str()) {
+            LOGGER.warn(&format!("Skipped another {} entry of type {:?}.", entry.name, entry.r#type));
+            continue;
+        }
+        let mut item = entry.clone();
+        if crates.contains(&entry.name) {
+            let crate_sources = read_crate(&sources.join(&entry.name)).await?;
+            vendor_crate(&mut item, &crate_sources, &workspace_manifest, &crates)?;
+        } else if entry.files.iter().flatten().any(|file| file.content.is_none()) {
+            LOGGER.warn(&format!("Skipped {}, there is no crate for it in {}.", entry.name, sources.display()));
+            continue;
+        }
+        index.push(item);
+    }
+    for name in &crates {
+        if !names.contains(name.as_str()) {
+            LOGGER.warn(&format!("Skipped the {name} crate, it has no registry entry."));
+        }
+    }
+
+    let errors = validate_registry(&index);
+    if !errors.is_empty() {
+        LOGGER.r#break();
+        for error in &errors {
+            LOGGER.error(error);
+        }
+        bail!("The registry is invalid.");
+    }
+    Ok(index)
+}
+
+pub async fn build(options: BuildOptions) -> Result<()> {
+    let output = options.cwd.join(&options.output);
+    let index = build_index(&options.cwd.join(&options.sources), &options.cwd.join("Cargo.toml")).await?;
+
+    // Every style vendors the whole crate, so the items are the same.
+    for style in STYLES.iter().map(|style| style.name) {
+        for item in &index {
+            write_json(&output.join(style.to_string()).join(format!("{}.json", item.name)), item).await?;
+        }
+    }
+    // The index lists the files without their content.
+    let mut summary = index.clone();
+    for file in summary.iter_mut().flat_map(|entry| entry.files.iter_mut().flatten()) {
+        file.content = None;
+    }
+    write_json(&output.join("index.json"), &summary).await?;
+    if let Some(bundle) = &options.bundle {
+        write_json(&options.cwd.join(bundle), &index).await?;
+    }
+
+    LOGGER.success(&format!(
+        "Built {} registry items for {} styles in {}.",
+        index.len(),
+        STYLES.len(),
+        HIGHLIGHTER.info(&output.to_string_lossy())
+    ));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::registry::get_registry_index;
+
+    #[tokio::test]
+    async fn test_bundled_registry_is_up_to_date() {
+        // The component crates are only around in the workspace.
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        if !workspace.join("packages/leptos").is_dir() {
+            return;
+        }
+        let index = build_index(&workspace.join("packages/leptos"), &workspace.join("Cargo.toml")).await.unwrap();
+        assert!(
+            serde_json::to_value(&index).unwrap() == serde_json::to_value(get_registry_index().unwrap()).unwrap(),
+            "registry/leptos.json is out of date, run `rust-shadcn registry build --bundle packages/shadcn/registry/leptos.json`."
+        );
+    }
+}
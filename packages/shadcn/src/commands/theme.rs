@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use clap::{Args, ValueEnum};
+use shadcn_registry::theme_generator::{CONTRAST_AA, Hsl, ThemeOptions as GeneratorOptions, generate_theme};
+use tokio::fs;
+
+use crate::utils::{highlighter::HIGHLIGHTER, logger::LOGGER};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ThemeFormat {
+    /// `:root` and `.dark` rules to paste into the global CSS file.
+    Css,
+    /// A registry item of type `registry:theme`.
+    Json,
+}
+
+#[derive(Args)]
+pub struct ThemeOptions {
+    #[arg(
+        required = true,
+        help = "the brand colors, as #rrggbb or \"H S% L%\": primary, then secondary and accent."
+    )]
+    pub colors: Vec<String>,
+
+    #[arg(short, long, help = "the name of the theme.", default_value = "custom")]
+    pub name: String,
+
+    #[arg(short, long, help = "the border radius.", default_value = "0.5rem")]
+    pub radius: String,
+
+    #[arg(short, long, help = "the output format.", value_enum, default_value = "css")]
+    pub format: ThemeFormat,
+
+    #[arg(short, long, help = "the file to write the theme to. defaults to stdout.")]
+    pub output: Option<PathBuf>,
+}
+
+pub async fn theme(options: ThemeOptions) -> Result<()> {
+    let mut brand = Vec::new();
+    for color in &options.colors {
+        match Hsl::parse(color) {
+            Some(color) => brand.push(color),
+            None => bail!("Invalid color {color}, expected #rrggbb or \"H S% L%\"."),
+        }
+    }
+    let theme = generate_theme(&GeneratorOptions {
+        name: options.name,
+        brand,
+        radius: options.radius,
+    });
+
+    let content = match options.format {
+        ThemeFormat::Css => theme.to_css(),
+        ThemeFormat::Json => serde_json::to_string_pretty(&theme.to_registry_entry())? + "\n",
+    };
+    let failures = theme.failures().collect::<Vec<_>>();
+    let Some(output) = options.output else {
+        // Keep stdout to the theme, so it can be piped.
+        print!("{content}");
+        for check in failures {
+            eprintln!(
+                "{}",
+                HIGHLIGHTER.warn(&format!(
+                    "{:?} {} on {} has a contrast of {:.2}:1.",
+                    check.mode, check.foreground, check.background, check.ratio
+                ))
+            );
+        }
+        return Ok(());
+    };
+
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(&output, content).await?;
+
+    for check in &theme.checks {
+        let text = format!(
+            "{:<5} {:<22} on {:<11} {:>5.2}:1",
+            format!("{:?}", check.mode).to_lowercase(),
+            check.foreground,
+            check.background,
+            check.ratio
+        );
+        if check.passed {
+            LOGGER.log(&text);
+        } else {
+            LOGGER.warn(&text);
+        }
+    }
+    LOGGER.r#break();
+    if failures.is_empty() {
+        LOGGER.success(&format!(
+            "Wrote theme-{} to {}, all pairs meet a contrast of {CONTRAST_AA}:1.",
+            theme.name,
+            HIGHLIGHTER.info(&output.to_string_lossy())
+        ));
+    } else {
+        LOGGER.warn(&format!(
+            "Wrote theme-{} to {}, {} pair(s) are below a contrast of {CONTRAST_AA}:1.",
+            theme.name,
+            HIGHLIGHTER.info(&output.to_string_lossy()),
+            failures.len()
+        ));
+    }
+    Ok(())
+}
@@ -19,7 +19,7 @@ pub struct PreFlightInitResult {
     pub project_info: Option<ProjectInfo>,
 }
 
-pub async fn pre_flight_init(options: InitOptions) -> Result<PreFlightInitResult> {
+pub async fn pre_flight_init(options: &InitOptions) -> Result<PreFlightInitResult> {
     let mut errors: HashMap<ErrorType, bool> = HashMap::new();
 
     // Ensure target directory exists.
@@ -66,34 +66,12 @@ pub async fn pre_flight_init(options: InitOptions) -> Result<PreFlightInitResult
     // if project_info.framework.name == "manual" {}
     // framework_spinner.succeed(Some(format!("Verifying framework. Found {}.", HIGHLIGHTER.info(project_info.framework.label))));
 
+    // A missing Tailwind CSS setup is reported to init, which scaffolds one.
     let mut tailwind_spinner = spinner("Validating Tailwind CSS.", SpinnerOptions { silent: true });
     if project_info.tailwind_config_file.is_none() || project_info.tailwind_css_file.is_none() {
         errors.insert(ErrorType::TailwindNotConfigured, true);
-        tailwind_spinner.fail();
-    } else {
-        tailwind_spinner.succeed(None);
-    }
-
-    if !errors.is_empty() {
-        if errors
-            .get(&ErrorType::TailwindNotConfigured)
-            .copied()
-            .unwrap_or_default()
-        {
-            LOGGER.r#break();
-            LOGGER.error(&format!(
-                "No Tailwind CSS configuration found at {}.",
-                HIGHLIGHTER.info(&options.cwd.to_string_lossy())
-            ));
-            LOGGER.error("It is likely you do not have Tailwind CSS installed or have an invalid configuration.");
-            LOGGER.error("Install Tailwind CSS then try again.");
-
-            // TODO: framework link
-        }
-
-        LOGGER.r#break();
-        bail!("");
     }
+    tailwind_spinner.succeed(None);
 
     Ok(PreFlightInitResult {
         errors,
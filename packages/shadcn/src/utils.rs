@@ -1,5 +1,13 @@
+pub mod crate_sources;
+pub mod diff;
 pub mod errors;
+pub mod get_config;
 pub mod get_project_info;
 pub mod highlighter;
 pub mod logger;
+pub mod registry;
+pub mod registry_source;
 pub mod spinner;
+pub mod templates;
+pub mod transformers;
+pub mod updaters;
@@ -0,0 +1,386 @@
+//! Sources of the Leptos component crates.
+//!
+//! A crate is vendored as a module of the components directory, `ui/<module>/`,
+//! with one registry file per module of the crate. Tests are left out, and the
+//! dependencies come from the crate's manifest.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use shadcn_registry::schema::{RegistryEntry, RegistryItemFile};
+use tokio::fs;
+
+/// Manifest and `src` files of a component crate, by path relative to `src`.
+#[derive(Clone, Debug, Default)]
+pub struct CrateSources {
+    pub manifest: String,
+    pub files: BTreeMap<String, String>,
+}
+
+/// Test modules and test directories, which aren't vendored.
+pub fn is_test_path(path: &str) -> bool {
+    path.split('/').any(|segment| segment.contains("test"))
+}
+
+/// Sources of the crate in `crate_dir`.
+pub async fn read_crate(crate_dir: &Path) -> Result<CrateSources> {
+    let manifest = crate_dir.join("Cargo.toml");
+    let manifest =
+        fs::read_to_string(&manifest).await.with_context(|| format!("No manifest at {}.", manifest.display()))?;
+
+    let mut files = BTreeMap::new();
+    let mut pending = vec![String::new()];
+    while let Some(prefix) = pending.pop() {
+        let mut entries = fs::read_dir(crate_dir.join("src").join(&prefix)).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative = format!("{prefix}{name}");
+            if is_test_path(&relative) {
+                continue;
+            }
+            if entry.file_type().await?.is_dir() {
+                pending.push(format!("{relative}/"));
+            } else if name.ends_with(".rs") {
+                files.insert(relative, fs::read_to_string(entry.path()).await?);
+            }
+        }
+    }
+    Ok(CrateSources { manifest, files })
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// `content` with comments and the insides of string and char literals
+/// blanked out, so offsets into it are offsets into `content`.
+fn code_only(content: &str) -> Vec<u8> {
+    let bytes = content.as_bytes();
+    let mut code = bytes.to_vec();
+    let blank = |code: &mut Vec<u8>, from: usize, to: usize| {
+        for byte in &mut code[from..to.min(bytes.len())] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    };
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let after_ident = i > 0 && is_ident_byte(bytes[i - 1]);
+        if rest.starts_with(b"//") {
+            let end = rest.iter().position(|&byte| byte == b'\n').map_or(bytes.len(), |end| i + end);
+            blank(&mut code, i, end);
+            i = end;
+        } else if rest.starts_with(b"/*") {
+            let mut depth = 0;
+            let mut end = i;
+            while end < bytes.len() {
+                if bytes[end..].starts_with(b"/*") {
+                    depth += 1;
+                    end += 2;
+                } else if bytes[end..].starts_with(b"*/") {
+                    depth -= 1;
+                    end += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    end += 1;
+                }
+            }
+            blank(&mut code, i, end);
+            i = end;
+        } else if bytes[i] == b'r' && !after_ident && rest[1..].iter().find(|&&byte| byte != b'#') == Some(&b'"') {
+            // Raw strings end at a quote followed by as many hashes.
+            let hashes = rest[1..].iter().take_while(|&&byte| byte == b'#').count();
+            let start = i + 2 + hashes;
+            let closing = [b"\"".as_slice(), &vec![b'#'; hashes]].concat();
+            let end = bytes[start..]
+                .windows(closing.len())
+                .position(|window| window == closing)
+                .map_or(bytes.len(), |end| start + end + closing.len());
+            blank(&mut code, start, end - closing.len());
+            i = end;
+        } else if bytes[i] == b'"' {
+            let mut end = i + 1;
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+            blank(&mut code, i + 1, end);
+            i = end + 1;
+        } else if bytes[i] == b'\'' {
+            // A char literal, unless it's a lifetime or a label.
+            let len = content[i + 1..].chars().next().map_or(0, char::len_utf8);
+            let end = if rest.get(1) == Some(&b'\\') {
+                rest[2..].iter().position(|&byte| byte == b'\'').map(|end| i + 2 + end)
+            } else {
+                (bytes.get(i + 1 + len) == Some(&b'\'')).then_some(i + 1 + len)
+            };
+            match end {
+                Some(end) => {
+                    blank(&mut code, i + 1, end);
+                    i = end + 1;
+                }
+                None => i += 1,
+            }
+        } else {
+            i += 1;
+        }
+    }
+    code
+}
+
+/// Start of the line containing `offset`.
+fn line_start(content: &str, offset: usize) -> usize {
+    content[..offset].rfind('\n').map_or(0, |newline| newline + 1)
+}
+
+/// Modules declared in `content` as `(name, offset of the name, is inline)`.
+fn modules(content: &str, code: &[u8]) -> Vec<(String, usize, bool)> {
+    let mut modules = Vec::new();
+    let mut i = 0;
+    while let Some(found) = code[i..].windows(4).position(|window| window == b"mod ") {
+        let start = i + found;
+        i = start + 4;
+        if start > 0 && is_ident_byte(code[start - 1]) {
+            continue;
+        }
+        let name_start = i + code[i..].iter().take_while(|byte| byte.is_ascii_whitespace()).count();
+        let name_end = name_start + code[name_start..].iter().take_while(|&&byte| is_ident_byte(byte)).count();
+        let next = code[name_end..].iter().find(|byte| !byte.is_ascii_whitespace());
+        if name_end > name_start && matches!(next, Some(b';' | b'{')) {
+            modules.push((content[name_start..name_end].to_string(), name_start, next == Some(&b'{')));
+        }
+    }
+    modules
+}
+
+/// `content` without its test modules, inline or declared, along with their
+/// attributes and doc comments.
+pub fn strip_test_modules(content: &str) -> String {
+    let code = code_only(content);
+    let mut removed = Vec::<(usize, usize)>::new();
+    for (name, offset, _) in modules(content, &code) {
+        if !name.contains("test") || removed.iter().any(|&(start, end)| (start..end).contains(&offset)) {
+            continue;
+        }
+
+        // Ends at the semicolon or at the brace closing the module.
+        let mut depth = 0;
+        let mut end = offset;
+        for (index, &byte) in code.iter().enumerate().skip(offset) {
+            match byte {
+                b';' if depth == 0 => {
+                    end = index + 1;
+                    break;
+                }
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = index + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut end = content[end..].find('\n').map_or(content.len(), |newline| end + newline + 1);
+
+        let mut start = line_start(content, offset);
+        while start > 0 {
+            let previous = line_start(content, start - 1);
+            let line = content[previous..start].trim();
+            if !(line.starts_with("#[") || line.starts_with("///")) {
+                break;
+            }
+            start = previous;
+        }
+        // Blank lines around the module would be left doubled.
+        if start == 0 || content[..start].ends_with("\n\n") {
+            end += content[end..].bytes().take_while(|&byte| byte == b'\n').count();
+        }
+        removed.push((start, end));
+    }
+
+    let mut stripped = String::with_capacity(content.len());
+    let mut kept = 0;
+    for (start, end) in removed {
+        stripped.push_str(&content[kept..start]);
+        kept = end;
+    }
+    stripped.push_str(&content[kept..]);
+    stripped
+}
+
+/// One registry file per module of the crate, starting from `lib.rs`, which
+/// becomes the `mod.rs` of `ui/<module>/`.
+pub fn module_files(entry: &RegistryEntry, sources: &CrateSources) -> Result<Vec<RegistryItemFile>> {
+    let module = entry.name.replace('-', "_");
+    let mut files = Vec::new();
+    let mut pending = vec!["lib.rs".to_string()];
+    while let Some(path) = pending.pop() {
+        let content = sources.files.get(&path).ok_or_else(|| anyhow!("{} has no src/{path}.", entry.name))?;
+        let content = strip_test_modules(content);
+
+        // Submodules of `lib.rs` and `mod.rs` are next to them, those of
+        // `name.rs` are in `name/`.
+        let dir = match path.rsplit_once('/') {
+            _ if path == "lib.rs" => String::new(),
+            Some((dir, "mod.rs")) => format!("{dir}/"),
+            _ => format!("{}/", path.trim_end_matches(".rs")),
+        };
+        let code = code_only(&content);
+        for (name, _, inline) in modules(&content, &code) {
+            if inline {
+                continue;
+            }
+            // The crate builds, so a module without a file is configured out.
+            let submodule = [format!("{dir}{name}.rs"), format!("{dir}{name}/mod.rs")]
+                .into_iter()
+                .find(|candidate| sources.files.contains_key(candidate));
+            pending.extend(submodule);
+        }
+
+        let vendored = if path == "lib.rs" { "mod.rs" } else { &path };
+        files.push(RegistryItemFile {
+            path: format!("ui/{module}/{vendored}"),
+            content: Some(content),
+            r#type: entry.r#type,
+            target: None,
+        });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn string_array(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(toml::Value::as_array)
+        .map(|values| values.iter().filter_map(|value| value.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+/// Dependencies of a crate manifest as `name@version[features]`, version and
+/// features being optional, and the components among them. Components are
+/// the `leptos-shadcn-*` crates of `components` taken from the workspace,
+/// which get vendored too. Other crates depended on by path need a version
+/// to be installed from crates.io.
+pub fn crate_dependencies(
+    manifest: &str,
+    workspace_manifest: &str,
+    components: &BTreeSet<String>,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let manifest = manifest.parse::<toml::Table>()?;
+    let workspace = workspace_manifest.parse::<toml::Table>().context("The workspace manifest is invalid.")?;
+    let workspace = workspace.get("workspace");
+    let workspace_dependencies =
+        workspace.and_then(|workspace| workspace.get("dependencies")).and_then(toml::Value::as_table);
+
+    let mut dependencies = Vec::new();
+    let mut registry_dependencies = Vec::new();
+    for (name, value) in manifest.get("dependencies").and_then(toml::Value::as_table).into_iter().flatten() {
+        let table = value.as_table();
+        if table.and_then(|table| table.get("optional")).and_then(toml::Value::as_bool) == Some(true) {
+            continue;
+        }
+
+        let from_workspace =
+            table.and_then(|table| table.get("workspace")).and_then(toml::Value::as_bool) == Some(true);
+        if from_workspace
+            && let Some(component) = name.strip_prefix("leptos-shadcn-")
+            && components.contains(component)
+        {
+            registry_dependencies.push(component.to_string());
+            continue;
+        }
+
+        let inherited =
+            from_workspace.then(|| workspace_dependencies.and_then(|dependencies| dependencies.get(name))).flatten();
+        let version = [inherited, Some(value)].into_iter().flatten().find_map(|value| match value {
+            toml::Value::String(version) => Some(version.clone()),
+            value => value.get("version").and_then(toml::Value::as_str).map(String::from),
+        });
+        let by_path = [inherited, Some(value)].into_iter().flatten().any(|value| value.get("path").is_some());
+        if version.is_none() && by_path {
+            bail!("{name} is a path dependency without a version, it can't be installed.");
+        }
+        let mut features = [inherited, Some(value)]
+            .into_iter()
+            .flatten()
+            .flat_map(|value| string_array(value.get("features")))
+            .collect::<BTreeSet<_>>();
+        // The rendering mode of leptos is for the app to choose.
+        if name == "leptos" {
+            features.clear();
+        }
+
+        let mut dependency = name.clone();
+        if let Some(version) = version {
+            dependency.push_str(&format!("@{version}"));
+        }
+        if !features.is_empty() {
+            dependency.push_str(&format!("[{}]", features.into_iter().collect::<Vec<_>>().join(",")));
+        }
+        dependencies.push(dependency);
+    }
+    Ok((dependencies, registry_dependencies))
+}
+
+/// Points the files and dependencies of `entry` at the sources of its crate.
+pub fn vendor_crate(
+    entry: &mut RegistryEntry,
+    sources: &CrateSources,
+    workspace_manifest: &str,
+    components: &BTreeSet<String>,
+) -> Result<()> {
+    let (dependencies, registry_dependencies) =
+        crate_dependencies(&sources.manifest, workspace_manifest, components)
+            .with_context(|| format!("The manifest of {} is invalid.", entry.name))?;
+    entry.files = Some(module_files(entry, sources)?);
+    entry.dependencies = Some(dependencies);
+    entry.registry_dependencies = (!registry_dependencies.is_empty()).then_some(registry_dependencies);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_test_modules() {
+        let content = "pub mod default;\n\n#[cfg(test)]\nmod tests;\n\n/// Doc\n#[cfg(test)]\nmod tdd_tests {\n    const BRACE: char = '}';\n    // }\n    pub mod rendering_tests;\n}\n\npub use default::*;\n";
+        assert_eq!(strip_test_modules(content), "pub mod default;\n\npub use default::*;\n");
+    }
+
+    #[test]
+    fn test_vendored_crates_are_complete() {
+        let registry = crate::utils::registry::get_registry_index().unwrap();
+        let slider = registry.iter().find(|entry| entry.name == "slider").unwrap();
+        let paths = slider.files.iter().flatten().map(|file| file.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "ui/slider/default.rs",
+                "ui/slider/math.rs",
+                "ui/slider/mod.rs",
+                "ui/slider/new_york.rs",
+                "ui/slider/signal_managed.rs"
+            ]
+        );
+        let dependencies = slider.dependencies.as_deref().unwrap();
+        assert!(dependencies.contains(&"leptos@0.8.9".to_string()));
+        assert!(dependencies.iter().any(|dependency| dependency.starts_with("web-sys@0.3[")));
+        assert!(dependencies.contains(&"leptos-shadcn-tailwind-merge@0.9.0".to_string()));
+
+        let utils = registry.iter().find(|entry| entry.name == "utils").unwrap();
+        let paths = utils.files.iter().flatten().map(|file| file.path.as_str()).collect::<Vec<_>>();
+        assert!(paths.contains(&"ui/utils/modal.rs") && paths.contains(&"ui/utils/query.rs"));
+
+        let dialog = registry.iter().find(|entry| entry.name == "dialog").unwrap();
+        assert_eq!(dialog.registry_dependencies.as_deref(), Some(["utils".to_string()].as_slice()));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Write;
+
+use super::highlighter::HIGHLIGHTER;
+
+/// Lines of unchanged context around the changes of a hunk.
+const CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Lines of `content`, each with its line break.
+fn lines(content: &str) -> Vec<&str> {
+    content.split_inclusive('\n').collect()
+}
+
+/// Edit script turning `old` into `new`, from their longest common subsequence.
+fn edits(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let prefix = old.iter().zip(new).take_while(|(old, new)| old == new).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (a, b) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    // lcs[i][j] is the length of the LCS of a[i..] and b[j..].
+    let width = b.len() + 1;
+    let mut lcs = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i * width + j] = if a[i] == b[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut edits = (0..prefix).map(|i| Edit::Equal(i, i)).collect::<Vec<_>>();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            edits.push(Edit::Equal(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            edits.push(Edit::Delete(prefix + i));
+            i += 1;
+        } else {
+            edits.push(Edit::Insert(prefix + j));
+            j += 1;
+        }
+    }
+    edits.extend((0..suffix).map(|n| Edit::Equal(old.len() - suffix + n, new.len() - suffix + n)));
+    edits
+}
+
+fn push_line(diff: &mut String, prefix: char, line: &str) {
+    diff.push(prefix);
+    diff.push_str(line);
+    if !line.ends_with('\n') {
+        diff.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// Unified diff from `old` to `new`, empty when they're equal.
+pub fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let (old_lines, new_lines) = (lines(old), lines(new));
+    let edits = edits(&old_lines, &new_lines);
+    let changes = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Equal(..)))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Changes closer than twice the context share a hunk.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &index in &changes {
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + CONTEXT + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = format!("--- {old_name}\n+++ {new_name}\n");
+    for (start, end) in hunks {
+        let hunk = &edits[start..end];
+        let old_start = hunk.iter().find_map(|edit| match edit {
+            Edit::Equal(i, _) | Edit::Delete(i) => Some(*i),
+            Edit::Insert(_) => None,
+        });
+        let new_start = hunk.iter().find_map(|edit| match edit {
+            Edit::Equal(_, j) | Edit::Insert(j) => Some(*j),
+            Edit::Delete(_) => None,
+        });
+        let old_count = hunk.iter().filter(|edit| !matches!(edit, Edit::Insert(_))).count();
+        let new_count = hunk.iter().filter(|edit| !matches!(edit, Edit::Delete(_))).count();
+        // Only the hunk of an empty file has no lines on one side.
+        let range = |start: Option<usize>, count: usize| match start {
+            Some(start) => format!("{},{count}", start + 1),
+            None => "0,0".to_string(),
+        };
+        let _ = writeln!(
+            diff,
+            "@@ -{} +{} @@",
+            range(old_start, old_count),
+            range(new_start, new_count)
+        );
+        for edit in hunk {
+            match *edit {
+                Edit::Equal(i, _) => push_line(&mut diff, ' ', old_lines[i]),
+                Edit::Delete(i) => push_line(&mut diff, '-', old_lines[i]),
+                Edit::Insert(j) => push_line(&mut diff, '+', new_lines[j]),
+            }
+        }
+    }
+    diff
+}
+
+/// Colors the added, removed and hunk header lines of a unified diff.
+pub fn colorize(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            if line.starts_with("+++") || line.starts_with("---") {
+                line.to_string()
+            } else if line.starts_with('+') {
+                HIGHLIGHTER.success(line)
+            } else if line.starts_with('-') {
+                HIGHLIGHTER.error(line)
+            } else if line.starts_with("@@") {
+                HIGHLIGHTER.info(line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Result of a three-way merge.
+#[derive(Debug, PartialEq)]
+pub struct Merge {
+    pub content: String,
+    /// Regions changed differently on both sides, left between conflict markers.
+    pub conflicts: usize,
+}
+
+fn push_side(content: &mut String, marker: &str, lines: &[&str]) {
+    content.push_str(marker);
+    content.extend(lines.iter().copied());
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+}
+
+/// Merges the changes from `base` to `theirs` into `ours`.
+pub fn merge3(base: &str, ours: &str, theirs: &str) -> Merge {
+    let (base, ours, theirs) = (lines(base), lines(ours), lines(theirs));
+
+    // Base lines kept on both sides split the files into regions that are
+    // merged independently.
+    let matches = |other: &[&str]| {
+        let mut matched = vec![None; base.len()];
+        for edit in edits(&base, other) {
+            if let Edit::Equal(i, j) = edit {
+                matched[i] = Some(j);
+            }
+        }
+        matched
+    };
+    let (in_ours, in_theirs) = (matches(&ours), matches(&theirs));
+    let mut stable = (0..base.len())
+        .filter_map(|i| Some((i, in_ours[i]?, in_theirs[i]?)))
+        .collect::<Vec<_>>();
+    stable.push((base.len(), ours.len(), theirs.len()));
+
+    let mut content = String::new();
+    let mut conflicts = 0;
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    for (si, sj, sk) in stable {
+        let (b, o, t) = (&base[i..si], &ours[j..sj], &theirs[k..sk]);
+        let merged = if o == b || o == t {
+            t
+        } else if t == b {
+            o
+        } else {
+            conflicts += 1;
+            push_side(&mut content, "<<<<<<< local\n", o);
+            push_side(&mut content, "=======\n", t);
+            content.push_str(">>>>>>> registry\n");
+            &[]
+        };
+        content.extend(merged.iter().copied());
+        if si < base.len() {
+            content.push_str(base[si]);
+        }
+        (i, j, k) = (si + 1, sj + 1, sk + 1);
+    }
+
+    Merge { content, conflicts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk";
+        assert_eq!(
+            unified_diff(old, new, "local", "registry"),
+            "--- local\n+++ registry\n@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n@@ -8,3 +8,4 @@\n h\n i\n j\n+k\n\\ No newline at end of file\n"
+        );
+        assert_eq!(unified_diff(old, old, "local", "registry"), "");
+        assert_eq!(unified_diff("", "a\n", "local", "registry"), "--- local\n+++ registry\n@@ -0,0 +1,1 @@\n+a\n");
+    }
+
+    #[test]
+    fn test_merge3() {
+        let base = "use a;\n\nfn one() {}\n\nfn two() {}\n";
+        let ours = "use a;\n\nfn one() { custom() }\n\nfn two() {}\n";
+        let theirs = "use a;\nuse b;\n\nfn one() {}\n\nfn two() { fixed() }\n";
+        assert_eq!(
+            merge3(base, ours, theirs),
+            Merge {
+                content: "use a;\nuse b;\n\nfn one() { custom() }\n\nfn two() { fixed() }\n".into(),
+                conflicts: 0,
+            }
+        );
+
+        let theirs = "use a;\n\nfn one() { upstream() }\n\nfn two() {}\n";
+        let merge = merge3(base, ours, theirs);
+        assert_eq!(merge.conflicts, 1);
+        assert!(merge.content.contains(
+            "<<<<<<< local\nfn one() { custom() }\n=======\nfn one() { upstream() }\n>>>>>>> registry\n"
+        ));
+    }
+}
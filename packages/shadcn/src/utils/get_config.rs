@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use shadcn_registry::schema::Style;
+use tokio::fs;
+
+pub const CONFIG_FILE: &str = "components.toml";
+
+/// Project configuration stored in `components.toml`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Config {
+    pub style: Style,
+    #[serde(default)]
+    pub tailwind: TailwindConfig,
+    /// Directory components are added to, relative to the project root.
+    #[serde(default = "default_components_dir")]
+    pub components_dir: String,
+    #[serde(default)]
+    pub aliases: Aliases,
+    /// Registries of `@namespace/name` items, by namespace.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub registries: BTreeMap<String, RegistryConfig>,
+    /// Components added to the project, by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, VendoredComponent>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            style: Style::Default,
+            tailwind: TailwindConfig::default(),
+            components_dir: default_components_dir(),
+            aliases: Aliases::default(),
+            registries: BTreeMap::new(),
+            components: BTreeMap::new(),
+        }
+    }
+}
+
+/// Tailwind CSS setup of the project, paths relative to the project root.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct TailwindConfig {
+    pub config: String,
+    pub css: String,
+    /// Name of one of the registry's base colors, e.g. `zinc`.
+    pub base_color: String,
+    /// Whether colors are CSS variables in `css` or inlined into `config`.
+    pub css_variables: bool,
+}
+
+impl Default for TailwindConfig {
+    fn default() -> Self {
+        Self {
+            config: "tailwind.config.js".into(),
+            css: "style/tailwind.css".into(),
+            base_color: "neutral".into(),
+            css_variables: true,
+        }
+    }
+}
+
+/// Module paths that vendored components are reachable at.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Aliases {
+    pub components: String,
+    pub ui: String,
+}
+
+impl Default for Aliases {
+    fn default() -> Self {
+        Self {
+            components: "crate::components".into(),
+            ui: "crate::components::ui".into(),
+        }
+    }
+}
+
+/// A custom registry, e.g. `url = "https://registry.acme.com/r/{name}.json"`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RegistryConfig {
+    /// URL or path of an item, with `{name}` and optionally `{style}` placeholders.
+    pub url: String,
+    /// Headers sent with every request, where `${VAR}` reads environment variable `VAR`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Registry version a component was added or last updated from. The files as
+/// they came from the registry are the base of three-way merges in `diff`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct VendoredComponent {
+    pub version: String,
+    /// URL or path of the item for components from other registries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Base content by path relative to the project root.
+    pub files: BTreeMap<String, String>,
+}
+
+fn default_components_dir() -> String {
+    "src/components/ui".into()
+}
+
+/// Reads `components.toml` from `cwd`, if there is one.
+pub async fn get_config(cwd: &Path) -> Result<Option<Config>> {
+    let path = cwd.join(CONFIG_FILE);
+    if !fs::try_exists(&path).await? {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path).await?;
+    let config = toml::from_str(&content).with_context(|| format!("Invalid configuration in {}.", path.display()))?;
+    Ok(Some(config))
+}
+
+/// Writes `config` to `components.toml` in `cwd`.
+pub async fn write_config(cwd: &Path, config: &Config) -> Result<()> {
+    fs::write(cwd.join(CONFIG_FILE), toml::to_string(config)?).await?;
+    Ok(())
+}
@@ -1,6 +1,23 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use tokio::fs;
+
+const TAILWIND_CONFIG_FILES: &[&str] = &[
+    "tailwind.config.js",
+    "tailwind.config.cjs",
+    "tailwind.config.mjs",
+    "tailwind.config.ts",
+];
+
+const TAILWIND_CSS_FILES: &[&str] = &[
+    "style/tailwind.css",
+    "style/main.css",
+    "style/input.css",
+    "input.css",
+    "styles.css",
+    "src/styles.css",
+];
 
 pub struct ProjectInfo {
     // pub framework: Framework,
@@ -12,13 +29,21 @@ pub struct ProjectInfo {
     // pub alias_prefix: Option<String>,
 }
 
-pub async fn get_project_info(cwd: &Path) -> Result<ProjectInfo> {
-    // TODO
+/// First of `candidates` that exists in `cwd`, relative to `cwd`.
+async fn find_file(cwd: &Path, candidates: &[&str]) -> Result<Option<PathBuf>> {
+    for candidate in candidates {
+        if fs::try_exists(cwd.join(candidate)).await? {
+            return Ok(Some(PathBuf::from(candidate)));
+        }
+    }
+    Ok(None)
+}
 
+pub async fn get_project_info(cwd: &Path) -> Result<ProjectInfo> {
     let r#type = ProjectInfo {
-        is_src_dir: false,
-        tailwind_config_file: None,
-        tailwind_css_file: None,
+        is_src_dir: fs::try_exists(cwd.join("src")).await?,
+        tailwind_config_file: find_file(cwd, TAILWIND_CONFIG_FILES).await?,
+        tailwind_css_file: find_file(cwd, TAILWIND_CSS_FILES).await?,
     };
 
     Ok(r#type)
@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use anyhow::{Result, anyhow};
+use shadcn_registry::REGISTRY;
+use shadcn_registry::registry_base_colors::BASE_COLORS;
+use shadcn_registry::schema::{FrameworkName, Mode, RegistryEntry, RegistryItemFile, RegistryItemType};
+
+use super::templates::CssVars;
+use super::transformers::module_name;
+
+/// The built-in registry ships with the CLI, so it shares its version.
+pub const REGISTRY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The Leptos registry with the component crates vendored into it, as
+/// written to `registry/leptos.json` by `registry build --bundle`.
+static LEPTOS_INDEX: LazyLock<Result<Vec<RegistryEntry>, String>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../../registry/leptos.json"))
+        .map_err(|error| format!("The bundled registry is invalid: {error}"))
+});
+
+pub fn get_registry_index() -> Result<&'static [RegistryEntry]> {
+    LEPTOS_INDEX.as_deref().map_err(|error| anyhow!("{error}"))
+}
+
+/// Entries of the Leptos registry as defined in `shadcn-registry`, with the
+/// files of the component crates not vendored yet.
+pub fn get_registry_definitions() -> Result<&'static [RegistryEntry]> {
+    REGISTRY
+        .get(&FrameworkName::Leptos)
+        .map(Vec::as_slice)
+        .ok_or_else(|| anyhow!("The Leptos registry is missing."))
+}
+
+/// Entries for `names` and everything they depend on through
+/// `registry_dependencies`, dependencies first.
+pub fn resolve_tree<'a>(index: &'a [RegistryEntry], names: &[String]) -> Result<Vec<&'a RegistryEntry>> {
+    fn visit<'a>(
+        index: &'a [RegistryEntry],
+        name: &str,
+        required_by: Option<&str>,
+        visited: &mut HashSet<String>,
+        tree: &mut Vec<&'a RegistryEntry>,
+    ) -> Result<()> {
+        // Inserting before recursing also stops dependency cycles.
+        if !visited.insert(name.to_string()) {
+            return Ok(());
+        }
+
+        let entry = index.iter().find(|entry| entry.name == name).ok_or_else(|| match required_by {
+            Some(parent) => anyhow!("Component {name}, required by {parent}, was not found in the registry."),
+            None => anyhow!("Component {name} was not found in the registry."),
+        })?;
+        for dependency in entry.registry_dependencies.iter().flatten() {
+            visit(index, dependency, Some(name), visited, tree)?;
+        }
+        tree.push(entry);
+        Ok(())
+    }
+
+    let mut visited = HashSet::new();
+    let mut tree = Vec::new();
+    for name in names {
+        visit(index, name, None, &mut visited, &mut tree)?;
+    }
+    Ok(tree)
+}
+
+/// Light and dark CSS variables of a base color, with those of `theme`, a
+/// theme entry such as `theme-daylight`, laid over them.
+pub fn get_css_vars(base_color: &str, theme: Option<&str>) -> Result<(CssVars, CssVars)> {
+    let base_color = BASE_COLORS.iter().find(|color| color.name == base_color).ok_or_else(|| {
+        let names = BASE_COLORS.iter().map(|color| color.name.as_str()).collect::<Vec<_>>();
+        anyhow!("Unknown base color {base_color}, use one of: {}.", names.join(", "))
+    })?;
+    let theme = match theme {
+        Some(name) => Some(
+            get_registry_index()?
+                .iter()
+                .find(|entry| entry.name == name && entry.r#type == RegistryItemType::Theme)
+                .ok_or_else(|| anyhow!("Theme {name} was not found in the registry."))?,
+        ),
+        None => None,
+    };
+
+    let vars = |mode: Mode| {
+        let mut vars = base_color.css_vars.get(&mode).cloned().unwrap_or_default().into_iter().collect::<CssVars>();
+        if let Some(theme_vars) = theme.and_then(|theme| theme.css_vars.as_ref()).and_then(|vars| vars.get(&mode)) {
+            vars.extend(theme_vars.clone());
+        }
+        vars
+    };
+    Ok((vars(Mode::Light), vars(Mode::Dark)))
+}
+
+/// Path of a registry file relative to the components directory: what
+/// follows its `ui/` directory. Vendored crates keep their layout there,
+/// `ui/<module>/`.
+pub fn vendored_path(file: &RegistryItemFile) -> Option<String> {
+    let relative = file
+        .path
+        .strip_prefix("ui/")
+        .or_else(|| file.path.rsplit_once("/ui/").map(|(_, relative)| relative))?;
+    Some(module_name(relative))
+}
+
+/// Module of the vendored crate a registry file belongs to.
+pub fn crate_module(file: &RegistryItemFile) -> Option<String> {
+    vendored_path(file)?.split_once('/').map(|(module, _)| module.to_string())
+}
+
+/// Where a registry file goes in the project: its target, or otherwise its
+/// vendored path in `components_dir`.
+pub fn file_target(cwd: &Path, components_dir: &Path, entry: &RegistryEntry, file: &RegistryItemFile) -> PathBuf {
+    match &file.target {
+        Some(target) => cwd.join(target),
+        None => {
+            let relative = vendored_path(file).unwrap_or_else(|| {
+                let file_name = Path::new(&file.path).file_name().map_or_else(
+                    || format!("{}.rs", entry.name),
+                    |file_name| file_name.to_string_lossy().into_owned(),
+                );
+                module_name(&file_name)
+            });
+            components_dir.join(relative)
+        }
+    }
+}
+
+/// Content of a registry file. Files of the built-in registry are embedded
+/// in it, those of other registries are inlined.
+pub fn get_file_content(file: &RegistryItemFile) -> Result<String> {
+    file.content.clone().ok_or_else(|| anyhow!("No content for {}.", file.path))
+}
+
+/// References to components that aren't in the registry.
+pub fn validate_registry(index: &[RegistryEntry]) -> Vec<String> {
+    let names = index.iter().map(|entry| entry.name.as_str()).collect::<HashSet<_>>();
+    let mut errors = Vec::new();
+    for entry in index {
+        for dependency in entry.registry_dependencies.iter().flatten() {
+            if !names.contains(dependency.as_str()) {
+                errors.push(format!("{} depends on {dependency}, which is not in the registry.", entry.name));
+            }
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, registry_dependencies: &[&str]) -> RegistryEntry {
+        RegistryEntry {
+            name: name.into(),
+            r#type: RegistryItemType::Ui,
+            description: None,
+            dependencies: None,
+            dev_dependencies: None,
+            registry_dependencies: Some(registry_dependencies.iter().map(|name| name.to_string()).collect()),
+            files: None,
+            tailwind: None,
+            css_vars: None,
+            source: None,
+            category: None,
+            subcategory: None,
+            chunks: None,
+            docs: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_tree() {
+        let index = vec![
+            entry("utils", &[]),
+            entry("button", &["utils"]),
+            entry("dialog", &["button", "utils", "dialog"]),
+        ];
+        let tree = resolve_tree(&index, &["dialog".into(), "button".into()]).unwrap();
+        let names = tree.iter().map(|entry| entry.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["utils", "button", "dialog"]);
+
+        let error = resolve_tree(&[entry("form", &["field"])], &["form".into()]).unwrap_err();
+        assert_eq!(error.to_string(), "Component field, required by form, was not found in the registry.");
+    }
+
+    #[test]
+    fn test_validate_registry() {
+        let index = vec![entry("utils", &[]), entry("button", &["utils"]), entry("form", &["field", "button"])];
+        assert_eq!(validate_registry(&index), ["form depends on field, which is not in the registry."]);
+    }
+
+    #[test]
+    fn test_get_css_vars() {
+        let (light, dark) = get_css_vars("zinc", None).unwrap();
+        assert_eq!(light["primary"], "240 5.9% 10%");
+        assert_eq!(dark["background"], "240 10% 3.9%");
+
+        let (light, _) = get_css_vars("zinc", Some("theme-daylight")).unwrap();
+        assert_eq!(light["background"], "36 39% 88%");
+        assert_eq!(light["radius"], "0.5rem");
+
+        assert!(get_css_vars("teal", None).is_err());
+    }
+}
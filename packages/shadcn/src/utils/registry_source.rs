@@ -0,0 +1,526 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::{Duration, SystemTime};
+use std::{env, io};
+
+use anyhow::{Context, Result, anyhow, bail};
+use regex::Regex;
+use reqwest::{StatusCode, Url};
+use sha2::{Digest, Sha256};
+use shadcn_registry::schema::RegistryEntry;
+use tokio::fs;
+
+use super::errors::ErrorType;
+use super::get_config::Config;
+use super::logger::LOGGER;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// How long a cached item is used without asking its registry again.
+const CACHE_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Where registry items are read from.
+pub trait RegistrySource: Send + Sync {
+    /// Raw content of the item at `location`, a path or URL.
+    fn fetch<'a>(&'a self, location: &'a str) -> BoxFuture<'a, Result<String>>;
+
+    /// Whether items of this source are worth caching.
+    fn is_remote(&self) -> bool {
+        false
+    }
+
+    /// Headers sent with each request, such as credentials.
+    fn headers(&self) -> &[(String, String)] {
+        &[]
+    }
+}
+
+/// Items in files, such as the output of `registry build`.
+pub struct FileSystemSource;
+
+impl RegistrySource for FileSystemSource {
+    fn fetch<'a>(&'a self, location: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            fs::read_to_string(location)
+                .await
+                .with_context(|| format!("Could not read the registry item at {location}."))
+        })
+    }
+}
+
+/// Items served over HTTP, with the headers of their registry.
+pub struct HttpSource {
+    client: reqwest::Client,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpSource {
+    pub fn new(headers: Vec<(String, String)>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            headers,
+        }
+    }
+}
+
+fn status_error(status: StatusCode) -> Option<ErrorType> {
+    match status {
+        StatusCode::BAD_REQUEST => Some(ErrorType::ComponentUrlBadRequest),
+        StatusCode::UNAUTHORIZED => Some(ErrorType::ComponentUrlUnauthorized),
+        StatusCode::FORBIDDEN => Some(ErrorType::ComponentUrlForbidden),
+        StatusCode::NOT_FOUND => Some(ErrorType::ComponentUrlNotFound),
+        status if status.is_success() => None,
+        status if status.is_server_error() => Some(ErrorType::ComponentUrlInternalServerError),
+        _ => Some(ErrorType::ComponentUrlBadRequest),
+    }
+}
+
+impl RegistrySource for HttpSource {
+    fn fetch<'a>(&'a self, location: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let mut request = self.client.get(location);
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            let response = request
+                .send()
+                .await
+                .with_context(|| format!("Could not reach the registry at {location}."))?;
+
+            let status = response.status();
+            match status_error(status) {
+                None => Ok(response.text().await?),
+                Some(ErrorType::ComponentUrlNotFound) => bail!("The registry item at {location} was not found."),
+                Some(ErrorType::ComponentUrlUnauthorized) => bail!(
+                    "You are not authorized to access {location}. Check the headers of its registry in components.toml."
+                ),
+                Some(ErrorType::ComponentUrlForbidden) => bail!("You do not have access to {location}."),
+                Some(_) => bail!("The registry responded to {location} with {status}."),
+            }
+        })
+    }
+
+    fn is_remote(&self) -> bool {
+        true
+    }
+
+    fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+}
+
+/// Where a component given to `add` comes from.
+#[derive(Debug, PartialEq)]
+pub enum ItemAddress {
+    /// A component of the built-in registry.
+    BuiltIn(String),
+    Remote(RemoteAddress),
+}
+
+/// Location of an item in another registry and the headers to fetch it with.
+#[derive(Debug, PartialEq)]
+pub struct RemoteAddress {
+    pub location: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl RemoteAddress {
+    pub fn source(&self) -> Box<dyn RegistrySource> {
+        if is_url(&self.location) {
+            Box::new(HttpSource::new(self.headers.clone()))
+        } else {
+            Box::new(FileSystemSource)
+        }
+    }
+}
+
+fn is_url(address: &str) -> bool {
+    address.starts_with("https://") || address.starts_with("http://")
+}
+
+/// Whether `url` is on the host of a registry's `url` and in the directory
+/// its items are in. Only whole path segments count, so `/r/item-{name}`
+/// covers everything in `/r/` but not `/rx/`.
+fn is_registry_url(registry_url: &str, url: &str) -> bool {
+    let prefix = registry_url.split('{').next().unwrap_or_default();
+    let (Ok(registry), Ok(url)) = (Url::parse(prefix), Url::parse(url)) else {
+        return false;
+    };
+    let directory = registry.path().rsplit_once('/').map_or("", |(directory, _)| directory);
+    registry.scheme() == url.scheme()
+        && registry.host_str() == url.host_str()
+        && registry.port_or_known_default() == url.port_or_known_default()
+        && url.path().strip_prefix(directory).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Replaces `${VAR}` with environment variable `VAR`.
+fn expand_env(value: &str, namespace: &str) -> Result<String> {
+    let pattern = Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("Variable pattern should be valid.");
+    let mut missing = None;
+    let expanded = pattern.replace_all(value, |captures: &regex::Captures| {
+        env::var(&captures[1]).unwrap_or_else(|_| {
+            missing.get_or_insert_with(|| captures[1].to_string());
+            String::new()
+        })
+    });
+    match missing {
+        Some(variable) => Err(anyhow!(
+            "Environment variable {variable}, used by the {namespace} registry, is not set."
+        )),
+        None => Ok(expanded.into_owned()),
+    }
+}
+
+fn expand_headers(headers: &BTreeMap<String, String>, namespace: &str) -> Result<Vec<(String, String)>> {
+    headers
+        .iter()
+        .map(|(name, value)| Ok((name.clone(), expand_env(value, namespace)?)))
+        .collect()
+}
+
+/// Resolves a component as given to `add`: a name of the built-in registry,
+/// an `@namespace/name` of a registry in `components.toml`, a URL or a path
+/// to a `.json` item.
+pub fn parse_address(address: &str, cwd: &Path, config: &Config) -> Result<ItemAddress> {
+    if let Some((namespace, name)) = address.split_once('/').filter(|_| address.starts_with('@')) {
+        let registry = config.registries.get(namespace).ok_or_else(|| {
+            anyhow!("Unknown registry {namespace}, add it to [registries] in components.toml.")
+        })?;
+        let location = registry
+            .url
+            .replace("{name}", name)
+            .replace("{style}", &config.style.to_string());
+        let location = if is_url(&location) {
+            location
+        } else {
+            cwd.join(location).to_string_lossy().into_owned()
+        };
+        return Ok(ItemAddress::Remote(RemoteAddress {
+            location,
+            headers: expand_headers(&registry.headers, namespace)?,
+        }));
+    }
+
+    if is_url(address) {
+        // URLs of a configured registry are sent with its headers.
+        let headers = config
+            .registries
+            .iter()
+            .find(|(_, registry)| is_registry_url(&registry.url, address))
+            .map(|(namespace, registry)| expand_headers(&registry.headers, namespace))
+            .transpose()?
+            .unwrap_or_default();
+        return Ok(ItemAddress::Remote(RemoteAddress {
+            location: address.to_string(),
+            headers,
+        }));
+    }
+
+    if address.ends_with(".json") {
+        return Ok(ItemAddress::Remote(RemoteAddress {
+            location: cwd.join(address).to_string_lossy().into_owned(),
+            headers: Vec::new(),
+        }));
+    }
+
+    Ok(ItemAddress::BuiltIn(address.to_string()))
+}
+
+/// Parses a registry item, checking that it can be installed: it has a name
+/// and all of its files are inlined.
+pub fn parse_item(content: &str, location: &str) -> Result<RegistryEntry> {
+    let entry: RegistryEntry =
+        serde_json::from_str(content).with_context(|| format!("Invalid registry item at {location}."))?;
+    if entry.name.trim().is_empty() {
+        bail!("The registry item at {location} has no name.");
+    }
+    if let Some(file) = entry.files.iter().flatten().find(|file| file.content.is_none()) {
+        bail!("The registry item at {location} has no content for {}.", file.path);
+    }
+    Ok(entry)
+}
+
+/// Registry items fetched before, by location and the headers they were
+/// fetched with: an item fetched with credentials is only served to requests
+/// with the same credentials.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// The cache in `$SHADCN_CACHE_DIR`, `$XDG_CACHE_HOME/shadcn` or `~/.cache/shadcn`.
+    pub fn new() -> Option<Self> {
+        let dir = env::var_os("SHADCN_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("shadcn")))
+            .or_else(|| env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".cache/shadcn")))?;
+        Some(Self { dir })
+    }
+
+    /// File of the item, named after a hash so that credentials aren't written
+    /// to the disk.
+    fn path(&self, location: &str, headers: &[(String, String)]) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(location);
+        for (name, value) in headers {
+            hasher.update(format!("\n{name}: {value}"));
+        }
+        self.dir.join(format!("{:x}.json", hasher.finalize()))
+    }
+
+    /// Cached content of `location`, if any and, with `fresh`, younger than [`CACHE_MAX_AGE`].
+    async fn get(&self, location: &str, headers: &[(String, String)], fresh: bool) -> Option<String> {
+        let path = self.path(location, headers);
+        let modified = fs::metadata(&path).await.ok()?.modified().ok()?;
+        let age = SystemTime::now().duration_since(modified).unwrap_or_default();
+        if fresh && age > CACHE_MAX_AGE {
+            return None;
+        }
+        fs::read_to_string(path).await.ok()
+    }
+
+    async fn put(&self, location: &str, headers: &[(String, String)], content: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir).await?;
+        fs::write(self.path(location, headers), content).await
+    }
+}
+
+async fn cached(cache: Option<&Cache>, source: &dyn RegistrySource, location: &str, fresh: bool) -> Option<String> {
+    cache?.get(location, source.headers(), fresh).await
+}
+
+/// Whether `error` is a failure to reach the registry, rather than the
+/// registry refusing the request.
+fn is_unreachable(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|error| error.is_connect() || error.is_timeout())
+}
+
+/// Fetches and validates the item at `location`. Items of remote sources are
+/// cached; a stale cached item is used when the registry can't be reached,
+/// but not when it refuses the request.
+pub async fn fetch_item(source: &dyn RegistrySource, location: &str, cache: Option<&Cache>) -> Result<RegistryEntry> {
+    let cache = cache.filter(|_| source.is_remote());
+    if let Some(entry) = cached(cache, source, location, true)
+        .await
+        .and_then(|content| parse_item(&content, location).ok())
+    {
+        return Ok(entry);
+    }
+
+    match source.fetch(location).await {
+        Ok(content) => {
+            let entry = parse_item(&content, location)?;
+            if let Some(cache) = cache {
+                // The item was fetched, failing to cache it is not an error.
+                let _ = cache.put(location, source.headers(), &content).await;
+            }
+            Ok(entry)
+        }
+        Err(error) => {
+            if !is_unreachable(&error) {
+                return Err(error);
+            }
+            let Some(content) = cached(cache, source, location, false).await else {
+                return Err(error);
+            };
+            LOGGER.warn(&format!("{error:#} Using the cached item."));
+            parse_item(&content, location)
+        }
+    }
+}
+
+/// A component from a registry other than the built-in one.
+pub struct RemoteItem {
+    pub location: String,
+    pub entry: RegistryEntry,
+}
+
+/// Fetches the remote items of `components` and the remote items they
+/// depend on. Returns them, dependencies first, with the names of the
+/// built-in components they need.
+pub async fn resolve_remote_items(
+    components: &[String],
+    cwd: &Path,
+    config: &Config,
+) -> Result<(Vec<RemoteItem>, Vec<String>)> {
+    let cache = Cache::new();
+    let mut built_in = Vec::new();
+    let mut items = Vec::new();
+    let mut visited = HashSet::new();
+    let mut queue = components.iter().cloned().collect::<VecDeque<_>>();
+    while let Some(component) = queue.pop_front() {
+        match parse_address(&component, cwd, config)? {
+            ItemAddress::Remote(address) => {
+                if !visited.insert(address.location.clone()) {
+                    continue;
+                }
+                let entry = fetch_item(address.source().as_ref(), &address.location, cache.as_ref()).await?;
+                queue.extend(entry.registry_dependencies.iter().flatten().cloned());
+                items.push(RemoteItem {
+                    location: address.location,
+                    entry,
+                });
+            }
+            ItemAddress::BuiltIn(name) => {
+                if !built_in.contains(&name) {
+                    built_in.push(name);
+                }
+            }
+        }
+    }
+    // Dependencies are found after the items that need them.
+    items.reverse();
+    Ok((items, built_in))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::utils::get_config::RegistryConfig;
+
+    const ITEM: &str = r#"{"name":"data-grid","type":"registry:ui","registryDependencies":["table"],"files":[{"path":"ui/data-grid.rs","content":"pub struct DataGrid;\n","type":"registry:ui"}]}"#;
+
+    /// Serves `ITEM` to requests authorized with `Bearer secret`.
+    async fn serve() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = vec![0; 4096];
+                let read = stream.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+                let (status, body) = if request.contains("authorization: bearer secret") {
+                    ("200 OK", ITEM)
+                } else {
+                    ("401 Unauthorized", "")
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        format!("http://{address}")
+    }
+
+    fn config(url: &str) -> Config {
+        let mut config = Config::default();
+        config.registries.insert(
+            "@acme".into(),
+            RegistryConfig {
+                url: url.into(),
+                headers: BTreeMap::from([("Authorization".into(), "Bearer ${SHADCN_TEST_TOKEN}".into())]),
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn test_parse_address() {
+        let config = config("https://registry.acme.com/r/{style}/{name}.json");
+        let cwd = Path::new("/app");
+        assert_eq!(parse_address("button", cwd, &config).unwrap(), ItemAddress::BuiltIn("button".into()));
+        assert_eq!(
+            parse_address("./items/grid.json", cwd, &Config::default()).unwrap(),
+            ItemAddress::Remote(RemoteAddress {
+                location: "/app/./items/grid.json".into(),
+                headers: Vec::new(),
+            })
+        );
+        assert_eq!(
+            parse_address("@acme/data-grid", cwd, &config).unwrap_err().to_string(),
+            "Environment variable SHADCN_TEST_TOKEN, used by the @acme registry, is not set."
+        );
+        assert!(parse_address("@other/data-grid", cwd, &config).is_err());
+
+        let mut config = config;
+        config.registries.get_mut("@acme").unwrap().headers.clear();
+        assert_eq!(
+            parse_address("@acme/data-grid", cwd, &config).unwrap(),
+            ItemAddress::Remote(RemoteAddress {
+                location: "https://registry.acme.com/r/default/data-grid.json".into(),
+                headers: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_registry_headers() {
+        let mut config = config("https://registry.acme.com/r/{style}/{name}.json");
+        config.registries.get_mut("@acme").unwrap().headers =
+            BTreeMap::from([("Authorization".into(), "Bearer secret".into())]);
+        let headers = |address: &str| match parse_address(address, Path::new("/app"), &config).unwrap() {
+            ItemAddress::Remote(address) => address.headers,
+            ItemAddress::BuiltIn(_) => unreachable!(),
+        };
+        assert_eq!(headers("https://registry.acme.com/r/default/grid.json").len(), 1);
+        assert_eq!(headers("https://registry.acme.com:443/r/new-york/grid.json").len(), 1);
+        assert!(headers("https://registry.acme.com/rx/grid.json").is_empty());
+        assert!(headers("https://registry.acme.com/r/../grid.json").is_empty());
+        assert!(headers("http://registry.acme.com/r/default/grid.json").is_empty());
+        assert!(headers("https://registry.acme.com:8443/r/default/grid.json").is_empty());
+        assert!(headers("https://registry.acme.com.evil.io/r/default/grid.json").is_empty());
+        assert!(headers("https://registry.acme.com@evil.io/r/default/grid.json").is_empty());
+
+        assert!(is_registry_url("https://registry.acme.com{name}", "https://registry.acme.com/grid.json"));
+        assert!(!is_registry_url("https://registry.acme.com{name}", "https://registry.acme.com.evil.io/grid.json"));
+        assert!(is_registry_url("https://acme.com/r/item-{name}.json", "https://acme.com/r/item-grid.json"));
+        assert!(!is_registry_url("./registry/{name}.json", "https://acme.com/registry/grid.json"));
+    }
+
+    /// Caches `ITEM` for `location` as if it was fetched long ago.
+    async fn cache_stale(cache: &Cache, location: &str, headers: &[(String, String)]) {
+        cache.put(location, headers, ITEM).await.unwrap();
+        let file = std::fs::File::options().write(true).open(cache.path(location, headers)).unwrap();
+        file.set_modified(SystemTime::now() - 2 * CACHE_MAX_AGE).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_item() {
+        let url = serve().await;
+        let location = format!("{url}/r/data-grid.json");
+
+        let unauthorized = fetch_item(&HttpSource::new(Vec::new()), &location, None).await;
+        assert!(unauthorized.unwrap_err().to_string().contains("not authorized"));
+
+        let cache = Cache {
+            dir: env::temp_dir().join(format!("shadcn-cache-{}", std::process::id())),
+        };
+        let source = HttpSource::new(vec![("Authorization".into(), "Bearer secret".into())]);
+        let entry = fetch_item(&source, &location, Some(&cache)).await.unwrap();
+        assert_eq!(entry.name, "data-grid");
+        assert_eq!(entry.registry_dependencies, Some(vec!["table".into()]));
+
+        // Cached for requests with the same credentials only.
+        let mut cached_files = std::fs::read_dir(&cache.dir).unwrap().map(|file| file.unwrap().path());
+        assert_eq!(cached_files.next(), Some(cache.path(&location, source.headers())));
+        assert_eq!(cached_files.next(), None);
+        let unauthorized = fetch_item(&HttpSource::new(Vec::new()), &location, Some(&cache)).await;
+        assert!(unauthorized.unwrap_err().to_string().contains("not authorized"));
+        assert_ne!(cache.path(&format!("{url}/r/a_b.json"), &[]), cache.path(&format!("{url}/r/a/b.json"), &[]));
+
+        // A stale item is used when the registry is down, not when it refuses the request.
+        let revoked = HttpSource::new(vec![("Authorization".into(), "Bearer revoked".into())]);
+        cache_stale(&cache, &location, revoked.headers()).await;
+        let unauthorized = fetch_item(&revoked, &location, Some(&cache)).await;
+        assert!(unauthorized.unwrap_err().to_string().contains("not authorized"));
+
+        let closed = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let unreachable = format!("http://{closed}/r/data-grid.json");
+        assert!(fetch_item(&source, &unreachable, Some(&cache)).await.is_err());
+        cache_stale(&cache, &unreachable, source.headers()).await;
+        assert_eq!(fetch_item(&source, &unreachable, Some(&cache)).await.unwrap().name, "data-grid");
+        let _ = std::fs::remove_dir_all(&cache.dir);
+
+        let error = parse_item(&ITEM.replace(r#""content":"pub struct DataGrid;\n","#, ""), "grid.json").unwrap_err();
+        assert_eq!(error.to_string(), "The registry item at grid.json has no content for ui/data-grid.rs.");
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// CSS variables of one mode by name without the leading `--`, e.g. `background`.
+pub type CssVars = BTreeMap<String, String>;
+
+/// Colors of the Tailwind theme. Those with a shade have a `foreground` pair.
+const COLORS: &[(&str, bool)] = &[
+    ("border", false),
+    ("input", false),
+    ("ring", false),
+    ("background", false),
+    ("foreground", false),
+    ("primary", true),
+    ("secondary", true),
+    ("destructive", true),
+    ("muted", true),
+    ("accent", true),
+    ("popover", true),
+    ("card", true),
+];
+
+const CHARTS: usize = 5;
+
+/// Expands to the variable, or to its light value when colors are inlined.
+fn var(name: &str, inline: Option<&CssVars>) -> String {
+    match inline.and_then(|vars| vars.get(name)) {
+        Some(value) => value.clone(),
+        None => format!("var(--{name})"),
+    }
+}
+
+fn color(name: &str, inline: Option<&CssVars>) -> String {
+    format!("hsl({})", var(name, inline))
+}
+
+/// `tailwind.config.js` mapping the theme onto the CSS variables, or onto
+/// the light values of `inline` for projects without CSS variables.
+pub fn tailwind_config(inline: Option<&CssVars>) -> String {
+    let mut colors = String::new();
+    for (name, shaded) in COLORS {
+        if *shaded {
+            let _ = writeln!(
+                colors,
+                "        {name}: {{\n          DEFAULT: \"{}\",\n          foreground: \"{}\",\n        }},",
+                color(name, inline),
+                color(&format!("{name}-foreground"), inline),
+            );
+        } else {
+            let _ = writeln!(colors, "        {name}: \"{}\",", color(name, inline));
+        }
+    }
+    colors.push_str("        chart: {\n");
+    for chart in 1..=CHARTS {
+        let _ = writeln!(colors, "          \"{chart}\": \"{}\",", color(&format!("chart-{chart}"), inline));
+    }
+    colors.push_str("        },\n");
+
+    let radius = var("radius", inline);
+    let dark_mode = if inline.is_some() { "" } else { "  darkMode: [\"class\"],\n" };
+
+    format!(
+        r#"/** @type {{import('tailwindcss').Config}} */
+module.exports = {{
+{dark_mode}  content: ["./index.html", "./src/**/*.rs"],
+  theme: {{
+    container: {{
+      center: true,
+      padding: "2rem",
+      screens: {{
+        "2xl": "1400px",
+      }},
+    }},
+    extend: {{
+      colors: {{
+{colors}      }},
+      borderRadius: {{
+        lg: "{radius}",
+        md: "calc({radius} - 2px)",
+        sm: "calc({radius} - 4px)",
+      }},
+      keyframes: {{
+        "accordion-down": {{
+          from: {{ height: "0" }},
+          to: {{ height: "var(--radix-accordion-content-height)" }},
+        }},
+        "accordion-up": {{
+          from: {{ height: "var(--radix-accordion-content-height)" }},
+          to: {{ height: "0" }},
+        }},
+        "collapsible-down": {{
+          from: {{ height: "0" }},
+          to: {{ height: "var(--radix-collapsible-content-height)" }},
+        }},
+        "collapsible-up": {{
+          from: {{ height: "var(--radix-collapsible-content-height)" }},
+          to: {{ height: "0" }},
+        }},
+      }},
+      animation: {{
+        "accordion-down": "accordion-down 0.2s ease-out",
+        "accordion-up": "accordion-up 0.2s ease-out",
+        "collapsible-down": "collapsible-down 0.2s ease-out",
+        "collapsible-up": "collapsible-up 0.2s ease-out",
+      }},
+    }},
+  }},
+  plugins: [require("tailwindcss-animate")],
+}};
+"#
+    )
+}
+
+fn css_block(selector: &str, vars: &CssVars) -> String {
+    let mut block = format!("  {selector} {{\n");
+    for (name, value) in vars {
+        let _ = writeln!(block, "    --{name}: {value};");
+    }
+    block.push_str("  }\n");
+    block
+}
+
+/// Global stylesheet with the Tailwind directives and, unless colors are
+/// inlined, the variables for `:root` (light) and `.dark`.
+pub fn tailwind_css(vars: Option<(&CssVars, &CssVars)>) -> String {
+    let mut css = String::from("@tailwind base;\n@tailwind components;\n@tailwind utilities;\n");
+    if let Some((light, dark)) = vars {
+        let _ = write!(
+            css,
+            "\n@layer base {{\n{}\n{}}}\n",
+            css_block(":root", light),
+            css_block(".dark", dark)
+        );
+    }
+    css.push_str("\n@layer base {\n  * {\n    @apply border-border;\n  }\n  body {\n    @apply bg-background text-foreground;\n  }\n}\n");
+    css
+}
+
+/// Manifest of a new client-side rendered Leptos app.
+pub fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+leptos = {{ version = "0.8", features = ["csr"] }}
+"#
+    )
+}
+
+pub const MAIN_RS: &str = r#"use leptos::prelude::*;
+
+fn main() {
+    mount_to_body(App);
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <main class="container py-10">
+            <h1 class="text-3xl font-semibold tracking-tight">"Hello, Leptos!"</h1>
+        </main>
+    }
+}
+"#;
+
+/// Trunk entry point building the stylesheet at `css` with Tailwind CSS.
+pub fn index_html(css: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <link data-trunk rel="tailwind-css" href="{css}" />
+  </head>
+  <body></body>
+</html>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(entries: &[(&str, &str)]) -> CssVars {
+        entries.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn test_tailwind_config() {
+        let config = tailwind_config(None);
+        assert!(config.contains("darkMode: [\"class\"]"));
+        assert!(config.contains("DEFAULT: \"hsl(var(--primary))\""));
+        assert!(config.contains("md: \"calc(var(--radius) - 2px)\""));
+
+        let light = vars(&[("primary", "240 5.9% 10%"), ("radius", "0.5rem")]);
+        let config = tailwind_config(Some(&light));
+        assert!(!config.contains("darkMode"));
+        assert!(config.contains("DEFAULT: \"hsl(240 5.9% 10%)\""));
+        assert!(config.contains("md: \"calc(0.5rem - 2px)\""));
+    }
+
+    #[test]
+    fn test_tailwind_css() {
+        let light = vars(&[("foreground", "0 0% 3.9%"), ("background", "0 0% 100%")]);
+        let dark = vars(&[("background", "0 0% 3.9%")]);
+        let css = tailwind_css(Some((&light, &dark)));
+        assert!(css.contains(
+            "@layer base {\n  :root {\n    --background: 0 0% 100%;\n    --foreground: 0 0% 3.9%;\n  }\n\n  .dark {\n    --background: 0 0% 3.9%;\n  }\n}\n"
+        ));
+        assert!(!tailwind_css(None).contains(":root"));
+    }
+}
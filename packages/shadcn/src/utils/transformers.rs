@@ -0,0 +1,56 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static CRATE_PATH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bleptos_shadcn_([a-z0-9_]+)\b").expect("Crate path pattern should be valid."));
+
+// `$crate` in macros stays as it is.
+static CRATE_ROOT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(^|[^$\w])crate::").expect("Crate root pattern should be valid."));
+
+/// Module name of a component, e.g. `radio_group` for `radio-group`.
+pub fn module_name(component: &str) -> String {
+    component.replace('-', "_")
+}
+
+/// Points paths into the crates of vendored components (`leptos_shadcn_*`)
+/// at their vendored modules under `ui_alias`. In a file of a vendored crate,
+/// `crate_module`, paths from the crate root point at the module too.
+pub fn transform_crate_paths(content: &str, crate_module: Option<&str>, vendored: &[String], ui_alias: &str) -> String {
+    let content = match crate_module {
+        Some(module) => CRATE_ROOT.replace_all(content, format!("${{1}}{ui_alias}::{module}::")),
+        None => content.into(),
+    };
+    CRATE_PATH
+        .replace_all(&content, |captures: &regex::Captures| {
+            let module = &captures[1];
+            if vendored.iter().any(|component| module_name(component) == module) {
+                format!("{ui_alias}::{module}")
+            } else {
+                captures[0].to_string()
+            }
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_crate_paths() {
+        let content = "use leptos_shadcn_utils::modal::use_modal;\nuse leptos_shadcn_radio_group::RadioGroup;\nuse leptos_shadcn_icons::Icon;";
+        let vendored = ["utils".to_string(), "radio-group".to_string()];
+        assert_eq!(
+            transform_crate_paths(content, None, &vendored, "crate::components::ui"),
+            "use crate::components::ui::utils::modal::use_modal;\nuse crate::components::ui::radio_group::RadioGroup;\nuse leptos_shadcn_icons::Icon;"
+        );
+
+        let content = "use crate::math::clamp;\npub(crate) fn f() -> crate::Size { $crate::size!() }";
+        assert_eq!(
+            transform_crate_paths(content, Some("slider"), &vendored, "crate::components::ui"),
+            "use crate::components::ui::slider::math::clamp;\npub(crate) fn f() -> crate::components::ui::slider::Size { $crate::size!() }"
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod update_dependencies;
+pub mod update_files;
@@ -0,0 +1,147 @@
+use anyhow::{Context, Result};
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, Value, value};
+
+/// Versions for dependencies listed without one.
+const KNOWN_VERSIONS: &[(&str, &str)] = &[
+    ("js-sys", "0.3"),
+    ("leptos", "0.8"),
+    ("leptos-node-ref", "0.2"),
+    ("leptos-shadcn-tailwind-merge", "0.9"),
+    ("leptos-struct-component", "0.2"),
+    ("leptos-style", "0.2"),
+    ("wasm-bindgen", "0.2"),
+    ("web-sys", "0.3"),
+];
+
+/// Name, version and features of a dependency listed as
+/// `name@version[feature,...]`, the version and features being optional. A
+/// version of `git+<repository>` takes the crate from a git repository.
+pub fn parse_dependency(dependency: &str) -> (&str, &str, Vec<&str>) {
+    let (dependency, features) = match dependency.strip_suffix(']').and_then(|rest| rest.split_once('[')) {
+        Some((dependency, features)) => {
+            (dependency, features.split(',').filter(|feature| !feature.is_empty()).collect())
+        }
+        None => (dependency, Vec::new()),
+    };
+    match dependency.split_once('@') {
+        Some((name, version)) => (name, version, features),
+        None => {
+            let version = KNOWN_VERSIONS
+                .iter()
+                .find(|(name, _)| *name == dependency)
+                .map_or("*", |(_, version)| version);
+            (dependency, version, features)
+        }
+    }
+}
+
+/// Adds the dependencies missing from a Cargo manifest, and the features
+/// missing from those it has, keeping its formatting. Returns the new
+/// manifest and what was added.
+pub fn add_dependencies(manifest: &str, dependencies: &[String]) -> Result<(String, Vec<String>)> {
+    let mut document = manifest.parse::<DocumentMut>().context("Cargo.toml is not valid TOML.")?;
+    let table = document
+        .entry("dependencies")
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_like_mut()
+        .context("[dependencies] in Cargo.toml is not a table.")?;
+
+    let mut added = Vec::new();
+    for dependency in dependencies {
+        let (name, version, features) = parse_dependency(dependency);
+        let Some(item) = table.get_mut(name) else {
+            let git = version.strip_prefix("git+");
+            let item = if features.is_empty() && git.is_none() {
+                value(version)
+            } else {
+                let mut inline = InlineTable::new();
+                match git {
+                    Some(repository) => inline.insert("git", repository.into()),
+                    None => inline.insert("version", version.into()),
+                };
+                if !features.is_empty() {
+                    inline.insert("features", Value::Array(features.iter().copied().collect::<Array>()));
+                }
+                value(inline)
+            };
+            added.push(format!("{name} = {}", item.to_string().trim()));
+            table.insert(name, item);
+            continue;
+        };
+
+        let missing = match item.as_table_like() {
+            Some(existing) => {
+                let enabled = existing.get("features").and_then(Item::as_array);
+                features
+                    .iter()
+                    .filter(|feature| {
+                        !enabled.is_some_and(|enabled| enabled.iter().any(|value| value.as_str() == Some(feature)))
+                    })
+                    .copied()
+                    .collect::<Vec<_>>()
+            }
+            None => features.clone(),
+        };
+        if missing.is_empty() {
+            continue;
+        }
+        // `name = "version"` becomes an inline table to hold the features.
+        if let Some(existing) = item.as_str().map(String::from) {
+            let mut inline = InlineTable::new();
+            inline.insert("version", existing.into());
+            *item = value(inline);
+        }
+        let existing = item
+            .as_table_like_mut()
+            .with_context(|| format!("{name} in Cargo.toml is neither a version nor a table."))?;
+        let enabled = existing.entry("features").or_insert(value(Array::new()));
+        enabled
+            .as_array_mut()
+            .with_context(|| format!("The features of {name} in Cargo.toml are not an array."))?
+            .extend(missing.iter().copied());
+        added.push(format!("{name} features {}", missing.join(", ")));
+    }
+    Ok((document.to_string(), added))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_dependencies_keeps_formatting() {
+        let manifest = "[package]\nname = \"app\"\n\n[dependencies]\n# UI\nleptos = { version = \"0.8\", features = [\"csr\"] }\n";
+        let (manifest, added) =
+            add_dependencies(manifest, &["leptos".into(), "leptos-shadcn-tailwind-merge".into(), "chrono@0.4".into()])
+                .unwrap();
+        assert_eq!(added, ["leptos-shadcn-tailwind-merge = \"0.9\"", "chrono = \"0.4\""]);
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"app\"\n\n[dependencies]\n# UI\nleptos = { version = \"0.8\", features = [\"csr\"] }\nleptos-shadcn-tailwind-merge = \"0.9\"\nchrono = \"0.4\"\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependencies_with_features() {
+        let manifest = "[dependencies]\nweb-sys = \"0.3\"\nserde = { version = \"1\", features = [\"derive\"] }\n";
+        let dependencies = [
+            "web-sys@0.3[Window,Storage]".to_string(),
+            "serde[derive]".into(),
+            "wasm-bindgen[std]".into(),
+            "shadcn-registry@git+https://github.com/cloud-shuttle/leptos-shadcn-ui".into(),
+        ];
+        let (manifest, added) = add_dependencies(manifest, &dependencies).unwrap();
+        assert_eq!(
+            added,
+            [
+                "web-sys features Window, Storage",
+                "wasm-bindgen = { version = \"0.2\", features = [\"std\"] }",
+                "shadcn-registry = { git = \"https://github.com/cloud-shuttle/leptos-shadcn-ui\" }",
+            ]
+        );
+        assert_eq!(
+            manifest,
+            "[dependencies]\nweb-sys = { version = \"0.3\", features = [\"Window\", \"Storage\"] }\nserde = { version = \"1\", features = [\"derive\"] }\nwasm-bindgen = { version = \"0.2\", features = [\"std\"] }\nshadcn-registry = { git = \"https://github.com/cloud-shuttle/leptos-shadcn-ui\" }\n"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tokio::fs;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FileAction {
+    Create,
+    Overwrite,
+    Unchanged,
+    /// The file exists with other content and overwriting wasn't allowed.
+    Skip,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub content: String,
+    pub action: FileAction,
+}
+
+/// What writing `content` to `path` would do, given its current content.
+pub fn plan_file(path: PathBuf, content: String, existing: Option<&str>, overwrite: bool) -> FileChange {
+    let action = match existing {
+        None => FileAction::Create,
+        Some(existing) if existing == content => FileAction::Unchanged,
+        Some(_) if overwrite => FileAction::Overwrite,
+        Some(_) => FileAction::Skip,
+    };
+    FileChange { path, content, action }
+}
+
+/// Writes the files that are created or overwritten.
+pub async fn update_files(changes: &[FileChange]) -> Result<()> {
+    for change in changes {
+        if matches!(change.action, FileAction::Create | FileAction::Overwrite) {
+            if let Some(parent) = change.path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            fs::write(&change.path, &change.content).await?;
+        }
+    }
+    Ok(())
+}
+
+/// `mod.rs` with `pub mod <module>;` added, or `None` if it's already declared.
+pub fn register_module(mod_rs: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {module};");
+    if mod_rs.lines().any(|line| line.trim() == declaration) {
+        return None;
+    }
+
+    let mut mod_rs = mod_rs.to_string();
+    if !mod_rs.is_empty() && !mod_rs.ends_with('\n') {
+        mod_rs.push('\n');
+    }
+    mod_rs.push_str(&declaration);
+    mod_rs.push('\n');
+    Some(mod_rs)
+}
+
+pub async fn read_existing(path: &Path) -> Result<Option<String>> {
+    if fs::try_exists(path).await? {
+        Ok(Some(fs::read_to_string(path).await?))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_file() {
+        let plan = |existing, overwrite| plan_file("button.rs".into(), "new".into(), existing, overwrite).action;
+        assert_eq!(plan(None, false), FileAction::Create);
+        assert_eq!(plan(Some("new"), false), FileAction::Unchanged);
+        assert_eq!(plan(Some("old"), false), FileAction::Skip);
+        assert_eq!(plan(Some("old"), true), FileAction::Overwrite);
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(register_module("pub mod button;", "card").as_deref(), Some("pub mod button;\npub mod card;\n"));
+        assert_eq!(register_module("pub mod card;\n", "card"), None);
+    }
+}
@@ -0,0 +1,520 @@
+//! Conflict-aware merging of Tailwind CSS classes.
+//!
+//! Later classes override earlier ones of the same class group, so a user's
+//! `px-2` replaces a component's `px-4`. Classes only conflict under the same
+//! variants (`hover:`, `md:`, ...) and importance, and a class removes the
+//! more specific classes it covers, e.g. `p-2` removes an earlier `px-4`.
+//! Classes that aren't Tailwind utilities are kept as they are.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// Merges a whitespace separated list of classes, resolving conflicts in
+/// favour of the later classes.
+///
+/// ```rust
+/// use leptos_shadcn_tailwind_merge::merge_classes;
+///
+/// assert_eq!(merge_classes("px-4 py-2 bg-primary px-2"), "py-2 bg-primary px-2");
+/// assert_eq!(merge_classes("p-4 hover:p-2 px-1"), "p-4 hover:p-2 px-1");
+/// assert_eq!(merge_classes("text-sm text-muted-foreground text-lg"), "text-muted-foreground text-lg");
+/// ```
+pub fn merge_classes(classes: &str) -> String {
+    // Components merge the same few class lists on every render.
+    thread_local! {
+        static CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    }
+    const CACHE_SIZE: usize = 512;
+
+    if let Some(merged) = CACHE.with(|cache| cache.borrow().get(classes).cloned()) {
+        return merged;
+    }
+    let merged = merge_uncached(classes);
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CACHE_SIZE {
+            cache.clear();
+        }
+        cache.insert(classes.to_string(), merged.clone());
+    });
+    merged
+}
+
+/// Merges class lists, later lists overriding earlier ones.
+///
+/// ```rust
+/// use leptos_shadcn_tailwind_merge::tw_merge;
+///
+/// let class = Some("bg-destructive");
+/// assert_eq!(tw_merge!("rounded-md bg-primary", class.unwrap_or_default()), "rounded-md bg-destructive");
+/// ```
+#[macro_export]
+macro_rules! tw_merge {
+    ($($classes:expr),* $(,)?) => {
+        $crate::merge_class_lists(&[$(::core::convert::AsRef::<str>::as_ref(&$classes)),*])
+    };
+}
+
+/// Merges class lists, later lists overriding earlier ones; see [`tw_merge!`].
+pub fn merge_class_lists(lists: &[&str]) -> String {
+    merge_classes(&lists.join(" "))
+}
+
+fn merge_uncached(classes: &str) -> String {
+    let mut seen_classes = HashSet::new();
+    let mut seen_groups = HashSet::new();
+    let mut kept = Vec::new();
+    for class in classes.split_whitespace().rev() {
+        if !seen_classes.insert(class) {
+            continue;
+        }
+        let Some(parsed) = ParsedClass::parse(class) else {
+            kept.push(class);
+            continue;
+        };
+        let Some(group) = parsed.group() else {
+            kept.push(class);
+            continue;
+        };
+        let modifiers = parsed.modifier_key();
+        if seen_groups.contains(&(modifiers.clone(), group.clone())) {
+            continue;
+        }
+        for conflict in conflicting_groups(&group) {
+            seen_groups.insert((modifiers.clone(), conflict.to_string()));
+        }
+        seen_groups.insert((modifiers, group));
+        kept.push(class);
+    }
+    kept.reverse();
+    kept.join(" ")
+}
+
+/// Splits `class` at `separator`, except inside brackets and parentheses.
+fn split_top_level(class: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, char) in class.char_indices() {
+        match char {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            _ if char == separator && depth == 0 => {
+                parts.push(&class[start..index]);
+                start = index + char.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&class[start..]);
+    parts
+}
+
+/// A class split into its variants, importance and utility.
+#[derive(Debug, PartialEq)]
+struct ParsedClass<'a> {
+    variants: Vec<&'a str>,
+    important: bool,
+    /// The utility without `!` and the negative sign, e.g. `mt-2` of `md:-mt-2`.
+    base: &'a str,
+}
+
+impl<'a> ParsedClass<'a> {
+    fn parse(class: &'a str) -> Option<Self> {
+        let mut parts = split_top_level(class, ':');
+        let base = parts.pop()?;
+        let (important, base) = match (base.strip_prefix('!'), base.strip_suffix('!')) {
+            (Some(base), _) | (None, Some(base)) => (true, base),
+            (None, None) => (false, base),
+        };
+        let base = base.strip_prefix('-').unwrap_or(base);
+        (!base.is_empty()).then_some(Self { variants: parts, important, base })
+    }
+
+    /// Variants and importance under which classes conflict. The order of
+    /// variants doesn't matter, except around arbitrary variants.
+    fn modifier_key(&self) -> String {
+        let mut key = Vec::new();
+        let mut run = Vec::new();
+        for variant in &self.variants {
+            if variant.starts_with('[') {
+                run.sort_unstable();
+                key.append(&mut run);
+                key.push(*variant);
+            } else {
+                run.push(*variant);
+            }
+        }
+        run.sort_unstable();
+        key.append(&mut run);
+        let mut key = key.join(":");
+        if self.important {
+            key.push('!');
+        }
+        key
+    }
+
+    /// The class group, trying the utility without a `/` modifier first,
+    /// e.g. `text-lg` of `text-lg/7`.
+    fn group(&self) -> Option<String> {
+        let parts = split_top_level(self.base, '/');
+        if parts.len() == 2
+            && let Some(group) = class_group(parts[0])
+        {
+            return Some(group);
+        }
+        class_group(self.base)
+    }
+}
+
+fn is_arbitrary(value: &str) -> bool {
+    value.starts_with('[') && value.ends_with(']')
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.parse::<f64>().is_ok()
+}
+
+/// Whether an arbitrary value like `[3px]` or `[length:var(--x)]` is a length.
+fn is_arbitrary_length(value: &str) -> bool {
+    if !is_arbitrary(value) {
+        return false;
+    }
+    let inner = &value[1..value.len() - 1];
+    inner.starts_with("length:")
+        || inner.starts_with(|char: char| char.is_ascii_digit() || char == '.')
+        || ["calc(", "min(", "max(", "clamp("].iter().any(|function| inner.starts_with(function))
+}
+
+/// A width of a border, outline, ring or stroke, e.g. `2` or `[3px]`.
+fn is_width(value: &str) -> bool {
+    value.is_empty() || is_number(value) || is_arbitrary_length(value)
+}
+
+const DISPLAY: &[&str] = &[
+    "block", "inline-block", "inline", "flex", "inline-flex", "table", "inline-table", "table-caption",
+    "table-cell", "table-column", "table-column-group", "table-footer-group", "table-header-group",
+    "table-row-group", "table-row", "flow-root", "grid", "inline-grid", "contents", "list-item", "hidden",
+];
+
+const FONT_SIZES: &[&str] = &[
+    "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+];
+
+const FONT_WEIGHTS: &[&str] = &[
+    "thin", "extralight", "light", "normal", "medium", "semibold", "bold", "extrabold", "black",
+];
+
+const LINE_STYLES: &[&str] = &["solid", "dashed", "dotted", "double", "hidden", "none"];
+
+const ALIGNMENTS: &[&str] = &["start", "end", "center", "between", "around", "evenly", "baseline", "normal", "stretch"];
+
+/// Classes that are groups on their own.
+fn keyword_group(class: &str) -> Option<&'static str> {
+    let group = match class {
+        _ if DISPLAY.contains(&class) => "display",
+        "static" | "fixed" | "absolute" | "relative" | "sticky" => "position",
+        "visible" | "invisible" | "collapse" => "visibility",
+        "isolate" | "isolation-auto" => "isolation",
+        "sr-only" | "not-sr-only" => "sr",
+        "container" => "container",
+        "truncate" | "text-ellipsis" | "text-clip" => "text-overflow",
+        "uppercase" | "lowercase" | "capitalize" | "normal-case" => "text-transform",
+        "italic" | "not-italic" => "font-style",
+        "antialiased" | "subpixel-antialiased" => "font-smoothing",
+        "underline" | "overline" | "line-through" | "no-underline" => "text-decoration",
+        "transform" | "transform-gpu" | "transform-cpu" | "transform-none" => "transform",
+        "filter" | "filter-none" => "filter",
+        "normal-nums" => "fvn-normal",
+        "ordinal" => "fvn-ordinal",
+        "slashed-zero" => "fvn-slashed-zero",
+        "lining-nums" | "oldstyle-nums" => "fvn-figure",
+        "proportional-nums" | "tabular-nums" => "fvn-spacing",
+        "diagonal-fractions" | "stacked-fractions" => "fvn-fraction",
+        "grow" => "grow",
+        "shrink" => "shrink",
+        "border-collapse" | "border-separate" => "border-collapse",
+        "box-border" | "box-content" => "box-sizing",
+        _ => return None,
+    };
+    Some(group)
+}
+
+/// Utilities whose group only depends on their prefix, longest first.
+const PREFIX_GROUPS: &[&str] = &[
+    "backdrop-blur", "backdrop-brightness", "backdrop-opacity", "break-after", "break-before", "break-inside",
+    "justify-items", "justify-self", "place-content", "place-items", "place-self", "pointer-events",
+    "underline-offset", "outline-offset", "line-clamp", "translate-x", "translate-y", "overflow-x", "overflow-y",
+    "grid-cols", "grid-rows", "grid-flow", "auto-cols", "auto-rows", "col-start", "col-end", "row-start",
+    "row-end", "inset-x", "inset-y", "space-x", "space-y", "scale-x", "scale-y", "skew-x", "skew-y", "min-w",
+    "min-h", "max-w", "max-h", "gap-x", "gap-y", "mix-blend", "bg-blend", "will-change", "scroll-m",
+    "scroll-p", "whitespace", "animate", "duration", "ease", "delay", "transition", "leading", "tracking",
+    "indent", "opacity", "cursor", "select", "resize", "appearance", "touch", "aspect", "columns", "inset",
+    "top", "right", "bottom", "left", "start", "end", "order", "basis", "grow", "shrink", "overflow",
+    "rotate", "scale", "origin", "blur", "brightness", "contrast", "grayscale", "invert", "saturate", "sepia",
+    "float", "clear", "items", "self", "size", "gap", "fill", "caret", "accent", "from", "via", "to", "z",
+    "w", "h", "p", "px", "py", "ps", "pe", "pt", "pr", "pb", "pl", "m", "mx", "my", "ms", "me", "mt", "mr",
+    "mb", "ml", "col", "row",
+];
+
+/// Utilities taking a spacing or size, so `my-class` isn't a margin.
+const SPACING_PREFIXES: &[&str] = &[
+    "p", "px", "py", "ps", "pe", "pt", "pr", "pb", "pl", "m", "mx", "my", "ms", "me", "mt", "mr", "mb", "ml", "w",
+    "h", "size", "min-w", "min-h", "max-w", "max-h", "inset", "inset-x", "inset-y", "top", "right", "bottom",
+    "left", "start", "end", "gap", "gap-x", "gap-y", "space-x", "space-y", "translate-x", "translate-y",
+    "scroll-m", "scroll-p", "basis", "indent",
+];
+
+/// Whether `value`, after the `-` of a utility, is a spacing or size like
+/// `4`, `1/2`, `px`, `full`, `lg` or `[3rem]`.
+fn is_spacing(value: &str) -> bool {
+    let Some(value) = value.strip_prefix('-') else {
+        return false;
+    };
+    let value = split_top_level(value, '/')[0];
+    is_number(value)
+        || is_arbitrary(value)
+        || value.starts_with("screen")
+        || FONT_SIZES.contains(&value)
+        || ["px", "auto", "full", "min", "max", "fit", "svh", "lvh", "dvh", "svw", "lvw", "dvw", "prose", "none", "md"]
+            .contains(&value)
+}
+
+/// The class group of a utility without variants, or `None` if it's not a
+/// known Tailwind utility.
+fn class_group(class: &str) -> Option<String> {
+    if let Some(group) = keyword_group(class) {
+        return Some(group.to_string());
+    }
+    // Arbitrary properties, e.g. `[mask-type:luminance]`.
+    if is_arbitrary(class) {
+        let property = class[1..].split_once(':')?.0;
+        return Some(format!("[{property}]"));
+    }
+
+    let (head, value) = match class.split_once('-') {
+        Some((head, value)) => (head, value),
+        None => (class, ""),
+    };
+    let group = match head {
+        "text" => match value {
+            _ if FONT_SIZES.contains(&value) || is_arbitrary_length(value) => "font-size",
+            "left" | "center" | "right" | "justify" | "start" | "end" => "text-alignment",
+            "wrap" | "nowrap" | "balance" | "pretty" => "text-wrap",
+            _ => "text-color",
+        },
+        "font" => match value {
+            _ if FONT_WEIGHTS.contains(&value) || is_number(value.trim_matches(['[', ']'])) => "font-weight",
+            _ => "font-family",
+        },
+        "bg" => match value {
+            "fixed" | "local" | "scroll" => "bg-attachment",
+            "bottom" | "center" | "left" | "left-bottom" | "left-top" | "right" | "right-bottom" | "right-top"
+            | "top" => "bg-position",
+            "repeat" | "no-repeat" | "repeat-x" | "repeat-y" | "repeat-round" | "repeat-space" => "bg-repeat",
+            "auto" | "cover" | "contain" => "bg-size",
+            "none" => "bg-image",
+            _ if value.starts_with("gradient-") || value.starts_with("[url(") || value.starts_with("[image:") => {
+                "bg-image"
+            }
+            _ if value.starts_with("clip-") => "bg-clip",
+            _ if value.starts_with("origin-") => "bg-origin",
+            _ if value.starts_with("opacity-") => "bg-opacity",
+            _ => "bg-color",
+        },
+        "flex" => match value {
+            "row" | "row-reverse" | "col" | "col-reverse" => "flex-direction",
+            "wrap" | "wrap-reverse" | "nowrap" => "flex-wrap",
+            _ => "flex",
+        },
+        "border" => return Some(border_group("border", value)),
+        "divide" => match value {
+            "x" | "y" => return Some(format!("divide-{value}")),
+            "x-reverse" | "y-reverse" => return Some(format!("divide-{value}")),
+            _ if value.starts_with("x-") || value.starts_with("y-") => return Some(format!("divide-{}", &value[..1])),
+            _ if LINE_STYLES.contains(&value) => "divide-style",
+            _ => "divide-color",
+        },
+        "rounded" => {
+            let side = value.split('-').next().unwrap_or_default();
+            return Some(
+                match side {
+                    "t" | "r" | "b" | "l" | "s" | "e" | "tl" | "tr" | "br" | "bl" | "ss" | "se" | "es" | "ee" => {
+                        format!("rounded-{side}")
+                    }
+                    _ => "rounded".to_string(),
+                },
+            );
+        }
+        "ring" => match value {
+            "inset" => "ring-inset",
+            _ if is_width(value) => "ring-w",
+            _ if value.starts_with("offset-") => {
+                if is_width(&value["offset-".len()..]) { "ring-offset-w" } else { "ring-offset-color" }
+            }
+            _ if value.starts_with("opacity-") => "ring-opacity",
+            _ => "ring-color",
+        },
+        "outline" => match value {
+            "" | "none" | "dashed" | "dotted" | "double" | "solid" => "outline-style",
+            _ if value.starts_with("offset-") => "outline-offset",
+            _ if is_width(value) => "outline-w",
+            _ => "outline-color",
+        },
+        "shadow" => match value {
+            "" | "sm" | "md" | "lg" | "xl" | "2xl" | "inner" | "none" => "shadow",
+            _ if is_arbitrary(value) && value.contains('_') => "shadow",
+            _ => "shadow-color",
+        },
+        "stroke" if is_width(value) && !value.is_empty() => "stroke-w",
+        "stroke" => "stroke",
+        "decoration" => match value {
+            "solid" | "double" | "dotted" | "dashed" | "wavy" => "decoration-style",
+            "auto" | "from-font" => "decoration-thickness",
+            _ if is_width(value) => "decoration-thickness",
+            _ => "decoration-color",
+        },
+        "object" => match value {
+            "contain" | "cover" | "fill" | "none" | "scale-down" => "object-fit",
+            _ => "object-position",
+        },
+        "justify" if !value.starts_with("items") && !value.starts_with("self") => "justify-content",
+        "content" if ALIGNMENTS.contains(&value) => "align-content",
+        "content" => "content",
+        "list" => match value {
+            "inside" | "outside" => "list-style-position",
+            _ if value.starts_with("image-") => "list-image",
+            _ => "list-style-type",
+        },
+        "break" if !value.starts_with("after") && !value.starts_with("before") && !value.starts_with("inside") => {
+            "word-break"
+        }
+        "space" if value.ends_with("-reverse") => return Some(format!("space-{value}")),
+        _ => {
+            let prefix = PREFIX_GROUPS
+                .iter()
+                .filter(|prefix| {
+                    class.strip_prefix(**prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+                })
+                .max_by_key(|prefix| prefix.len())?;
+            if SPACING_PREFIXES.contains(prefix) && !is_spacing(&class[prefix.len()..]) {
+                return None;
+            }
+            return Some(match *prefix {
+                "items" => "align-items".to_string(),
+                "self" => "align-self".to_string(),
+                prefix => prefix.to_string(),
+            });
+        }
+    };
+    Some(group.to_string())
+}
+
+/// Border widths, styles and colors, e.g. `border-2`, `border-t-0`,
+/// `border-dashed` or `border-x-input`.
+fn border_group(prefix: &str, value: &str) -> String {
+    let (side, value) = match value.split_once('-') {
+        Some((side, rest)) if ["x", "y", "t", "r", "b", "l", "s", "e"].contains(&side) => (Some(side), rest),
+        _ if ["x", "y", "t", "r", "b", "l", "s", "e"].contains(&value) => (Some(value), ""),
+        _ => (None, value),
+    };
+    let kind = match value {
+        _ if is_width(value) => "w",
+        _ if LINE_STYLES.contains(&value) && side.is_none() => return format!("{prefix}-style"),
+        _ => "color",
+    };
+    match side {
+        Some(side) => format!("{prefix}-{kind}-{side}"),
+        None => format!("{prefix}-{kind}"),
+    }
+}
+
+/// Groups a class of `group` overrides besides its own.
+fn conflicting_groups(group: &str) -> &'static [&'static str] {
+    match group {
+        "inset" => &["inset-x", "inset-y", "start", "end", "top", "right", "bottom", "left"],
+        "inset-x" => &["right", "left"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "gap" => &["gap-x", "gap-y"],
+        "p" => &["px", "py", "ps", "pe", "pt", "pr", "pb", "pl"],
+        "px" => &["pr", "pl"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "ms", "me", "mt", "mr", "mb", "ml"],
+        "mx" => &["mr", "ml"],
+        "my" => &["mt", "mb"],
+        "size" => &["w", "h"],
+        "scale" => &["scale-x", "scale-y"],
+        "font-size" => &["leading"],
+        "line-clamp" => &["display", "overflow"],
+        "fvn-normal" => &["fvn-ordinal", "fvn-slashed-zero", "fvn-figure", "fvn-spacing", "fvn-fraction"],
+        "rounded" => &[
+            "rounded-s", "rounded-e", "rounded-t", "rounded-r", "rounded-b", "rounded-l", "rounded-ss", "rounded-se",
+            "rounded-ee", "rounded-es", "rounded-tl", "rounded-tr", "rounded-br", "rounded-bl",
+        ],
+        "rounded-s" => &["rounded-ss", "rounded-es"],
+        "rounded-e" => &["rounded-se", "rounded-ee"],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "border-w" => &[
+            "border-w-x", "border-w-y", "border-w-s", "border-w-e", "border-w-t", "border-w-r", "border-w-b",
+            "border-w-l",
+        ],
+        "border-w-x" => &["border-w-r", "border-w-l"],
+        "border-w-y" => &["border-w-t", "border-w-b"],
+        "border-color" => &[
+            "border-color-x", "border-color-y", "border-color-s", "border-color-e", "border-color-t",
+            "border-color-r", "border-color-b", "border-color-l",
+        ],
+        "border-color-x" => &["border-color-r", "border-color-l"],
+        "border-color-y" => &["border-color-t", "border-color-b"],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conflicting_utilities() {
+        assert_eq!(merge_classes("px-4 py-2 px-2"), "py-2 px-2");
+        assert_eq!(merge_classes("px-4 p-2"), "p-2");
+        assert_eq!(merge_classes("p-2 px-4"), "p-2 px-4");
+        assert_eq!(merge_classes("inline-flex hidden"), "hidden");
+        assert_eq!(merge_classes("bg-primary hover:bg-primary/90 bg-destructive"), "hover:bg-primary/90 bg-destructive");
+        assert_eq!(merge_classes("text-sm text-muted-foreground text-base"), "text-muted-foreground text-base");
+        assert_eq!(merge_classes("text-lg leading-7 text-sm/6"), "text-sm/6");
+        assert_eq!(merge_classes("border border-input border-2 border-t-0"), "border-input border-2 border-t-0");
+        assert_eq!(merge_classes("rounded-t-md rounded-lg"), "rounded-lg");
+        assert_eq!(merge_classes("ring-2 ring-ring ring-offset-2 ring-0"), "ring-ring ring-offset-2 ring-0");
+        assert_eq!(merge_classes("h-10 w-10 size-4"), "size-4");
+        assert_eq!(merge_classes("font-medium font-mono font-bold"), "font-mono font-bold");
+        assert_eq!(merge_classes("justify-center justify-items-start justify-between"), "justify-items-start justify-between");
+        assert_eq!(merge_classes("mt-2 -mt-4"), "-mt-4");
+        // Classes that aren't Tailwind utilities are kept, once.
+        assert_eq!(merge_classes("peer my-class p-2 my-class"), "peer p-2 my-class");
+        assert_eq!(merge_classes("w-4 max-w-sm w-full max-w-screen-md"), "w-full max-w-screen-md");
+    }
+
+    #[test]
+    fn test_variants_and_modifiers() {
+        assert_eq!(merge_classes("p-4 hover:p-2 md:p-1 hover:p-3"), "p-4 md:p-1 hover:p-3");
+        assert_eq!(merge_classes("hover:focus:bg-accent focus:hover:bg-muted"), "focus:hover:bg-muted");
+        assert_eq!(merge_classes("[&>svg]:hover:p-1 hover:[&>svg]:p-2"), "[&>svg]:hover:p-1 hover:[&>svg]:p-2");
+        assert_eq!(merge_classes("!p-4 p-2 !p-1"), "p-2 !p-1");
+        assert_eq!(merge_classes("p-4! p-2"), "p-4! p-2");
+        assert_eq!(merge_classes("data-[state=open]:bg-accent data-[state=open]:bg-muted"), "data-[state=open]:bg-muted");
+    }
+
+    #[test]
+    fn test_arbitrary_values() {
+        assert_eq!(merge_classes("w-4 w-[calc(100%-2rem)]"), "w-[calc(100%-2rem)]");
+        assert_eq!(merge_classes("text-[#fff] text-[14px] text-red-500"), "text-[14px] text-red-500");
+        assert_eq!(merge_classes("[mask-type:luminance] [mask-type:alpha] [--x:1]"), "[mask-type:alpha] [--x:1]");
+        assert_eq!(merge_classes("shadow-sm shadow-[0_1px_2px_black]"), "shadow-[0_1px_2px_black]");
+        assert_eq!(tw_merge!("px-4 py-2", String::from("px-2"), ""), "py-2 px-2");
+    }
+}
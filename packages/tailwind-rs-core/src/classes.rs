@@ -80,11 +80,12 @@ impl TailwindClasses {
         classes.join(" ")
     }
 
-    /// Merge with another TailwindClasses instance.
+    /// Merge with another TailwindClasses instance; its base classes override
+    /// conflicting base classes of this one.
     pub fn merge(mut self, other: TailwindClasses) -> Self {
         // Merge base classes
         if !other.base.is_empty() {
-            self.base = format!("{} {}", self.base, other.base);
+            self.base = crate::merge::merge_class_lists(&[&self.base, &other.base]);
         }
         
         // Merge variants
@@ -236,5 +237,9 @@ mod tests {
         assert!(result.contains("px-4 py-2 rounded-md"));
         assert!(result.contains("bg-blue-600"));
         assert!(result.contains("bg-gray-200"));
+
+        // Later base classes override conflicting ones
+        let merged = TailwindClasses::new("px-4 py-2").merge(TailwindClasses::new("px-2"));
+        assert_eq!(merged.base, "py-2 px-2");
     }
 }
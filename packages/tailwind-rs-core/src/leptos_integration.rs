@@ -33,10 +33,10 @@ impl ClassSignal {
         self.set_classes.set(classes.to_string());
     }
 
-    /// Merge new classes with existing ones.
+    /// Merge new classes with existing ones, overriding conflicting classes.
     pub fn merge(&self, new_classes: impl Into<String>) {
         let current = self.classes.get();
-        let merged = format!("{} {}", current, new_classes.into());
+        let merged = crate::merge::merge_class_lists(&[&current, &new_classes.into()]);
         self.set_classes.set(merged);
     }
 
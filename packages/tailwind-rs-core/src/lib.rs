@@ -64,6 +64,7 @@ pub mod validation;
 // Re-export main types and macros
 pub use classes::*;
 pub use colors::*;
+pub use leptos_shadcn_tailwind_merge::{self as merge, merge_class_lists, merge_classes, tw_merge};
 pub use responsive::*;
 pub use themes::*;
 pub use validation::*;
@@ -338,7 +338,8 @@ pub fn validate_classes(classes: &[&str]) -> Vec<ValidationResult> {
     VALIDATOR.validate_classes(classes)
 }
 
-/// Optimize a class string by removing duplicates and invalid classes.
+/// Optimize a class string by removing duplicates, invalid classes and
+/// classes overridden by later conflicting ones.
 pub fn optimize_classes(classes: &str) -> String {
     let class_list: Vec<&str> = classes.split_whitespace().collect();
     let mut valid_classes = Vec::new();
@@ -351,7 +352,7 @@ pub fn optimize_classes(classes: &str) -> String {
         }
     }
 
-    valid_classes.join(" ")
+    crate::merge::merge_classes(&valid_classes.join(" "))
 }
 
 #[cfg(test)]